@@ -0,0 +1,31 @@
+use crate::bounding_volume::Aabb;
+use crate::math::{Isometry, Real, Vector};
+use crate::shape::Ellipsoid;
+
+impl Ellipsoid {
+    /// Computes the world-space [`Aabb`] of this ellipsoid, transformed by `pos`.
+    ///
+    /// This is the smallest [`Aabb`] containing the transformed ellipsoid.
+    #[inline]
+    pub fn aabb(&self, pos: &Isometry<Real>) -> Aabb {
+        let rot = pos.rotation.to_rotation_matrix();
+        let half_extents = Vector::from_fn(|i, _| {
+            rot.matrix()
+                .row(i)
+                .transpose()
+                .component_mul(&self.radii)
+                .norm()
+        });
+        let center = pos.translation.vector;
+        Aabb::new(
+            (center - half_extents).into(),
+            (center + half_extents).into(),
+        )
+    }
+
+    /// Computes the local-space [`Aabb`] of this ellipsoid.
+    #[inline]
+    pub fn local_aabb(&self) -> Aabb {
+        Aabb::new((-self.radii).into(), self.radii.into())
+    }
+}
@@ -0,0 +1,19 @@
+use crate::bounding_volume::Aabb;
+use crate::math::{Isometry, Real};
+use crate::shape::Sdf;
+
+impl Sdf {
+    /// Computes the world-space Aabb of this signed distance field, transformed by `pos`.
+    #[inline]
+    pub fn aabb(&self, pos: &Isometry<Real>) -> Aabb {
+        self.local_aabb().transform_by(pos)
+    }
+
+    /// Computes the local-space Aabb of this signed distance field.
+    ///
+    /// This is the domain covered by the grid samples.
+    #[inline]
+    pub fn local_aabb(&self) -> Aabb {
+        self.domain()
+    }
+}
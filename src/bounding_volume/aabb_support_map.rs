@@ -1,9 +1,9 @@
 use crate::bounding_volume;
-use crate::bounding_volume::Aabb;
+use crate::bounding_volume::{Aabb, BoundingSphere};
 use crate::math::{Isometry, Real};
-use crate::shape::Segment;
 #[cfg(feature = "dim3")]
 use crate::shape::{Cone, Cylinder};
+use crate::shape::{MinkowskiSum, Scaled, Segment, SupportMap};
 
 #[cfg(feature = "dim3")]
 impl Cone {
@@ -48,3 +48,55 @@ impl Segment {
         bounding_volume::details::local_support_map_aabb(self)
     }
 }
+
+impl<S1: SupportMap, S2: SupportMap> MinkowskiSum<S1, S2> {
+    /// Computes the world-space [`Aabb`] of this Minkowski sum, transformed by `pos`.
+    #[inline]
+    pub fn aabb(&self, pos: &Isometry<Real>) -> Aabb {
+        bounding_volume::details::support_map_aabb(pos, self)
+    }
+
+    /// Computes the local-space [`Aabb`] of this Minkowski sum.
+    #[inline]
+    pub fn local_aabb(&self) -> Aabb {
+        bounding_volume::details::local_support_map_aabb(self)
+    }
+
+    /// Computes the world-space bounding sphere of this Minkowski sum, transformed by `pos`.
+    #[inline]
+    pub fn bounding_sphere(&self, pos: &Isometry<Real>) -> BoundingSphere {
+        self.local_bounding_sphere().transform_by(pos)
+    }
+
+    /// Computes the local-space bounding sphere of this Minkowski sum.
+    #[inline]
+    pub fn local_bounding_sphere(&self) -> BoundingSphere {
+        self.local_aabb().bounding_sphere()
+    }
+}
+
+impl<S: SupportMap> Scaled<S> {
+    /// Computes the world-space [`Aabb`] of this scaled shape, transformed by `pos`.
+    #[inline]
+    pub fn aabb(&self, pos: &Isometry<Real>) -> Aabb {
+        bounding_volume::details::support_map_aabb(pos, self)
+    }
+
+    /// Computes the local-space [`Aabb`] of this scaled shape.
+    #[inline]
+    pub fn local_aabb(&self) -> Aabb {
+        bounding_volume::details::local_support_map_aabb(self)
+    }
+
+    /// Computes the world-space bounding sphere of this scaled shape, transformed by `pos`.
+    #[inline]
+    pub fn bounding_sphere(&self, pos: &Isometry<Real>) -> BoundingSphere {
+        self.local_bounding_sphere().transform_by(pos)
+    }
+
+    /// Computes the local-space bounding sphere of this scaled shape.
+    #[inline]
+    pub fn local_bounding_sphere(&self) -> BoundingSphere {
+        self.local_aabb().bounding_sphere()
+    }
+}
@@ -0,0 +1,23 @@
+use crate::bounding_volume::Aabb;
+use crate::math::{Isometry, Real, Vector};
+use crate::shape::TaperedCapsule;
+
+impl TaperedCapsule {
+    /// The axis-aligned bounding box of this tapered capsule.
+    #[inline]
+    pub fn aabb(&self, pos: &Isometry<Real>) -> Aabb {
+        self.transform_by(pos).local_aabb()
+    }
+
+    /// The axis-aligned bounding box of this tapered capsule.
+    #[inline]
+    pub fn local_aabb(&self) -> Aabb {
+        let a = self.segment.a.coords;
+        let b = self.segment.b.coords;
+        let ra = Vector::repeat(self.radius_a);
+        let rb = Vector::repeat(self.radius_b);
+        let mins = (a - ra).inf(&(b - rb));
+        let maxs = (a + ra).sup(&(b + rb));
+        Aabb::new(mins.into(), maxs.into())
+    }
+}
@@ -0,0 +1,17 @@
+use crate::bounding_volume::{self, Aabb};
+use crate::math::{Isometry, Real};
+use crate::shape::Tetrahedron;
+
+impl Tetrahedron {
+    /// Computes the world-space [`Aabb`] of this tetrahedron, transformed by `pos`.
+    #[inline]
+    pub fn aabb(&self, pos: &Isometry<Real>) -> Aabb {
+        self.transformed(pos).local_aabb()
+    }
+
+    /// Computes the local-space [`Aabb`] of this tetrahedron.
+    #[inline]
+    pub fn local_aabb(&self) -> Aabb {
+        bounding_volume::details::local_point_cloud_aabb(self.vertices())
+    }
+}
@@ -0,0 +1,31 @@
+use crate::bounding_volume::Aabb;
+use crate::math::{Isometry, Point, Real, Vector};
+use crate::shape::Torus;
+
+#[cfg(not(feature = "std"))]
+use na::ComplexField;
+
+impl Torus {
+    /// Computes the world-space [`Aabb`] of this torus, transformed by `pos`.
+    ///
+    /// This is the smallest [`Aabb`] containing the transformed torus.
+    #[inline]
+    pub fn aabb(&self, pos: &Isometry<Real>) -> Aabb {
+        // The extent of the core circle along a coordinate axis only depends on the angle
+        // between that axis and the torus’ revolution axis.
+        let axis = pos * Vector::y_axis();
+        let half_extents =
+            axis.map(|a| self.major_radius * (1.0 - a * a).max(0.0).sqrt() + self.minor_radius);
+        Aabb::from_half_extents(Point::from(pos.translation.vector), half_extents)
+    }
+
+    /// Computes the local-space [`Aabb`] of this torus.
+    #[inline]
+    pub fn local_aabb(&self) -> Aabb {
+        let radius = self.major_radius + self.minor_radius;
+        Aabb::from_half_extents(
+            Point::origin(),
+            Vector::new(radius, self.minor_radius, radius),
+        )
+    }
+}
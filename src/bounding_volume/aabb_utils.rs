@@ -6,7 +6,6 @@ use crate::shape::SupportMap;
 use na;
 
 /// Computes the [`Aabb`] of an [support mapped shape](SupportMap).
-#[cfg(feature = "dim3")]
 pub fn support_map_aabb<G>(m: &Isometry<Real>, i: &G) -> Aabb
 where
     G: SupportMap,
@@ -0,0 +1,18 @@
+use crate::bounding_volume::BoundingSphere;
+use crate::math::{Isometry, Point, Real};
+use crate::shape::Ellipsoid;
+
+impl Ellipsoid {
+    /// Computes the world-space bounding sphere of this ellipsoid, transformed by `pos`.
+    #[inline]
+    pub fn bounding_sphere(&self, pos: &Isometry<Real>) -> BoundingSphere {
+        let bv: BoundingSphere = self.local_bounding_sphere();
+        bv.transform_by(pos)
+    }
+
+    /// Computes the local-space bounding sphere of this ellipsoid.
+    #[inline]
+    pub fn local_bounding_sphere(&self) -> BoundingSphere {
+        BoundingSphere::new(Point::origin(), self.radii.max())
+    }
+}
@@ -0,0 +1,17 @@
+use crate::bounding_volume::BoundingSphere;
+use crate::math::{Isometry, Real};
+use crate::shape::Sdf;
+
+impl Sdf {
+    /// Computes the world-space bounding sphere of this signed distance field, transformed by `pos`.
+    #[inline]
+    pub fn bounding_sphere(&self, pos: &Isometry<Real>) -> BoundingSphere {
+        self.local_bounding_sphere().transform_by(pos)
+    }
+
+    /// Computes the local-space bounding sphere of this signed distance field.
+    #[inline]
+    pub fn local_bounding_sphere(&self) -> BoundingSphere {
+        self.local_aabb().bounding_sphere()
+    }
+}
@@ -0,0 +1,18 @@
+use crate::bounding_volume::BoundingSphere;
+use crate::math::{Isometry, Real};
+use crate::shape::TaperedCapsule;
+
+impl TaperedCapsule {
+    /// Computes the world-space bounding sphere of this tapered capsule, transformed by `pos`.
+    #[inline]
+    pub fn bounding_sphere(&self, pos: &Isometry<Real>) -> BoundingSphere {
+        self.local_bounding_sphere().transform_by(pos)
+    }
+
+    /// Computes the local-space bounding sphere of this tapered capsule.
+    #[inline]
+    pub fn local_bounding_sphere(&self) -> BoundingSphere {
+        let radius = self.half_height() + self.radius_a.max(self.radius_b);
+        BoundingSphere::new(self.center(), radius)
+    }
+}
@@ -22,15 +22,23 @@ mod aabb_convex_polygon;
 #[cfg(feature = "alloc")]
 mod aabb_convex_polyhedron;
 mod aabb_cuboid;
+mod aabb_ellipsoid;
 mod aabb_halfspace;
 #[cfg(feature = "alloc")]
 mod aabb_heightfield;
 mod aabb_support_map;
+mod aabb_tapered_capsule;
+#[cfg(feature = "dim3")]
+mod aabb_tetrahedron;
+#[cfg(feature = "dim3")]
+mod aabb_torus;
 mod aabb_triangle;
 mod aabb_utils;
 
 mod aabb_capsule;
 #[cfg(feature = "alloc")]
+mod aabb_sdf;
+#[cfg(feature = "alloc")]
 mod aabb_voxels;
 #[doc(hidden)]
 pub mod bounding_sphere;
@@ -47,12 +55,20 @@ mod bounding_sphere_convex_polygon;
 mod bounding_sphere_cuboid;
 #[cfg(feature = "dim3")]
 mod bounding_sphere_cylinder;
+mod bounding_sphere_ellipsoid;
 mod bounding_sphere_halfspace;
 #[cfg(feature = "alloc")]
 mod bounding_sphere_heightfield;
 #[cfg(feature = "alloc")]
 mod bounding_sphere_polyline;
+#[cfg(feature = "alloc")]
+mod bounding_sphere_sdf;
 mod bounding_sphere_segment;
+mod bounding_sphere_tapered_capsule;
+#[cfg(feature = "dim3")]
+mod bounding_sphere_tetrahedron;
+#[cfg(feature = "dim3")]
+mod bounding_sphere_torus;
 mod bounding_sphere_triangle;
 #[cfg(feature = "alloc")]
 mod bounding_sphere_trimesh;
@@ -63,8 +79,8 @@ mod simd_aabb;
 
 /// Free functions for some special cases of bounding-volume computation.
 pub mod details {
-    #[cfg(feature = "dim3")]
-    pub use super::aabb_utils::support_map_aabb;
-    pub use super::aabb_utils::{local_point_cloud_aabb, local_support_map_aabb, point_cloud_aabb};
+    pub use super::aabb_utils::{
+        local_point_cloud_aabb, local_support_map_aabb, point_cloud_aabb, support_map_aabb,
+    };
     pub use super::bounding_sphere_utils::point_cloud_bounding_sphere;
 }
@@ -0,0 +1,48 @@
+use crate::mass_properties::MassProperties;
+use crate::math::{Point, PrincipalAngularInertia, Real, Vector};
+use na::RealField;
+
+impl MassProperties {
+    pub(crate) fn ellipsoid_volume_unit_inertia(
+        radii: Vector<Real>,
+    ) -> (Real, PrincipalAngularInertia<Real>) {
+        let sq = radii.component_mul(&radii);
+
+        #[cfg(feature = "dim2")]
+        {
+            let volume = Real::pi() * radii.x * radii.y;
+            (volume, (sq.x + sq.y) / 4.0)
+        }
+
+        #[cfg(feature = "dim3")]
+        {
+            let volume = Real::pi() * radii.x * radii.y * radii.z * 4.0 / 3.0;
+            let i = Vector::new(sq.y + sq.z, sq.x + sq.z, sq.x + sq.y) / 5.0;
+            (volume, i)
+        }
+    }
+
+    /// Computes the mass properties of an ellipsoid (or an ellipse in 2D) with the given
+    /// semi-axes lengths.
+    pub fn from_ellipsoid(density: Real, radii: Vector<Real>) -> Self {
+        let (vol, unit_i) = Self::ellipsoid_volume_unit_inertia(radii);
+        let mass = vol * density;
+        Self::new(Point::origin(), mass, unit_i * mass)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::mass_properties::MassProperties;
+    use crate::math::Vector;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn ellipsoid_mass_properties_match_ball() {
+        assert_relative_eq!(
+            MassProperties::from_ellipsoid(2.0, Vector::repeat(1.5)),
+            MassProperties::from_ball(2.0, 1.5),
+            epsilon = 1.0e-5
+        );
+    }
+}
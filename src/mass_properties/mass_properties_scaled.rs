@@ -0,0 +1,55 @@
+use crate::mass_properties::MassProperties;
+use crate::math::Real;
+use crate::shape::{Scaled, Shape, SupportMap};
+
+#[cfg(feature = "dim2")]
+use crate::shape::{Ball, ConvexPolygon};
+#[cfg(feature = "dim3")]
+use na::Matrix3;
+
+/// The number of directions of the support points approximating the convex hull of a 2D scaled
+/// shape.
+#[cfg(feature = "dim2")]
+const NUM_SUPPORT_POINTS: u32 = 64;
+
+impl MassProperties {
+    /// Computes the mass properties of a shape scaled non-uniformly.
+    ///
+    /// In 3D, they are computed exactly from the mass properties of the inner shape. In 2D, the
+    /// angular inertia of the inner shape doesn’t carry enough information to be scaled
+    /// non-uniformly, so the mass properties are approximated from the convex hull of sampled
+    /// support points of the scaled shape.
+    pub fn from_scaled<S: Shape + SupportMap>(density: Real, shape: &Scaled<S>) -> Self {
+        #[cfg(feature = "dim2")]
+        {
+            let points: alloc::vec::Vec<_> = Ball::new(1.0)
+                .to_polyline(NUM_SUPPORT_POINTS)
+                .iter()
+                .map(|dir| shape.local_support_point(&dir.coords))
+                .collect();
+            ConvexPolygon::from_convex_hull(&points)
+                .map(|hull| Self::from_convex_polygon(density, hull.points()))
+                .unwrap_or_default()
+        }
+
+        #[cfg(feature = "dim3")]
+        {
+            let scale = shape.scale();
+            let mprops = shape.inner_shape().mass_properties(density);
+            let det = (scale.x * scale.y * scale.z).abs();
+            let inertia = mprops.reconstruct_inertia_matrix();
+            // Covariance matrix of the mass distribution, relative to its center of mass.
+            let covariance = Matrix3::from_diagonal_element(inertia.trace() / 2.0) - inertia;
+            let scaling = Matrix3::from_diagonal(scale);
+            let scaled_covariance = scaling * covariance * scaling * det;
+            let scaled_inertia =
+                Matrix3::from_diagonal_element(scaled_covariance.trace()) - scaled_covariance;
+
+            Self::with_inertia_matrix(
+                mprops.local_com.coords.component_mul(scale).into(),
+                mprops.mass() * det,
+                scaled_inertia,
+            )
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use crate::bounding_volume::Aabb;
+use crate::mass_properties::MassProperties;
+use crate::math::{Point, Real, Vector, DIM};
+use crate::shape::Sdf;
+
+impl MassProperties {
+    /// Computes an approximation of the mass properties of a signed distance field.
+    ///
+    /// Each sample is attributed the box of size `sdf.cell_size()` centered on it (clipped to the
+    /// grid’s domain). That box is considered filled up to the fraction `0.5 - d / h` (clamped to
+    /// `[0, 1]`), where `d` is the sample’s signed distance and `h` the smallest cell dimension.
+    /// This is exact when the surface only crosses the boundaries of these boxes, and converges to
+    /// the actual mass properties as the grid resolution increases.
+    pub fn from_sdf(density: Real, sdf: &Sdf) -> Self {
+        let domain = sdf.domain();
+        let cell_size = sdf.cell_size();
+        let h = cell_size.min();
+        let dims = sdf.dimensions();
+        let mut result = MassProperties::default();
+
+        for (i, value) in sdf.values().iter().enumerate() {
+            let fill_ratio = (0.5 - value / h).clamp(0.0, 1.0);
+
+            if fill_ratio == 0.0 {
+                continue;
+            }
+
+            let mut key = Point::origin();
+            let mut id = i;
+            for k in 0..DIM {
+                key[k] = (id % dims[k] as usize) as u32;
+                id /= dims[k] as usize;
+            }
+
+            let center = sdf.sample_point(key);
+            let half_cell: Vector<Real> = cell_size / 2.0;
+            let cell = Aabb::new(center - half_cell, center + half_cell);
+            let Some(cell) = cell.intersection(&domain) else {
+                continue;
+            };
+
+            result += MassProperties::from_cuboid(density * fill_ratio, cell.half_extents())
+                .transform_by(&cell.center().coords.into());
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::mass_properties::MassProperties;
+    use crate::math::{Point, Vector};
+    use crate::shape::{Cuboid, Sdf, Shape};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn sdf_mass_properties() {
+        // A slab with its top face located between two rows of samples.
+        let sdf = Sdf::from_fn(
+            Point::origin(),
+            Vector::repeat(0.5),
+            Vector::repeat(9),
+            |pt| pt.y - 1.25,
+        );
+        let mut half_extents = Vector::repeat(2.0);
+        half_extents.y = 0.625;
+        let expected = Cuboid::new(half_extents)
+            .mass_properties(2.0)
+            .transform_by(&half_extents.into());
+
+        assert_relative_eq!(
+            MassProperties::from_sdf(2.0, &sdf),
+            expected,
+            epsilon = 1.0e-4
+        );
+        assert_relative_eq!(sdf.mass_properties(2.0), expected, epsilon = 1.0e-4);
+    }
+}
@@ -0,0 +1,19 @@
+use crate::mass_properties::MassProperties;
+use crate::math::{Isometry, Point, Real};
+
+impl MassProperties {
+    /// Computes the mass properties of a set of balls.
+    ///
+    /// The balls are assumed not to overlap: the mass of overlapping regions is counted once per
+    /// ball containing them.
+    pub fn from_sphere_set(density: Real, centers: &[Point<Real>], radii: &[Real]) -> Self {
+        centers
+            .iter()
+            .zip(radii.iter())
+            .map(|(center, radius)| {
+                let pos = Isometry::from_parts(center.coords.into(), Default::default());
+                Self::from_ball(density, *radius).transform_by(&pos)
+            })
+            .sum()
+    }
+}
@@ -0,0 +1,205 @@
+use crate::mass_properties::MassProperties;
+use crate::math::{Point, Real, Vector};
+use crate::shape::TaperedCapsule;
+#[cfg(feature = "dim2")]
+use crate::shape::Triangle;
+#[cfg(not(feature = "std"))]
+use na::ComplexField;
+use na::RealField;
+#[cfg(feature = "dim2")]
+use num::Zero;
+
+impl MassProperties {
+    /// Computes the mass properties of a tapered capsule, i.e., the convex hull of the ball
+    /// centered at `a` with radius `radius_a` and of the ball centered at `b` with radius
+    /// `radius_b`.
+    pub fn from_tapered_capsule(
+        density: Real,
+        a: Point<Real>,
+        b: Point<Real>,
+        radius_a: Real,
+        radius_b: Real,
+    ) -> Self {
+        let shape = TaperedCapsule::new(a, b, radius_a, radius_b);
+        let (sin, cos) = shape.slope();
+        let axis = (b - a)
+            .try_normalize(Real::EPSILON)
+            .unwrap_or_else(Vector::y);
+
+        #[cfg(feature = "dim2")]
+        {
+            let normal = Vector::new(-axis.y, axis.x);
+            // Half of the angle of the circular arc of each ball that lies on the boundary.
+            let half_angle_a = Real::frac_pi_2() + sin.asin();
+            let half_angle_b = Real::pi() - half_angle_a;
+
+            let mut result = circular_sector(density, a, radius_a, -axis, half_angle_a)
+                + circular_sector(density, b, radius_b, axis, half_angle_b);
+
+            // The quadrilaterals between the axis and each lateral side.
+            for side in [normal, -normal] {
+                let dir = axis * sin + side * cos;
+                let ta = a + dir * radius_a;
+                let tb = b + dir * radius_b;
+                result += triangle(density, a, b, tb);
+                result += triangle(density, a, tb, ta);
+            }
+
+            result
+        }
+
+        #[cfg(feature = "dim3")]
+        {
+            // The tapered capsule is a solid of revolution, split into three parts along its
+            // axis: the cap of the ball `a`, a truncated cone, and the cap of the ball `b`.
+            // We integrate (exactly, since every integrand is a polynomial of degree at most
+            // 4) the volume, first moment, and inertia of its disk slices with a 3-points
+            // Gauss-Legendre quadrature. The abscissa `x` is measured from `a` along the axis.
+            let height = shape.height();
+            let ra = radius_a;
+            let rb = radius_b;
+            let sq_radius_cap_a = |x: Real| ra * ra - x * x;
+            let sq_radius_cap_b = |x: Real| rb * rb - (x - height) * (x - height);
+            let (xa, xb) = (ra * sin, height + rb * sin);
+            let sq_radius_cone = |x: Real| {
+                let t = if xb > xa { (x - xa) / (xb - xa) } else { 0.0 };
+                let r = (ra + (rb - ra) * t) * cos;
+                r * r
+            };
+
+            let mut integrals = [0.0; 4];
+            gauss_legendre_disk_slices(-ra, xa, sq_radius_cap_a, &mut integrals);
+            gauss_legendre_disk_slices(xa, xb, sq_radius_cone, &mut integrals);
+            gauss_legendre_disk_slices(xb, height + rb, sq_radius_cap_b, &mut integrals);
+            let [volume, moment, axial_inertia, inertia_wrt_a] = integrals;
+
+            let com_x = moment / volume;
+            let ortho_inertia = inertia_wrt_a - volume * com_x * com_x;
+            let local_com = a + axis * com_x;
+            let principal_inertia = Vector::new(ortho_inertia, axial_inertia, ortho_inertia);
+
+            Self::with_principal_inertia_frame(
+                local_com,
+                volume * density,
+                principal_inertia * density,
+                shape.rotation_wrt_y(),
+            )
+        }
+    }
+}
+
+/// The mass properties of the circular sector with its apex at `center`, symmetric wrt.
+/// `bisector`, and with an angle of `2 * half_angle`.
+#[cfg(feature = "dim2")]
+fn circular_sector(
+    density: Real,
+    center: Point<Real>,
+    radius: Real,
+    bisector: Vector<Real>,
+    half_angle: Real,
+) -> MassProperties {
+    if half_angle <= 0.0 {
+        return MassProperties::zero();
+    }
+
+    let area = half_angle * radius * radius;
+    let com_dist = 2.0 * radius * half_angle.sin() / (3.0 * half_angle);
+    let inertia_wrt_center = area * radius * radius / 2.0;
+    MassProperties::new(
+        center + bisector * com_dist,
+        area * density,
+        (inertia_wrt_center - area * com_dist * com_dist) * density,
+    )
+}
+
+/// The mass properties of a triangle, with its angular inertia wrt. its center of mass.
+#[cfg(feature = "dim2")]
+fn triangle(density: Real, a: Point<Real>, b: Point<Real>, c: Point<Real>) -> MassProperties {
+    let triangle = Triangle::new(a, b, c);
+    let area = triangle.area();
+    let com = triangle.center();
+
+    if area == 0.0 {
+        return MassProperties::zero();
+    }
+
+    // `unit_angular_inertia` is expressed wrt. the first vertex of the triangle.
+    let inertia_wrt_a = triangle.unit_angular_inertia() * area;
+    let inertia = inertia_wrt_a - area * (com - a).norm_squared();
+    MassProperties::new(com, area * density, inertia * density)
+}
+
+/// Accumulates, for the solid of revolution around the `x` axis with squared radius
+/// `sq_radius(x)` between `x0` and `x1`: its volume, its first moment along `x`, its inertia
+/// wrt. the `x` axis, and its inertia wrt. any axis orthogonal to `x` passing through the origin.
+#[cfg(feature = "dim3")]
+fn gauss_legendre_disk_slices(
+    x0: Real,
+    x1: Real,
+    sq_radius: impl Fn(Real) -> Real,
+    out: &mut [Real; 4],
+) {
+    if x1 <= x0 {
+        return;
+    }
+
+    let sample = Real::sqrt(0.6);
+    let half_width = (x1 - x0) / 2.0;
+    let mid = (x0 + x1) / 2.0;
+    let samples = [
+        (mid - half_width * sample, 5.0 / 9.0),
+        (mid, 8.0 / 9.0),
+        (mid + half_width * sample, 5.0 / 9.0),
+    ];
+
+    for (x, weight) in samples {
+        let r2 = sq_radius(x).max(0.0);
+        let w = weight * half_width * Real::pi();
+        out[0] += w * r2;
+        out[1] += w * r2 * x;
+        out[2] += w * r2 * r2 / 2.0;
+        out[3] += w * (r2 * r2 / 4.0 + r2 * x * x);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::mass_properties::MassProperties;
+    use crate::math::{Point, Vector};
+    use crate::shape::{Shape, TaperedCapsule};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn tapered_capsule_mass_properties_match_discretization() {
+        let a = Point::from(Vector::repeat(0.2));
+        let b = Point::from(Vector::repeat(-0.8));
+        let shape = TaperedCapsule::new(a, b, 0.9, 0.3);
+        let exact = MassProperties::from_tapered_capsule(1.5, a, b, 0.9, 0.3);
+
+        #[cfg(feature = "dim2")]
+        let approx = MassProperties::from_convex_polygon(1.5, &shape.to_polyline(500));
+        #[cfg(feature = "dim3")]
+        let approx = {
+            let (vertices, indices) = shape.to_trimesh(200, 200);
+            crate::shape::TriMesh::new(vertices, indices)
+                .unwrap()
+                .mass_properties(1.5)
+        };
+
+        assert_relative_eq!(exact.mass(), approx.mass(), max_relative = 1.0e-3);
+        assert_relative_eq!(exact.local_com, approx.local_com, epsilon = 1.0e-3);
+        #[cfg(feature = "dim2")]
+        assert_relative_eq!(
+            exact.principal_inertia(),
+            approx.principal_inertia(),
+            max_relative = 1.0e-3
+        );
+        #[cfg(feature = "dim3")]
+        assert_relative_eq!(
+            exact.reconstruct_inertia_matrix(),
+            approx.reconstruct_inertia_matrix(),
+            epsilon = 1.0e-3
+        );
+        assert_relative_eq!(shape.mass_properties(1.5), exact);
+    }
+}
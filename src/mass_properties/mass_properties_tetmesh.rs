@@ -0,0 +1,24 @@
+use crate::mass_properties::MassProperties;
+use crate::math::{Point, Real};
+
+impl MassProperties {
+    /// Computes the mass properties of a tetrahedral mesh.
+    pub fn from_tetmesh(
+        density: Real,
+        vertices: &[Point<Real>],
+        indices: &[[u32; 4]],
+    ) -> MassProperties {
+        indices
+            .iter()
+            .map(|idx| {
+                Self::from_tetrahedron(
+                    density,
+                    &vertices[idx[0] as usize],
+                    &vertices[idx[1] as usize],
+                    &vertices[idx[2] as usize],
+                    &vertices[idx[3] as usize],
+                )
+            })
+            .sum()
+    }
+}
@@ -0,0 +1,68 @@
+use crate::mass_properties::MassProperties;
+use crate::math::{Matrix, Point, Real};
+use crate::shape::Tetrahedron;
+
+impl MassProperties {
+    /// Computes the mass properties of a tetrahedron.
+    pub fn from_tetrahedron(
+        density: Real,
+        a: &Point<Real>,
+        b: &Point<Real>,
+        c: &Point<Real>,
+        d: &Point<Real>,
+    ) -> MassProperties {
+        let tetrahedron = Tetrahedron::new(*a, *b, *c, *d);
+        let volume = tetrahedron.volume();
+        let com = tetrahedron.center();
+
+        if volume == 0.0 {
+            return MassProperties::with_inertia_matrix(com, 0.0, Matrix::zeros());
+        }
+
+        // The covariance of the mass distribution relative to the center of mass is
+        // mass / 20 * sum(v * vᵀ), where the vertices v are relative to the center of mass.
+        let mass = volume * density;
+        let covariance = tetrahedron
+            .vertices()
+            .iter()
+            .fold(Matrix::zeros(), |acc, pt| {
+                let v = pt - com;
+                acc + v * v.transpose()
+            })
+            * (mass / 20.0);
+        let inertia = Matrix::from_diagonal_element(covariance.trace()) - covariance;
+
+        Self::with_inertia_matrix(com, mass, inertia)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::mass_properties::MassProperties;
+    use crate::math::Point;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn tetrahedron_mass_properties_match_convex_polyhedron() {
+        let pts = [
+            Point::new(0.1, 0.0, 0.2),
+            Point::new(2.0, 0.3, 0.0),
+            Point::new(0.0, 1.5, -0.4),
+            Point::new(0.5, 0.2, 1.7),
+        ];
+        let mprops = MassProperties::from_tetrahedron(2.0, &pts[0], &pts[1], &pts[2], &pts[3]);
+        let expected = MassProperties::from_convex_polyhedron(
+            2.0,
+            &pts,
+            &[[0, 2, 1], [0, 1, 3], [0, 3, 2], [1, 2, 3]],
+        );
+
+        assert_relative_eq!(mprops.mass(), expected.mass(), epsilon = 1.0e-5);
+        assert_relative_eq!(mprops.local_com, expected.local_com, epsilon = 1.0e-5);
+        assert_relative_eq!(
+            mprops.reconstruct_inertia_matrix(),
+            expected.reconstruct_inertia_matrix(),
+            epsilon = 1.0e-4
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use crate::mass_properties::MassProperties;
+use crate::math::{Point, PrincipalAngularInertia, Real, Vector};
+use na::RealField;
+
+impl MassProperties {
+    pub(crate) fn torus_y_volume_unit_inertia(
+        major_radius: Real,
+        minor_radius: Real,
+    ) -> (Real, PrincipalAngularInertia<Real>) {
+        let sq_major = major_radius * major_radius;
+        let sq_minor = minor_radius * minor_radius;
+        let volume = Real::two_pi() * Real::pi() * major_radius * sq_minor;
+        let off_principal = sq_major / 2.0 + sq_minor * 5.0 / 8.0;
+        let principal = sq_major + sq_minor * 3.0 / 4.0;
+
+        let inertia = Vector::new(off_principal, principal, off_principal);
+        (volume, inertia)
+    }
+
+    /// Computes the mass properties of a torus with its revolution axis aligned with the `y` axis.
+    pub fn from_torus(density: Real, major_radius: Real, minor_radius: Real) -> Self {
+        let (vol, unit_i) = Self::torus_y_volume_unit_inertia(major_radius, minor_radius);
+        let mass = vol * density;
+        Self::new(Point::origin(), mass, unit_i * mass)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::mass_properties::MassProperties;
+    use crate::shape::Torus;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn torus_mass_properties_match_trimesh() {
+        let torus = Torus::new(2.0, 0.5);
+        let (vtx, idx) = torus.to_trimesh(200, 100);
+        let expected = MassProperties::from_trimesh(2.0, &vtx, &idx);
+        let mprops = MassProperties::from_torus(2.0, 2.0, 0.5);
+
+        assert_relative_eq!(mprops.mass(), expected.mass(), max_relative = 1.0e-2);
+        // NOTE: the principal axes computed for the triangle mesh may be permuted, so compare the
+        //       full inertia matrices.
+        assert_relative_eq!(
+            mprops.reconstruct_inertia_matrix(),
+            expected.reconstruct_inertia_matrix(),
+            epsilon = 1.0e-2,
+            max_relative = 1.0e-2
+        );
+    }
+}
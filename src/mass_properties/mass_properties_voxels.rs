@@ -0,0 +1,325 @@
+use crate::bounding_volume::Aabb;
+use crate::mass_properties::MassProperties;
+use crate::math::{Matrix, Point, Real, Vector, DIM};
+use crate::shape::{OctantPattern, VoxelPrimitiveGeometry, Voxels};
+use core::ops::{AddAssign, SubAssign};
+use na::RealField;
+
+impl MassProperties {
+    /// Computes the mass properties of a voxels shape.
+    ///
+    /// Filled voxels are integrated exactly, taking their [`VoxelPrimitiveGeometry`] into account:
+    /// pseudo-cube voxels are full cuboids, whereas pseudo-ball voxels have their exposed corners
+    /// (and edges, in 3D) rounded. Contiguous filled voxels along the `x` axis are integrated
+    /// all at once as a single cuboid, so the cost of this computation is mostly dependent on
+    /// the number of such runs rather than on the total number of voxels.
+    pub fn from_voxels(density: Real, voxels: &Voxels) -> Self {
+        // Integrate relative to the domain center to limit precision losses on grids located
+        // far from the origin.
+        let reference = voxels.domain_center();
+        let voxel_size = voxels.voxel_size();
+        let mut moments = VolumeMoments::zero();
+
+        // For pseudo-balls, the volume missing from each rounded octant, relative to a full
+        // cube octant. This is indexed by `OctantPattern`.
+        let octant_deficits = match voxels.primitive_geometry() {
+            VoxelPrimitiveGeometry::PseudoCube => None,
+            VoxelPrimitiveGeometry::PseudoBall => {
+                Some(pseudo_ball_octant_deficits(voxel_size.x / 2.0))
+            }
+        };
+        let octant_signs = Aabb::new(
+            Point::from(Vector::repeat(-1.0)),
+            Point::from(Vector::repeat(1.0)),
+        )
+        .vertices();
+
+        for (first_key, states) in voxels.filled_runs() {
+            let mut last_key = first_key + Vector::repeat(1);
+            last_key.x = first_key.x + states.len() as i32;
+            let run_aabb = voxels.voxel_range_aabb(first_key, last_key);
+            moments += VolumeMoments::cuboid(
+                run_aabb.mins - reference.coords,
+                run_aabb.maxs - reference.coords,
+            );
+
+            if let Some(deficits) = &octant_deficits {
+                for (i, state) in states.iter().enumerate() {
+                    let octant_mask = state.octant_mask();
+                    if octant_mask == 0 {
+                        // Only full octants.
+                        continue;
+                    }
+
+                    let mut key = first_key;
+                    key.x += i as i32;
+                    let center = voxels.voxel_center(key) - reference.coords;
+
+                    for (octant_id, signs) in octant_signs.iter().enumerate() {
+                        let pattern = (octant_mask >> (octant_id * 3)) & 0b0111;
+                        if let Some(deficit) = &deficits[pattern as usize] {
+                            moments -= deficit.reflected(&signs.coords).translated(&center.coords);
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut result = moments.into_mass_properties(density);
+        result.local_com += reference.coords;
+        result
+    }
+}
+
+/// The volume, first moment, and second moment of a solid, relative to some reference point.
+#[derive(Copy, Clone, Debug)]
+struct VolumeMoments {
+    volume: Real,
+    first: Vector<Real>,
+    second: Matrix<Real>,
+}
+
+impl VolumeMoments {
+    fn zero() -> Self {
+        Self {
+            volume: 0.0,
+            first: Vector::zeros(),
+            second: Matrix::zeros(),
+        }
+    }
+
+    /// The moments of the axis-aligned box with the given corners.
+    fn cuboid(mins: Point<Real>, maxs: Point<Real>) -> Self {
+        let volume = (maxs - mins).product();
+        let center = na::center(&mins, &maxs).coords;
+        let mut second = center * center.transpose() * volume;
+
+        for i in 0..DIM {
+            second[(i, i)] =
+                volume * (mins[i] * mins[i] + mins[i] * maxs[i] + maxs[i] * maxs[i]) / 3.0;
+        }
+
+        Self {
+            volume,
+            first: center * volume,
+            second,
+        }
+    }
+
+    /// The moments of the same solid after being translated by `shift`.
+    fn translated(&self, shift: &Vector<Real>) -> Self {
+        Self {
+            volume: self.volume,
+            first: self.first + shift * self.volume,
+            second: self.second
+                + shift * self.first.transpose()
+                + self.first * shift.transpose()
+                + shift * shift.transpose() * self.volume,
+        }
+    }
+
+    /// The moments of the same solid after being reflected along the axes with a negative sign.
+    fn reflected(&self, signs: &Vector<Real>) -> Self {
+        Self {
+            volume: self.volume,
+            first: self.first.component_mul(signs),
+            second: self.second.component_mul(&(signs * signs.transpose())),
+        }
+    }
+
+    fn minus(&self, rhs: &Self) -> Self {
+        let mut result = *self;
+        result -= *rhs;
+        result
+    }
+
+    fn into_mass_properties(self, density: Real) -> MassProperties {
+        if self.volume <= 0.0 {
+            return MassProperties::default();
+        }
+
+        let com = self.first / self.volume;
+        let second_wrt_com = self.second - com * com.transpose() * self.volume;
+        let mass = self.volume * density;
+
+        #[cfg(feature = "dim2")]
+        {
+            MassProperties::new(com.into(), mass, second_wrt_com.trace() * density)
+        }
+
+        #[cfg(feature = "dim3")]
+        {
+            let inertia = Matrix::from_diagonal_element(second_wrt_com.trace()) - second_wrt_com;
+            MassProperties::with_inertia_matrix(com.into(), mass, inertia * density)
+        }
+    }
+}
+
+impl AddAssign for VolumeMoments {
+    fn add_assign(&mut self, rhs: Self) {
+        self.volume += rhs.volume;
+        self.first += rhs.first;
+        self.second += rhs.second;
+    }
+}
+
+impl SubAssign for VolumeMoments {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.volume -= rhs.volume;
+        self.first -= rhs.first;
+        self.second -= rhs.second;
+    }
+}
+
+/// For each octant pattern, the moments of the volume removed from the positive octant
+/// `[0, radius]^DIM` of a cube when it gets rounded as part of a pseudo-ball voxel.
+#[cfg(feature = "dim2")]
+fn pseudo_ball_octant_deficits(radius: Real) -> [Option<VolumeMoments>; 8] {
+    let full = VolumeMoments::cuboid(Point::origin(), Point::from(Vector::repeat(radius)));
+    let r2 = radius * radius;
+    let (r3, r4) = (r2 * radius, r2 * r2);
+
+    // A quarter-disk.
+    let vertex = VolumeMoments {
+        volume: Real::pi() * r2 / 4.0,
+        first: Vector::repeat(r3 / 3.0),
+        second: Matrix::new(
+            Real::pi() * r4 / 16.0,
+            r4 / 8.0,
+            r4 / 8.0,
+            Real::pi() * r4 / 16.0,
+        ),
+    };
+
+    let mut result = [None; 8];
+    result[OctantPattern::VERTEX as usize] = Some(full.minus(&vertex));
+    result
+}
+
+/// For each octant pattern, the moments of the volume removed from the positive octant
+/// `[0, radius]^DIM` of a cube when it gets rounded as part of a pseudo-ball voxel.
+#[cfg(feature = "dim3")]
+fn pseudo_ball_octant_deficits(radius: Real) -> [Option<VolumeMoments>; 8] {
+    let full = VolumeMoments::cuboid(Point::origin(), Point::from(Vector::repeat(radius)));
+    let r2 = radius * radius;
+    let (r3, r4, r5) = (r2 * radius, r2 * r2, r2 * r2 * radius);
+
+    // An eighth of a ball.
+    let vertex = VolumeMoments {
+        volume: Real::pi() * r3 / 6.0,
+        first: Vector::repeat(Real::pi() * r4 / 16.0),
+        second: Matrix::from_fn(|i, j| {
+            if i == j {
+                Real::pi() * r5 / 30.0
+            } else {
+                r5 / 15.0
+            }
+        }),
+    };
+
+    // A quarter-cylinder with its axis aligned with the `axis`-th coordinate axis.
+    let edge = |axis: usize| VolumeMoments {
+        volume: Real::pi() * r3 / 4.0,
+        first: Vector::from_fn(|i, _| {
+            if i == axis {
+                Real::pi() * r4 / 8.0
+            } else {
+                r4 / 3.0
+            }
+        }),
+        second: Matrix::from_fn(|i, j| {
+            if i == j {
+                if i == axis {
+                    Real::pi() * r5 / 12.0
+                } else {
+                    Real::pi() * r5 / 16.0
+                }
+            } else if i == axis || j == axis {
+                r5 / 6.0
+            } else {
+                r5 / 8.0
+            }
+        }),
+    };
+
+    let mut result = [None; 8];
+    result[OctantPattern::VERTEX as usize] = Some(full.minus(&vertex));
+    result[OctantPattern::EDGE_X as usize] = Some(full.minus(&edge(0)));
+    result[OctantPattern::EDGE_Y as usize] = Some(full.minus(&edge(1)));
+    result[OctantPattern::EDGE_Z as usize] = Some(full.minus(&edge(2)));
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use crate::mass_properties::MassProperties;
+    use crate::math::{Point, Real, Vector};
+    use crate::shape::{Ball, Capsule, Cuboid, Shape, VoxelPrimitiveGeometry, Voxels};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn voxels_pseudo_cube_mass_properties() {
+        // A 2x3(x4) block of voxels must behave like a single cuboid.
+        let voxel_size = Vector::repeat(0.5);
+        #[cfg(feature = "dim2")]
+        let keys: alloc::vec::Vec<_> = (0..2)
+            .flat_map(|x| (0..3).map(move |y| Point::new(x, y)))
+            .collect();
+        #[cfg(feature = "dim3")]
+        let keys: alloc::vec::Vec<_> = (0..2)
+            .flat_map(|x| (0..3).flat_map(move |y| (0..4).map(move |z| Point::new(x, y, z))))
+            .collect();
+
+        let voxels = Voxels::new(VoxelPrimitiveGeometry::PseudoCube, voxel_size, &keys);
+        let dims = voxels
+            .dimensions()
+            .cast::<Real>()
+            .component_mul(&voxel_size);
+        let expected = Cuboid::new(dims / 2.0)
+            .mass_properties(2.0)
+            .transform_by(&(dims / 2.0).into());
+
+        assert_relative_eq!(voxels.mass_properties(2.0), expected, epsilon = 1.0e-5);
+    }
+
+    #[test]
+    fn voxels_pseudo_ball_mass_properties() {
+        // An isolated pseudo-ball voxel is a ball.
+        let voxels = Voxels::new(
+            VoxelPrimitiveGeometry::PseudoBall,
+            Vector::repeat(2.0),
+            &[Point::from(Vector::repeat(3))],
+        );
+        let expected = Ball::new(1.0)
+            .mass_properties(1.0)
+            .transform_by(&Vector::repeat(7.0).into());
+        assert_relative_eq!(
+            MassProperties::from_voxels(1.0, &voxels),
+            expected,
+            epsilon = 1.0e-5
+        );
+
+        // Two adjacent pseudo-ball voxels form a capsule.
+        let voxels = Voxels::new(
+            VoxelPrimitiveGeometry::PseudoBall,
+            Vector::repeat(2.0),
+            &[Point::origin(), Point::from(Vector::<i32>::x())],
+        );
+        let mut center = Vector::repeat(1.0);
+        center.x = 2.0;
+        let expected = Capsule::new_x(1.0, 1.0)
+            .mass_properties(1.0)
+            .transform_by(&center.into());
+        let mprops = voxels.mass_properties(1.0);
+
+        // NOTE: in 2D, the angular inertia given by `MassProperties::from_capsule` relies on the
+        //       centroid of a 3D hemisphere so we only compare masses and centers of mass.
+        #[cfg(feature = "dim2")]
+        {
+            assert_relative_eq!(mprops.mass(), expected.mass(), epsilon = 1.0e-5);
+            assert_relative_eq!(mprops.local_com, expected.local_com, epsilon = 1.0e-5);
+        }
+        #[cfg(feature = "dim3")]
+        assert_relative_eq!(mprops, expected, epsilon = 1.0e-5);
+    }
+}
@@ -17,6 +17,21 @@ mod mass_properties_convex_polygon;
 mod mass_properties_convex_polyhedron;
 mod mass_properties_cuboid;
 mod mass_properties_cylinder;
+mod mass_properties_ellipsoid;
+#[cfg(any(feature = "dim3", feature = "alloc"))]
+mod mass_properties_scaled;
+#[cfg(feature = "alloc")]
+mod mass_properties_sdf;
+#[cfg(feature = "alloc")]
+mod mass_properties_sphere_set;
+mod mass_properties_tapered_capsule;
+#[cfg(feature = "dim3")]
+#[cfg(feature = "alloc")]
+mod mass_properties_tetmesh;
+#[cfg(feature = "dim3")]
+mod mass_properties_tetrahedron;
+#[cfg(feature = "dim3")]
+mod mass_properties_torus;
 #[cfg(feature = "dim2")]
 mod mass_properties_triangle;
 #[cfg(feature = "dim2")]
@@ -25,6 +40,8 @@ mod mass_properties_trimesh2d;
 #[cfg(feature = "dim3")]
 #[cfg(feature = "alloc")]
 mod mass_properties_trimesh3d;
+#[cfg(feature = "alloc")]
+mod mass_properties_voxels;
 
 /// Free functions for some special-cases of mass-properties computation.
 pub mod details {
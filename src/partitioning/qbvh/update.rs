@@ -12,7 +12,7 @@ use super::{IndexedData, NodeIndex, Qbvh, QbvhNode, QbvhNodeFlags};
 #[cfg(test)]
 mod tests;
 
-#[derive(Clone, Default)]
+#[derive(Clone, Default, Debug)]
 /// Workspace for QBVH update.
 ///
 /// Re-using the same workspace for multiple QBVH modification isn’t mandatory, but it improves
@@ -263,13 +263,44 @@ impl<LeafData: IndexedData> Qbvh<LeafData> {
     /// Update all the nodes that have been marked as dirty by [`Qbvh::pre_update_or_insert`],
     /// and [`Qbvh::remove`].
     ///
-    /// This will not alter the topology of this `Qbvh`.
+    /// This will not alter the topology of this `Qbvh`. Its [`Qbvh::root_aabb`] is updated
+    /// accordingly.
     pub fn refit<F>(
         &mut self,
         margin: Real,
         workspace: &mut QbvhUpdateWorkspace,
         aabb_builder: F,
     ) -> usize
+    where
+        F: Fn(&LeafData) -> Aabb,
+    {
+        self.do_refit(margin, workspace, aabb_builder, false)
+    }
+
+    /// Same as [`Qbvh::refit`], except that the Aabbs of the dirty leaves (and of their
+    /// ancestors) are also shrunk if they are larger than needed.
+    ///
+    /// This is useful for leaves moving continuously (e.g. the triangles of a deformable mesh),
+    /// since [`Qbvh::refit`] only ever enlarges the Aabbs of the leaves.
+    pub(crate) fn refit_tight<F>(
+        &mut self,
+        margin: Real,
+        workspace: &mut QbvhUpdateWorkspace,
+        aabb_builder: F,
+    ) -> usize
+    where
+        F: Fn(&LeafData) -> Aabb,
+    {
+        self.do_refit(margin, workspace, aabb_builder, true)
+    }
+
+    fn do_refit<F>(
+        &mut self,
+        margin: Real,
+        workspace: &mut QbvhUpdateWorkspace,
+        aabb_builder: F,
+        shrink: bool,
+    ) -> usize
     where
         F: Fn(&LeafData) -> Aabb,
     {
@@ -303,7 +334,7 @@ impl<LeafData: IndexedData> Qbvh<LeafData> {
                     let new_simd_aabb = SimdAabb::from(new_aabbs);
                     node.set_dirty(false);
 
-                    if !first_iter || !node.simd_aabb.contains(&new_simd_aabb).all() {
+                    if !first_iter || shrink || !node.simd_aabb.contains(&new_simd_aabb).all() {
                         node.set_changed(true);
                         node.simd_aabb = new_simd_aabb;
                         node.simd_aabb.loosen(margin);
@@ -324,6 +355,10 @@ impl<LeafData: IndexedData> Qbvh<LeafData> {
             core::mem::swap(&mut self.dirty_nodes, &mut workspace.dirty_parent_nodes);
         }
 
+        if let Some(root) = self.nodes.first() {
+            self.root_aabb = root.simd_aabb.to_merged_aabb();
+        }
+
         num_changed
     }
 
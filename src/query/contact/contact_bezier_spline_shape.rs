@@ -0,0 +1,252 @@
+use crate::bounding_volume::BoundingVolume;
+use crate::math::{Isometry, Point, Real, Vector};
+use crate::query::{Contact, PointProjection};
+use crate::shape::bezier_spline::{eval, eval_derivative};
+use crate::shape::{BezierSpline, Shape};
+use alloc::vec::Vec;
+use na::Unit;
+
+/// The number of intervals each curve segment is sampled with for finding the local minima of
+/// its distance to the other shape.
+const NUM_SAMPLE_INTERVALS: usize = 8;
+/// The maximum number of bisection steps for refining the parameter of a local minimum.
+const MAX_BISECTION_STEPS: usize = 64;
+/// The width of the parameter interval below which the bisection stops.
+const PARAMETER_TOLERANCE: Real = 1.0e-6;
+
+/// Contact between a Bézier spline and a convex shape (Ball, Capsule, Cuboid, ConvexPolygon, etc.)
+///
+/// This returns the deepest of the contacts computed by [`bezier_spline_shape_contacts`].
+pub fn contact_bezier_spline_shape<G: ?Sized + Shape>(
+    pos12: &Isometry<Real>,
+    curve1: &BezierSpline,
+    shape2: &G,
+    prediction: Real,
+) -> Option<Contact> {
+    let mut result: Option<Contact> = None;
+
+    bezier_spline_shape_contacts(pos12, curve1, shape2, prediction, |contact| {
+        if result.map(|r| contact.dist < r.dist).unwrap_or(true) {
+            result = Some(contact);
+        }
+    });
+
+    result
+}
+
+/// Contact between a convex shape (Ball, Capsule, Cuboid, ConvexPolygon, etc.) and a Bézier spline.
+pub fn contact_shape_bezier_spline<G: ?Sized + Shape>(
+    pos12: &Isometry<Real>,
+    shape1: &G,
+    curve2: &BezierSpline,
+    prediction: Real,
+) -> Option<Contact> {
+    contact_bezier_spline_shape(&pos12.inverse(), curve2, shape1, prediction).map(|c| c.flipped())
+}
+
+/// Computes the contacts between a Bézier spline and a convex shape.
+///
+/// The contacts are the local minima, along the curve, of the signed distance between the
+/// points of the curve and `shape2`. Each curve segment close to `shape2` is sampled to find
+/// them, then their exact location is found by bisection on the derivative of the signed
+/// distance. Because the curve isn’t convex, there can be several contacts (e.g. with a capsule
+/// lying across an arch). Every contact closer than `prediction` is passed to `f`.
+///
+/// The shape `shape2` is assumed to be convex.
+pub fn bezier_spline_shape_contacts<G: ?Sized + Shape>(
+    pos12: &Isometry<Real>,
+    curve1: &BezierSpline,
+    shape2: &G,
+    prediction: Real,
+    mut f: impl FnMut(Contact),
+) {
+    let aabb2 = shape2.compute_aabb(pos12).loosened(prediction);
+    let mut candidates = Vec::new();
+    curve1.qbvh().intersect_aabb(&aabb2, &mut candidates);
+
+    let num_segments = curve1.num_segments() as u32;
+    let closed = curve1.is_closed();
+
+    for i in candidates {
+        let ctrl = curve1.segment(i);
+        let sample = |t: Real| CurveSample::new(pos12, &ctrl, shape2, t);
+        let samples: [CurveSample; NUM_SAMPLE_INTERVALS + 1] =
+            core::array::from_fn(|k| sample(k as Real / NUM_SAMPLE_INTERVALS as Real));
+        let mut found = [0.0; NUM_SAMPLE_INTERVALS + 1];
+        let mut num_found = 0;
+
+        for k in 0..=NUM_SAMPLE_INTERVALS {
+            let left = k.checked_sub(1).map(|k| samples[k].dist);
+            let right = samples.get(k + 1).map(|s| s.dist);
+
+            if left.is_some_and(|d| d <= samples[k].dist)
+                || right.is_some_and(|d| d < samples[k].dist)
+            {
+                continue;
+            }
+
+            let lo = k.saturating_sub(1);
+            let hi = (k + 1).min(NUM_SAMPLE_INTERVALS);
+            let best = refine_local_minimum(&samples[lo], &samples[hi], sample);
+
+            if best.t == 0.0 && (i > 0 || closed) {
+                // This point is also the end of the previous segment, which handles it.
+                continue;
+            }
+
+            if best.t == 1.0 && (i + 1 < num_segments || closed) {
+                // Skip the joint with the next segment if the distance keeps decreasing along
+                // that segment: the actual local minimum will be found there.
+                let next = curve1.segment((i + 1) % num_segments);
+
+                if best.normal1.dot(&eval_derivative(&next, 0.0)) > 0.0 {
+                    continue;
+                }
+            }
+
+            if best.dist > prediction
+                || found[..num_found]
+                    .iter()
+                    .any(|t| (t - best.t).abs() <= PARAMETER_TOLERANCE * 10.0)
+            {
+                continue;
+            }
+
+            found[num_found] = best.t;
+            num_found += 1;
+
+            let normal1 =
+                Unit::try_new(best.normal1, crate::math::DEFAULT_EPSILON).unwrap_or_else(|| {
+                    // The curve touches the boundary of `shape2`: use the curve’s normal
+                    // facing the center of `shape2`.
+                    let tangent = eval_derivative(&ctrl, best.t);
+                    let normal = Vector::new(-tangent.y, tangent.x);
+                    let dir = pos12.translation.vector - best.point.coords;
+                    let normal = if normal.dot(&dir) < 0.0 {
+                        -normal
+                    } else {
+                        normal
+                    };
+                    Unit::try_new(normal, crate::math::DEFAULT_EPSILON)
+                        .unwrap_or_else(Vector::y_axis)
+                });
+            let point2 = pos12.inverse_transform_point(&best.proj.point);
+            let normal2 = pos12.inverse_transform_unit_vector(&-normal1);
+            f(Contact::new(
+                best.point, point2, normal1, normal2, best.dist,
+            ));
+        }
+    }
+}
+
+/// A point of a curve segment, with its projection on the other shape.
+struct CurveSample {
+    t: Real,
+    point: Point<Real>,
+    proj: PointProjection,
+    /// The signed distance between the point and the other shape, negative if the point is
+    /// inside of it.
+    dist: Real,
+    /// The (non-normalized) direction from the curve toward the other shape.
+    normal1: Vector<Real>,
+    /// The derivative of `dist` with respect to `t`, if `normal1` is normalized.
+    slope: Real,
+}
+
+impl CurveSample {
+    fn new<G: ?Sized + Shape>(
+        pos12: &Isometry<Real>,
+        ctrl: &[Point<Real>; 4],
+        shape2: &G,
+        t: Real,
+    ) -> Self {
+        let point = eval(ctrl, t);
+        let proj = shape2.project_point(pos12, &point, false);
+        let dpos = proj.point - point;
+        let (dist, normal1) = if proj.is_inside {
+            (-dpos.norm(), -dpos)
+        } else {
+            (dpos.norm(), dpos)
+        };
+        // The gradient of the signed distance is `-normal1`.
+        let slope = -normal1.dot(&eval_derivative(ctrl, t));
+
+        Self {
+            t,
+            point,
+            proj,
+            dist,
+            normal1,
+            slope,
+        }
+    }
+}
+
+/// Finds the local minimum of the signed distance between `lo` and `hi`.
+///
+/// The derivative of the signed distance is assumed to change sign at most once on
+/// this interval.
+fn refine_local_minimum(
+    lo: &CurveSample,
+    hi: &CurveSample,
+    sample: impl Fn(Real) -> CurveSample,
+) -> CurveSample {
+    if lo.t == 0.0 && lo.slope >= 0.0 {
+        return sample(0.0);
+    }
+
+    if hi.t == 1.0 && hi.slope <= 0.0 {
+        return sample(1.0);
+    }
+
+    let (mut t_lo, mut t_hi) = (lo.t, hi.t);
+    let mut best = sample((t_lo + t_hi) * 0.5);
+
+    for _ in 0..MAX_BISECTION_STEPS {
+        if t_hi - t_lo <= PARAMETER_TOLERANCE {
+            break;
+        }
+
+        if best.slope < 0.0 {
+            t_lo = best.t;
+        } else {
+            t_hi = best.t;
+        }
+
+        best = sample((t_lo + t_hi) * 0.5);
+    }
+
+    best
+}
+
+#[cfg(test)]
+mod test {
+    use crate::math::{Isometry, Point, Vector};
+    use crate::query::details::contact_bezier_spline_shape;
+    use crate::shape::{BezierSpline, Cuboid};
+    use alloc::vec;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn cuboid_penetrating_a_closed_curve() {
+        // A closed curve made of two arches, roughly looking like an ellipse.
+        let curve = BezierSpline::new(vec![
+            Point::new(-2.0, 0.0),
+            Point::new(-2.0, 1.5),
+            Point::new(2.0, 1.5),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, -1.5),
+            Point::new(-2.0, -1.5),
+            Point::new(-2.0, 0.0),
+        ])
+        .unwrap();
+        assert!(curve.is_closed());
+
+        // A cuboid centered on the leftmost point of the curve.
+        let cuboid = Cuboid::new(Vector::new(0.1, 0.5));
+        let pos12 = Isometry::translation(-2.0, 0.0);
+        let contact = contact_bezier_spline_shape(&pos12, &curve, &cuboid, 0.0).unwrap();
+        assert_relative_eq!(contact.dist, -0.1, epsilon = 1.0e-3);
+        assert_relative_eq!(contact.normal1.x.abs(), 1.0, epsilon = 1.0e-3);
+    }
+}
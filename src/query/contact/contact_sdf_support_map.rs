@@ -0,0 +1,115 @@
+use crate::math::{Isometry, Point, Real, Vector, DIM};
+use crate::query::Contact;
+use crate::shape::{Sdf, SupportMap};
+
+/// The number of directions along which support points are sampled, i.e., the number of
+/// non-zero vectors with components in `{-1, 0, 1}`.
+const NUM_SAMPLE_DIRECTIONS: usize = if DIM == 2 { 8 } else { 26 };
+/// The maximum number of iterations for locating the deepest point of the support-mapped shape.
+const MAX_DEEPEST_POINT_ITERATIONS: usize = 8;
+
+/// Contact between a signed distance field and a support-mapped shape (Cuboid, ConvexHull, etc.)
+///
+/// This returns the deepest of the contacts computed by [`sdf_support_map_contacts`].
+pub fn contact_sdf_support_map<G: ?Sized + SupportMap>(
+    pos12: &Isometry<Real>,
+    sdf1: &Sdf,
+    other: &G,
+    prediction: Real,
+) -> Option<Contact> {
+    let mut result: Option<Contact> = None;
+
+    sdf_support_map_contacts(pos12, sdf1, other, prediction, |_, contact| {
+        if result.map(|r| contact.dist < r.dist).unwrap_or(true) {
+            result = Some(contact);
+        }
+    });
+
+    result
+}
+
+/// Contact between a support-mapped shape (Cuboid, ConvexHull, etc.) and a signed distance field.
+pub fn contact_support_map_sdf<G: ?Sized + SupportMap>(
+    pos12: &Isometry<Real>,
+    other: &G,
+    sdf2: &Sdf,
+    prediction: Real,
+) -> Option<Contact> {
+    contact_sdf_support_map(&pos12.inverse(), sdf2, other, prediction).map(|c| c.flipped())
+}
+
+/// Computes the contacts between a signed distance field and a support-mapped shape.
+///
+/// The candidate contact points are the support points of `other` along a fixed set of
+/// directions (the axes and diagonals of the signed distance field’s local frame), as well as
+/// the point of `other` located the deepest along the signed distance field’s gradient. Every
+/// candidate closer to the signed distance field than `prediction` is passed to `f`, together
+/// with an index identifying the direction it was sampled from.
+pub fn sdf_support_map_contacts<G: ?Sized + SupportMap>(
+    pos12: &Isometry<Real>,
+    sdf1: &Sdf,
+    other: &G,
+    prediction: Real,
+    mut f: impl FnMut(u32, Contact),
+) {
+    let mut candidates = [Point::origin(); NUM_SAMPLE_DIRECTIONS + 1];
+    let mut num_candidates = 0;
+    let tolerance = sdf1.cell_size().min() * 1.0e-2;
+
+    let mut push_candidate = |pt: Point<Real>, id: u32| {
+        // Ignore duplicate points, e.g., the same vertex of a polyhedron being the support
+        // point along several directions.
+        if candidates[..num_candidates]
+            .iter()
+            .any(|c| na::distance_squared(c, &pt) <= tolerance * tolerance)
+        {
+            return;
+        }
+
+        candidates[num_candidates] = pt;
+        num_candidates += 1;
+
+        let dist = sdf1.distance_at_point(&pt);
+
+        if dist <= prediction {
+            if let Some(normal1) = sdf1.normal_at_point(&pt) {
+                let point1 = pt - *normal1 * dist;
+                let point2 = pos12.inverse_transform_point(&pt);
+                let normal2 = pos12.inverse_transform_unit_vector(&-normal1);
+                f(id, Contact::new(point1, point2, normal1, normal2, dist));
+            }
+        }
+    };
+
+    // Descend along the gradient, starting from the origin of `other`, to find its deepest point.
+    let mut deepest = Point::from(pos12.translation.vector);
+    for _ in 0..MAX_DEEPEST_POINT_ITERATIONS {
+        let Some(normal1) = sdf1.normal_at_point(&deepest) else {
+            break;
+        };
+        let next = other.support_point_toward(pos12, &-normal1);
+        let converged = na::distance_squared(&next, &deepest) <= tolerance * tolerance;
+        deepest = next;
+
+        if converged {
+            break;
+        }
+    }
+    push_candidate(deepest, NUM_SAMPLE_DIRECTIONS as u32);
+
+    let mut id = 0;
+    for i in 0..3usize.pow(DIM as u32) {
+        let mut dir = Vector::zeros();
+        let mut code = i;
+
+        for k in 0..DIM {
+            dir[k] = (code % 3) as Real - 1.0;
+            code /= 3;
+        }
+
+        if dir != Vector::zeros() {
+            push_candidate(other.support_point(pos12, &dir), id);
+            id += 1;
+        }
+    }
+}
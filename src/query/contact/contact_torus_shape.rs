@@ -0,0 +1,173 @@
+use crate::math::{Isometry, Point, Real};
+use crate::query::{Contact, PointProjection, PointQuery};
+use crate::shape::Torus;
+use na::Unit;
+
+#[cfg(not(feature = "std"))]
+use na::ComplexField;
+
+/// The number of points of the torus’ core circle from which the closest points are searched.
+const NUM_STARTING_POINTS: usize = 4;
+/// The maximum number of alternating projections for converging towards a pair of closest points.
+const MAX_ITERATIONS: usize = 32;
+/// The angle (in radians) between the neighbors used to check that a converged point of the core
+/// circle is a local minimum of the distance.
+const LOCAL_MINIMUM_CHECK_ANGLE: Real = 0.05;
+
+/// Contact between a torus and a convex shape (Ball, Capsule, Cuboid, ConvexHull, etc.)
+///
+/// This returns the deepest of the contacts computed by [`torus_shape_contacts`].
+pub fn contact_torus_shape<G: ?Sized + PointQuery>(
+    pos12: &Isometry<Real>,
+    torus1: &Torus,
+    shape2: &G,
+    prediction: Real,
+) -> Option<Contact> {
+    let mut result: Option<Contact> = None;
+
+    torus_shape_contacts(pos12, torus1, shape2, prediction, |contact| {
+        if result.map(|r| contact.dist < r.dist).unwrap_or(true) {
+            result = Some(contact);
+        }
+    });
+
+    result
+}
+
+/// Contact between a convex shape (Ball, Capsule, Cuboid, ConvexHull, etc.) and a torus.
+pub fn contact_shape_torus<G: ?Sized + PointQuery>(
+    pos12: &Isometry<Real>,
+    shape1: &G,
+    torus2: &Torus,
+    prediction: Real,
+) -> Option<Contact> {
+    contact_torus_shape(&pos12.inverse(), torus2, shape1, prediction).map(|c| c.flipped())
+}
+
+/// Computes the contacts between a torus and a convex shape.
+///
+/// A torus is the set of points closer than its minor radius to its core circle. The contacts
+/// are derived from the locally closest pairs of points between the core circle and `shape2`,
+/// found by alternating projections from a few evenly spaced starting points of the core circle.
+/// Because the torus isn’t convex, there can be several such pairs (e.g. with a capsule lying
+/// across the torus). Every contact closer than `prediction` is passed to `f`.
+///
+/// The shape `shape2` is assumed to be convex.
+pub fn torus_shape_contacts<G: ?Sized + PointQuery>(
+    pos12: &Isometry<Real>,
+    torus1: &Torus,
+    shape2: &G,
+    prediction: Real,
+    mut f: impl FnMut(Contact),
+) {
+    let tolerance = torus1.major_radius * 1.0e-4;
+    let mut found = [Point::origin(); NUM_STARTING_POINTS];
+    let mut num_found = 0;
+    let mut start = torus1.closest_core_point(&Point::from(pos12.translation.vector));
+
+    for _ in 0..NUM_STARTING_POINTS {
+        let mut core_pt = start;
+        let mut proj = shape2.project_point(pos12, &core_pt, false);
+        // Rotate the next starting point by 90 degrees around the revolution axis.
+        start = Point::new(start.z, 0.0, -start.x);
+
+        for _ in 0..MAX_ITERATIONS {
+            let new_core_pt = torus1.closest_core_point(&proj.point);
+            let converged = na::distance_squared(&new_core_pt, &core_pt) <= tolerance * tolerance;
+            core_pt = new_core_pt;
+            proj = shape2.project_point(pos12, &core_pt, false);
+
+            if converged {
+                break;
+            }
+        }
+
+        if found[..num_found]
+            .iter()
+            .any(|pt| na::distance_squared(pt, &core_pt) <= tolerance * tolerance)
+        {
+            continue;
+        }
+
+        found[num_found] = core_pt;
+        num_found += 1;
+
+        let signed_dist = core_signed_distance(&core_pt, &proj);
+
+        if signed_dist - torus1.minor_radius > prediction {
+            continue;
+        }
+
+        // The alternating projections may stop at a point of the core circle that isn’t the
+        // closest to `shape2` (e.g. a saddle point for symmetric configurations). Those are
+        // discarded since the actual local minima are reached from the other starting points.
+        let (sin, cos) = LOCAL_MINIMUM_CHECK_ANGLE.sin_cos();
+        let is_local_minimum = [sin, -sin].iter().all(|sin| {
+            let neighbor = Point::new(
+                core_pt.x * cos - core_pt.z * sin,
+                0.0,
+                core_pt.x * sin + core_pt.z * cos,
+            );
+            let neighbor_proj = shape2.project_point(pos12, &neighbor, false);
+            core_signed_distance(&neighbor, &neighbor_proj) >= signed_dist - tolerance
+        });
+
+        if !is_local_minimum {
+            continue;
+        }
+
+        let dpos = proj.point - core_pt;
+        let normal1 = Unit::try_new(
+            if proj.is_inside { -dpos } else { dpos },
+            crate::math::DEFAULT_EPSILON,
+        )
+        .unwrap_or_else(|| Unit::new_normalize(core_pt.coords));
+        let point1 = core_pt + *normal1 * torus1.minor_radius;
+        let point2 = pos12.inverse_transform_point(&proj.point);
+        let normal2 = pos12.inverse_transform_unit_vector(&-normal1);
+        f(Contact::new(
+            point1,
+            point2,
+            normal1,
+            normal2,
+            signed_dist - torus1.minor_radius,
+        ));
+    }
+}
+
+/// The signed distance between a point of the torus’ core circle and its projection on the
+/// other shape, negative if the point is inside of the other shape.
+fn core_signed_distance(core_pt: &Point<Real>, proj: &PointProjection) -> Real {
+    let dist = na::distance(core_pt, &proj.point);
+
+    if proj.is_inside {
+        -dist
+    } else {
+        dist
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::math::{Isometry, Real};
+    use crate::query::details::contact_shape_torus;
+    use crate::shape::{Ball, Torus};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn ball_on_the_torus_axis_touches_the_whole_tube() {
+        let torus = Torus::new(2.0, 0.5);
+        let ball = Ball::new(1.6);
+        let pos12 = Isometry::translation(0.0, -0.2, 0.0);
+        let contact = contact_shape_torus(&pos12, &ball, &torus, 0.0).unwrap();
+        let expected_dist = Real::sqrt(4.04) - 2.1;
+        assert_relative_eq!(contact.dist, expected_dist, epsilon = 1.0e-5);
+        // The ball’s normal points toward the tube’s core circle, located below the ball’s center.
+        assert!(contact.normal1.y < 0.0);
+        assert_relative_eq!(
+            contact.normal1.xz().norm(),
+            2.0 / Real::sqrt(4.04),
+            epsilon = 1.0e-5
+        );
+    }
+}
@@ -5,6 +5,11 @@ pub use self::contact_ball_ball::contact_ball_ball;
 pub use self::contact_ball_convex_polyhedron::{
     contact_ball_convex_polyhedron, contact_convex_polyhedron_ball,
 };
+#[cfg(feature = "dim2")]
+#[cfg(feature = "alloc")]
+pub use self::contact_bezier_spline_shape::{
+    bezier_spline_shape_contacts, contact_bezier_spline_shape, contact_shape_bezier_spline,
+};
 #[cfg(feature = "alloc")]
 pub use self::contact_composite_shape_shape::{
     contact_composite_shape_shape, contact_shape_composite_shape,
@@ -13,19 +18,34 @@ pub use self::contact_cuboid_cuboid::contact_cuboid_cuboid;
 pub use self::contact_halfspace_support_map::{
     contact_halfspace_support_map, contact_support_map_halfspace,
 };
+#[cfg(feature = "alloc")]
+pub use self::contact_sdf_support_map::{
+    contact_sdf_support_map, contact_support_map_sdf, sdf_support_map_contacts,
+};
 pub use self::contact_shape_shape::contact;
 #[cfg(feature = "alloc")]
 pub use self::contact_support_map_support_map::{
     contact_support_map_support_map, contact_support_map_support_map_with_params,
 };
+#[cfg(feature = "dim3")]
+pub use self::contact_torus_shape::{
+    contact_shape_torus, contact_torus_shape, torus_shape_contacts,
+};
 
 mod contact;
 mod contact_ball_ball;
 mod contact_ball_convex_polyhedron;
+#[cfg(feature = "dim2")]
+#[cfg(feature = "alloc")]
+mod contact_bezier_spline_shape;
 #[cfg(feature = "alloc")]
 mod contact_composite_shape_shape;
 mod contact_cuboid_cuboid;
 mod contact_halfspace_support_map;
+#[cfg(feature = "alloc")]
+mod contact_sdf_support_map;
 mod contact_shape_shape;
 #[cfg(feature = "alloc")]
 mod contact_support_map_support_map;
+#[cfg(feature = "dim3")]
+mod contact_torus_shape;
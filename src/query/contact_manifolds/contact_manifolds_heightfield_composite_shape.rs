@@ -11,7 +11,9 @@ use crate::query::visitors::BoundingVolumeIntersectionsVisitor;
 use crate::query::ContactManifold;
 #[cfg(feature = "dim2")]
 use crate::shape::Capsule;
-use crate::shape::{HeightField, Shape, SimdCompositeShape};
+#[cfg(feature = "dim3")]
+use crate::shape::RoundShape;
+use crate::shape::{HeightField, RoundHeightField, Shape, SimdCompositeShape};
 use crate::utils::hashmap::{Entry, HashMap};
 use crate::utils::IsometryOpt;
 
@@ -70,6 +72,65 @@ pub fn contact_manifolds_heightfield_composite_shape<ManifoldData, ContactData>(
 ) where
     ManifoldData: Default + Clone,
     ContactData: Default + Copy,
+{
+    contact_manifolds_heightfield_with_border_composite_shape(
+        dispatcher,
+        pos12,
+        pos21,
+        heightfield1,
+        0.0,
+        composite2,
+        prediction,
+        manifolds,
+        workspace,
+        flipped,
+    )
+}
+
+/// Computes the contact manifold between an heightfield with rounded triangles (or segments in
+/// 2D) and a composite shape.
+pub fn contact_manifolds_round_heightfield_composite_shape<ManifoldData, ContactData>(
+    dispatcher: &dyn PersistentQueryDispatcher<ManifoldData, ContactData>,
+    pos12: &Isometry<Real>,
+    pos21: &Isometry<Real>,
+    heightfield1: &RoundHeightField,
+    composite2: &dyn SimdCompositeShape,
+    prediction: Real,
+    manifolds: &mut Vec<ContactManifold<ManifoldData, ContactData>>,
+    workspace: &mut Option<ContactManifoldsWorkspace>,
+    flipped: bool,
+) where
+    ManifoldData: Default + Clone,
+    ContactData: Default + Copy,
+{
+    contact_manifolds_heightfield_with_border_composite_shape(
+        dispatcher,
+        pos12,
+        pos21,
+        &heightfield1.inner_shape,
+        heightfield1.border_radius,
+        composite2,
+        prediction,
+        manifolds,
+        workspace,
+        flipped,
+    )
+}
+
+fn contact_manifolds_heightfield_with_border_composite_shape<ManifoldData, ContactData>(
+    dispatcher: &dyn PersistentQueryDispatcher<ManifoldData, ContactData>,
+    pos12: &Isometry<Real>,
+    pos21: &Isometry<Real>,
+    heightfield1: &HeightField,
+    border_radius: Real,
+    composite2: &dyn SimdCompositeShape,
+    prediction: Real,
+    manifolds: &mut Vec<ContactManifold<ManifoldData, ContactData>>,
+    workspace: &mut Option<ContactManifoldsWorkspace>,
+    flipped: bool,
+) where
+    ManifoldData: Default + Clone,
+    ContactData: Default + Copy,
 {
     ensure_workspace_exists(workspace);
     let workspace: &mut HeightFieldCompositeShapeContactManifoldsWorkspace =
@@ -82,16 +143,32 @@ pub fn contact_manifolds_heightfield_composite_shape<ManifoldData, ContactData>(
      */
     let qbvh2 = composite2.qbvh();
     let mut stack2 = Vec::new();
-    let ls_aabb2_1 = qbvh2.root_aabb().transform_by(pos12).loosened(prediction);
+    let ls_aabb2_1 = qbvh2
+        .root_aabb()
+        .transform_by(pos12)
+        .loosened(prediction + border_radius);
     let mut old_manifolds = core::mem::take(manifolds);
 
     heightfield1.map_elements_in_local_aabb(&ls_aabb2_1, &mut |leaf1, part1| {
         #[cfg(feature = "dim2")]
-        let sub_shape1 = Capsule::new(part1.a, part1.b, 0.0); // TODO: use a segment instead.
+        let capsule1 = Capsule::new(part1.a, part1.b, border_radius); // TODO: use a segment instead.
+        #[cfg(feature = "dim2")]
+        let sub_shape1: &dyn Shape = &capsule1;
+        #[cfg(feature = "dim3")]
+        let round_part1 = RoundShape {
+            inner_shape: *part1,
+            border_radius,
+        };
         #[cfg(feature = "dim3")]
-        let sub_shape1 = *part1;
+        let sub_shape1: &dyn Shape = if border_radius == 0.0 {
+            part1
+        } else {
+            &round_part1
+        };
 
-        let ls_aabb1_2 = part1.compute_aabb(pos21).loosened(prediction);
+        let ls_aabb1_2 = part1
+            .compute_aabb(pos21)
+            .loosened(prediction + border_radius);
         let mut leaf_fn2 = |leaf2: &u32| {
             composite2.map_part_at(
                 *leaf2,
@@ -142,7 +219,7 @@ pub fn contact_manifolds_heightfield_composite_shape<ManifoldData, ContactData>(
                         let _ = dispatcher.contact_manifold_convex_convex(
                             &part_pos2.inv_mul(pos21),
                             part_shape2,
-                            &sub_shape1,
+                            sub_shape1,
                             normal_constraints2,
                             normal_constraints1,
                             prediction,
@@ -151,7 +228,7 @@ pub fn contact_manifolds_heightfield_composite_shape<ManifoldData, ContactData>(
                     } else {
                         let _ = dispatcher.contact_manifold_convex_convex(
                             &part_pos2.prepend_to(pos12),
-                            &sub_shape1,
+                            sub_shape1,
                             part_shape2,
                             normal_constraints1,
                             normal_constraints2,
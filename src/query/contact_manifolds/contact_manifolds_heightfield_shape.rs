@@ -10,7 +10,9 @@ use crate::query::query_dispatcher::PersistentQueryDispatcher;
 use crate::query::ContactManifold;
 #[cfg(feature = "dim2")]
 use crate::shape::Capsule;
-use crate::shape::{HeightField, Shape};
+#[cfg(feature = "dim3")]
+use crate::shape::RoundShape;
+use crate::shape::{HeightField, RoundHeightField, Shape};
 use crate::utils::hashmap::{Entry, HashMap};
 
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
@@ -73,6 +75,28 @@ pub fn contact_manifolds_heightfield_shape_shapes<ManifoldData, ContactData>(
             workspace,
             true,
         )
+    } else if let Some(heightfield1) = shape1.as_round_heightfield() {
+        contact_manifolds_round_heightfield_shape(
+            dispatcher,
+            pos12,
+            heightfield1,
+            shape2,
+            prediction,
+            manifolds,
+            workspace,
+            false,
+        )
+    } else if let Some(heightfield2) = shape2.as_round_heightfield() {
+        contact_manifolds_round_heightfield_shape(
+            dispatcher,
+            &pos12.inverse(),
+            heightfield2,
+            shape1,
+            prediction,
+            manifolds,
+            workspace,
+            true,
+        )
     }
 }
 
@@ -105,6 +129,61 @@ pub fn contact_manifolds_heightfield_shape<ManifoldData, ContactData>(
 ) where
     ManifoldData: Default + Clone,
     ContactData: Default + Copy,
+{
+    contact_manifolds_heightfield_with_border_shape(
+        dispatcher,
+        pos12,
+        heightfield1,
+        0.0,
+        shape2,
+        prediction,
+        manifolds,
+        workspace,
+        flipped,
+    )
+}
+
+/// Computes the contact manifold between an heightfield with rounded triangles (or segments in
+/// 2D) and an abstract shape.
+pub fn contact_manifolds_round_heightfield_shape<ManifoldData, ContactData>(
+    dispatcher: &dyn PersistentQueryDispatcher<ManifoldData, ContactData>,
+    pos12: &Isometry<Real>,
+    heightfield1: &RoundHeightField,
+    shape2: &dyn Shape,
+    prediction: Real,
+    manifolds: &mut Vec<ContactManifold<ManifoldData, ContactData>>,
+    workspace: &mut Option<ContactManifoldsWorkspace>,
+    flipped: bool,
+) where
+    ManifoldData: Default + Clone,
+    ContactData: Default + Copy,
+{
+    contact_manifolds_heightfield_with_border_shape(
+        dispatcher,
+        pos12,
+        &heightfield1.inner_shape,
+        heightfield1.border_radius,
+        shape2,
+        prediction,
+        manifolds,
+        workspace,
+        flipped,
+    )
+}
+
+fn contact_manifolds_heightfield_with_border_shape<ManifoldData, ContactData>(
+    dispatcher: &dyn PersistentQueryDispatcher<ManifoldData, ContactData>,
+    pos12: &Isometry<Real>,
+    heightfield1: &HeightField,
+    border_radius: Real,
+    shape2: &dyn Shape,
+    prediction: Real,
+    manifolds: &mut Vec<ContactManifold<ManifoldData, ContactData>>,
+    workspace: &mut Option<ContactManifoldsWorkspace>,
+    flipped: bool,
+) where
+    ManifoldData: Default + Clone,
+    ContactData: Default + Copy,
 {
     ensure_workspace_exists(workspace);
     let workspace: &mut HeightFieldShapeContactManifoldsWorkspace =
@@ -116,14 +195,27 @@ pub fn contact_manifolds_heightfield_shape<ManifoldData, ContactData>(
      * Compute interferences.
      */
     // TODO: somehow precompute the Aabb and reuse it?
-    let ls_aabb2 = shape2.compute_aabb(pos12).loosened(prediction);
+    let ls_aabb2 = shape2
+        .compute_aabb(pos12)
+        .loosened(prediction + border_radius);
     let mut old_manifolds = core::mem::take(manifolds);
 
     heightfield1.map_elements_in_local_aabb(&ls_aabb2, &mut |i, part1| {
         #[cfg(feature = "dim2")]
-        let sub_shape1 = Capsule::new(part1.a, part1.b, 0.0); // TODO: use a segment instead.
+        let capsule1 = Capsule::new(part1.a, part1.b, border_radius); // TODO: use a segment instead.
+        #[cfg(feature = "dim2")]
+        let sub_shape1: &dyn Shape = &capsule1;
+        #[cfg(feature = "dim3")]
+        let round_part1 = RoundShape {
+            inner_shape: *part1,
+            border_radius,
+        };
         #[cfg(feature = "dim3")]
-        let sub_shape1 = *part1;
+        let sub_shape1: &dyn Shape = if border_radius == 0.0 {
+            part1
+        } else {
+            &round_part1
+        };
 
         let sub_detector = match workspace.sub_detectors.entry(i) {
             Entry::Occupied(entry) => {
@@ -164,7 +256,7 @@ pub fn contact_manifolds_heightfield_shape<ManifoldData, ContactData>(
             let _ = dispatcher.contact_manifold_convex_convex(
                 &pos12.inverse(),
                 shape2,
-                &sub_shape1,
+                sub_shape1,
                 None,
                 normal_constraints1,
                 prediction,
@@ -173,7 +265,7 @@ pub fn contact_manifolds_heightfield_shape<ManifoldData, ContactData>(
         } else {
             let _ = dispatcher.contact_manifold_convex_convex(
                 pos12,
-                &sub_shape1,
+                sub_shape1,
                 shape2,
                 normal_constraints1,
                 None,
@@ -197,3 +289,77 @@ impl WorkspaceData for HeightFieldShapeContactManifoldsWorkspace {
         Box::new(self.clone())
     }
 }
+
+#[cfg(test)]
+#[cfg(feature = "dim3")]
+mod test {
+    use crate::math::{Isometry, Point, Real, Vector};
+    use crate::query::{
+        self, ContactManifold, DefaultQueryDispatcher, PersistentQueryDispatcher, PointQuery, Ray,
+        RayCast, ShapeCastOptions,
+    };
+    use crate::shape::{Ball, HeightField, RoundHeightField, RoundShape};
+    use alloc::vec::Vec;
+    use approx::assert_relative_eq;
+
+    /// A flat heightfield spanning `[-2, 2]` along `x` and `z`, with a border radius of `0.1`.
+    fn flat_round_heightfield() -> RoundHeightField {
+        RoundShape {
+            inner_shape: HeightField::new(na::DMatrix::zeros(3, 3), Vector::new(4.0, 1.0, 4.0)),
+            border_radius: 0.1,
+        }
+    }
+
+    #[test]
+    fn round_heightfield_queries() {
+        let heightfield = flat_round_heightfield();
+
+        let ray = Ray::new(Point::new(0.3, 5.0, 0.2), -Vector::y());
+        let hit = heightfield
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+            .unwrap();
+        assert_relative_eq!(hit.time_of_impact, 4.9, epsilon = 1.0e-3);
+        assert_relative_eq!(hit.normal, Vector::y(), epsilon = 1.0e-3);
+
+        // Hitting the rounded border, outside of the heightfield.
+        let ray = Ray::new(Point::new(2.05, 5.0, 0.0), -Vector::y());
+        let toi = heightfield.cast_local_ray(&ray, Real::MAX, true).unwrap();
+        assert_relative_eq!(toi, 5.0 - Real::sqrt(0.0075), epsilon = 1.0e-3);
+        // A ray going along the heightfield, right above its border.
+        let ray = Ray::new(Point::new(-3.0, 0.15, 0.0), Vector::x());
+        assert_eq!(heightfield.cast_local_ray(&ray, Real::MAX, true), None);
+
+        let proj = heightfield.project_local_point(&Point::new(0.5, 1.0, 0.5), true);
+        assert!(!proj.is_inside);
+        assert_relative_eq!(proj.point, Point::new(0.5, 0.1, 0.5), epsilon = 1.0e-5);
+        assert!(heightfield.contains_local_point(&Point::new(0.5, 0.05, 0.5)));
+
+        let ball = Ball::new(0.5);
+        let dispatcher = DefaultQueryDispatcher;
+        let mut manifolds: Vec<ContactManifold<(), ()>> = Vec::new();
+        let pos12 = Isometry::translation(0.5, 0.55, 0.5);
+        dispatcher
+            .contact_manifolds(&pos12, &heightfield, &ball, 0.0, &mut manifolds, &mut None)
+            .unwrap();
+        let deepest = manifolds
+            .iter()
+            .flat_map(|m| m.points.iter())
+            .map(|pt| pt.dist)
+            .fold(Real::MAX, Real::min);
+        assert_relative_eq!(deepest, -0.05, epsilon = 1.0e-3);
+
+        let hit = query::cast_shapes(
+            &Isometry::identity(),
+            &Vector::zeros(),
+            &heightfield,
+            &Isometry::translation(0.5, 3.0, 0.5),
+            &-Vector::y(),
+            &ball,
+            ShapeCastOptions::default(),
+        )
+        .unwrap()
+        .unwrap();
+        assert_relative_eq!(hit.time_of_impact, 2.4, epsilon = 1.0e-3);
+        assert_relative_eq!(hit.witness1, Point::new(0.5, 0.1, 0.5), epsilon = 1.0e-3);
+    }
+}
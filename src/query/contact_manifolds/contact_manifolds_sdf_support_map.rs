@@ -0,0 +1,73 @@
+use crate::math::{Isometry, Real};
+use crate::query::details::sdf_support_map_contacts;
+use crate::query::{ContactManifold, TrackedContact};
+use crate::shape::{PackedFeatureId, Sdf, Shape, SupportMap};
+
+/// Computes the contact manifold between a signed distance field and a support-mapped shape, both
+/// represented as a `Shape` trait-object.
+pub fn contact_manifold_sdf_support_map_shapes<ManifoldData, ContactData>(
+    pos12: &Isometry<Real>,
+    shape1: &dyn Shape,
+    shape2: &dyn Shape,
+    prediction: Real,
+    manifold: &mut ContactManifold<ManifoldData, ContactData>,
+) where
+    ContactData: Default + Copy,
+{
+    if let (Some(sdf1), Some(s2)) = (shape1.as_sdf(), shape2.as_support_map()) {
+        contact_manifold_sdf_support_map(pos12, sdf1, s2, prediction, manifold, false);
+    } else if let (Some(s1), Some(sdf2)) = (shape1.as_support_map(), shape2.as_sdf()) {
+        contact_manifold_sdf_support_map(&pos12.inverse(), sdf2, s1, prediction, manifold, true);
+    }
+}
+
+/// Computes the contact manifold between a signed distance field and a support-mapped shape.
+///
+/// The manifold’s normal is the signed distance field’s normal at the deepest contact point.
+pub fn contact_manifold_sdf_support_map<'a, ManifoldData, ContactData, S2>(
+    pos12: &Isometry<Real>,
+    sdf1: &'a Sdf,
+    s2: &'a S2,
+    prediction: Real,
+    manifold: &mut ContactManifold<ManifoldData, ContactData>,
+    flipped: bool,
+) where
+    S2: ?Sized + SupportMap,
+    ContactData: Default + Copy,
+{
+    // We do this clone to perform contact tracking and transfer impulses.
+    // TODO: find a more efficient way of doing this.
+    let old_manifold_points = core::mem::take(&mut manifold.points);
+    let mut deepest = None;
+
+    sdf_support_map_contacts(pos12, sdf1, s2, prediction, |id, contact| {
+        manifold.points.push(TrackedContact::flipped(
+            contact.point1,
+            contact.point2,
+            PackedFeatureId::face(0),
+            PackedFeatureId::vertex(id),
+            contact.dist,
+            flipped,
+        ));
+
+        if deepest
+            .map(|(dist, _, _)| contact.dist < dist)
+            .unwrap_or(true)
+        {
+            deepest = Some((contact.dist, contact.normal1, contact.normal2));
+        }
+    });
+
+    if let Some((_, normal1, normal2)) = deepest {
+        if flipped {
+            manifold.local_n1 = *normal2;
+            manifold.local_n2 = *normal1;
+        } else {
+            manifold.local_n1 = *normal1;
+            manifold.local_n2 = *normal2;
+        }
+    }
+
+    // Transfer impulses.
+    manifold.match_contacts(&old_manifold_points);
+}
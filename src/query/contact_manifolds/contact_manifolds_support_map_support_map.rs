@@ -0,0 +1,78 @@
+use crate::math::{Isometry, Real};
+use crate::query::{
+    self,
+    gjk::{GJKResult, VoronoiSimplex},
+    ContactManifold, TrackedContact,
+};
+use crate::shape::{PackedFeatureId, Shape, SupportMap};
+use na::Unit;
+
+/// Computes the contact manifold between two convex shapes implementing the `SupportMap` trait,
+/// both represented as `Shape` trait-objects.
+pub fn contact_manifold_support_map_support_map_shapes<ManifoldData, ContactData>(
+    pos12: &Isometry<Real>,
+    shape1: &dyn Shape,
+    shape2: &dyn Shape,
+    prediction: Real,
+    manifold: &mut ContactManifold<ManifoldData, ContactData>,
+) where
+    ContactData: Default + Copy,
+{
+    if let (Some(s1), Some(s2)) = (shape1.as_support_map(), shape2.as_support_map()) {
+        contact_manifold_support_map_support_map(pos12, s1, s2, prediction, manifold);
+    }
+}
+
+/// Computes the contact manifold between two convex shapes implementing the `SupportMap` trait.
+///
+/// This generates at most one contact point, so it is best suited for smooth shapes (e.g.
+/// ellipsoids) that don’t implement the `PolygonalFeatureMap` trait.
+pub fn contact_manifold_support_map_support_map<'a, ManifoldData, ContactData, S1, S2>(
+    pos12: &Isometry<Real>,
+    s1: &'a S1,
+    s2: &'a S2,
+    prediction: Real,
+    manifold: &mut ContactManifold<ManifoldData, ContactData>,
+) where
+    S1: ?Sized + SupportMap,
+    S2: ?Sized + SupportMap,
+    ContactData: Default + Copy,
+{
+    let init_dir = Unit::try_new(manifold.local_n1, crate::math::DEFAULT_EPSILON);
+    let contact = query::details::contact_support_map_support_map_with_params(
+        pos12,
+        s1,
+        s2,
+        prediction,
+        &mut VoronoiSimplex::new(),
+        init_dir,
+    );
+
+    let old_manifold_points = core::mem::take(&mut manifold.points);
+
+    match contact {
+        GJKResult::ClosestPoints(p1, p2_1, dir) => {
+            let local_n2 = pos12.inverse_transform_unit_vector(&-dir);
+            manifold.points.push(TrackedContact::new(
+                p1,
+                pos12.inverse_transform_point(&p2_1),
+                PackedFeatureId::face(0),
+                PackedFeatureId::face(0),
+                (p2_1 - p1).dot(&dir),
+            ));
+            manifold.local_n1 = *dir;
+            manifold.local_n2 = *local_n2;
+        }
+        GJKResult::NoIntersection(dir) => {
+            // Use the manifold normal as a cache.
+            manifold.local_n1 = *dir;
+        }
+        _ => {
+            // Reset the cached direction.
+            manifold.local_n1.fill(0.0);
+        }
+    }
+
+    // Transfer impulses.
+    manifold.match_contacts(&old_manifold_points);
+}
@@ -0,0 +1,136 @@
+use crate::math::{Isometry, Real};
+use crate::query::details::torus_shape_contacts;
+use crate::query::{ContactManifold, PointQuery, TrackedContact};
+use crate::shape::{PackedFeatureId, Shape, Torus};
+use alloc::vec::Vec;
+
+/// Computes the contact manifolds between a torus and a convex shape, both represented as
+/// `Shape` trait-objects.
+pub fn contact_manifolds_torus_shape_shapes<ManifoldData, ContactData>(
+    pos12: &Isometry<Real>,
+    shape1: &dyn Shape,
+    shape2: &dyn Shape,
+    prediction: Real,
+    manifolds: &mut Vec<ContactManifold<ManifoldData, ContactData>>,
+) where
+    ManifoldData: Default,
+    ContactData: Default + Copy,
+{
+    if let Some(torus1) = shape1.as_torus() {
+        contact_manifolds_torus_shape(pos12, torus1, shape2, prediction, manifolds, false);
+    } else if let Some(torus2) = shape2.as_torus() {
+        contact_manifolds_torus_shape(
+            &pos12.inverse(),
+            torus2,
+            shape1,
+            prediction,
+            manifolds,
+            true,
+        );
+    }
+}
+
+/// Computes the contact manifolds between a torus and a convex shape.
+///
+/// Because the torus isn’t convex, the contacts may have very different normals. Therefore, one
+/// single-point manifold is generated for each contact.
+pub fn contact_manifolds_torus_shape<ManifoldData, ContactData, G>(
+    pos12: &Isometry<Real>,
+    torus1: &Torus,
+    shape2: &G,
+    prediction: Real,
+    manifolds: &mut Vec<ContactManifold<ManifoldData, ContactData>>,
+    flipped: bool,
+) where
+    G: ?Sized + PointQuery,
+    ManifoldData: Default,
+    ContactData: Default + Copy,
+{
+    // TODO: match the new contacts with the old ones to transfer impulses.
+    manifolds.clear();
+
+    torus_shape_contacts(pos12, torus1, shape2, prediction, |contact| {
+        let mut manifold = ContactManifold::<ManifoldData, ContactData>::new();
+        manifold.points.push(TrackedContact::flipped(
+            contact.point1,
+            contact.point2,
+            PackedFeatureId::face(0),
+            PackedFeatureId::UNKNOWN,
+            contact.dist,
+            flipped,
+        ));
+
+        if flipped {
+            manifold.local_n1 = *contact.normal2;
+            manifold.local_n2 = *contact.normal1;
+        } else {
+            manifold.local_n1 = *contact.normal1;
+            manifold.local_n2 = *contact.normal2;
+        }
+
+        manifolds.push(manifold);
+    });
+}
+
+/// Computes the contact manifold between a torus and a convex shape, both represented as
+/// `Shape` trait-objects.
+///
+/// Only the deepest contact is kept. Use [`contact_manifolds_torus_shape_shapes`] to get all
+/// the contacts.
+pub fn contact_manifold_torus_shape_shapes<ManifoldData, ContactData>(
+    pos12: &Isometry<Real>,
+    shape1: &dyn Shape,
+    shape2: &dyn Shape,
+    prediction: Real,
+    manifold: &mut ContactManifold<ManifoldData, ContactData>,
+) where
+    ContactData: Default + Copy,
+{
+    if let Some(torus1) = shape1.as_torus() {
+        contact_manifold_torus_shape(pos12, torus1, shape2, prediction, manifold, false);
+    } else if let Some(torus2) = shape2.as_torus() {
+        contact_manifold_torus_shape(&pos12.inverse(), torus2, shape1, prediction, manifold, true);
+    }
+}
+
+/// Computes the contact manifold between a torus and a convex shape.
+///
+/// Only the deepest contact is kept. Use [`contact_manifolds_torus_shape`] to get all the
+/// contacts.
+pub fn contact_manifold_torus_shape<ManifoldData, ContactData, G>(
+    pos12: &Isometry<Real>,
+    torus1: &Torus,
+    shape2: &G,
+    prediction: Real,
+    manifold: &mut ContactManifold<ManifoldData, ContactData>,
+    flipped: bool,
+) where
+    G: ?Sized + PointQuery,
+    ContactData: Default + Copy,
+{
+    let old_manifold_points = core::mem::take(&mut manifold.points);
+
+    if let Some(contact) =
+        crate::query::details::contact_torus_shape(pos12, torus1, shape2, prediction)
+    {
+        manifold.points.push(TrackedContact::flipped(
+            contact.point1,
+            contact.point2,
+            PackedFeatureId::face(0),
+            PackedFeatureId::UNKNOWN,
+            contact.dist,
+            flipped,
+        ));
+
+        if flipped {
+            manifold.local_n1 = *contact.normal2;
+            manifold.local_n2 = *contact.normal1;
+        } else {
+            manifold.local_n1 = *contact.normal1;
+            manifold.local_n2 = *contact.normal2;
+        }
+    }
+
+    // Transfer impulses.
+    manifold.match_contacts(&old_manifold_points);
+}
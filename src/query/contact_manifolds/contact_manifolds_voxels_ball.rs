@@ -67,6 +67,7 @@ pub fn contact_manifolds_voxels_ball<'a, ManifoldData, ContactData>(
 
             detect_hit_voxel_ball(
                 *pos12,
+                vox1.linear_id,
                 vox1.center,
                 radius1,
                 vox1.state,
@@ -83,6 +84,7 @@ pub fn contact_manifolds_voxels_ball<'a, ManifoldData, ContactData>(
 
 pub(crate) fn detect_hit_voxel_ball<ManifoldData, ContactData>(
     pos12: Isometry<Real>,
+    linear_id1: u32,
     center1: Point<Real>,
     radius1: Vector<Real>,
     data1: VoxelState,
@@ -114,10 +116,12 @@ pub(crate) fn detect_hit_voxel_ball<ManifoldData, ContactData>(
             let local_n2 = pos12.inverse_transform_vector(&-local_n1);
             let local_p1 = center2_1 - local_n1 * dist_to_voxel;
             let local_p2 = center2 + local_n2 * radius2;
+            // The voxel’s feature is its linearized index so that its user-data can
+            // be retrieved with `Voxels::feature_user_data`.
             let contact_point = TrackedContact::<ContactData>::flipped(
                 local_p1,
                 local_p2,
-                PackedFeatureId::UNKNOWN,
+                PackedFeatureId::face(linear_id1),
                 PackedFeatureId::UNKNOWN,
                 dist,
                 flipped,
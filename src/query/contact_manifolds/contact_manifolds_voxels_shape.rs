@@ -5,7 +5,8 @@ use crate::query::{
     TypedWorkspaceData, WorkspaceData,
 };
 use crate::shape::{
-    AxisMask, Cuboid, RoundShape, Shape, SupportMap, VoxelPrimitiveGeometry, VoxelType, Voxels,
+    AxisMask, Cuboid, PackedFeatureId, RoundShape, Shape, SupportMap, VoxelPrimitiveGeometry,
+    VoxelType, Voxels,
 };
 use crate::utils::hashmap::{Entry, HashMap};
 use crate::utils::IsometryOpt;
@@ -22,6 +23,59 @@ struct SubDetector {
     manifold_id: usize,
     selected_contacts: u32,
     timestamp: bool,
+    // The linearized index of the voxel that selected each contact of the manifold.
+    contact_voxels: Vec<u32>,
+    // The canonical shape’s features of the contacts reported by the manifold, which reports
+    // the voxels’ linearized indices as features instead.
+    canonical_fids: Vec<PackedFeatureId>,
+}
+
+impl SubDetector {
+    /// Replaces the voxels’ features reported by the given manifold, computed by this
+    /// sub-detector at the previous step, with the canonical shape’s features, so that its
+    /// contacts can be matched with the new ones when it gets updated.
+    fn restore_canonical_fids<ManifoldData, ContactData>(
+        &self,
+        manifold: &mut ContactManifold<ManifoldData, ContactData>,
+        flipped: bool,
+    ) {
+        for (pt, fid) in manifold.points.iter_mut().zip(&self.canonical_fids) {
+            let fid1 = if flipped { &mut pt.fid2 } else { &mut pt.fid1 };
+            *fid1 = *fid;
+        }
+    }
+
+    /// Keeps only the contacts of the given manifold selected by this sub-detector, and
+    /// reports the linearized index of the voxel each of them belongs to as its feature.
+    ///
+    /// The canonical shape’s features are saved for [`Self::restore_canonical_fids`].
+    fn finalize_manifold<ManifoldData, ContactData>(
+        &mut self,
+        manifold: &mut ContactManifold<ManifoldData, ContactData>,
+        flipped: bool,
+    ) {
+        let selected_contacts = self.selected_contacts;
+        let mut k = 0;
+        manifold.points.retain(|_| {
+            let keep = (selected_contacts & (1 << k)) != 0;
+            k += 1;
+            keep
+        });
+
+        let selected_voxels = self
+            .contact_voxels
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| (selected_contacts & (1 << i)) != 0)
+            .map(|(_, id)| *id);
+        self.canonical_fids.clear();
+
+        for (pt, voxel_id) in manifold.points.iter_mut().zip(selected_voxels) {
+            let fid1 = if flipped { &mut pt.fid2 } else { &mut pt.fid1 };
+            self.canonical_fids.push(*fid1);
+            *fid1 = PackedFeatureId::face(voxel_id);
+        }
+    }
 }
 
 // NOTE: this is using a similar kind of cache as compound shape and height-field.
@@ -31,7 +85,7 @@ struct SubDetector {
 #[derive(Clone, Default)]
 pub struct VoxelsShapeContactManifoldsWorkspace {
     timestamp: bool,
-    sub_detectors: HashMap<[u32; 2], SubDetector>,
+    sub_detectors: HashMap<[Point<i32>; 2], SubDetector>,
 }
 
 impl VoxelsShapeContactManifoldsWorkspace {
@@ -161,10 +215,7 @@ pub fn contact_manifolds_voxels_shape<ManifoldData, ContactData>(
                 adjust_canon(AxisMask::Z_NEG, 2, &mut key_low, mins[2]);
             }
 
-            let workspace_key = [
-                voxels1.linear_index(key_low),
-                voxels1.linear_index(key_high),
-            ];
+            let workspace_key = [key_low, key_high];
 
             // TODO: could we refactor the workspace system between Voxels, HeightField, and CompoundShape?
             //       (and maybe TriMesh too but it’s using a different approach).
@@ -174,12 +225,11 @@ pub fn contact_manifolds_voxels_shape<ManifoldData, ContactData>(
                         let sub_detector = entry.into_mut();
 
                         if sub_detector.timestamp != new_timestamp {
-                            let manifold = old_manifolds[sub_detector.manifold_id].take();
-                            *sub_detector = SubDetector {
-                                manifold_id: manifolds.len(),
-                                timestamp: new_timestamp,
-                                selected_contacts: 0,
-                            };
+                            let mut manifold = old_manifolds[sub_detector.manifold_id].take();
+                            sub_detector.restore_canonical_fids(&mut manifold, flipped);
+                            sub_detector.manifold_id = manifolds.len();
+                            sub_detector.timestamp = new_timestamp;
+                            sub_detector.selected_contacts = 0;
 
                             manifolds.push(manifold);
                             (sub_detector, false)
@@ -192,6 +242,8 @@ pub fn contact_manifolds_voxels_shape<ManifoldData, ContactData>(
                             manifold_id: manifolds.len(),
                             selected_contacts: 0,
                             timestamp: new_timestamp,
+                            contact_voxels: Vec::new(),
+                            canonical_fids: Vec::new(),
                         };
 
                         let vid = vox1.linear_id;
@@ -296,6 +348,11 @@ pub fn contact_manifolds_voxels_shape<ManifoldData, ContactData>(
                         manifold,
                     );
                 }
+
+                sub_detector.contact_voxels.clear();
+                sub_detector
+                    .contact_voxels
+                    .resize(manifold.points.len(), u32::MAX);
             }
 
             /*
@@ -334,22 +391,20 @@ pub fn contact_manifolds_voxels_shape<ManifoldData, ContactData>(
                 } else {
                     manifold.subshape_pos1.transform_point(&pt.local_p1) - vox1.center.coords
                 };
-                sub_detector.selected_contacts |=
-                    (test_voxel.contains_local_point(&pt_in_voxel_space) as u32) << i;
+                if test_voxel.contains_local_point(&pt_in_voxel_space) {
+                    sub_detector.selected_contacts |= 1 << i;
+                    sub_detector.contact_voxels[i] = vox1.linear_id;
+                }
             }
         }
     }
 
-    // Remove contacts marked as ignored.
-    for sub_detector in workspace.sub_detectors.values() {
+    // Remove contacts marked as ignored, and report the linearized index of the voxel each
+    // contact belongs to as its feature, so that the voxel’s user-data can be retrieved with
+    // `Voxels::feature_user_data`.
+    for sub_detector in workspace.sub_detectors.values_mut() {
         if sub_detector.timestamp == new_timestamp {
-            let manifold = &mut manifolds[sub_detector.manifold_id];
-            let mut k = 0;
-            manifold.points.retain(|_| {
-                let keep = (sub_detector.selected_contacts & (1 << k)) != 0;
-                k += 1;
-                keep
-            });
+            sub_detector.finalize_manifold(&mut manifolds[sub_detector.manifold_id], flipped);
         }
     }
 
@@ -2,6 +2,11 @@ pub use self::contact_manifold::{ContactManifold, TrackedContact};
 pub use self::contact_manifolds_ball_ball::{
     contact_manifold_ball_ball, contact_manifold_ball_ball_shapes,
 };
+#[cfg(feature = "dim2")]
+pub use self::contact_manifolds_bezier_spline_shape::{
+    contact_manifold_bezier_spline_shape, contact_manifold_bezier_spline_shape_shapes,
+    contact_manifolds_bezier_spline_shape, contact_manifolds_bezier_spline_shape_shapes,
+};
 pub use self::contact_manifolds_capsule_capsule::{
     contact_manifold_capsule_capsule, contact_manifold_capsule_capsule_shapes,
 };
@@ -22,13 +27,28 @@ pub use self::contact_manifolds_cuboid_triangle::{
 pub use self::contact_manifolds_halfspace_pfm::{
     contact_manifold_halfspace_pfm, contact_manifold_halfspace_pfm_shapes,
 };
-pub use self::contact_manifolds_heightfield_composite_shape::contact_manifolds_heightfield_composite_shape;
+pub use self::contact_manifolds_heightfield_composite_shape::{
+    contact_manifolds_heightfield_composite_shape,
+    contact_manifolds_round_heightfield_composite_shape,
+};
 pub use self::contact_manifolds_heightfield_shape::{
     contact_manifolds_heightfield_shape, contact_manifolds_heightfield_shape_shapes,
+    contact_manifolds_round_heightfield_shape,
 };
 pub use self::contact_manifolds_pfm_pfm::{
     contact_manifold_pfm_pfm, contact_manifold_pfm_pfm_shapes,
 };
+pub use self::contact_manifolds_sdf_support_map::{
+    contact_manifold_sdf_support_map, contact_manifold_sdf_support_map_shapes,
+};
+pub use self::contact_manifolds_support_map_support_map::{
+    contact_manifold_support_map_support_map, contact_manifold_support_map_support_map_shapes,
+};
+#[cfg(feature = "dim3")]
+pub use self::contact_manifolds_torus_shape::{
+    contact_manifold_torus_shape, contact_manifold_torus_shape_shapes,
+    contact_manifolds_torus_shape, contact_manifolds_torus_shape_shapes,
+};
 pub use self::contact_manifolds_trimesh_shape::{
     contact_manifolds_trimesh_shape, contact_manifolds_trimesh_shape_shapes,
 };
@@ -52,6 +72,8 @@ use {
 
 mod contact_manifold;
 mod contact_manifolds_ball_ball;
+#[cfg(feature = "dim2")]
+mod contact_manifolds_bezier_spline_shape;
 mod contact_manifolds_capsule_capsule;
 mod contact_manifolds_convex_ball;
 // mod contact_manifolds_cuboid_capsule;
@@ -63,6 +85,10 @@ mod contact_manifolds_halfspace_pfm;
 mod contact_manifolds_heightfield_composite_shape;
 mod contact_manifolds_heightfield_shape;
 mod contact_manifolds_pfm_pfm;
+mod contact_manifolds_sdf_support_map;
+mod contact_manifolds_support_map_support_map;
+#[cfg(feature = "dim3")]
+mod contact_manifolds_torus_shape;
 mod contact_manifolds_trimesh_shape;
 mod contact_manifolds_voxels_ball;
 mod contact_manifolds_voxels_shape;
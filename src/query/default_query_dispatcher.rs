@@ -176,8 +176,39 @@ impl QueryDispatcher for DefaultQueryDispatcher {
                 pos12, shape1, b2, prediction,
             ))
         } else {
+            #[cfg(feature = "dim3")]
+            if let (Some(t1), true) = (shape1.as_torus(), shape2.is_convex()) {
+                return Ok(query::details::contact_torus_shape(
+                    pos12, t1, shape2, prediction,
+                ));
+            } else if let (true, Some(t2)) = (shape1.is_convex(), shape2.as_torus()) {
+                return Ok(query::details::contact_shape_torus(
+                    pos12, shape1, t2, prediction,
+                ));
+            }
+
+            #[cfg(all(feature = "dim2", feature = "alloc"))]
+            if let (Some(c1), true) = (shape1.as_bezier_spline(), shape2.is_convex()) {
+                return Ok(query::details::contact_bezier_spline_shape(
+                    pos12, c1, shape2, prediction,
+                ));
+            } else if let (true, Some(c2)) = (shape1.is_convex(), shape2.as_bezier_spline()) {
+                return Ok(query::details::contact_shape_bezier_spline(
+                    pos12, shape1, c2, prediction,
+                ));
+            }
+
             #[cfg(feature = "alloc")]
-            if let (Some(s1), Some(s2)) = (shape1.as_support_map(), shape2.as_support_map()) {
+            if let (Some(sdf1), Some(s2)) = (shape1.as_sdf(), shape2.as_support_map()) {
+                return Ok(query::details::contact_sdf_support_map(
+                    pos12, sdf1, s2, prediction,
+                ));
+            } else if let (Some(s1), Some(sdf2)) = (shape1.as_support_map(), shape2.as_sdf()) {
+                return Ok(query::details::contact_support_map_sdf(
+                    pos12, s1, sdf2, prediction,
+                ));
+            } else if let (Some(s1), Some(s2)) = (shape1.as_support_map(), shape2.as_support_map())
+            {
                 return Ok(query::details::contact_support_map_support_map(
                     pos12, s1, s2, prediction,
                 ));
@@ -327,6 +358,24 @@ impl QueryDispatcher for DefaultQueryDispatcher {
                     heightfield2,
                     options,
                 );
+            } else if let Some(heightfield1) = shape1.as_round_heightfield() {
+                return query::details::cast_shapes_round_heightfield_shape(
+                    self,
+                    pos12,
+                    local_vel12,
+                    heightfield1,
+                    shape2,
+                    options,
+                );
+            } else if let Some(heightfield2) = shape2.as_round_heightfield() {
+                return query::details::cast_shapes_shape_round_heightfield(
+                    self,
+                    pos12,
+                    local_vel12,
+                    shape1,
+                    heightfield2,
+                    options,
+                );
             } else if let (Some(s1), Some(s2)) = (shape1.as_support_map(), shape2.as_support_map())
             {
                 return Ok(query::details::cast_shapes_support_map_support_map(
@@ -532,6 +581,44 @@ where
                     );
                 }
             }
+            (ShapeType::RoundHeightField, _) => {
+                if let Some(composite2) = composite2 {
+                    contact_manifolds_round_heightfield_composite_shape(
+                        self,
+                        pos12,
+                        &pos12.inverse(),
+                        shape1.as_round_heightfield().unwrap(),
+                        composite2,
+                        prediction,
+                        manifolds,
+                        workspace,
+                        false,
+                    )
+                } else {
+                    contact_manifolds_heightfield_shape_shapes(
+                        self, pos12, shape1, shape2, prediction, manifolds, workspace,
+                    );
+                }
+            }
+            (_, ShapeType::RoundHeightField) => {
+                if let Some(composite1) = composite1 {
+                    contact_manifolds_round_heightfield_composite_shape(
+                        self,
+                        &pos12.inverse(),
+                        pos12,
+                        shape2.as_round_heightfield().unwrap(),
+                        composite1,
+                        prediction,
+                        manifolds,
+                        workspace,
+                        true,
+                    )
+                } else {
+                    contact_manifolds_heightfield_shape_shapes(
+                        self, pos12, shape1, shape2, prediction, manifolds, workspace,
+                    );
+                }
+            }
             (ShapeType::Voxels, ShapeType::Ball) | (ShapeType::Ball, ShapeType::Voxels) => {
                 contact_manifolds_voxels_ball_shapes(pos12, shape1, shape2, prediction, manifolds)
             }
@@ -540,6 +627,20 @@ where
                     self, pos12, shape1, shape2, prediction, manifolds, workspace,
                 )
             }
+            #[cfg(feature = "dim3")]
+            (ShapeType::Torus, _) | (_, ShapeType::Torus)
+                if shape1.is_convex() || shape2.is_convex() =>
+            {
+                contact_manifolds_torus_shape_shapes(pos12, shape1, shape2, prediction, manifolds)
+            }
+            #[cfg(feature = "dim2")]
+            (ShapeType::BezierSpline, _) | (_, ShapeType::BezierSpline)
+                if shape1.is_convex() || shape2.is_convex() =>
+            {
+                contact_manifolds_bezier_spline_shape_shapes(
+                    pos12, shape1, shape2, prediction, manifolds,
+                )
+            }
             _ => {
                 if let Some(composite1) = composite1 {
                     contact_manifolds_composite_shape_shape(
@@ -606,6 +707,29 @@ where
             (ShapeType::Capsule, ShapeType::Capsule) => {
                 contact_manifold_capsule_capsule_shapes(pos12, shape1, shape2, prediction, manifold)
             }
+            (ShapeType::Sdf, _) | (_, ShapeType::Sdf) => {
+                if shape1.as_support_map().is_some() || shape2.as_support_map().is_some() {
+                    contact_manifold_sdf_support_map_shapes(pos12, shape1, shape2, prediction, manifold)
+                } else {
+                    return Err(Unsupported)
+                }
+            }
+            #[cfg(feature = "dim3")]
+            (ShapeType::Torus, _) | (_, ShapeType::Torus) => {
+                if shape1.is_convex() || shape2.is_convex() {
+                    contact_manifold_torus_shape_shapes(pos12, shape1, shape2, prediction, manifold)
+                } else {
+                    return Err(Unsupported)
+                }
+            }
+            #[cfg(feature = "dim2")]
+            (ShapeType::BezierSpline, _) | (_, ShapeType::BezierSpline) => {
+                if shape1.is_convex() || shape2.is_convex() {
+                    contact_manifold_bezier_spline_shape_shapes(pos12, shape1, shape2, prediction, manifold)
+                } else {
+                    return Err(Unsupported)
+                }
+            }
             (_, ShapeType::Ball) | (ShapeType::Ball, _) => {
                 contact_manifold_convex_ball_shapes(pos12, shape1, shape2, normal_constraints1, normal_constraints2, prediction, manifold)
             }
@@ -652,6 +776,12 @@ where
                     contact_manifold_pfm_pfm(
                         pos12, pfm1.0, pfm1.1, normal_constraints1, pfm2.0, pfm2.1, normal_constraints2, prediction, manifold,
                     )
+                } else if let (Some(s1), Some(s2)) = (
+                    shape1.as_support_map(),
+                    shape2.as_support_map(),
+                ) {
+                    // Smooth shapes (e.g. ellipsoids) don’t have polygonal features.
+                    contact_manifold_support_map_support_map(pos12, s1, s2, prediction, manifold)
                 } else {
                     return Err(Unsupported);
                 }
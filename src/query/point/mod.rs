@@ -12,6 +12,9 @@ pub use self::point_support_map::local_point_projection_on_support_map;
 
 mod point_aabb;
 mod point_ball;
+#[cfg(feature = "dim2")]
+#[cfg(feature = "alloc")]
+mod point_bezier_spline;
 mod point_bounding_sphere;
 mod point_capsule;
 #[cfg(feature = "alloc")]
@@ -21,17 +24,35 @@ mod point_cone;
 mod point_cuboid;
 #[cfg(feature = "dim3")]
 mod point_cylinder;
+mod point_ellipsoid;
 mod point_halfspace;
 #[cfg(feature = "alloc")]
 mod point_heightfield;
+mod point_minkowski_sum;
+#[cfg(feature = "dim2")]
+#[cfg(feature = "alloc")]
+mod point_polygon;
 #[doc(hidden)]
 pub mod point_query;
+#[cfg(feature = "alloc")]
+mod point_round_composite_shape;
 mod point_round_shape;
+mod point_scaled;
+#[cfg(feature = "alloc")]
+mod point_sdf;
 mod point_segment;
 #[cfg(feature = "alloc")]
 mod point_support_map;
+#[cfg(feature = "alloc")]
+mod point_swept_shape;
+mod point_tapered_capsule;
+#[cfg(feature = "dim3")]
+#[cfg(feature = "alloc")]
+mod point_tetmesh;
 #[cfg(feature = "dim3")]
 mod point_tetrahedron;
+#[cfg(feature = "dim3")]
+mod point_torus;
 mod point_triangle;
 #[cfg(feature = "alloc")]
 mod point_voxels;
@@ -0,0 +1,27 @@
+use crate::math::{Point, Real};
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{BezierSpline, FeatureId};
+
+impl PointQuery for BezierSpline {
+    #[inline]
+    fn project_local_point(&self, point: &Point<Real>, _solid: bool) -> PointProjection {
+        self.project_local_point_and_get_feature(point).0
+    }
+
+    /// Projects a point on this curve.
+    ///
+    /// The returned feature is `FeatureId::Face(i)` where `i` is the index of the curve segment
+    /// the point is projected on.
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        point: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        // The curve has no interior, so the point is never inside of it.
+        let (i, t) = self.closest_parameter(point);
+        (
+            PointProjection::new(false, self.point_at(i, t)),
+            FeatureId::Face(i),
+        )
+    }
+}
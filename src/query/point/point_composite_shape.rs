@@ -11,7 +11,7 @@ use crate::shape::{
 use na;
 use simba::simd::{SimdBool as _, SimdPartialOrd, SimdValue};
 
-use crate::shape::{Compound, Polyline};
+use crate::shape::{Compound, Polyline, SphereSet};
 
 impl PointQuery for Polyline {
     #[inline]
@@ -123,6 +123,36 @@ impl PointQuery for Compound {
     }
 }
 
+impl PointQuery for SphereSet {
+    #[inline]
+    fn project_local_point(&self, point: &Point<Real>, solid: bool) -> PointProjection {
+        let mut visitor = PointCompositeShapeProjBestFirstVisitor::new(self, point, solid);
+        self.qbvh().traverse_best_first(&mut visitor).unwrap().1 .0
+    }
+
+    /// Projects a point on this sphere set.
+    ///
+    /// The returned feature is `FeatureId::Face(i)` where `i` is the index of the sphere the
+    /// point is projected on.
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        point: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        let mut visitor =
+            PointCompositeShapeProjWithFeatureBestFirstVisitor::new(self, point, false);
+        let (proj, (id, _)) = self.qbvh().traverse_best_first(&mut visitor).unwrap().1;
+        (proj, FeatureId::Face(id))
+    }
+
+    #[inline]
+    fn contains_local_point(&self, point: &Point<Real>) -> bool {
+        let mut visitor = CompositePointContainmentTest::new(self, point);
+        let _ = self.qbvh().traverse_depth_first(&mut visitor);
+        visitor.found
+    }
+}
+
 impl PointQueryWithLocation for Polyline {
     type Location = (u32, SegmentPointLocation);
 
@@ -0,0 +1,138 @@
+use crate::math::{Point, Real, Vector, DIM};
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{Ellipsoid, FeatureId};
+
+/// The maximum number of bisection steps performed when solving for the projected point.
+///
+/// This is large enough for the bisection to reach the floating-point precision limit.
+const MAX_BISECTION_STEPS: usize = 1100;
+
+impl PointQuery for Ellipsoid {
+    #[inline]
+    fn project_local_point(&self, pt: &Point<Real>, solid: bool) -> PointProjection {
+        let inside = self.contains_local_point(pt);
+
+        if inside && solid {
+            PointProjection::new(true, *pt)
+        } else {
+            PointProjection::new(inside, project_on_ellipsoid_boundary(&self.radii, pt))
+        }
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        pt: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        (self.project_local_point(pt, false), FeatureId::Face(0))
+    }
+
+    #[inline]
+    fn contains_local_point(&self, pt: &Point<Real>) -> bool {
+        Ellipsoid::contains_local_point(self, pt)
+    }
+}
+
+/// Computes the point on the ellipsoid’s boundary closest to `pt`.
+///
+/// The projection `x` of a point `y` satisfies `x_i = r_i² y_i / (t + r_i²)` where `t` is the
+/// root of `F(t) = Σ (r_i y_i / (t + r_i²))² - 1`. Working with the absolute values of the
+/// coordinates, `F` is strictly decreasing on `]-min(r_i²), +∞[` so its root is found by
+/// bisection, as described in “Distance from a Point to an Ellipse, an Ellipsoid, or a
+/// Hyperellipsoid” by David Eberly.
+fn project_on_ellipsoid_boundary(radii: &Vector<Real>, pt: &Point<Real>) -> Point<Real> {
+    // Points lying on one of the principal planes are nudged off of it so that every term of
+    // `F` contributes. This only affects the result for points on the ellipsoid’s medial
+    // surface, where the projection isn’t unique anyway.
+    let min_coord = radii.max() * crate::math::DEFAULT_EPSILON;
+    let y = pt.coords.abs().map(|y| y.max(min_coord));
+    let radii_sq = radii.component_mul(radii);
+    let ry = radii.component_mul(&y);
+
+    let f = |t: Real| {
+        (0..DIM)
+            .map(|i| {
+                let ratio = ry[i] / (t + radii_sq[i]);
+                ratio * ratio
+            })
+            .sum::<Real>()
+            - 1.0
+    };
+
+    // Bracket the root.
+    let i_min = radii.imin();
+    let mut t0 = -radii_sq[i_min] + ry[i_min];
+    let mut t1 = ry.norm();
+
+    for _ in 0..MAX_BISECTION_STEPS {
+        let mid = (t0 + t1) / 2.0;
+
+        if mid == t0 || mid == t1 {
+            break;
+        }
+
+        if f(mid) > 0.0 {
+            t0 = mid;
+        } else {
+            t1 = mid;
+        }
+    }
+
+    let t = (t0 + t1) / 2.0;
+    let projected = Vector::from_fn(|i, _| radii_sq[i] * y[i] / (t + radii_sq[i]));
+    Point::from(projected.zip_map(&pt.coords, |p, s| p.copysign(s)))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::math::{Point, Real, Vector};
+    use crate::query::PointQuery;
+    use crate::shape::Ellipsoid;
+    use approx::assert_relative_eq;
+
+    #[cfg(feature = "dim2")]
+    fn radii() -> Vector<Real> {
+        Vector::new(3.0, 1.0)
+    }
+    #[cfg(feature = "dim3")]
+    fn radii() -> Vector<Real> {
+        Vector::new(3.0, 1.0, 2.0)
+    }
+
+    #[test]
+    fn ellipsoid_point_projection() {
+        let ellipsoid = Ellipsoid::new(radii());
+
+        // Points on the principal axes project along those axes.
+        let proj = ellipsoid.project_local_point(&Point::from(Vector::x() * 5.0), true);
+        assert!(!proj.is_inside);
+        assert_relative_eq!(proj.point, Point::from(Vector::x() * 3.0), epsilon = 1.0e-5);
+        let proj = ellipsoid.project_local_point(&Point::from(Vector::y() * -0.5), false);
+        assert!(proj.is_inside);
+        assert_relative_eq!(
+            proj.point,
+            Point::from(Vector::y() * -1.0),
+            epsilon = 1.0e-5
+        );
+        assert!(ellipsoid.contains_point(&Default::default(), &Point::from(Vector::x() * 2.9)));
+
+        // The projection is on the boundary, and the offset to the original point is
+        // aligned with the boundary’s normal.
+        for pt in [
+            Vector::repeat(2.0),
+            Vector::repeat(-0.3),
+            -Vector::repeat(4.0),
+        ] {
+            let pt = Point::from(pt);
+            let proj = ellipsoid.project_local_point(&pt, false).point;
+            assert_relative_eq!(
+                proj.coords.component_div(&ellipsoid.radii).norm(),
+                1.0,
+                epsilon = 1.0e-5
+            );
+            let normal = ellipsoid.normal_at_point(&proj).unwrap();
+            let offset = pt - proj;
+            assert_relative_eq!(offset.normalize().dot(&normal).abs(), 1.0, epsilon = 1.0e-4);
+        }
+    }
+}
@@ -59,8 +59,27 @@ impl PointQuery for HeightField {
         &self,
         point: &Point<Real>,
     ) -> (PointProjection, FeatureId) {
-        // TODO: compute the feature properly.
-        (self.project_local_point(point, false), FeatureId::Unknown)
+        let mut smallest_dist = Real::MAX;
+        let mut best_proj = PointProjection::new(false, *point);
+        let mut best_feature = FeatureId::Unknown;
+
+        #[cfg(feature = "dim2")]
+        let iter = (0..self.num_cells()).filter_map(|i| Some((i as u32, self.segment_at(i)?)));
+        #[cfg(feature = "dim3")]
+        let iter = (0..(self.nrows() * self.ncols() * 2) as u32)
+            .filter_map(|id| Some((id, self.triangle_at_id(id)?)));
+        for (id, elt) in iter {
+            let proj = elt.project_local_point(point, false);
+            let dist = na::distance_squared(point, &proj.point);
+
+            if dist < smallest_dist {
+                smallest_dist = dist;
+                best_proj = proj;
+                best_feature = FeatureId::Face(id);
+            }
+        }
+
+        (best_proj, best_feature)
     }
 
     // TODO: implement distance_to_point too?
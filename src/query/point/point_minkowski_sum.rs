@@ -0,0 +1,29 @@
+use crate::math::{Point, Real};
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{FeatureId, MinkowskiSum, SupportMap};
+
+impl<S1: SupportMap, S2: SupportMap> PointQuery for MinkowskiSum<S1, S2> {
+    #[inline]
+    fn project_local_point(&self, point: &Point<Real>, solid: bool) -> PointProjection {
+        #[cfg(not(feature = "alloc"))]
+        return unimplemented!(
+            "The projection of points on a Minkowski sum isn't supported without alloc yet."
+        );
+
+        #[cfg(feature = "alloc")]
+        return crate::query::details::local_point_projection_on_support_map(
+            self,
+            &mut crate::query::gjk::VoronoiSimplex::new(),
+            point,
+            solid,
+        );
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        point: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        (self.project_local_point(point, false), FeatureId::Unknown)
+    }
+}
@@ -0,0 +1,72 @@
+use crate::math::{Point, Real};
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{FeatureId, Polygon};
+
+impl PointQuery for Polygon {
+    #[inline]
+    fn project_local_point(&self, point: &Point<Real>, solid: bool) -> PointProjection {
+        if solid && self.contains_local_point(point) {
+            PointProjection::new(true, *point)
+        } else {
+            self.project_local_point_and_get_feature(point).0
+        }
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        point: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        let (mut proj, feature) = self.boundary().project_local_point_and_get_feature(point);
+        proj.is_inside = self.contains_local_point(point);
+        (proj, feature)
+    }
+
+    #[inline]
+    fn contains_local_point(&self, point: &Point<Real>) -> bool {
+        self.convex_decomposition().contains_local_point(point)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::math::Point;
+    use crate::query::PointQuery;
+    use crate::shape::Polygon;
+    use alloc::vec;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn polygon_point_projection() {
+        let outer = vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 4.0),
+            Point::new(0.0, 4.0),
+        ];
+        let hole = vec![
+            Point::new(1.0, 1.0),
+            Point::new(3.0, 1.0),
+            Point::new(3.0, 3.0),
+            Point::new(1.0, 3.0),
+        ];
+        let polygon = Polygon::new(outer, vec![hole]).unwrap();
+
+        // Inside of the solid part.
+        let pt = Point::new(0.25, 2.0);
+        let proj = polygon.project_local_point(&pt, true);
+        assert!(proj.is_inside);
+        assert_eq!(proj.point, pt);
+        let proj = polygon.project_local_point(&pt, false);
+        assert!(proj.is_inside);
+        assert_relative_eq!(proj.point, Point::new(0.0, 2.0));
+        assert_relative_eq!(polygon.distance_to_local_point(&pt, false), -0.25);
+
+        // Inside of the hole.
+        let pt = Point::new(2.0, 2.5);
+        let proj = polygon.project_local_point(&pt, true);
+        assert!(!proj.is_inside);
+        assert_relative_eq!(proj.point, Point::new(2.0, 3.0));
+        assert_relative_eq!(polygon.distance_to_local_point(&pt, true), 0.5);
+    }
+}
@@ -0,0 +1,99 @@
+use crate::math::{Point, Real, Vector};
+use crate::query::{PointProjection, PointQuery};
+#[cfg(feature = "dim3")]
+use crate::shape::RoundPolyline;
+use crate::shape::{FeatureId, RoundHeightField, RoundTriMesh};
+
+impl PointQuery for RoundTriMesh {
+    #[inline]
+    fn project_local_point(&self, point: &Point<Real>, solid: bool) -> PointProjection {
+        let proj = self.mesh().project_local_point(point, solid);
+        dilate_projection(point, proj, self.border_radius(), solid)
+    }
+
+    /// Projects a point on this round triangle mesh.
+    ///
+    /// The returned feature is the feature of the inner triangle mesh the point is projected on.
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        point: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        let (proj, feature) = self.mesh().project_local_point_and_get_feature(point);
+        (
+            dilate_projection(point, proj, self.border_radius(), false),
+            feature,
+        )
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl PointQuery for RoundPolyline {
+    #[inline]
+    fn project_local_point(&self, point: &Point<Real>, solid: bool) -> PointProjection {
+        let proj = self.polyline().project_local_point(point, solid);
+        dilate_projection(point, proj, self.radius(), solid)
+    }
+
+    /// Projects a point on this round polyline.
+    ///
+    /// The returned feature is `FeatureId::Face(i)` where `i` is the index of the capsule the
+    /// point is projected on.
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        point: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        let (proj, feature) = self.polyline().project_local_point_and_get_feature(point);
+        let feature = match feature {
+            FeatureId::Edge(i) => FeatureId::Face(i),
+            _ => feature,
+        };
+        (
+            dilate_projection(point, proj, self.radius(), false),
+            feature,
+        )
+    }
+}
+
+impl PointQuery for RoundHeightField {
+    #[inline]
+    fn project_local_point(&self, point: &Point<Real>, solid: bool) -> PointProjection {
+        let proj = self.inner_shape.project_local_point(point, solid);
+        dilate_projection(point, proj, self.border_radius, solid)
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        point: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        // TODO: compute the feature properly.
+        (self.project_local_point(point, false), FeatureId::Unknown)
+    }
+}
+
+/// Converts the projection of `point` on a shape into its projection on that shape dilated
+/// by a sphere with radius `border_radius`.
+fn dilate_projection(
+    point: &Point<Real>,
+    proj: PointProjection,
+    border_radius: Real,
+    solid: bool,
+) -> PointProjection {
+    let is_inside = proj.is_inside || na::distance(point, &proj.point) <= border_radius;
+
+    if is_inside && solid {
+        return PointProjection::new(true, *point);
+    }
+
+    let dir = if proj.is_inside {
+        proj.point - point
+    } else {
+        point - proj.point
+    };
+    // If the point lies exactly on the inner shape, the projection direction is
+    // arbitrary, so we just pick the `y` axis, i.e., the up direction of heightfields.
+    let dir = dir.try_normalize(Real::EPSILON).unwrap_or_else(Vector::y);
+    PointProjection::new(is_inside, proj.point + dir * border_radius)
+}
@@ -0,0 +1,41 @@
+use crate::math::{Point, Real};
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{FeatureId, Scaled, SupportMap};
+
+impl<S: SupportMap + PointQuery> PointQuery for Scaled<S> {
+    #[inline]
+    fn project_local_point(&self, point: &Point<Real>, solid: bool) -> PointProjection {
+        // Projections aren’t preserved by non-uniform scaling so they are computed with GJK,
+        // based on the scaled support function.
+        if solid && self.contains_local_point(point) {
+            return PointProjection::new(true, *point);
+        }
+
+        #[cfg(not(feature = "alloc"))]
+        return unimplemented!(
+            "The projection of points on a scaled shape isn't supported without alloc yet."
+        );
+
+        #[cfg(feature = "alloc")]
+        return crate::query::details::local_point_projection_on_support_map(
+            self,
+            &mut crate::query::gjk::VoronoiSimplex::new(),
+            point,
+            solid,
+        );
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        point: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        (self.project_local_point(point, false), FeatureId::Unknown)
+    }
+
+    #[inline]
+    fn contains_local_point(&self, point: &Point<Real>) -> bool {
+        self.inner_shape()
+            .contains_local_point(&self.unscale_point(point))
+    }
+}
@@ -0,0 +1,62 @@
+use crate::math::{Point, Real};
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{FeatureId, Sdf};
+
+/// The maximum number of Newton iterations used to reach the zero isosurface.
+const MAX_PROJECTION_ITERATIONS: usize = 32;
+
+impl PointQuery for Sdf {
+    #[inline]
+    fn project_local_point(&self, pt: &Point<Real>, solid: bool) -> PointProjection {
+        let distance = self.distance_at_point(pt);
+        let is_inside = distance <= 0.0;
+
+        if is_inside && solid {
+            return PointProjection::new(true, *pt);
+        }
+
+        let domain = self.domain();
+        let tolerance = self.cell_size().min() * 1.0e-3;
+        let mut projection = self.clamp_to_domain(pt);
+
+        // Newton iterations toward the zero isosurface, staying within the grid’s domain.
+        for _ in 0..MAX_PROJECTION_ITERATIONS {
+            let value = self.distance_at_point(&projection);
+
+            if value.abs() <= tolerance {
+                break;
+            }
+
+            let gradient = self.gradient_at_point(&projection);
+            let gradient_norm_squared = gradient.norm_squared();
+
+            if gradient_norm_squared <= crate::math::DEFAULT_EPSILON {
+                break;
+            }
+
+            projection =
+                self.clamp_to_domain(&(projection - gradient * (value / gradient_norm_squared)));
+        }
+
+        if is_inside {
+            // The shape is clipped by the grid’s domain, so its boundary may be closer.
+            let boundary = domain.project_local_point(pt, false).point;
+
+            if self.distance_at_point(&boundary) <= 0.0
+                && na::distance_squared(pt, &boundary) < na::distance_squared(pt, &projection)
+            {
+                projection = boundary;
+            }
+        }
+
+        PointProjection::new(is_inside, projection)
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        pt: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        (self.project_local_point(pt, false), FeatureId::Unknown)
+    }
+}
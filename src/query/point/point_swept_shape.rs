@@ -0,0 +1,24 @@
+use crate::math::{Point, Real};
+use crate::query::gjk::VoronoiSimplex;
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{FeatureId, SweptShape};
+
+impl PointQuery for SweptShape {
+    #[inline]
+    fn project_local_point(&self, point: &Point<Real>, solid: bool) -> PointProjection {
+        crate::query::details::local_point_projection_on_support_map(
+            self,
+            &mut VoronoiSimplex::new(),
+            point,
+            solid,
+        )
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        point: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        (self.project_local_point(point, false), FeatureId::Unknown)
+    }
+}
@@ -0,0 +1,113 @@
+use crate::math::{Point, Real, Vector};
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{FeatureId, TaperedCapsule};
+
+impl PointQuery for TaperedCapsule {
+    #[inline]
+    fn project_local_point(&self, pt: &Point<Real>, solid: bool) -> PointProjection {
+        let a = self.segment.a;
+        let b = self.segment.b;
+        let (ra, rb) = (self.radius_a, self.radius_b);
+
+        if self.is_degenerate() {
+            // The tapered capsule is just its biggest ball.
+            return if ra >= rb {
+                project_on_ball(pt, &a, ra, -self.axis(), solid)
+            } else {
+                project_on_ball(pt, &b, rb, self.axis(), solid)
+            };
+        }
+
+        let height = self.height();
+        let axis = self.axis();
+        let (sin, cos) = self.slope();
+
+        // Express the point in the plane containing the principal axis: `x` is the coordinate
+        // along the axis (relative to `a`), and `y >= 0` its distance to the axis.
+        let dpt = pt - a;
+        let x = dpt.dot(&axis);
+        let radial = dpt - axis * x;
+        let y = radial.norm();
+
+        // The lateral boundary starts at `a + ra * (sin, cos)` and ends at `b + rb * (sin, cos)`.
+        // Its parametrization along its direction `(cos, -sin)` tells on which part of the
+        // boundary the point projects.
+        let t = x * cos - y * sin;
+
+        if t < 0.0 {
+            project_on_ball(pt, &a, ra, -axis, solid)
+        } else if t > height * cos {
+            project_on_ball(pt, &b, rb, axis, solid)
+        } else {
+            let dist = x * sin + y * cos - ra;
+            let inside = dist <= 0.0;
+
+            if solid && inside {
+                return PointProjection::new(true, *pt);
+            }
+
+            let radial_dir = radial
+                .try_normalize(Real::EPSILON)
+                .unwrap_or_else(|| self.any_radial_direction());
+            let normal = axis * sin + radial_dir * cos;
+            PointProjection::new(inside, pt - normal * dist)
+        }
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        pt: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        (self.project_local_point(pt, false), FeatureId::Face(0))
+    }
+}
+
+impl TaperedCapsule {
+    /// The unit direction from `a` toward `b`, or the `y` axis if they are equal.
+    fn axis(&self) -> Vector<Real> {
+        (self.segment.b - self.segment.a)
+            .try_normalize(Real::EPSILON)
+            .unwrap_or_else(Vector::y)
+    }
+
+    /// A unit direction orthogonal to the principal axis.
+    fn any_radial_direction(&self) -> Vector<Real> {
+        #[cfg(feature = "dim2")]
+        if let Some(dir) = self.segment.normal() {
+            *dir
+        } else {
+            // The segment has no normal, likely because it degenerates to a point.
+            Vector::x()
+        }
+
+        #[cfg(feature = "dim3")]
+        if let Some(dir) = self.segment.direction() {
+            use crate::utils::WBasis;
+            dir.orthonormal_basis()[0]
+        } else {
+            // The segment has no normal, likely because it degenerates to a point.
+            Vector::x()
+        }
+    }
+}
+
+/// Projects `pt` on a ball, using `fallback_dir` if `pt` is at the center of the ball.
+fn project_on_ball(
+    pt: &Point<Real>,
+    center: &Point<Real>,
+    radius: Real,
+    fallback_dir: Vector<Real>,
+    solid: bool,
+) -> PointProjection {
+    let dpt = pt - center;
+    let dist = dpt.norm();
+    let inside = dist <= radius;
+
+    if solid && inside {
+        return PointProjection::new(true, *pt);
+    }
+
+    let dir = dpt.try_normalize(Real::EPSILON).unwrap_or(fallback_dir);
+    PointProjection::new(inside, center + dir * radius)
+}
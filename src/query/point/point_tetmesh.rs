@@ -0,0 +1,32 @@
+use crate::math::{Point, Real};
+use crate::query::visitors::CompositePointContainmentTest;
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{FeatureId, TetMesh};
+
+impl PointQuery for TetMesh {
+    #[inline]
+    fn project_local_point(&self, point: &Point<Real>, solid: bool) -> PointProjection {
+        if solid && self.contains_local_point(point) {
+            PointProjection::new(true, *point)
+        } else {
+            self.project_local_point_and_get_feature(point).0
+        }
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        point: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        let (mut proj, feature) = self.boundary().project_local_point_and_get_feature(point);
+        proj.is_inside = self.contains_local_point(point);
+        (proj, feature)
+    }
+
+    #[inline]
+    fn contains_local_point(&self, point: &Point<Real>) -> bool {
+        let mut visitor = CompositePointContainmentTest::new(self, point);
+        let _ = self.qbvh().traverse_depth_first(&mut visitor);
+        visitor.found
+    }
+}
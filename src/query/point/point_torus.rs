@@ -0,0 +1,87 @@
+use crate::math::{Point, Real};
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{FeatureId, Torus};
+
+impl PointQuery for Torus {
+    #[inline]
+    fn project_local_point(&self, pt: &Point<Real>, solid: bool) -> PointProjection {
+        // The torus is the set of points closer than `minor_radius` to its core circle, so the
+        // projection is obtained by projecting on the core circle first.
+        let core_pt = self.closest_core_point(pt);
+        let dpos = pt - core_pt;
+        let dist = dpos.norm();
+        let inside = dist <= self.minor_radius;
+
+        if inside && solid {
+            PointProjection::new(true, *pt)
+        } else {
+            let dir = if dist > crate::math::DEFAULT_EPSILON {
+                dpos / dist
+            } else {
+                // The point is on the core circle: any direction orthogonal to the circle works.
+                core_pt.coords / self.major_radius
+            };
+            PointProjection::new(inside, core_pt + dir * self.minor_radius)
+        }
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        pt: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        (self.project_local_point(pt, false), FeatureId::Face(0))
+    }
+
+    #[inline]
+    fn distance_to_local_point(&self, pt: &Point<Real>, solid: bool) -> Real {
+        let dist = self.signed_distance(pt);
+
+        if solid && dist < 0.0 {
+            0.0
+        } else {
+            dist
+        }
+    }
+
+    #[inline]
+    fn contains_local_point(&self, pt: &Point<Real>) -> bool {
+        self.signed_distance(pt) <= 0.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::math::Point;
+    use crate::query::PointQuery;
+    use crate::shape::Torus;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn torus_point_projection() {
+        let torus = Torus::new(2.0, 0.5);
+
+        // Point in the hole of the torus.
+        let proj = torus.project_local_point(&Point::new(0.3, 0.0, 0.4), true);
+        assert!(!proj.is_inside);
+        assert_relative_eq!(proj.point, Point::new(0.9, 0.0, 1.2), epsilon = 1.0e-5);
+
+        // Point above the tube.
+        let proj = torus.project_local_point(&Point::new(0.0, 2.0, -2.0), true);
+        assert!(!proj.is_inside);
+        assert_relative_eq!(proj.point, Point::new(0.0, 0.5, -2.0), epsilon = 1.0e-5);
+
+        // Point inside of the tube.
+        let pt = Point::new(2.2, 0.1, 0.0);
+        assert_eq!(torus.project_local_point(&pt, true).point, pt);
+        let proj = torus.project_local_point(&pt, false);
+        assert!(proj.is_inside);
+        assert_relative_eq!(torus.signed_distance(&proj.point), 0.0, epsilon = 1.0e-5);
+        assert_relative_eq!(
+            torus.distance_to_local_point(&pt, false),
+            -0.5 + crate::math::Real::sqrt(0.05),
+            epsilon = 1.0e-5
+        );
+        assert_eq!(torus.distance_to_local_point(&pt, true), 0.0);
+    }
+}
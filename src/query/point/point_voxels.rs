@@ -1,30 +1,11 @@
 use crate::math::{Point, Real};
 use crate::query::{PointProjection, PointQuery};
-use crate::shape::{Cuboid, FeatureId, VoxelType, Voxels};
+use crate::shape::{Cuboid, FeatureId, Voxels};
 
 impl PointQuery for Voxels {
     #[inline]
     fn project_local_point(&self, pt: &Point<Real>, solid: bool) -> PointProjection {
-        // TODO: optimize this very naive implementation.
-        let base_cuboid = Cuboid::new(self.voxel_size() / 2.0);
-        let mut smallest_dist = Real::MAX;
-        let mut result = PointProjection::new(false, *pt);
-
-        for vox in self.voxels() {
-            if vox.state.voxel_type() != VoxelType::Empty {
-                let mut candidate =
-                    base_cuboid.project_local_point(&(pt - vox.center.coords), solid);
-                candidate.point += vox.center.coords;
-
-                let candidate_dist = (candidate.point - pt).norm();
-                if candidate_dist < smallest_dist {
-                    result = candidate;
-                    smallest_dist = candidate_dist;
-                }
-            }
-        }
-
-        result
+        project_local_point_on_voxels(self, pt, solid).0
     }
 
     #[inline]
@@ -32,7 +13,32 @@ impl PointQuery for Voxels {
         &self,
         pt: &Point<Real>,
     ) -> (PointProjection, FeatureId) {
-        // TODO: get the actual feature.
-        (self.project_local_point(pt, false), FeatureId::Unknown)
+        // NOTE: the feature identifies the voxel the point is projected on (rather than one
+        //       of its faces) so its user-data can be retrieved with `Voxels::feature_user_data`.
+        project_local_point_on_voxels(self, pt, false)
     }
 }
+
+fn project_local_point_on_voxels(
+    voxels: &Voxels,
+    pt: &Point<Real>,
+    solid: bool,
+) -> (PointProjection, FeatureId) {
+    // TODO: optimize this very naive implementation.
+    let base_cuboid = Cuboid::new(voxels.voxel_size() / 2.0);
+    let mut smallest_dist = Real::MAX;
+    let mut result = (PointProjection::new(false, *pt), FeatureId::Unknown);
+
+    for vox in voxels.filled_voxels() {
+        let mut candidate = base_cuboid.project_local_point(&(pt - vox.center.coords), solid);
+        candidate.point += vox.center.coords;
+
+        let candidate_dist = (candidate.point - pt).norm();
+        if candidate_dist < smallest_dist {
+            result = (candidate, FeatureId::Face(vox.linear_id));
+            smallest_dist = candidate_dist;
+        }
+    }
+
+    result
+}
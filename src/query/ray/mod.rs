@@ -17,15 +17,33 @@ pub use self::simd_ray::SimdRay;
 pub mod ray;
 mod ray_aabb;
 mod ray_ball;
+#[cfg(feature = "dim2")]
+#[cfg(feature = "alloc")]
+mod ray_bezier_spline;
 mod ray_bounding_sphere;
 #[cfg(feature = "alloc")]
 mod ray_composite_shape;
 mod ray_cuboid;
+mod ray_ellipsoid;
 mod ray_halfspace;
 #[cfg(feature = "alloc")]
 mod ray_heightfield;
+mod ray_minkowski_sum;
+#[cfg(feature = "dim2")]
+#[cfg(feature = "alloc")]
+mod ray_polygon;
 mod ray_round_shape;
+mod ray_scaled;
+#[cfg(feature = "alloc")]
+mod ray_sdf;
 mod ray_support_map;
+#[cfg(feature = "alloc")]
+mod ray_swept_shape;
+#[cfg(feature = "dim3")]
+#[cfg(feature = "alloc")]
+mod ray_tetmesh;
+#[cfg(feature = "dim3")]
+mod ray_torus;
 mod ray_triangle;
 #[cfg(feature = "alloc")]
 mod ray_voxels;
@@ -0,0 +1,114 @@
+use crate::math::{Real, Vector};
+use crate::query::visitors::RayIntersectionsVisitor;
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::bezier_spline::{
+    eval, eval_derivative, power_basis, quadratic_roots_in_unit_interval,
+};
+use crate::shape::{BezierSpline, FeatureId};
+
+/// The maximum number of bisection steps for refining a root of the ray/curve cubic.
+const MAX_BISECTION_STEPS: usize = 100;
+
+impl RayCast for BezierSpline {
+    #[inline]
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_time_of_impact: Real,
+        _solid: bool,
+    ) -> Option<RayIntersection> {
+        if ray.dir == Vector::zeros() {
+            return None;
+        }
+
+        let mut best: Option<RayIntersection> = None;
+        let mut callback = |i: &u32| {
+            let max_toi = best.map(|b| b.time_of_impact).unwrap_or(max_time_of_impact);
+
+            if let Some(hit) = cast_ray_on_segment(self, *i, ray, max_toi) {
+                best = Some(hit);
+            }
+
+            true
+        };
+        let mut visitor = RayIntersectionsVisitor::new(ray, max_time_of_impact, &mut callback);
+        let _ = self.qbvh().traverse_depth_first(&mut visitor);
+        best
+    }
+}
+
+/// Casts a ray on the `i`-th segment of a curve.
+///
+/// The hit points are the roots in `[0, 1]` of the cubic polynomial `n · (c(t) - o)` where `c`
+/// is the curve segment, `o` the ray’s origin and `n` a vector orthogonal to the ray.
+fn cast_ray_on_segment(
+    curve: &BezierSpline,
+    i: u32,
+    ray: &Ray,
+    max_time_of_impact: Real,
+) -> Option<RayIntersection> {
+    let ctrl = curve.segment(i);
+    let ortho = Vector::new(-ray.dir.y, ray.dir.x);
+    let [a, b, c, d] = power_basis(&ctrl);
+    let coeffs = [
+        a.dot(&ortho),
+        b.dot(&ortho),
+        c.dot(&ortho),
+        (d - ray.origin.coords).dot(&ortho),
+    ];
+    let f = |t: Real| ((coeffs[0] * t + coeffs[1]) * t + coeffs[2]) * t + coeffs[3];
+
+    // Split [0, 1] into intervals where the cubic is monotonic, and find its root
+    // on each of them.
+    let (critical, num_critical) =
+        quadratic_roots_in_unit_interval(3.0 * coeffs[0], 2.0 * coeffs[1], coeffs[2]);
+    let mut bounds = [0.0, 1.0, 1.0, 1.0];
+    bounds[1..=num_critical].copy_from_slice(&critical[..num_critical]);
+
+    let mut best: Option<(Real, Real)> = None;
+
+    for k in 0..=num_critical {
+        let (mut lo, mut hi) = (bounds[k], bounds[k + 1]);
+        let (f_lo, f_hi) = (f(lo), f(hi));
+
+        if f_lo * f_hi > 0.0 {
+            continue;
+        }
+
+        let increasing = f_lo < f_hi;
+
+        for _ in 0..MAX_BISECTION_STEPS {
+            let mid = (lo + hi) * 0.5;
+
+            if mid <= lo || mid >= hi {
+                break;
+            }
+
+            if (f(mid) < 0.0) == increasing {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+
+        let t = (lo + hi) * 0.5;
+        let toi = (eval(&ctrl, t) - ray.origin).dot(&ray.dir) / ray.dir.norm_squared();
+
+        if toi >= 0.0 && toi <= max_time_of_impact && best.map(|b| toi < b.1).unwrap_or(true) {
+            best = Some((t, toi));
+        }
+    }
+
+    let (t, toi) = best?;
+    let tangent = eval_derivative(&ctrl, t);
+    let mut normal = Vector::new(-tangent.y, tangent.x)
+        .try_normalize(Real::EPSILON)
+        .unwrap_or_else(|| -ray.dir.normalize());
+
+    // The curve has no interior: the normal faces the ray’s origin.
+    if normal.dot(&ray.dir) > 0.0 {
+        normal = -normal;
+    }
+
+    Some(RayIntersection::new(toi, normal, FeatureId::Face(i)))
+}
@@ -2,7 +2,11 @@ use crate::bounding_volume::SimdAabb;
 use crate::math::{Real, SimdBool, SimdReal, SIMD_WIDTH};
 use crate::partitioning::{SimdBestFirstVisitStatus, SimdBestFirstVisitor};
 use crate::query::{Ray, RayCast, RayIntersection, SimdRay};
-use crate::shape::{Compound, FeatureId, Polyline, TriMesh, TypedSimdCompositeShape};
+#[cfg(feature = "dim3")]
+use crate::shape::RoundPolyline;
+use crate::shape::{
+    Compound, FeatureId, Polyline, RoundTriMesh, SphereSet, TriMesh, TypedSimdCompositeShape,
+};
 use simba::simd::{SimdBool as _, SimdPartialOrd, SimdValue};
 
 impl RayCast for TriMesh {
@@ -107,6 +111,112 @@ impl RayCast for Compound {
     }
 }
 
+impl RayCast for SphereSet {
+    #[inline]
+    fn cast_local_ray(&self, ray: &Ray, max_time_of_impact: Real, solid: bool) -> Option<Real> {
+        let mut visitor =
+            RayCompositeShapeToiBestFirstVisitor::new(self, ray, max_time_of_impact, solid);
+
+        self.qbvh()
+            .traverse_best_first(&mut visitor)
+            .map(|res| res.1 .1)
+    }
+
+    #[inline]
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_time_of_impact: Real,
+        solid: bool,
+    ) -> Option<RayIntersection> {
+        let mut visitor = RayCompositeShapeToiAndNormalBestFirstVisitor::new(
+            self,
+            ray,
+            max_time_of_impact,
+            solid,
+        );
+
+        self.qbvh()
+            .traverse_best_first(&mut visitor)
+            .map(|(_, (best, mut res))| {
+                // Identify the sphere that was hit.
+                res.feature = FeatureId::Face(best);
+                res
+            })
+    }
+}
+
+impl RayCast for RoundTriMesh {
+    #[inline]
+    fn cast_local_ray(&self, ray: &Ray, max_time_of_impact: Real, solid: bool) -> Option<Real> {
+        let mut visitor =
+            RayCompositeShapeToiBestFirstVisitor::new(self, ray, max_time_of_impact, solid);
+
+        self.qbvh()
+            .traverse_best_first(&mut visitor)
+            .map(|res| res.1 .1)
+    }
+
+    #[inline]
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_time_of_impact: Real,
+        solid: bool,
+    ) -> Option<RayIntersection> {
+        let mut visitor = RayCompositeShapeToiAndNormalBestFirstVisitor::new(
+            self,
+            ray,
+            max_time_of_impact,
+            solid,
+        );
+
+        self.qbvh()
+            .traverse_best_first(&mut visitor)
+            .map(|(_, (best, mut res))| {
+                // Identify the triangle that was hit.
+                res.feature = FeatureId::Face(best);
+                res
+            })
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl RayCast for RoundPolyline {
+    #[inline]
+    fn cast_local_ray(&self, ray: &Ray, max_time_of_impact: Real, solid: bool) -> Option<Real> {
+        let mut visitor =
+            RayCompositeShapeToiBestFirstVisitor::new(self, ray, max_time_of_impact, solid);
+
+        self.qbvh()
+            .traverse_best_first(&mut visitor)
+            .map(|res| res.1 .1)
+    }
+
+    #[inline]
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_time_of_impact: Real,
+        solid: bool,
+    ) -> Option<RayIntersection> {
+        let mut visitor = RayCompositeShapeToiAndNormalBestFirstVisitor::new(
+            self,
+            ray,
+            max_time_of_impact,
+            solid,
+        );
+
+        self.qbvh()
+            .traverse_best_first(&mut visitor)
+            .map(|(_, (best, mut res))| {
+                // Identify the capsule that was hit.
+                res.feature = FeatureId::Face(best);
+                res
+            })
+    }
+}
+
 /*
  * Visitors
  */
@@ -0,0 +1,84 @@
+use super::ray_toi_with_ball;
+use crate::math::Real;
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::{Ellipsoid, FeatureId};
+use na::Point;
+
+impl RayCast for Ellipsoid {
+    #[inline]
+    fn cast_local_ray(&self, ray: &Ray, max_time_of_impact: Real, solid: bool) -> Option<Real> {
+        // Casting the ray on the unit ball in the space where the ellipsoid is a unit ball leaves
+        // the time of impact unchanged.
+        let unit_ray = Ray::new(
+            ray.origin.coords.component_div(&self.radii).into(),
+            ray.dir.component_div(&self.radii),
+        );
+        ray_toi_with_ball(&Point::origin(), 1.0, &unit_ray, solid)
+            .1
+            .filter(|time_of_impact| *time_of_impact <= max_time_of_impact)
+    }
+
+    #[inline]
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_time_of_impact: Real,
+        solid: bool,
+    ) -> Option<RayIntersection> {
+        let unit_ray = Ray::new(
+            ray.origin.coords.component_div(&self.radii).into(),
+            ray.dir.component_div(&self.radii),
+        );
+        let (inside, toi) = ray_toi_with_ball(&Point::origin(), 1.0, &unit_ray, solid);
+        let toi = toi.filter(|time_of_impact| *time_of_impact <= max_time_of_impact)?;
+
+        if inside && solid {
+            return Some(RayIntersection::new(toi, na::zero(), FeatureId::Face(0)));
+        }
+
+        let normal = self
+            .normal_at_point(&ray.point_at(toi))
+            .map(|n| {
+                if inside {
+                    -n.into_inner()
+                } else {
+                    n.into_inner()
+                }
+            })
+            .unwrap_or_else(na::zero);
+        Some(RayIntersection::new(toi, normal, FeatureId::Face(0)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::math::{Point, Real, Vector};
+    use crate::query::{Ray, RayCast};
+    use crate::shape::Ellipsoid;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn ellipsoid_ray_cast() {
+        let mut radii = Vector::repeat(1.0);
+        radii.x = 3.0;
+        let ellipsoid = Ellipsoid::new(radii);
+
+        let ray = Ray::new(Point::from(Vector::x() * -5.0), Vector::x());
+        let hit = ellipsoid
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+            .unwrap();
+        assert_relative_eq!(hit.time_of_impact, 2.0, epsilon = 1.0e-5);
+        assert_relative_eq!(hit.normal, -Vector::x(), epsilon = 1.0e-5);
+
+        let ray = Ray::new(Point::origin(), Vector::y() * 2.0);
+        assert_eq!(ellipsoid.cast_local_ray(&ray, Real::MAX, true), Some(0.0));
+        let hit = ellipsoid
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, false)
+            .unwrap();
+        assert_relative_eq!(hit.time_of_impact, 0.5, epsilon = 1.0e-5);
+        assert_relative_eq!(hit.normal, -Vector::y(), epsilon = 1.0e-5);
+
+        let ray = Ray::new(Point::from(Vector::y() * 3.0), Vector::x());
+        assert!(ellipsoid.cast_local_ray(&ray, Real::MAX, true).is_none());
+    }
+}
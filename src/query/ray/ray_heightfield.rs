@@ -1,10 +1,13 @@
+use crate::bounding_volume::{Aabb, BoundingVolume};
 use crate::math::Real;
 #[cfg(feature = "dim2")]
 use crate::query;
 use crate::query::{Ray, RayCast, RayIntersection};
 #[cfg(feature = "dim2")]
-use crate::shape::FeatureId;
-use crate::shape::HeightField;
+use crate::shape::Capsule;
+#[cfg(feature = "dim3")]
+use crate::shape::RoundShape;
+use crate::shape::{FeatureId, HeightField, RoundHeightField};
 
 #[cfg(feature = "dim2")]
 impl RayCast for HeightField {
@@ -242,3 +245,74 @@ impl RayCast for HeightField {
         None
     }
 }
+
+impl RayCast for RoundHeightField {
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_time_of_impact: Real,
+        solid: bool,
+    ) -> Option<RayIntersection> {
+        let border_radius = self.border_radius;
+        let heightfield = &self.inner_shape;
+        let aabb = heightfield.local_aabb().loosened(border_radius);
+        let (min_t, max_t) = aabb.clip_ray_parameters(ray)?;
+        let max_t = max_t.min(max_time_of_impact);
+
+        if min_t > max_t {
+            return None;
+        }
+
+        // The path of the ray is traversed by chunks about as long as a cell, so that only the
+        // elements close to the ray are tested.
+        #[cfg(feature = "dim2")]
+        let cell_size = heightfield.cell_width();
+        #[cfg(feature = "dim3")]
+        let cell_size = heightfield.cell_width().min(heightfield.cell_height());
+        let dir_norm = ray.dir.norm();
+        let step = if dir_norm * (max_t - min_t) > cell_size {
+            cell_size / dir_norm
+        } else {
+            max_t - min_t
+        };
+
+        let mut best: Option<RayIntersection> = None;
+        let mut chunk_start = min_t;
+
+        loop {
+            let chunk_end = (chunk_start + step).min(max_t);
+            let mut chunk_aabb = Aabb::new_invalid();
+            chunk_aabb.take_point(ray.point_at(chunk_start));
+            chunk_aabb.take_point(ray.point_at(chunk_end));
+
+            heightfield.map_elements_in_local_aabb(
+                &chunk_aabb.loosened(border_radius),
+                &mut |id, elt| {
+                    #[cfg(feature = "dim2")]
+                    let part = Capsule::new(elt.a, elt.b, border_radius);
+                    #[cfg(feature = "dim3")]
+                    let part = RoundShape {
+                        inner_shape: *elt,
+                        border_radius,
+                    };
+
+                    if let Some(mut hit) =
+                        part.cast_local_ray_and_get_normal(ray, max_time_of_impact, solid)
+                    {
+                        if best.map(|b| hit.time_of_impact < b.time_of_impact) != Some(false) {
+                            hit.feature = FeatureId::Face(id);
+                            best = Some(hit);
+                        }
+                    }
+                },
+            );
+
+            // Any element hit before the end of this chunk would have been found already.
+            if chunk_end >= max_t || best.map(|b| b.time_of_impact <= chunk_end) == Some(true) {
+                return best;
+            }
+
+            chunk_start = chunk_end;
+        }
+    }
+}
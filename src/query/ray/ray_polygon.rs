@@ -0,0 +1,69 @@
+use crate::math::{Real, Vector};
+use crate::query::{PointQuery, Ray, RayCast, RayIntersection};
+use crate::shape::{FeatureId, Polygon};
+
+impl RayCast for Polygon {
+    #[inline]
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_time_of_impact: Real,
+        solid: bool,
+    ) -> Option<RayIntersection> {
+        if solid && self.contains_local_point(&ray.origin) {
+            return Some(RayIntersection::new(
+                0.0,
+                Vector::zeros(),
+                FeatureId::Face(0),
+            ));
+        }
+
+        // The normals of the boundary segments are oriented toward the ray so they are
+        // outward-facing if the ray starts outside, and inward-facing if it starts inside.
+        self.boundary()
+            .cast_local_ray_and_get_normal(ray, max_time_of_impact, solid)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::math::{Point, Real, Vector};
+    use crate::query::{Ray, RayCast};
+    use crate::shape::Polygon;
+    use alloc::vec;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn polygon_ray_cast() {
+        // A non-convex "L" shape.
+        let polygon = Polygon::new(
+            vec![
+                Point::new(0.0, 0.0),
+                Point::new(4.0, 0.0),
+                Point::new(4.0, 1.0),
+                Point::new(1.0, 1.0),
+                Point::new(1.0, 4.0),
+                Point::new(0.0, 4.0),
+            ],
+            vec![],
+        )
+        .unwrap();
+
+        // Through the concavity.
+        let ray = Ray::new(Point::new(5.0, 2.0), -Vector::x());
+        let hit = polygon
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+            .unwrap();
+        assert_relative_eq!(hit.time_of_impact, 4.0);
+        assert_relative_eq!(hit.normal, Vector::x());
+
+        // From inside.
+        let ray = Ray::new(Point::new(0.5, 0.5), Vector::x());
+        assert_eq!(polygon.cast_local_ray(&ray, Real::MAX, true), Some(0.0));
+        let hit = polygon
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, false)
+            .unwrap();
+        assert_relative_eq!(hit.time_of_impact, 3.5);
+        assert_relative_eq!(hit.normal, -Vector::x());
+    }
+}
@@ -0,0 +1,30 @@
+use crate::math::{Real, Vector};
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::Scaled;
+
+impl<S: RayCast> RayCast for Scaled<S> {
+    #[inline]
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_time_of_impact: Real,
+        solid: bool,
+    ) -> Option<RayIntersection> {
+        // The time of impact is invariant by the scaling since both the ray’s origin and
+        // direction are scaled.
+        let unscaled_ray = Ray::new(
+            self.unscale_point(&ray.origin),
+            ray.dir.component_div(self.scale()),
+        );
+        let mut hit = self.inner_shape().cast_local_ray_and_get_normal(
+            &unscaled_ray,
+            max_time_of_impact,
+            solid,
+        )?;
+        hit.normal = self
+            .scale_normal(&hit.normal)
+            .map(|n| n.into_inner())
+            .unwrap_or_else(Vector::zeros);
+        Some(hit)
+    }
+}
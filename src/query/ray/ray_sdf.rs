@@ -0,0 +1,105 @@
+use crate::bounding_volume::Aabb;
+use crate::math::{Point, Real, Vector};
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::{FeatureId, Sdf};
+
+/// The number of bisection steps used to locate the isosurface once it has been crossed.
+const NUM_REFINEMENT_STEPS: usize = 16;
+
+impl RayCast for Sdf {
+    #[inline]
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_time_of_impact: Real,
+        solid: bool,
+    ) -> Option<RayIntersection> {
+        let domain = self.domain();
+        let (min_t, exit_t) = domain.clip_ray_parameters(ray)?;
+
+        if min_t > max_time_of_impact {
+            return None;
+        }
+
+        let max_t = exit_t.min(max_time_of_impact);
+        let mut prev_t = min_t;
+        let mut prev_dist = self.distance_at_point(&ray.point_at(min_t));
+        let inside = prev_dist <= 0.0;
+
+        if inside {
+            if min_t > 0.0 {
+                // The ray enters the shape through the boundary of the grid’s domain.
+                let normal = domain_normal(&domain, &ray.point_at(min_t));
+                return Some(RayIntersection::new(min_t, normal, FeatureId::Unknown));
+            } else if solid {
+                return Some(RayIntersection::new(
+                    0.0,
+                    Vector::zeros(),
+                    FeatureId::Unknown,
+                ));
+            }
+        }
+
+        let ray_len = ray.dir.norm();
+        if ray_len == 0.0 {
+            return None;
+        }
+
+        // Sphere-tracing: the distance to the surface is a safe step length. The step is bounded
+        // from below so that the tracing makes progress even very close to the surface.
+        let min_step = self.cell_size().min() * 1.0e-3 / ray_len;
+
+        while prev_t < max_t {
+            let t = (prev_t + (prev_dist.abs() / ray_len).max(min_step)).min(max_t);
+            let dist = self.distance_at_point(&ray.point_at(t));
+
+            if (dist <= 0.0) != inside {
+                // The isosurface was crossed between `prev_t` and `t`.
+                let (mut t0, mut t1) = (prev_t, t);
+
+                for _ in 0..NUM_REFINEMENT_STEPS {
+                    let mid = (t0 + t1) / 2.0;
+
+                    if (self.distance_at_point(&ray.point_at(mid)) <= 0.0) == inside {
+                        t0 = mid;
+                    } else {
+                        t1 = mid;
+                    }
+                }
+
+                let toi = if inside { t0 } else { t1 };
+                let normal = self
+                    .normal_at_point(&ray.point_at(toi))
+                    .map(|n| {
+                        if inside {
+                            -n.into_inner()
+                        } else {
+                            n.into_inner()
+                        }
+                    })
+                    .unwrap_or_else(Vector::zeros);
+                return Some(RayIntersection::new(toi, normal, FeatureId::Unknown));
+            }
+
+            prev_t = t;
+            prev_dist = dist;
+        }
+
+        if inside && exit_t <= max_time_of_impact {
+            // The ray leaves the shape through the boundary of the grid’s domain.
+            let normal = -domain_normal(&domain, &ray.point_at(exit_t));
+            return Some(RayIntersection::new(exit_t, normal, FeatureId::Unknown));
+        }
+
+        None
+    }
+}
+
+/// The outward normal of the face of `domain` closest to `pt`.
+fn domain_normal(domain: &Aabb, pt: &Point<Real>) -> Vector<Real> {
+    let local = (pt - domain.center()).component_div(&domain.half_extents());
+    let axis = local.iamax();
+    let mut normal = Vector::zeros();
+    normal[axis] = if local[axis] < 0.0 { -1.0 } else { 1.0 };
+    normal
+}
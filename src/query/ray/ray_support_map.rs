@@ -9,9 +9,9 @@ use crate::query::{Ray, RayCast, RayIntersection};
 use crate::shape::ConvexPolygon;
 #[cfg(all(feature = "alloc", feature = "dim3"))]
 use crate::shape::ConvexPolyhedron;
-use crate::shape::{Capsule, FeatureId, Segment, SupportMap};
+use crate::shape::{Capsule, FeatureId, Segment, SupportMap, TaperedCapsule};
 #[cfg(feature = "dim3")]
-use crate::shape::{Cone, Cylinder};
+use crate::shape::{Cone, Cylinder, Tetrahedron};
 
 use num::Zero;
 
@@ -70,6 +70,24 @@ pub fn local_ray_intersection_with_support_map_with_params<G: ?Sized + SupportMa
     }
 }
 
+#[cfg(feature = "dim3")]
+impl RayCast for Tetrahedron {
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_time_of_impact: Real,
+        solid: bool,
+    ) -> Option<RayIntersection> {
+        local_ray_intersection_with_support_map_with_params(
+            self,
+            &mut VoronoiSimplex::new(),
+            ray,
+            max_time_of_impact,
+            solid,
+        )
+    }
+}
+
 #[cfg(feature = "dim3")]
 impl RayCast for Cylinder {
     fn cast_local_ray_and_get_normal(
@@ -123,6 +141,23 @@ impl RayCast for Capsule {
     }
 }
 
+impl RayCast for TaperedCapsule {
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_time_of_impact: Real,
+        solid: bool,
+    ) -> Option<RayIntersection> {
+        local_ray_intersection_with_support_map_with_params(
+            self,
+            &mut VoronoiSimplex::new(),
+            ray,
+            max_time_of_impact,
+            solid,
+        )
+    }
+}
+
 #[cfg(feature = "dim3")]
 #[cfg(feature = "alloc")]
 impl RayCast for ConvexPolyhedron {
@@ -0,0 +1,26 @@
+use crate::math::{Real, Vector};
+use crate::query::{PointQuery, Ray, RayCast, RayIntersection};
+use crate::shape::{FeatureId, TetMesh};
+
+impl RayCast for TetMesh {
+    #[inline]
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_time_of_impact: Real,
+        solid: bool,
+    ) -> Option<RayIntersection> {
+        if solid && self.contains_local_point(&ray.origin) {
+            return Some(RayIntersection::new(
+                0.0,
+                Vector::zeros(),
+                FeatureId::Face(0),
+            ));
+        }
+
+        // The ray either starts outside of the mesh, or inside with `solid == false`. In both
+        // cases, the first hit is located on the boundary.
+        self.boundary()
+            .cast_local_ray_and_get_normal(ray, max_time_of_impact, solid)
+    }
+}
@@ -0,0 +1,243 @@
+use crate::math::{Real, Vector};
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::{FeatureId, Torus};
+
+#[cfg(not(feature = "std"))]
+use na::ComplexField;
+
+/// The maximum number of bisection steps for refining a root of the ray/torus quartic.
+const MAX_BISECTION_STEPS: usize = 100;
+
+impl RayCast for Torus {
+    #[inline]
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray,
+        max_time_of_impact: Real,
+        solid: bool,
+    ) -> Option<RayIntersection> {
+        let inside = self.signed_distance(&ray.origin) <= 0.0;
+
+        if inside && solid {
+            return Some(RayIntersection::new(
+                0.0,
+                Vector::zeros(),
+                FeatureId::Face(0),
+            ));
+        }
+
+        let dir_norm = ray.dir.norm();
+
+        if dir_norm == 0.0 {
+            return None;
+        }
+
+        // Work with a normalized direction, starting from the point of the ray’s line that is
+        // the closest to the torus’ center. This keeps the polynomial’s coefficients small.
+        let dir = ray.dir / dir_norm;
+        let t_center = -ray.origin.coords.dot(&dir);
+        let origin = ray.origin + dir * t_center;
+
+        // Only the part of the line inside of the torus’ bounding sphere can hit it.
+        let bounding_radius = self.major_radius + self.minor_radius;
+        let half_chord_sq = bounding_radius * bounding_radius - origin.coords.norm_squared();
+
+        if half_chord_sq < 0.0 {
+            return None;
+        }
+
+        let half_chord = half_chord_sq.sqrt();
+        let lo = (-half_chord).max(-t_center);
+        let hi = half_chord.min(max_time_of_impact * dir_norm - t_center);
+
+        if lo > hi {
+            return None;
+        }
+
+        // The torus’ boundary is the zero-set of:
+        //     (|p|² + R² - r²)² - 4R²(p.x² + p.z²)
+        // which is negative inside of the torus. Plugging p = origin + dir * s yields a quartic
+        // polynomial in s.
+        let sq_major = self.major_radius * self.major_radius;
+        let a = origin.coords.norm_squared() + sq_major - self.minor_radius * self.minor_radius;
+        let b = origin.coords.dot(&dir);
+        let dir_xz_sq = dir.x * dir.x + dir.z * dir.z;
+        let origin_dir_xz = origin.x * dir.x + origin.z * dir.z;
+        let origin_xz_sq = origin.x * origin.x + origin.z * origin.z;
+        let coeffs = [
+            a * a - 4.0 * sq_major * origin_xz_sq,
+            4.0 * a * b - 8.0 * sq_major * origin_dir_xz,
+            4.0 * b * b + 2.0 * a - 4.0 * sq_major * dir_xz_sq,
+            4.0 * b,
+            1.0,
+        ];
+
+        let mut roots = [0.0; 4];
+        if polynomial_roots(&coeffs, lo, hi, &mut roots) == 0 {
+            return None;
+        }
+
+        let s = roots[0];
+        let time_of_impact = (s + t_center) / dir_norm;
+        let normal = self
+            .normal_at_point(&ray.point_at(time_of_impact))
+            .map(|n| {
+                if inside {
+                    -n.into_inner()
+                } else {
+                    n.into_inner()
+                }
+            })
+            .unwrap_or_else(Vector::zeros);
+
+        Some(RayIntersection::new(
+            time_of_impact,
+            normal,
+            FeatureId::Face(0),
+        ))
+    }
+}
+
+/// Evaluates the polynomial with the given coefficients (by increasing degree) at `t`.
+fn eval_polynomial(coeffs: &[Real], t: Real) -> Real {
+    coeffs.iter().rev().fold(0.0, |acc, c| acc * t + c)
+}
+
+/// Computes the roots within `[lo, hi]` of the polynomial of degree at most 4 with the given
+/// coefficients (by increasing degree), sorted in increasing order.
+///
+/// The roots of the polynomial’s derivative split the interval into sub-intervals where the
+/// polynomial is monotonic. Each sub-interval with a sign change is then refined by bisection.
+fn polynomial_roots(coeffs: &[Real], lo: Real, hi: Real, out: &mut [Real; 4]) -> usize {
+    let degree = coeffs.len() - 1;
+
+    if degree == 1 {
+        // NOTE: if the leading coefficient is zero, the root is NaN or infinite and is rejected.
+        let root = -coeffs[0] / coeffs[1];
+        if root >= lo && root <= hi {
+            out[0] = root;
+            return 1;
+        }
+        return 0;
+    }
+
+    let mut derivative = [0.0; 4];
+    for i in 1..=degree {
+        derivative[i - 1] = coeffs[i] * i as Real;
+    }
+
+    let mut critical_points = [0.0; 4];
+    let num_critical_points = polynomial_roots(&derivative[..degree], lo, hi, &mut critical_points);
+
+    let mut num_roots = 0;
+    let mut a = lo;
+    let mut fa = eval_polynomial(coeffs, a);
+
+    if fa == 0.0 {
+        out[0] = a;
+        num_roots = 1;
+    }
+
+    for b in critical_points[..num_critical_points]
+        .iter()
+        .copied()
+        .chain(core::iter::once(hi))
+    {
+        if num_roots == out.len() {
+            break;
+        }
+
+        let fb = eval_polynomial(coeffs, b);
+
+        if fb == 0.0 {
+            if num_roots == 0 || out[num_roots - 1] != b {
+                out[num_roots] = b;
+                num_roots += 1;
+            }
+        } else if fa != 0.0 && (fa < 0.0) != (fb < 0.0) {
+            out[num_roots] = bisect(coeffs, a, b, fa < 0.0);
+            num_roots += 1;
+        }
+
+        a = b;
+        fa = fb;
+    }
+
+    num_roots
+}
+
+/// Refines the root of the polynomial within `[lo, hi]`, assuming it is monotonic there.
+fn bisect(coeffs: &[Real], mut lo: Real, mut hi: Real, negative_at_lo: bool) -> Real {
+    for _ in 0..MAX_BISECTION_STEPS {
+        let mid = (lo + hi) / 2.0;
+
+        if mid <= lo || mid >= hi {
+            break;
+        }
+
+        if (eval_polynomial(coeffs, mid) < 0.0) == negative_at_lo {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+
+    (lo + hi) / 2.0
+}
+
+#[cfg(test)]
+mod test {
+    use crate::math::{Isometry, Point, Real, Vector};
+    use crate::query::{Ray, RayCast};
+    use crate::shape::Torus;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn torus_ray_cast() {
+        let torus = Torus::new(2.0, 0.5);
+
+        // Through the hole.
+        let ray = Ray::new(Point::new(0.0, 5.0, 0.0), -Vector::y());
+        assert_eq!(torus.cast_local_ray(&ray, Real::MAX, true), None);
+
+        // Along the x axis, hitting the outer boundary of the tube.
+        let ray = Ray::new(Point::new(-5.0, 0.0, 0.0), Vector::x() * 2.0);
+        let hit = torus
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+            .unwrap();
+        assert_relative_eq!(hit.time_of_impact, 1.25, epsilon = 1.0e-5);
+        assert_relative_eq!(hit.normal, -Vector::x(), epsilon = 1.0e-5);
+        assert_eq!(torus.cast_local_ray(&ray, 1.0, true), None);
+
+        // From inside of the hole, hitting the inner boundary of the tube.
+        let ray = Ray::new(Point::origin(), Vector::z());
+        let hit = torus
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+            .unwrap();
+        assert_relative_eq!(hit.time_of_impact, 1.5, epsilon = 1.0e-5);
+        assert_relative_eq!(hit.normal, -Vector::z(), epsilon = 1.0e-5);
+
+        // From inside of the tube.
+        let ray = Ray::new(Point::new(2.0, 0.0, 0.0), Vector::y());
+        assert_eq!(torus.cast_local_ray(&ray, Real::MAX, true), Some(0.0));
+        let hit = torus
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, false)
+            .unwrap();
+        assert_relative_eq!(hit.time_of_impact, 0.5, epsilon = 1.0e-5);
+        assert_relative_eq!(hit.normal, -Vector::y(), epsilon = 1.0e-5);
+
+        // Grazing the top of the tube at an angle, compared against the transformed ray.
+        let pos = Isometry::new(Vector::new(1.0, -2.0, 0.5), Vector::new(0.3, 0.2, -0.1));
+        let ray = Ray::new(Point::new(-4.0, 0.3, 1.0), Vector::new(1.0, 0.0, -0.2));
+        let local_toi = torus.cast_local_ray(&ray, Real::MAX, true).unwrap();
+        let toi = torus
+            .cast_ray(&pos, &ray.transform_by(&pos), Real::MAX, true)
+            .unwrap();
+        assert_relative_eq!(local_toi, toi, epsilon = 1.0e-4);
+        assert_relative_eq!(
+            torus.signed_distance(&ray.point_at(local_toi)),
+            0.0,
+            epsilon = 1.0e-4
+        );
+    }
+}
@@ -31,10 +31,15 @@ impl RayCast for Voxels {
         let [domain_mins, domain_maxs] = self.domain();
 
         loop {
-            let voxel = self.voxel_state(voxel_key);
-            let aabb = self.voxel_aabb(voxel_key);
+            // If the current voxel is part of an unallocated chunk, or of an empty brick of an
+            // allocated chunk, then that whole block of empty voxels can be traversed at once.
+            let empty_range = self.empty_range_containing(voxel_key);
+            let aabb = match empty_range {
+                Some([mins, maxs]) => self.voxel_range_aabb(mins, maxs),
+                None => self.voxel_aabb(voxel_key),
+            };
 
-            if !voxel.is_empty() {
+            if empty_range.is_none() && !self.voxel_state(voxel_key).is_empty() {
                 // We hit a voxel!
                 // TODO: if `solid` is false, and we started hitting from the first iteration,
                 //       then we should continue the ray propagation until we reach empty space again.
@@ -42,7 +47,10 @@ impl RayCast for Voxels {
 
                 if let Some(mut hit) = hit {
                     // TODO: have the feature id be based on the voxel type?
-                    hit.feature = FeatureId::Face(self.linear_index(voxel_key));
+                    hit.feature = self
+                        .linear_index(voxel_key)
+                        .map(FeatureId::Face)
+                        .unwrap_or_default();
                     return Some(hit);
                 }
             }
@@ -82,18 +90,42 @@ impl RayCast for Voxels {
 
             let imin = Vector::from(toi.map(|t| t.0)).imin();
 
+            if let Some([mins, maxs]) = empty_range {
+                // Jump to the voxel where the ray leaves the empty block. The coordinates
+                // along the other axes are clamped to guard against rounding errors.
+                let exit_key = self.voxel_at_point_unchecked(ray.point_at(toi[imin].0));
+                for i in ii {
+                    voxel_key[i] = exit_key[i].clamp(
+                        mins[i].max(domain_mins[i]),
+                        (maxs[i] - 1).min(domain_maxs[i] - 1),
+                    );
+                }
+            }
+
             if toi[imin].1 {
-                if voxel_key[imin] < domain_maxs[imin] - 1 {
-                    voxel_key[imin] += 1;
+                let last_key = match empty_range {
+                    Some([_, maxs]) => maxs[imin] - 1,
+                    None => voxel_key[imin],
+                };
+
+                if last_key < domain_maxs[imin] - 1 {
+                    voxel_key[imin] = last_key + 1;
                 } else {
                     // Leaving the shape’s bounds.
                     break;
                 }
-            } else if voxel_key[imin] > domain_mins[imin] {
-                voxel_key[imin] -= 1;
             } else {
-                // Leaving the shape’s bounds.
-                break;
+                let first_key = match empty_range {
+                    Some([mins, _]) => mins[imin],
+                    None => voxel_key[imin],
+                };
+
+                if first_key > domain_mins[imin] {
+                    voxel_key[imin] = first_key - 1;
+                } else {
+                    // Leaving the shape’s bounds.
+                    break;
+                }
             }
         }
 
@@ -11,7 +11,10 @@ pub use self::{
         cast_shapes_composite_shape_shape, cast_shapes_shape_composite_shape,
         TOICompositeShapeShapeBestFirstVisitor,
     },
-    shape_cast_heightfield_shape::{cast_shapes_heightfield_shape, cast_shapes_shape_heightfield},
+    shape_cast_heightfield_shape::{
+        cast_shapes_heightfield_shape, cast_shapes_round_heightfield_shape,
+        cast_shapes_shape_heightfield, cast_shapes_shape_round_heightfield,
+    },
     shape_cast_support_map_support_map::cast_shapes_support_map_support_map,
     shape_cast_voxels_shape::{cast_shapes_shape_voxels, cast_shapes_voxels_shape},
 };
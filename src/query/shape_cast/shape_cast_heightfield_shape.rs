@@ -2,7 +2,7 @@ use crate::bounding_volume::BoundingVolume;
 use crate::math::{Isometry, Real, Vector};
 use crate::query::details::ShapeCastOptions;
 use crate::query::{QueryDispatcher, Ray, ShapeCastHit, Unsupported};
-use crate::shape::{HeightField, Shape};
+use crate::shape::{HeightField, RoundHeightField, Shape};
 #[cfg(feature = "dim3")]
 use crate::{bounding_volume::Aabb, query::RayCast};
 
@@ -279,3 +279,56 @@ pub fn cast_shapes_shape_heightfield<D: ?Sized + QueryDispatcher>(
     )?
     .map(|hit| hit.swapped()))
 }
+
+/// Time Of Impact between a moving shape and a heightfield with rounded triangles (or segments
+/// in 2D).
+pub fn cast_shapes_round_heightfield_shape<D: ?Sized + QueryDispatcher>(
+    dispatcher: &D,
+    pos12: &Isometry<Real>,
+    vel12: &Vector<Real>,
+    heightfield1: &RoundHeightField,
+    g2: &dyn Shape,
+    options: ShapeCastOptions,
+) -> Result<Option<ShapeCastHit>, Unsupported> {
+    // Casting against the rounded heightfield is equivalent to casting against the heightfield
+    // itself with a target distance enlarged by the border radius.
+    let border_radius = heightfield1.border_radius;
+    let options = ShapeCastOptions {
+        target_distance: options.target_distance + border_radius,
+        ..options
+    };
+    let hit = cast_shapes_heightfield_shape(
+        dispatcher,
+        pos12,
+        vel12,
+        &heightfield1.inner_shape,
+        g2,
+        options,
+    )?;
+
+    Ok(hit.map(|mut hit| {
+        hit.witness1 += *hit.normal1 * border_radius;
+        hit
+    }))
+}
+
+/// Time Of Impact between a moving shape and a heightfield with rounded triangles (or segments
+/// in 2D).
+pub fn cast_shapes_shape_round_heightfield<D: ?Sized + QueryDispatcher>(
+    dispatcher: &D,
+    pos12: &Isometry<Real>,
+    vel12: &Vector<Real>,
+    g1: &dyn Shape,
+    heightfield2: &RoundHeightField,
+    options: ShapeCastOptions,
+) -> Result<Option<ShapeCastHit>, Unsupported> {
+    Ok(cast_shapes_round_heightfield_shape(
+        dispatcher,
+        &pos12.inverse(),
+        &-pos12.inverse_transform_vector(vel12),
+        heightfield2,
+        g1,
+        options,
+    )?
+    .map(|hit| hit.swapped()))
+}
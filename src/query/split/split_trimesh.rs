@@ -133,6 +133,8 @@ impl TriMesh {
         // 2. Split the triangles.
         let mut intersections_found = HashMap::default();
         let mut new_indices = indices.to_vec();
+        // The id of the original triangle each new triangle is part of, to preserve tags.
+        let mut new_parents: Vec<u32> = (0..indices.len() as u32).collect();
         let mut new_vertices = vertices.to_vec();
 
         for (tri_id, idx) in indices.iter().enumerate() {
@@ -223,6 +225,7 @@ impl TriMesh {
 
                     new_indices[tri_id] = new_tri_a;
                     new_indices.push(new_tri_b);
+                    new_parents.push(tri_id as u32);
 
                     if let Some(triangulation) = &mut triangulation {
                         triangulation.add_edge(intersection_idx, idx_c, &new_vertices);
@@ -251,6 +254,7 @@ impl TriMesh {
                     new_indices[tri_id] = new_tri1;
                     new_indices.push(new_tri2);
                     new_indices.push(new_tri3);
+                    new_parents.extend_from_slice(&[tri_id as u32; 2]);
 
                     if let Some(triangulation) = &mut triangulation {
                         triangulation.add_edge(intersection1, intersection2, &new_vertices);
@@ -265,6 +269,9 @@ impl TriMesh {
         let mut vertices_rhs = vec![];
         let mut indices_lhs = vec![];
         let mut indices_rhs = vec![];
+        let mut tags_lhs = vec![];
+        let mut tags_rhs = vec![];
+        let is_tagged = !self.tags().is_empty();
         let mut remap = vec![];
 
         for i in 0..new_vertices.len() {
@@ -286,25 +293,40 @@ impl TriMesh {
             }
         }
 
-        for idx in new_indices {
+        for (idx, parent) in new_indices.into_iter().zip(new_parents) {
             let idx = [idx[0] as usize, idx[1] as usize, idx[2] as usize]; // Convert to usize.
             let colors = [colors[idx[0]], colors[idx[1]], colors[idx[2]]];
             let remap = [remap[idx[0]], remap[idx[1]], remap[idx[2]]];
-
-            if colors[0] == 1 || colors[1] == 1 || colors[2] == 1 {
+            let tag = self.triangle_tag(parent);
+            let (in_lhs, in_rhs) = if colors[0] == 1 || colors[1] == 1 || colors[2] == 1 {
                 assert!(colors[0] != 2 && colors[1] != 2 && colors[2] != 2);
-                indices_lhs.push([remap[0].0, remap[1].0, remap[2].0]);
+                (true, false)
             } else if colors[0] == 2 || colors[1] == 2 || colors[2] == 2 {
                 assert!(colors[0] != 1 && colors[1] != 1 && colors[2] != 1);
-                indices_rhs.push([remap[0].1, remap[1].1, remap[2].1]);
+                (false, true)
             } else {
                 // The colors are all 0, so push into both trimeshes.
+                (true, true)
+            };
+
+            if in_lhs {
                 indices_lhs.push([remap[0].0, remap[1].0, remap[2].0]);
+                if is_tagged {
+                    tags_lhs.push(tag);
+                }
+            }
+
+            if in_rhs {
                 indices_rhs.push([remap[0].1, remap[1].1, remap[2].1]);
+                if is_tagged {
+                    tags_rhs.push(tag);
+                }
             }
         }
 
         // Push the triangulation if there is one.
+        // NOTE: the triangles capping the split meshes are given a zero tag (they are pushed
+        //       last so `TriMesh::with_flags_and_tags` fills their tags with zeros).
         if let Some(triangulation) = triangulation {
             for face in triangulation.delaunay.inner_faces() {
                 let vtx = face.vertices();
@@ -340,8 +362,11 @@ impl TriMesh {
         } else if indices_lhs.is_empty() {
             SplitResult::Positive
         } else {
-            let mesh_lhs = TriMesh::new(vertices_lhs, indices_lhs).unwrap();
-            let mesh_rhs = TriMesh::new(vertices_rhs, indices_rhs).unwrap();
+            let flags = TriMeshFlags::empty();
+            let mesh_lhs =
+                TriMesh::with_flags_and_tags(vertices_lhs, indices_lhs, flags, tags_lhs).unwrap();
+            let mesh_rhs =
+                TriMesh::with_flags_and_tags(vertices_rhs, indices_rhs, flags, tags_rhs).unwrap();
             SplitResult::Pair(mesh_lhs, mesh_rhs)
         }
     }
@@ -695,6 +720,8 @@ impl TriMesh {
         let mut clip_workspace = vec![];
         let mut new_vertices = vec![];
         let mut new_indices = vec![];
+        let mut new_tags = vec![];
+        let is_tagged = !self.tags().is_empty();
         let aabb = cuboid.local_aabb();
         let inv_pos = cuboid_position.inverse();
         let mut to_clip = vec![];
@@ -721,6 +748,9 @@ impl TriMesh {
                 let base_i = new_vertices.len();
                 for i in 1..to_clip.len() - 1 {
                     new_indices.push([base_i as u32, (base_i + i) as u32, (base_i + i + 1) as u32]);
+                    if is_tagged {
+                        new_tags.push(self.triangle_tag(tri));
+                    }
                 }
                 new_vertices.append(&mut to_clip);
             }
@@ -733,7 +763,15 @@ impl TriMesh {
         }
 
         Ok(if new_vertices.len() >= 3 {
-            Some(TriMesh::new(new_vertices, new_indices).unwrap())
+            Some(
+                TriMesh::with_flags_and_tags(
+                    new_vertices,
+                    new_indices,
+                    TriMeshFlags::empty(),
+                    new_tags,
+                )
+                .unwrap(),
+            )
         } else {
             None
         })
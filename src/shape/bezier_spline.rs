@@ -0,0 +1,699 @@
+use crate::bounding_volume::{Aabb, BoundingSphere};
+use crate::math::{Isometry, Point, Real, Vector};
+use crate::partitioning::Qbvh;
+use crate::query::PointQuery;
+use crate::shape::{Polyline, Segment};
+use alloc::vec::Vec;
+
+#[cfg(not(feature = "std"))]
+use na::ComplexField;
+
+/// The maximum number of subdivisions of a curve segment while converting it to a polyline.
+const MAX_FLATTENING_DEPTH: usize = 16;
+/// The number of subdivisions of a curve segment always applied before projecting a point on it.
+const MIN_PROJECTION_DEPTH: usize = 2;
+/// The maximum number of subdivisions of a curve segment while projecting a point on it.
+const MAX_PROJECTION_DEPTH: usize = 10;
+/// The maximum number of Newton iterations for refining the projection of a point.
+const MAX_NEWTON_ITERATIONS: usize = 16;
+
+/// Indicated an inconsistency while building a Bézier spline.
+#[derive(thiserror::Error, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BezierSplineBuilderError {
+    /// There are not enough control points to define at least one curve segment.
+    #[error("There are not enough control points to define at least one curve segment.")]
+    NotEnoughControlPoints,
+    /// The number of control points doesn’t match a chain of Bézier curves of the given degree.
+    #[error(
+        "The number of control points doesn’t match a chain of Bézier curves of the given degree."
+    )]
+    InvalidNumberOfControlPoints,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+/// A smooth 2D curve made of a chain of cubic Bézier segments.
+///
+/// The segment `i` is controlled by the points `3 * i` to `3 * i + 3` of [`Self::control_points`],
+/// so two consecutive segments share an endpoint. Quadratic Bézier chains and uniform cubic
+/// B-splines are converted exactly to this representation.
+///
+/// Like a [`Polyline`], this shape has no interior. Unlike a tessellated polyline, its point
+/// projections, ray-casts, and contacts are computed on the exact curve, so their normals vary
+/// smoothly along the curve.
+pub struct BezierSpline {
+    qbvh: Qbvh<u32>,
+    control_points: Vec<Point<Real>>,
+}
+
+impl BezierSpline {
+    /// Creates a chain of cubic Bézier curves from its control points.
+    ///
+    /// There must be `3 * n + 1` control points, where `n > 0` is the number of curve segments.
+    pub fn new(control_points: Vec<Point<Real>>) -> Result<Self, BezierSplineBuilderError> {
+        if control_points.len() < 4 {
+            return Err(BezierSplineBuilderError::NotEnoughControlPoints);
+        }
+
+        if control_points.len() % 3 != 1 {
+            return Err(BezierSplineBuilderError::InvalidNumberOfControlPoints);
+        }
+
+        Ok(Self::from_cubic_control_points(control_points))
+    }
+
+    /// Creates a chain of quadratic Bézier curves from its control points.
+    ///
+    /// There must be `2 * n + 1` control points, where `n > 0` is the number of curve segments.
+    /// Each quadratic segment is converted exactly to a cubic segment.
+    pub fn from_quadratic(
+        control_points: &[Point<Real>],
+    ) -> Result<Self, BezierSplineBuilderError> {
+        if control_points.len() < 3 {
+            return Err(BezierSplineBuilderError::NotEnoughControlPoints);
+        }
+
+        if control_points.len() % 2 != 1 {
+            return Err(BezierSplineBuilderError::InvalidNumberOfControlPoints);
+        }
+
+        let mut cubic = Vec::with_capacity(control_points.len() / 2 * 3 + 1);
+        cubic.push(control_points[0]);
+
+        for quad in control_points.windows(3).step_by(2) {
+            // Degree elevation of the quadratic curve.
+            cubic.push(quad[0] + (quad[1] - quad[0]) * (2.0 / 3.0));
+            cubic.push(quad[2] + (quad[1] - quad[2]) * (2.0 / 3.0));
+            cubic.push(quad[2]);
+        }
+
+        Ok(Self::from_cubic_control_points(cubic))
+    }
+
+    /// Creates the chain of cubic Bézier curves equivalent to a uniform cubic B-spline.
+    ///
+    /// A B-spline with `n + 3` control points has `n` curve segments. Unlike Bézier curves, it
+    /// doesn’t go through its control points, but is twice continuously differentiable. Repeat
+    /// the first and last control points three times each to make the curve end on them.
+    pub fn from_b_spline(control_points: &[Point<Real>]) -> Result<Self, BezierSplineBuilderError> {
+        if control_points.len() < 4 {
+            return Err(BezierSplineBuilderError::NotEnoughControlPoints);
+        }
+
+        let mut cubic = Vec::with_capacity((control_points.len() - 3) * 3 + 1);
+
+        for (i, d) in control_points.windows(4).enumerate() {
+            if i == 0 {
+                cubic.push(Point::from(
+                    (d[0].coords + d[1].coords * 4.0 + d[2].coords) / 6.0,
+                ));
+            }
+
+            cubic.push(Point::from((d[1].coords * 2.0 + d[2].coords) / 3.0));
+            cubic.push(Point::from((d[1].coords + d[2].coords * 2.0) / 3.0));
+            cubic.push(Point::from(
+                (d[1].coords + d[2].coords * 4.0 + d[3].coords) / 6.0,
+            ));
+        }
+
+        Ok(Self::from_cubic_control_points(cubic))
+    }
+
+    fn from_cubic_control_points(control_points: Vec<Point<Real>>) -> Self {
+        let num_segments = (control_points.len() - 1) / 3;
+        let data = (0..num_segments).map(|i| {
+            let segment = segment_control_points(&control_points, i);
+            (i as u32, segment_aabb(&segment))
+        });
+
+        let mut qbvh = Qbvh::new();
+        // NOTE: we apply no dilation factor because we won't
+        // update this tree dynamically.
+        qbvh.clear_and_rebuild(data, 0.0);
+
+        Self {
+            qbvh,
+            control_points,
+        }
+    }
+
+    /// Computes a scaled version of this curve.
+    ///
+    /// Bézier curves are invariant under affine transformations, so the result is exact.
+    pub fn scaled(mut self, scale: &Vector<Real>) -> Self {
+        self.control_points
+            .iter_mut()
+            .for_each(|pt| pt.coords.component_mul_assign(scale));
+        Self {
+            qbvh: self.qbvh.scaled(scale),
+            control_points: self.control_points,
+        }
+    }
+
+    /// Compute the axis-aligned bounding box of this curve.
+    pub fn aabb(&self, pos: &Isometry<Real>) -> Aabb {
+        self.qbvh.root_aabb().transform_by(pos)
+    }
+
+    /// Gets the local axis-aligned bounding box of this curve.
+    pub fn local_aabb(&self) -> &Aabb {
+        self.qbvh.root_aabb()
+    }
+
+    /// The bounding-sphere of this curve in its local-space.
+    pub fn local_bounding_sphere(&self) -> BoundingSphere {
+        self.local_aabb().bounding_sphere()
+    }
+
+    /// The acceleration structure used by this curve.
+    ///
+    /// Its leaves are the tight bounding boxes of the curve segments.
+    pub fn qbvh(&self) -> &Qbvh<u32> {
+        &self.qbvh
+    }
+
+    /// The control points of this curve.
+    pub fn control_points(&self) -> &[Point<Real>] {
+        &self.control_points
+    }
+
+    /// The number of cubic Bézier segments of this curve.
+    pub fn num_segments(&self) -> usize {
+        (self.control_points.len() - 1) / 3
+    }
+
+    /// Is the last point of this curve equal to its first point?
+    pub fn is_closed(&self) -> bool {
+        self.control_points.first() == self.control_points.last()
+    }
+
+    /// The four control points of the `i`-th curve segment.
+    pub fn segment(&self, i: u32) -> [Point<Real>; 4] {
+        segment_control_points(&self.control_points, i as usize)
+    }
+
+    /// The point of the `i`-th curve segment at the parameter `t ∈ [0, 1]`.
+    pub fn point_at(&self, i: u32, t: Real) -> Point<Real> {
+        eval(&self.segment(i), t)
+    }
+
+    /// The (non-normalized) tangent of the `i`-th curve segment at the parameter `t ∈ [0, 1]`.
+    ///
+    /// This is the derivative of the curve with respect to `t`.
+    pub fn tangent_at(&self, i: u32, t: Real) -> Vector<Real> {
+        eval_derivative(&self.segment(i), t)
+    }
+
+    /// Finds the closest point to `pt` on this curve.
+    ///
+    /// Returns the index of the curve segment containing that point and its parameter on
+    /// that segment.
+    pub fn closest_parameter(&self, pt: &Point<Real>) -> (u32, Real) {
+        // The segment endpoints lie on the curve, so the closest one gives an upper bound
+        // of the distance to the curve, used to find the segments worth checking.
+        let max_dist_sq = self
+            .control_points
+            .iter()
+            .step_by(3)
+            .map(|endpoint| na::distance_squared(pt, endpoint))
+            .fold(Real::MAX, Real::min);
+        let region = Aabb::from_half_extents(*pt, Vector::repeat(max_dist_sq.sqrt()));
+        let mut candidates = Vec::new();
+        self.qbvh.intersect_aabb(&region, &mut candidates);
+
+        let mut best = (0, 0.0, Real::MAX);
+
+        for i in candidates {
+            let (t, dist_sq) = project_on_segment(&self.segment(i), pt, best.2);
+
+            if dist_sq < best.2 {
+                best = (i, t, dist_sq);
+            }
+        }
+
+        (best.0, best.1)
+    }
+
+    /// Approximates this curve by a polyline.
+    ///
+    /// Each curve segment is subdivided adaptively until all the points of the curve are
+    /// closer than `tolerance` to the polyline. Flat parts of the curve result in fewer
+    /// polyline segments than curved parts.
+    pub fn to_polyline(&self, tolerance: Real) -> Polyline {
+        let mut vertices = Vec::new();
+        vertices.push(self.control_points[0]);
+
+        for i in 0..self.num_segments() {
+            let segment = segment_control_points(&self.control_points, i);
+            flatten(&segment, tolerance, 0, &mut vertices);
+        }
+
+        Polyline::new(vertices, None)
+    }
+}
+
+fn segment_control_points(control_points: &[Point<Real>], i: usize) -> [Point<Real>; 4] {
+    [
+        control_points[i * 3],
+        control_points[i * 3 + 1],
+        control_points[i * 3 + 2],
+        control_points[i * 3 + 3],
+    ]
+}
+
+/// Evaluates a cubic Bézier curve at the parameter `t`.
+pub(crate) fn eval(ctrl: &[Point<Real>; 4], t: Real) -> Point<Real> {
+    let s = 1.0 - t;
+    Point::from(
+        ctrl[0].coords * (s * s * s)
+            + ctrl[1].coords * (3.0 * s * s * t)
+            + ctrl[2].coords * (3.0 * s * t * t)
+            + ctrl[3].coords * (t * t * t),
+    )
+}
+
+/// Evaluates the first derivative of a cubic Bézier curve at the parameter `t`.
+pub(crate) fn eval_derivative(ctrl: &[Point<Real>; 4], t: Real) -> Vector<Real> {
+    let s = 1.0 - t;
+    (ctrl[1] - ctrl[0]) * (3.0 * s * s)
+        + (ctrl[2] - ctrl[1]) * (6.0 * s * t)
+        + (ctrl[3] - ctrl[2]) * (3.0 * t * t)
+}
+
+/// Evaluates the second derivative of a cubic Bézier curve at the parameter `t`.
+fn eval_second_derivative(ctrl: &[Point<Real>; 4], t: Real) -> Vector<Real> {
+    let d0 = ctrl[2] - ctrl[1] * 2.0 + ctrl[0].coords;
+    let d1 = ctrl[3] - ctrl[2] * 2.0 + ctrl[1].coords;
+    (d0 * (1.0 - t) + d1 * t) * 6.0
+}
+
+/// The coefficients `[a, b, c, d]` of the cubic polynomial `a t³ + b t² + c t + d` equal to
+/// the curve’s coordinates.
+pub(crate) fn power_basis(ctrl: &[Point<Real>; 4]) -> [Vector<Real>; 4] {
+    let [p0, p1, p2, p3] = ctrl.map(|pt| pt.coords);
+    [
+        p3 - p0 + (p1 - p2) * 3.0,
+        (p0 + p2) * 3.0 - p1 * 6.0,
+        (p1 - p0) * 3.0,
+        p0,
+    ]
+}
+
+/// Splits a cubic Bézier curve at `t = 0.5` with de Casteljau’s algorithm.
+fn split(ctrl: &[Point<Real>; 4]) -> ([Point<Real>; 4], [Point<Real>; 4]) {
+    let p01 = na::center(&ctrl[0], &ctrl[1]);
+    let p12 = na::center(&ctrl[1], &ctrl[2]);
+    let p23 = na::center(&ctrl[2], &ctrl[3]);
+    let p012 = na::center(&p01, &p12);
+    let p123 = na::center(&p12, &p23);
+    let mid = na::center(&p012, &p123);
+    ([ctrl[0], p01, p012, mid], [mid, p123, p23, ctrl[3]])
+}
+
+/// The maximum distance between the inner control points of a curve and its chord.
+///
+/// The curve being contained in the convex hull of its control points, this is an upper
+/// bound of the distance between the curve and its chord.
+fn flatness(ctrl: &[Point<Real>; 4]) -> Real {
+    let chord = Segment::new(ctrl[0], ctrl[3]);
+    chord
+        .distance_to_local_point(&ctrl[1], true)
+        .max(chord.distance_to_local_point(&ctrl[2], true))
+}
+
+/// The real roots of `a t² + b t + c` in the open interval `]0, 1[`.
+pub(crate) fn quadratic_roots_in_unit_interval(a: Real, b: Real, c: Real) -> ([Real; 2], usize) {
+    let mut roots = [0.0; 2];
+    let mut num_roots = 0;
+    let mut push = |t: Real| {
+        if t > 0.0 && t < 1.0 {
+            roots[num_roots] = t;
+            num_roots += 1;
+        }
+    };
+
+    if a.abs() <= Real::EPSILON * (b.abs() + c.abs()) {
+        if b != 0.0 {
+            push(-c / b);
+        }
+    } else {
+        let discr = b * b - 4.0 * a * c;
+
+        if discr >= 0.0 {
+            // Numerically stable formulation avoiding cancellations.
+            let q = -0.5 * (b + b.signum() * discr.sqrt());
+            push(q / a);
+
+            if q != 0.0 {
+                push(c / q);
+            }
+        }
+    }
+
+    if num_roots == 2 && roots[0] > roots[1] {
+        roots.swap(0, 1);
+    }
+
+    (roots, num_roots)
+}
+
+/// The tight axis-aligned bounding box of a cubic Bézier curve.
+fn segment_aabb(ctrl: &[Point<Real>; 4]) -> Aabb {
+    let mut aabb = Aabb::from_points(&[ctrl[0], ctrl[3]]);
+    let [a, b, c, _] = power_basis(ctrl);
+
+    // The curve reaches its extents at its endpoints or where its derivative along
+    // one of the axes vanishes.
+    for k in 0..2 {
+        let (roots, num_roots) = quadratic_roots_in_unit_interval(3.0 * a[k], 2.0 * b[k], c[k]);
+
+        for t in &roots[..num_roots] {
+            aabb.take_point(eval(ctrl, *t));
+        }
+    }
+
+    aabb
+}
+
+/// Appends to `out` the vertices (except the first one) of the polyline approximating a curve.
+fn flatten(ctrl: &[Point<Real>; 4], tolerance: Real, depth: usize, out: &mut Vec<Point<Real>>) {
+    if depth >= MAX_FLATTENING_DEPTH || flatness(ctrl) <= tolerance {
+        out.push(ctrl[3]);
+    } else {
+        let (left, right) = split(ctrl);
+        flatten(&left, tolerance, depth + 1, out);
+        flatten(&right, tolerance, depth + 1, out);
+    }
+}
+
+/// Projects a point on a cubic Bézier curve.
+///
+/// The curve is subdivided recursively, discarding the pieces whose bounding box is farther
+/// from `pt` than the best squared distance found so far (starting with `max_dist_sq`). Once
+/// they are flat enough, the closest point of each remaining piece is refined with Newton’s
+/// method. Returns the parameter of the closest point and its squared distance to `pt`, or
+/// `max_dist_sq` if no point is closer than that.
+fn project_on_segment(
+    ctrl: &[Point<Real>; 4],
+    pt: &Point<Real>,
+    max_dist_sq: Real,
+) -> (Real, Real) {
+    let mut best = (0.0, max_dist_sq);
+
+    for t in [0.0, 1.0] {
+        let dist_sq = na::distance_squared(pt, &eval(ctrl, t));
+
+        if dist_sq < best.1 {
+            best = (t, dist_sq);
+        }
+    }
+
+    let size = segment_aabb(ctrl).extents().norm();
+    let mut stack = Vec::new();
+    stack.push((*ctrl, 0.0, 1.0, 0));
+
+    while let Some((piece, t0, t1, depth)) = stack.pop() {
+        let dist = Aabb::from_points(&piece).distance_to_local_point(pt, true);
+
+        if dist * dist >= best.1 {
+            continue;
+        }
+
+        if depth < MAX_PROJECTION_DEPTH
+            && (depth < MIN_PROJECTION_DEPTH || flatness(&piece) > size * 1.0e-2)
+        {
+            let (left, right) = split(&piece);
+            let mid = (t0 + t1) * 0.5;
+            stack.push((left, t0, mid, depth + 1));
+            stack.push((right, mid, t1, depth + 1));
+            continue;
+        }
+
+        // Start Newton’s method from the projection of the point on the piece’s chord.
+        let chord = piece[3] - piece[0];
+        let chord_len_sq = chord.norm_squared();
+        let s = if chord_len_sq > 0.0 {
+            ((pt - piece[0]).dot(&chord) / chord_len_sq).clamp(0.0, 1.0)
+        } else {
+            0.5
+        };
+        let mut t = t0 + (t1 - t0) * s;
+
+        for _ in 0..MAX_NEWTON_ITERATIONS {
+            // Find a zero of the derivative of the squared distance, divided by 2.
+            let dpt = eval(ctrl, t) - pt;
+            let d1 = eval_derivative(ctrl, t);
+            let f = dpt.dot(&d1);
+            let df = d1.norm_squared() + dpt.dot(&eval_second_derivative(ctrl, t));
+
+            if df <= 0.0 {
+                break;
+            }
+
+            let new_t = (t - f / df).clamp(t0, t1);
+            let converged = (new_t - t).abs() <= Real::EPSILON * 10.0;
+            t = new_t;
+
+            if converged {
+                break;
+            }
+        }
+
+        let dist_sq = na::distance_squared(pt, &eval(ctrl, t));
+
+        if dist_sq < best.1 {
+            best = (t, dist_sq);
+        }
+    }
+
+    best
+}
+
+#[cfg(test)]
+mod test {
+    use crate::math::{Isometry, Point, Real, Vector};
+    use crate::query::{
+        self, ContactManifold, DefaultQueryDispatcher, PersistentQueryDispatcher, PointQuery, Ray,
+        RayCast,
+    };
+    use crate::shape::{
+        Ball, BezierSpline, BezierSplineBuilderError, Capsule, Cuboid, FeatureId, Shape,
+    };
+    use alloc::{vec, vec::Vec};
+    use approx::assert_relative_eq;
+
+    /// A quarter of a circle with radius 1 centered at the origin, from `(1, 0)` to `(0, 1)`.
+    fn quarter_circle() -> BezierSpline {
+        // The classical approximation of a circular arc with a cubic Bézier curve.
+        let k = 0.552_284_8;
+        BezierSpline::new(vec![
+            Point::new(1.0, 0.0),
+            Point::new(1.0, k),
+            Point::new(k, 1.0),
+            Point::new(0.0, 1.0),
+        ])
+        .unwrap()
+    }
+
+    /// A straight line from `(-5, 0)` to `(5, 0)` made of two quadratic segments.
+    fn straight_line() -> BezierSpline {
+        let points: Vec<_> = (0..5)
+            .map(|i| Point::new(-5.0 + 2.5 * i as Real, 0.0))
+            .collect();
+        BezierSpline::from_quadratic(&points).unwrap()
+    }
+
+    #[test]
+    fn bezier_spline_construction() {
+        assert_eq!(
+            BezierSpline::new(vec![Point::origin(); 3]).err(),
+            Some(BezierSplineBuilderError::NotEnoughControlPoints)
+        );
+        assert_eq!(
+            BezierSpline::new(vec![Point::origin(); 5]).err(),
+            Some(BezierSplineBuilderError::InvalidNumberOfControlPoints)
+        );
+        assert_eq!(
+            BezierSpline::from_quadratic(&[Point::origin(); 4]).err(),
+            Some(BezierSplineBuilderError::InvalidNumberOfControlPoints)
+        );
+
+        let line = straight_line();
+        assert_eq!(line.num_segments(), 2);
+        assert_relative_eq!(
+            line.point_at(1, 0.5),
+            Point::new(2.5, 0.0),
+            epsilon = 1.0e-5
+        );
+        assert_relative_eq!(line.tangent_at(0, 0.3), Vector::x() * 5.0, epsilon = 1.0e-5);
+
+        // The B-spline of a parabola’s samples is that parabola.
+        let points: Vec<_> = (0..6)
+            .map(|i| {
+                let x = i as Real;
+                Point::new(x, x * x)
+            })
+            .collect();
+        let spline = BezierSpline::from_b_spline(&points).unwrap();
+        assert_eq!(spline.num_segments(), 3);
+
+        for i in 0..3 {
+            for t in [0.0, 0.25, 0.5, 1.0] {
+                let pt = spline.point_at(i, t);
+                assert_relative_eq!(pt.y, pt.x * pt.x + 1.0 / 3.0, epsilon = 1.0e-4);
+            }
+        }
+
+        // The Aabb is tight around the curve.
+        let circle = quarter_circle();
+        assert_relative_eq!(circle.local_aabb().mins, Point::origin(), epsilon = 1.0e-5);
+        assert_relative_eq!(
+            circle.local_aabb().maxs,
+            Point::new(1.0, 1.0),
+            epsilon = 1.0e-5
+        );
+        assert_relative_eq!(circle.mass_properties(1.0).mass(), 0.0);
+    }
+
+    #[test]
+    fn bezier_spline_to_polyline() {
+        let circle = quarter_circle();
+        let coarse = circle.to_polyline(1.0e-2);
+        let fine = circle.to_polyline(1.0e-4);
+        assert!(coarse.num_segments() < fine.num_segments());
+
+        for polyline in [coarse, fine] {
+            assert_eq!(polyline.vertices()[0], Point::new(1.0, 0.0));
+            assert_eq!(polyline.vertices().last(), Some(&Point::new(0.0, 1.0)));
+
+            for pt in polyline.vertices() {
+                assert_relative_eq!(pt.coords.norm(), 1.0, epsilon = 1.0e-3);
+            }
+        }
+
+        // Straight parts of the curve aren’t subdivided.
+        assert_eq!(straight_line().to_polyline(1.0e-4).num_segments(), 2);
+    }
+
+    #[test]
+    fn bezier_spline_point_and_ray_queries() {
+        let circle = quarter_circle();
+
+        for pt in [
+            Point::new(2.0, 2.0),
+            Point::new(0.6, 1.2),
+            Point::new(3.0, 0.5),
+        ] {
+            let (proj, feature) = circle.project_local_point_and_get_feature(&pt);
+            assert!(!proj.is_inside);
+            assert_eq!(feature, FeatureId::Face(0));
+            assert_relative_eq!(
+                proj.point,
+                Point::from(pt.coords.normalize()),
+                epsilon = 1.0e-3
+            );
+        }
+
+        // Points closer to the endpoints are projected on them.
+        let proj = circle.project_local_point(&Point::new(2.0, -1.0), false);
+        assert_relative_eq!(proj.point, Point::new(1.0, 0.0), epsilon = 1.0e-5);
+
+        let ray = Ray::new(Point::origin(), Vector::new(1.0, 1.0));
+        let hit = circle
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+            .unwrap();
+        let expected_toi = Real::sqrt(0.5);
+        assert_relative_eq!(hit.time_of_impact, expected_toi, epsilon = 1.0e-3);
+        assert_relative_eq!(
+            hit.normal,
+            -Vector::new(1.0, 1.0).normalize(),
+            epsilon = 1.0e-3
+        );
+        assert_eq!(hit.feature, FeatureId::Face(0));
+        assert_eq!(circle.cast_local_ray(&ray, 0.5, true), None);
+
+        let ray = Ray::new(Point::new(2.0, 0.5), -Vector::x());
+        let toi = circle.cast_local_ray(&ray, Real::MAX, true).unwrap();
+        assert_relative_eq!(toi, 2.0 - Real::sqrt(0.75), epsilon = 1.0e-3);
+
+        let ray = Ray::new(Point::new(2.0, 2.0), Vector::x());
+        assert_eq!(circle.cast_local_ray(&ray, Real::MAX, true), None);
+    }
+
+    #[test]
+    fn bezier_spline_contacts() {
+        let circle = quarter_circle();
+        let dispatcher = DefaultQueryDispatcher;
+        let mut manifolds: Vec<ContactManifold<(), ()>> = Vec::new();
+
+        // A ball outside of the arc, along its diagonal.
+        let ball = Ball::new(0.5);
+        let dir = Vector::new(1.0, 1.0).normalize();
+        let pos12 = Isometry::translation(dir.x * 1.4, dir.y * 1.4);
+        let contact = query::contact(&Isometry::identity(), &circle, &pos12, &ball, 0.0)
+            .unwrap()
+            .unwrap();
+        assert_relative_eq!(contact.dist, -0.1, epsilon = 1.0e-3);
+        assert_relative_eq!(*contact.normal1, dir, epsilon = 1.0e-3);
+        assert_relative_eq!(*contact.normal2, -dir, epsilon = 1.0e-3);
+
+        // The same ball, seen from the other side of the arc.
+        let pos12 = Isometry::translation(dir.x * 0.6, dir.y * 0.6);
+        let contact = query::contact(&pos12, &ball, &Isometry::identity(), &circle, 0.0)
+            .unwrap()
+            .unwrap();
+        assert_relative_eq!(contact.dist, -0.1, epsilon = 1.0e-3);
+        assert_relative_eq!(*contact.normal1, dir, epsilon = 1.0e-3);
+        assert_relative_eq!(*contact.normal2, -dir, epsilon = 1.0e-3);
+
+        // A cuboid resting on a straight line.
+        let line = straight_line();
+        let cuboid = Cuboid::new(Vector::new(1.0, 0.5));
+        let pos12 = Isometry::new(Vector::new(0.5, 0.45), 0.0);
+        dispatcher
+            .contact_manifolds(&pos12, &line, &cuboid, 0.0, &mut manifolds, &mut None)
+            .unwrap();
+        assert!(!manifolds.is_empty());
+
+        for manifold in &manifolds {
+            assert_relative_eq!(manifold.points[0].dist, -0.05, epsilon = 1.0e-4);
+            assert_relative_eq!(manifold.local_n1, Vector::y(), epsilon = 1.0e-4);
+        }
+
+        // A capsule lying across the arc touches it in one place.
+        let capsule = Capsule::new_y(1.0, 0.1);
+        let pos12 = Isometry::translation(-0.5, 0.0);
+        dispatcher
+            .contact_manifolds(&pos12, &capsule, &circle, 0.0, &mut manifolds, &mut None)
+            .unwrap();
+        assert_eq!(manifolds.len(), 1);
+        assert_relative_eq!(manifolds[0].points[0].dist, -0.1, epsilon = 1.0e-3);
+
+        // An arc with both ends on the same side of a capsule touches it twice.
+        let arch = BezierSpline::new(vec![
+            Point::new(-1.0, 0.0),
+            Point::new(-1.0, 2.0),
+            Point::new(1.0, 2.0),
+            Point::new(1.0, 0.0),
+        ])
+        .unwrap();
+        let capsule = Capsule::new_x(2.0, 0.1);
+        let pos12 = Isometry::translation(0.0, 0.5);
+        dispatcher
+            .contact_manifolds(&pos12, &arch, &capsule, 0.0, &mut manifolds, &mut None)
+            .unwrap();
+        assert_eq!(manifolds.len(), 2);
+        assert_relative_eq!(
+            manifolds[0].points[0].dist,
+            manifolds[1].points[0].dist,
+            epsilon = 1.0e-3
+        );
+
+        // Far away shapes don’t generate any contact.
+        let pos12 = Isometry::translation(10.0, 0.0);
+        dispatcher
+            .contact_manifolds(&pos12, &circle, &ball, 0.0, &mut manifolds, &mut None)
+            .unwrap();
+        assert!(manifolds.is_empty());
+    }
+}
@@ -3,8 +3,8 @@
 //!
 
 use crate::bounding_volume::{Aabb, BoundingSphere, BoundingVolume};
-use crate::math::{Isometry, Real};
-use crate::partitioning::Qbvh;
+use crate::math::{Isometry, Real, Vector};
+use crate::partitioning::{Qbvh, QbvhUpdateWorkspace};
 use crate::query::details::NormalConstraints;
 #[cfg(feature = "dim2")]
 use crate::shape::{ConvexPolygon, TriMesh, Triangle};
@@ -18,15 +18,64 @@ use alloc::vec::Vec;
 /// A compound shape is a shape composed of the union of several simpler shape. This is
 /// the main way of creating a concave shape from convex parts. Each parts can have its own
 /// delta transformation to shift or rotate it with regard to the other shapes.
+///
+/// Each part is identified by a sub-shape id which doesn’t change when other parts are added
+/// with [`Compound::push`] or removed with [`Compound::remove`]. The sub-shape ids are the
+/// indices of the parts in [`Compound::shapes`] as long as no part is removed.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "CompoundData"))]
 #[derive(Clone, Debug)]
 pub struct Compound {
     shapes: Vec<(Isometry<Real>, SharedShape)>,
+    /// The sub-shape id of each element of `shapes`.
+    ids: Vec<u32>,
+    /// The index in `shapes` of each sub-shape id, or `u32::MAX` if it was removed.
+    slots: Vec<u32>,
+    free_ids: Vec<u32>,
     qbvh: Qbvh<u32>,
     aabbs: Vec<Aabb>,
     aabb: Aabb,
 }
 
+/// The serialized fields of a [`Compound`], which may come from a version of parry where
+/// parts couldn’t be removed and the sub-shape ids were always the indices of the parts.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct CompoundData {
+    shapes: Vec<(Isometry<Real>, SharedShape)>,
+    #[serde(default)]
+    ids: Vec<u32>,
+    #[serde(default)]
+    slots: Vec<u32>,
+    #[serde(default)]
+    free_ids: Vec<u32>,
+    qbvh: Qbvh<u32>,
+    aabbs: Vec<Aabb>,
+    aabb: Aabb,
+}
+
+#[cfg(feature = "serde")]
+impl From<CompoundData> for Compound {
+    fn from(data: CompoundData) -> Self {
+        let mut compound = Compound {
+            shapes: data.shapes,
+            ids: data.ids,
+            slots: data.slots,
+            free_ids: data.free_ids,
+            qbvh: data.qbvh,
+            aabbs: data.aabbs,
+            aabb: data.aabb,
+        };
+
+        if compound.ids.is_empty() && compound.slots.is_empty() {
+            compound.ids = (0..compound.shapes.len() as u32).collect();
+            compound.slots = compound.ids.clone();
+        }
+
+        compound
+    }
+}
+
 impl Compound {
     /// Builds a new compound shape.
     ///
@@ -54,12 +103,16 @@ impl Compound {
         }
 
         let mut qbvh = Qbvh::new();
-        // NOTE: we apply no dilation factor because we won't
-        // update this tree dynamically.
+        // NOTE: we apply no dilation factor because the tree is
+        // refitted exactly whenever it is updated.
         qbvh.clear_and_rebuild(leaves.into_iter(), 0.0);
+        let ids: Vec<u32> = (0..shapes.len() as u32).collect();
 
         Compound {
             shapes,
+            slots: ids.clone(),
+            ids,
+            free_ids: Vec::new(),
             qbvh,
             aabbs,
             aabb,
@@ -91,12 +144,197 @@ impl Compound {
 }
 
 impl Compound {
+    /// Adds a new shape to this compound shape, and returns its sub-shape id.
+    ///
+    /// The id of a previously removed shape may be reused. The [`Qbvh`] is refitted
+    /// incrementally, and rebalanced if `rebalance` is `true`. New leaves are attached close
+    /// to the root of the tree, so it should be rebalanced once in a while when many shapes
+    /// are added, e.g., with the last shape of a batch.
+    ///
+    /// Panics if `shape` is a composite shape (nested composite shapes are not allowed).
+    pub fn push(
+        &mut self,
+        position: Isometry<Real>,
+        shape: SharedShape,
+        workspace: &mut QbvhUpdateWorkspace,
+        rebalance: bool,
+    ) -> u32 {
+        assert!(
+            shape.as_composite_shape().is_none(),
+            "Nested composite shapes are not allowed."
+        );
+
+        let slot = self.shapes.len() as u32;
+        let id = if let Some(id) = self.free_ids.pop() {
+            self.slots[id as usize] = slot;
+            id
+        } else {
+            self.slots.push(slot);
+            self.slots.len() as u32 - 1
+        };
+
+        self.aabbs.push(shape.compute_aabb(&position));
+        self.shapes.push((position, shape));
+        self.ids.push(id);
+        self.qbvh.pre_update_or_insert(id);
+        self.refit_qbvh(workspace, rebalance);
+        id
+    }
+
+    /// Removes the shape with the given sub-shape id from this compound shape.
+    ///
+    /// The sub-shape ids of the other shapes are unchanged, but the last element of
+    /// [`Compound::shapes`] is moved in place of the removed one. Returns `None` if there is
+    /// no shape with this id. The [`Qbvh`] is refitted incrementally, and rebalanced if
+    /// `rebalance` is `true`. Note that the compound shape is left empty if its last shape is
+    /// removed.
+    pub fn remove(
+        &mut self,
+        id: u32,
+        workspace: &mut QbvhUpdateWorkspace,
+        rebalance: bool,
+    ) -> Option<(Isometry<Real>, SharedShape)> {
+        let slot = *self.slots.get(id as usize)? as usize;
+        if slot >= self.shapes.len() {
+            return None;
+        }
+
+        let removed = self.shapes.swap_remove(slot);
+        let _ = self.ids.swap_remove(slot);
+        let _ = self.aabbs.swap_remove(slot);
+        if let Some(moved_id) = self.ids.get(slot) {
+            self.slots[*moved_id as usize] = slot as u32;
+        }
+        self.slots[id as usize] = u32::MAX;
+        self.free_ids.push(id);
+
+        let _ = self.qbvh.remove(id);
+        self.refit_qbvh(workspace, rebalance);
+        Some(removed)
+    }
+
+    /// Sets the position, relative to this compound shape, of the shape with the given
+    /// sub-shape id.
+    ///
+    /// The [`Qbvh`] is refitted incrementally, and rebalanced if `rebalance` is `true`.
+    ///
+    /// Panics if there is no shape with this id.
+    pub fn set_shape_position(
+        &mut self,
+        id: u32,
+        position: Isometry<Real>,
+        workspace: &mut QbvhUpdateWorkspace,
+        rebalance: bool,
+    ) {
+        assert!(self.shape(id).is_some(), "There is no shape with this id.");
+        let slot = self.slots[id as usize] as usize;
+        let (pos, shape) = &mut self.shapes[slot];
+        *pos = position;
+        self.aabbs[slot] = shape.compute_aabb(pos);
+        self.qbvh.pre_update_or_insert(id);
+        self.refit_qbvh(workspace, rebalance);
+    }
+
+    /// Updates the [`Qbvh`] leaves modified since its last update, and optionally rebalances it.
+    fn refit_qbvh(&mut self, workspace: &mut QbvhUpdateWorkspace, rebalance: bool) {
+        let (aabbs, slots) = (&self.aabbs, &self.slots);
+        let _ = self
+            .qbvh
+            .refit_tight(0.0, workspace, |id| aabbs[slots[*id as usize] as usize]);
+
+        if rebalance {
+            self.qbvh.rebalance(0.0, workspace);
+        }
+
+        self.aabb = *self.qbvh.root_aabb();
+    }
+
+    /// Computes a scaled version of this compound shape, where each shape keeps its sub-shape
+    /// id.
+    ///
+    /// Returns `None` if one of the shapes can’t be scaled.
+    pub(crate) fn scaled(&self, scale: &Vector<Real>, num_subdivisions: u32) -> Option<Self> {
+        let shapes = self
+            .shapes
+            .iter()
+            .map(|(pos, shape)| {
+                let scaled_shape = shape.scale_dyn(scale, num_subdivisions)?;
+                Some((
+                    Isometry::from_parts(
+                        (pos.translation.vector.component_mul(scale)).into(),
+                        pos.rotation,
+                    ),
+                    SharedShape(scaled_shape.into()),
+                ))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let aabbs: Vec<_> = shapes
+            .iter()
+            .map(|(pos, shape)| shape.compute_aabb(pos))
+            .collect();
+
+        let mut qbvh = Qbvh::new();
+        let leaves = self.ids.iter().copied().zip(aabbs.iter().copied());
+        qbvh.clear_and_rebuild(leaves, 0.0);
+
+        Some(Compound {
+            shapes,
+            ids: self.ids.clone(),
+            slots: self.slots.clone(),
+            free_ids: self.free_ids.clone(),
+            aabb: *qbvh.root_aabb(),
+            qbvh,
+            aabbs,
+        })
+    }
+
     /// The shapes of this compound shape.
+    ///
+    /// The removed shapes aren’t part of this slice. As long as no shape was removed with
+    /// [`Compound::remove`], it is indexed by sub-shape id. Otherwise, the sub-shape id of each
+    /// of its elements is given by [`Compound::shape_ids`].
     #[inline]
     pub fn shapes(&self) -> &[(Isometry<Real>, SharedShape)] {
         &self.shapes[..]
     }
 
+    /// The sub-shape id of each element of [`Compound::shapes`].
+    #[inline]
+    pub fn shape_ids(&self) -> &[u32] {
+        &self.ids[..]
+    }
+
+    /// The shapes of this compound shape, indexed by their sub-shape id, where the shapes
+    /// removed with [`Compound::remove`] are `None`.
+    pub fn shape_slots(
+        &self,
+    ) -> impl ExactSizeIterator<Item = Option<&(Isometry<Real>, SharedShape)>> {
+        self.slots
+            .iter()
+            .map(|slot| self.shapes.get(*slot as usize))
+    }
+
+    /// Iterates through the sub-shape ids and the shapes of this compound shape, in the order
+    /// of [`Compound::shapes`].
+    pub fn iter_shapes(&self) -> impl Iterator<Item = (u32, &Isometry<Real>, &SharedShape)> {
+        self.ids
+            .iter()
+            .zip(self.shapes.iter())
+            .map(|(id, (pos, shape))| (*id, pos, shape))
+    }
+
+    /// The shape with the given sub-shape id, with its position relative to this compound shape.
+    #[inline]
+    pub fn shape(&self, id: u32) -> Option<&(Isometry<Real>, SharedShape)> {
+        self.shapes.get(*self.slots.get(id as usize)? as usize)
+    }
+
+    /// The number of shapes of this compound shape.
+    #[inline]
+    pub fn num_shapes(&self) -> usize {
+        self.shapes.len()
+    }
+
     /// The [`Aabb`] of this compound in its local-space.
     #[inline]
     pub fn local_aabb(&self) -> &Aabb {
@@ -109,7 +347,7 @@ impl Compound {
         self.aabb.bounding_sphere()
     }
 
-    /// The shapes Aabbs.
+    /// The shapes Aabbs, in the same order as [`Compound::shapes`].
     #[inline]
     pub fn aabbs(&self) -> &[Aabb] {
         &self.aabbs[..]
@@ -129,7 +367,7 @@ impl SimdCompositeShape for Compound {
         shape_id: u32,
         f: &mut dyn FnMut(Option<&Isometry<Real>>, &dyn Shape, Option<&dyn NormalConstraints>),
     ) {
-        if let Some(shape) = self.shapes.get(shape_id as usize) {
+        if let Some(shape) = self.shape(shape_id) {
             f(Some(&shape.0), &*shape.1, None)
         }
     }
@@ -155,7 +393,7 @@ impl TypedSimdCompositeShape for Compound {
             Option<&Self::PartNormalConstraints>,
         ),
     ) {
-        if let Some((part_pos, part)) = self.shapes.get(i as usize) {
+        if let Some((part_pos, part)) = self.shape(i) {
             f(Some(part_pos), &**part, None)
         }
     }
@@ -166,7 +404,7 @@ impl TypedSimdCompositeShape for Compound {
         i: u32,
         mut f: impl FnMut(Option<&Isometry<Real>>, &Self::PartShape, Option<&dyn NormalConstraints>),
     ) {
-        if let Some((part_pos, part)) = self.shapes.get(i as usize) {
+        if let Some((part_pos, part)) = self.shape(i) {
             f(Some(part_pos), &**part, None)
         }
     }
@@ -176,3 +414,144 @@ impl TypedSimdCompositeShape for Compound {
         &self.qbvh
     }
 }
+
+#[cfg(test)]
+#[cfg(feature = "dim3")]
+mod test {
+    use crate::math::{Isometry, Vector};
+    use crate::partitioning::QbvhUpdateWorkspace;
+    use crate::query::{self, ContactManifold, DefaultQueryDispatcher, PersistentQueryDispatcher};
+    use crate::shape::{Ball, Compound, Cuboid, Shape, SharedShape};
+    use alloc::{sync::Arc, vec, vec::Vec};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn compound_push_remove_and_move_shapes() {
+        let ball = SharedShape::ball(0.5);
+        let mut compound = Compound::new(vec![
+            (Isometry::identity(), ball.clone()),
+            (Isometry::translation(2.0, 0.0, 0.0), ball.clone()),
+            (Isometry::translation(4.0, 0.0, 0.0), ball.clone()),
+        ]);
+        let mut workspace = QbvhUpdateWorkspace::default();
+
+        // Removing a shape keeps the ids of the others.
+        assert!(compound.remove(2, &mut workspace, false).is_some());
+        assert!(compound.remove(2, &mut workspace, false).is_none());
+        assert_eq!(compound.num_shapes(), 2);
+        assert_eq!(compound.shapes().len(), 2);
+        assert!(compound.shape_slots().nth(2).unwrap().is_none());
+        // The removed shape isn’t kept alive by the compound.
+        assert_eq!(Arc::strong_count(&ball.0), 3);
+        assert_relative_eq!(compound.local_aabb().maxs.x, 2.5);
+
+        // The slot of the removed shape is reused.
+        let cuboid = SharedShape::cuboid(0.5, 0.5, 0.5);
+        let pos = Isometry::translation(0.0, 3.0, 0.0);
+        assert_eq!(compound.push(pos, cuboid, &mut workspace, false), 2);
+        let pos = Isometry::translation(0.0, 6.0, 0.0);
+        assert_eq!(compound.push(pos, ball.clone(), &mut workspace, true), 3);
+        assert_relative_eq!(compound.local_aabb().maxs.y, 6.5);
+
+        let pos = Isometry::translation(0.0, -6.0, 0.0);
+        compound.set_shape_position(3, pos, &mut workspace, false);
+        assert_relative_eq!(compound.local_aabb().mins.y, -6.5);
+        assert_relative_eq!(compound.local_aabb().maxs.y, 3.5);
+        compound.qbvh().check_topology(true, |id| {
+            let (pos, shape) = compound.shape(*id).unwrap();
+            shape.compute_aabb(pos)
+        });
+        // Removing a shape other than the last one moves the last one in its slot.
+        assert!(compound.remove(0, &mut workspace, false).is_some());
+        assert_eq!(compound.shape_ids(), &[3, 1, 2]);
+        assert_relative_eq!(
+            compound.shape(3).unwrap().0.translation.vector,
+            Vector::y() * -6.0
+        );
+        let pos = Isometry::translation(0.0, -1.0, 0.0);
+        assert_eq!(compound.push(pos, ball.clone(), &mut workspace, false), 0);
+
+        // Scaling keeps the sub-shape ids.
+        assert!(compound.remove(1, &mut workspace, false).is_some());
+        let scaled = compound.scale_dyn(&Vector::repeat(2.0), 10).unwrap();
+        let scaled = scaled.as_compound().unwrap();
+        assert!(scaled.shape(1).is_none());
+        assert_relative_eq!(
+            scaled.shape(3).unwrap().0.translation.vector,
+            Vector::y() * -12.0
+        );
+        let pos = Isometry::translation(2.0, 0.0, 0.0);
+        assert_eq!(compound.push(pos, ball.clone(), &mut workspace, true), 1);
+
+        // Queries report the stable sub-shape ids.
+        let dispatcher = DefaultQueryDispatcher;
+        let mut manifolds: Vec<ContactManifold<(), ()>> = Vec::new();
+        let pos12 = Isometry::translation(0.0, -7.0, 0.0);
+        dispatcher
+            .contact_manifolds(
+                &pos12,
+                &compound,
+                &Ball::new(1.0),
+                0.0,
+                &mut manifolds,
+                &mut None,
+            )
+            .unwrap();
+        assert_eq!(manifolds.len(), 1);
+        assert_eq!(manifolds[0].subshape1, 3);
+
+        let dist = query::distance(
+            &Isometry::identity(),
+            &compound,
+            &Isometry::translation(4.0, 0.0, 0.0),
+            &Cuboid::new(Vector::repeat(0.5)),
+        )
+        .unwrap();
+        assert_relative_eq!(dist, 1.0, epsilon = 1.0e-5);
+
+        let mprops = compound.mass_properties(1.0);
+        let expected_mass = 3.0 * Ball::new(0.5).mass_properties(1.0).mass() + 1.0;
+        assert_relative_eq!(mprops.mass(), expected_mass, epsilon = 1.0e-5);
+        assert!(compound.shape(1).is_some());
+        assert_relative_eq!(
+            compound.shape(3).unwrap().0.translation.vector,
+            Vector::y() * -6.0
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde-serialize", feature = "std"))]
+    fn compound_deserialization_from_old_layout() {
+        let compound = Compound::new(vec![
+            (Isometry::identity(), SharedShape::ball(0.5)),
+            (Isometry::translation(2.0, 0.0, 0.0), SharedShape::ball(0.5)),
+        ]);
+
+        // Compounds serialized before their parts could be removed don’t have these fields.
+        let mut json = serde_json::to_value(&compound).unwrap();
+        for field in ["ids", "slots", "free_ids"] {
+            assert!(json.as_object_mut().unwrap().remove(field).is_some());
+        }
+
+        let mut compound: Compound = serde_json::from_value(json).unwrap();
+        assert_eq!(compound.shape_ids(), &[0, 1]);
+        assert!(compound.shape(1).is_some());
+
+        let mut workspace = QbvhUpdateWorkspace::default();
+        assert!(compound.remove(0, &mut workspace, false).is_some());
+        let ball = SharedShape::ball(1.0);
+        assert_eq!(
+            compound.push(Isometry::identity(), ball, &mut workspace, true),
+            0
+        );
+
+        // New compounds survive a round-trip.
+        let json = serde_json::to_string(&compound).unwrap();
+        let deserialized: Compound = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.shape_ids(), &[1, 0]);
+        assert_eq!(
+            deserialized.shape(0).unwrap().1.as_ball().unwrap().radius,
+            1.0
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use crate::math::{Point, Real, Vector};
 use crate::shape::{FeatureId, PackedFeatureId, PolygonalFeature, PolygonalFeatureMap, SupportMap};
+use crate::transformation::HalfSpacesIntersectionError;
 use crate::utils;
 use alloc::vec::Vec;
 use na::{self, ComplexField, RealField, Unit};
@@ -27,6 +28,26 @@ impl ConvexPolygon {
         Self::from_convex_polyline(vertices)
     }
 
+    /// Creates a new 2D convex polygon from the intersection of a set of half-planes.
+    ///
+    /// Each half-plane is given as a pair `(normal, offset)` and contains the points `x` such
+    /// that `normal.dot(x) <= offset`. The normal points outward and doesn't have to be
+    /// normalized. Redundant half-planes are ignored, and the vertices that nearly coincide
+    /// (e.g. because of almost parallel lines) are merged. The normal of each edge is the
+    /// normalized normal of the half-plane supporting it.
+    ///
+    /// Returns an error if the intersection is empty, unbounded, or flat.
+    pub fn from_half_spaces(
+        half_spaces: &[(Vector<Real>, Real)],
+    ) -> Result<Self, HalfSpacesIntersectionError> {
+        let (points, edges) = crate::transformation::half_spaces_intersection(half_spaces)?;
+        let (normals, points) = edges
+            .into_iter()
+            .map(|(normal, vertices)| (normal, points[vertices[0] as usize]))
+            .unzip();
+        Ok(ConvexPolygon { points, normals })
+    }
+
     /// Creates a new 2D convex polygon from a set of points assumed to
     /// describe a counter-clockwise convex polyline.
     ///
@@ -1,10 +1,11 @@
 use crate::math::{Point, Real, Vector, DIM};
 use crate::shape::{FeatureId, PackedFeatureId, PolygonalFeature, PolygonalFeatureMap, SupportMap};
+use crate::transformation::HalfSpacesIntersectionError;
 // use crate::transformation;
 use crate::utils::hashmap::{Entry, HashMap};
 use crate::utils::{self, SortedPair};
 #[cfg(feature = "alloc")]
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 use core::f64;
 #[cfg(not(feature = "std"))]
 use na::ComplexField; // for .abs(), .sqrt(), and .sin_cos()
@@ -112,6 +113,64 @@ pub struct ConvexPolyhedron {
     vertices_adj_to_face: Vec<u32>,
 }
 
+/// Computes the vertices of a convex polyhedron, and the faces and edges adjacent to each of
+/// them, from the vertices and edges adjacent to its faces.
+fn vertices_adjacency(
+    num_points: usize,
+    faces: &[Face],
+    vertices_adj_to_face: &[u32],
+    edges_adj_to_face: &[u32],
+) -> (Vec<Vertex>, Vec<u32>, Vec<u32>) {
+    let empty_vertex = Vertex {
+        first_adj_face_or_edge: 0,
+        num_adj_faces_or_edge: 0,
+    };
+
+    let mut vertices = vec![empty_vertex; num_points];
+
+    // First, find their multiplicities.
+    for face in faces {
+        let first_vid = face.first_vertex_or_edge;
+        let last_vid = face.first_vertex_or_edge + face.num_vertices_or_edges;
+
+        for i in &vertices_adj_to_face[first_vid as usize..last_vid as usize] {
+            vertices[*i as usize].num_adj_faces_or_edge += 1;
+        }
+    }
+
+    // Now, find their starting id.
+    let mut total_num_adj_faces = 0;
+    for v in &mut vertices {
+        v.first_adj_face_or_edge = total_num_adj_faces;
+        total_num_adj_faces += v.num_adj_faces_or_edge;
+    }
+    let mut faces_adj_to_vertex = vec![0; total_num_adj_faces as usize];
+    let mut edges_adj_to_vertex = vec![0; total_num_adj_faces as usize];
+
+    // Reset the number of adjacent faces.
+    // It will be set again to the right value as
+    // the adjacent face list is filled.
+    for v in &mut vertices {
+        v.num_adj_faces_or_edge = 0;
+    }
+
+    for (face_id, face) in faces.iter().enumerate() {
+        let first_vid = face.first_vertex_or_edge;
+        let last_vid = face.first_vertex_or_edge + face.num_vertices_or_edges;
+
+        for vid in first_vid..last_vid {
+            let v = &mut vertices[vertices_adj_to_face[vid as usize] as usize];
+            faces_adj_to_vertex[(v.first_adj_face_or_edge + v.num_adj_faces_or_edge) as usize] =
+                face_id as u32;
+            edges_adj_to_vertex[(v.first_adj_face_or_edge + v.num_adj_faces_or_edge) as usize] =
+                edges_adj_to_face[vid as usize];
+            v.num_adj_faces_or_edge += 1;
+        }
+    }
+
+    (vertices, faces_adj_to_vertex, edges_adj_to_vertex)
+}
+
 impl ConvexPolyhedron {
     /// Creates a new convex polyhedron from an arbitrary set of points.
     ///
@@ -123,6 +182,101 @@ impl ConvexPolyhedron {
             .and_then(|(vertices, indices)| Self::from_convex_mesh(vertices, &indices))
     }
 
+    /// Creates a new convex polyhedron from the intersection of a set of half-spaces.
+    ///
+    /// Each half-space is given as a pair `(normal, offset)` and contains the points `x` such
+    /// that `normal.dot(x) <= offset`. The normal points outward and doesn't have to be
+    /// normalized. Redundant half-spaces are ignored, and the vertices that nearly coincide
+    /// (e.g. because of almost parallel planes) are merged. Each face of the polyhedron lies on
+    /// the plane of one half-space, and its normal is the normalized normal of this half-space.
+    ///
+    /// Returns an error if the intersection is empty, unbounded, or flat.
+    pub fn from_half_spaces(
+        half_spaces: &[(Vector<Real>, Real)],
+    ) -> Result<ConvexPolyhedron, HalfSpacesIntersectionError> {
+        let (points, faces) = crate::transformation::half_spaces_intersection(half_spaces)?;
+        Self::from_convex_faces(points, &faces).ok_or(HalfSpacesIntersectionError::Degenerate)
+    }
+
+    /// Creates a new convex polyhedron from its faces, each given as its outward unit normal
+    /// and the indices of its vertices in counterclockwise order around this normal.
+    ///
+    /// Returns `None` if the faces don't form a closed manifold polyhedron.
+    pub(crate) fn from_convex_faces(
+        points: Vec<Point<Real>>,
+        polygons: &[(Unit<Vector<Real>>, Vec<u32>)],
+    ) -> Option<ConvexPolyhedron> {
+        let mut edges = Vec::<Edge>::new();
+        let mut faces = Vec::with_capacity(polygons.len());
+        let mut edge_map = HashMap::default();
+        let mut edges_adj_to_face = Vec::new();
+        let mut vertices_adj_to_face = Vec::new();
+
+        for (face_id, (normal, polygon)) in polygons.iter().enumerate() {
+            faces.push(Face {
+                first_vertex_or_edge: vertices_adj_to_face.len() as u32,
+                num_vertices_or_edges: polygon.len() as u32,
+                normal: *normal,
+            });
+
+            for (i, &i1) in polygon.iter().enumerate() {
+                let i2 = polygon[(i + 1) % polygon.len()];
+                let edge_id = match edge_map.entry(SortedPair::new(i1, i2)) {
+                    Entry::Occupied(e) => {
+                        let edge = &mut edges[*e.get() as usize];
+
+                        if edge.faces[1] != u32::MAX || edge.vertices != Point2::new(i2, i1) {
+                            // We have a t-junction, or inconsistent orientations.
+                            return None;
+                        }
+
+                        edge.faces[1] = face_id as u32;
+                        *e.get()
+                    }
+                    Entry::Vacant(e) => {
+                        let dir = Unit::try_new(
+                            points[i2 as usize] - points[i1 as usize],
+                            crate::math::DEFAULT_EPSILON,
+                        )?;
+                        edges.push(Edge {
+                            vertices: Point2::new(i1, i2),
+                            faces: Point2::new(face_id as u32, u32::MAX),
+                            dir,
+                            deleted: false,
+                        });
+                        *e.insert(edges.len() as u32 - 1)
+                    }
+                };
+
+                edges_adj_to_face.push(edge_id);
+                vertices_adj_to_face.push(i1);
+            }
+        }
+
+        if edges.iter().any(|e| e.faces[1] == u32::MAX) {
+            // The polyhedron isn't closed.
+            return None;
+        }
+
+        let (vertices, faces_adj_to_vertex, edges_adj_to_vertex) = vertices_adjacency(
+            points.len(),
+            &faces,
+            &vertices_adj_to_face,
+            &edges_adj_to_face,
+        );
+
+        Some(ConvexPolyhedron {
+            points,
+            vertices,
+            faces,
+            edges,
+            faces_adj_to_vertex,
+            edges_adj_to_vertex,
+            edges_adj_to_face,
+            vertices_adj_to_face,
+        })
+    }
+
     /// Attempts to create a new solid assumed to be convex from the set of points and indices.
     ///
     /// The given points and index information are assumed to describe a convex polyhedron.
@@ -137,14 +291,11 @@ impl ConvexPolyhedron {
     ) -> Option<ConvexPolyhedron> {
         let eps = crate::math::DEFAULT_EPSILON.sqrt();
 
-        let mut vertices = Vec::new();
         let mut edges = Vec::<Edge>::new();
         let mut faces = Vec::<Face>::new();
         let mut triangles = Vec::new();
         let mut edge_map = HashMap::default();
 
-        let mut faces_adj_to_vertex = Vec::new();
-        let mut edges_adj_to_vertex = Vec::new();
         let mut edges_adj_to_face = Vec::new();
         let mut vertices_adj_to_face = Vec::new();
 
@@ -310,57 +461,12 @@ impl ConvexPolyhedron {
             }
         }
 
-        /*
-         * Initialize vertices
-         */
-        let empty_vertex = Vertex {
-            first_adj_face_or_edge: 0,
-            num_adj_faces_or_edge: 0,
-        };
-
-        vertices.resize(points.len(), empty_vertex);
-
-        // First, find their multiplicities.
-        for face in &faces {
-            let first_vid = face.first_vertex_or_edge;
-            let last_vid = face.first_vertex_or_edge + face.num_vertices_or_edges;
-
-            for i in &vertices_adj_to_face[first_vid as usize..last_vid as usize] {
-                vertices[*i as usize].num_adj_faces_or_edge += 1;
-            }
-        }
-
-        // Now, find their starting id.
-        let mut total_num_adj_faces = 0;
-        for v in &mut vertices {
-            v.first_adj_face_or_edge = total_num_adj_faces;
-            total_num_adj_faces += v.num_adj_faces_or_edge;
-        }
-        faces_adj_to_vertex.resize(total_num_adj_faces as usize, 0);
-        edges_adj_to_vertex.resize(total_num_adj_faces as usize, 0);
-
-        // Reset the number of adjacent faces.
-        // It will be set again to the right value as
-        // the adjacent face list is filled.
-        for v in &mut vertices {
-            v.num_adj_faces_or_edge = 0;
-        }
-
-        for (face_id, face) in faces.iter().enumerate() {
-            let first_vid = face.first_vertex_or_edge;
-            let last_vid = face.first_vertex_or_edge + face.num_vertices_or_edges;
-
-            for vid in first_vid..last_vid {
-                let v = &mut vertices[vertices_adj_to_face[vid as usize] as usize];
-                faces_adj_to_vertex
-                    [(v.first_adj_face_or_edge + v.num_adj_faces_or_edge) as usize] =
-                    face_id as u32;
-                edges_adj_to_vertex
-                    [(v.first_adj_face_or_edge + v.num_adj_faces_or_edge) as usize] =
-                    edges_adj_to_face[vid as usize];
-                v.num_adj_faces_or_edge += 1;
-            }
-        }
+        let (vertices, faces_adj_to_vertex, edges_adj_to_vertex) = vertices_adjacency(
+            points.len(),
+            &faces,
+            &vertices_adj_to_face,
+            &edges_adj_to_face,
+        );
 
         // Note numerical errors may throw off the Euler characteristic.
         // So we don't check it right now.
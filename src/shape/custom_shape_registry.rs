@@ -0,0 +1,649 @@
+use crate::math::{Isometry, Real, Vector};
+use crate::query::details::NormalConstraints;
+use crate::query::details::ShapeCastOptions;
+use crate::query::{
+    ClosestPoints, Contact, ContactManifold, ContactManifoldsWorkspace, NonlinearRigidMotion,
+    PersistentQueryDispatcher, QueryDispatcher, ShapeCastHit, Unsupported,
+};
+use crate::shape::{Shape, SharedShape};
+use alloc::collections::BTreeMap;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::any::{Any, TypeId};
+
+/// Error returned when registering a custom shape type.
+#[derive(thiserror::Error, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CustomShapeRegistryError {
+    /// Another custom shape type is already registered with this identifier.
+    #[error("Another custom shape type is already registered with the identifier {0}.")]
+    IdAlreadyRegistered(u32),
+    /// This custom shape type is already registered with another identifier.
+    #[error("This custom shape type is already registered with the identifier {0}.")]
+    TypeAlreadyRegistered(u32),
+    /// This custom shape type hasn’t been registered.
+    #[error("This custom shape type hasn’t been registered.")]
+    UnregisteredType,
+}
+
+/// The data of a serialized custom shape.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct CustomShapeData {
+    /// The identifier the shape type was registered with.
+    pub id: u32,
+    /// The shape serialized by the function given to [`CustomShapeRegistry::set_serialization`].
+    pub data: Vec<u8>,
+}
+
+type SerializeFn = dyn Fn(&dyn Shape) -> Option<Vec<u8>> + Send + Sync;
+type DeserializeFn = dyn Fn(&[u8]) -> Option<SharedShape> + Send + Sync;
+
+struct CustomShapeEntry<ManifoldData, ContactData> {
+    serialize: Option<Arc<SerializeFn>>,
+    deserialize: Option<Arc<DeserializeFn>>,
+    dispatcher: Option<Arc<dyn PersistentQueryDispatcher<ManifoldData, ContactData>>>,
+}
+
+impl<ManifoldData, ContactData> Default for CustomShapeEntry<ManifoldData, ContactData> {
+    fn default() -> Self {
+        Self {
+            serialize: None,
+            deserialize: None,
+            dispatcher: None,
+        }
+    }
+}
+
+impl<ManifoldData, ContactData> Clone for CustomShapeEntry<ManifoldData, ContactData> {
+    fn clone(&self) -> Self {
+        Self {
+            serialize: self.serialize.clone(),
+            deserialize: self.deserialize.clone(),
+            dispatcher: self.dispatcher.clone(),
+        }
+    }
+}
+
+/// A registry of user-defined shape types, each identified by a stable numeric identifier.
+///
+/// Custom shapes all report [`ShapeType::Custom`](crate::shape::ShapeType::Custom). Registering
+/// their type under an identifier allows to:
+/// - Tell them apart with [`Self::custom_shape_id`] without trying to downcast them to every
+///   possible type.
+/// - Serialize them as part of a [`SharedShape`] (see [`Self::set_serialization`] and
+///   [`Self::set_global`]). They are serialized as a [`CustomShapeData`]. This requires the
+///   `std` feature: without it, serializing a custom shape fails, and deserializing it returns
+///   `None`.
+/// - Dispatch geometric queries, including contact-manifold computations, to the
+///   [`PersistentQueryDispatcher`] registered for their type (see [`Self::set_dispatcher`]).
+///   The registry itself implements `PersistentQueryDispatcher`, so it can be chained with the
+///   [`DefaultQueryDispatcher`](crate::query::DefaultQueryDispatcher), e.g., with
+///   `registry.chain(DefaultQueryDispatcher)` so the registered dispatchers are tried first.
+///
+/// The `ManifoldData` and `ContactData` type parameters are the ones of the contact manifolds
+/// computed by the registered dispatchers.
+pub struct CustomShapeRegistry<ManifoldData = (), ContactData = ()> {
+    ids: BTreeMap<TypeId, u32>,
+    entries: BTreeMap<u32, CustomShapeEntry<ManifoldData, ContactData>>,
+}
+
+impl<ManifoldData, ContactData> Default for CustomShapeRegistry<ManifoldData, ContactData> {
+    fn default() -> Self {
+        Self {
+            ids: BTreeMap::new(),
+            entries: BTreeMap::new(),
+        }
+    }
+}
+
+impl<ManifoldData, ContactData> Clone for CustomShapeRegistry<ManifoldData, ContactData> {
+    fn clone(&self) -> Self {
+        Self {
+            ids: self.ids.clone(),
+            entries: self.entries.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+static GLOBAL_REGISTRY: std::sync::RwLock<Option<Arc<CustomShapeRegistry>>> =
+    std::sync::RwLock::new(None);
+
+impl CustomShapeRegistry {
+    /// Creates an empty registry.
+    ///
+    /// Use [`CustomShapeRegistry::default`] to create a registry with other contact-manifold data
+    /// types.
+    pub const fn new() -> Self {
+        Self {
+            ids: BTreeMap::new(),
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// The registry used for serializing and deserializing the custom shapes contained by a
+    /// [`SharedShape`], if it was set with [`Self::set_global`].
+    ///
+    /// It doesn’t contain any dispatcher.
+    #[cfg(feature = "std")]
+    pub fn global() -> Option<Arc<CustomShapeRegistry>> {
+        GLOBAL_REGISTRY
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+}
+
+impl<ManifoldData, ContactData> CustomShapeRegistry<ManifoldData, ContactData> {
+    /// Sets the registry used for serializing and deserializing the custom shapes contained by
+    /// a [`SharedShape`].
+    ///
+    /// Only the identifiers and serialization functions of `registry` are used: its dispatchers
+    /// are ignored.
+    #[cfg(feature = "std")]
+    pub fn set_global(registry: &Self) {
+        let entries = registry
+            .entries
+            .iter()
+            .map(|(id, entry)| {
+                let entry = CustomShapeEntry {
+                    serialize: entry.serialize.clone(),
+                    deserialize: entry.deserialize.clone(),
+                    dispatcher: None,
+                };
+                (*id, entry)
+            })
+            .collect();
+        let global = CustomShapeRegistry {
+            ids: registry.ids.clone(),
+            entries,
+        };
+        *GLOBAL_REGISTRY
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(Arc::new(global));
+    }
+
+    /// Registers the shape type `S` with the given identifier.
+    ///
+    /// The identifier should be stable (e.g. across different versions of an application) if
+    /// the shapes are serialized.
+    pub fn register<S: Shape>(&mut self, id: u32) -> Result<(), CustomShapeRegistryError> {
+        if let Some(existing_id) = self.ids.get(&TypeId::of::<S>()) {
+            return Err(CustomShapeRegistryError::TypeAlreadyRegistered(
+                *existing_id,
+            ));
+        }
+
+        if self.entries.contains_key(&id) {
+            return Err(CustomShapeRegistryError::IdAlreadyRegistered(id));
+        }
+
+        let _ = self.ids.insert(TypeId::of::<S>(), id);
+        let _ = self.entries.insert(id, CustomShapeEntry::default());
+        Ok(())
+    }
+
+    /// Sets the functions converting the registered shape type `S` to and from bytes.
+    pub fn set_serialization<S: Shape>(
+        &mut self,
+        serialize: fn(&S) -> Vec<u8>,
+        deserialize: fn(&[u8]) -> Option<S>,
+    ) -> Result<(), CustomShapeRegistryError> {
+        let entry = self.entry_mut::<S>()?;
+        entry.serialize = Some(Arc::new(move |shape: &dyn Shape| {
+            shape.downcast_ref::<S>().map(serialize)
+        }));
+        entry.deserialize = Some(Arc::new(move |data: &[u8]| {
+            deserialize(data).map(SharedShape::new)
+        }));
+        Ok(())
+    }
+
+    /// Sets the dispatcher handling the queries involving the registered shape type `S`.
+    ///
+    /// When both shapes of a query are custom shapes with a dispatcher, the dispatcher of the
+    /// first shape is tried first.
+    pub fn set_dispatcher<S: Shape>(
+        &mut self,
+        dispatcher: impl PersistentQueryDispatcher<ManifoldData, ContactData> + 'static,
+    ) -> Result<(), CustomShapeRegistryError> {
+        self.entry_mut::<S>()?.dispatcher = Some(Arc::new(dispatcher));
+        Ok(())
+    }
+
+    /// The identifier the type of `shape` was registered with, if any.
+    pub fn custom_shape_id(&self, shape: &dyn Shape) -> Option<u32> {
+        self.ids.get(&Any::type_id(shape.as_any())).copied()
+    }
+
+    /// Serializes `shape` with the functions registered for its type.
+    ///
+    /// Returns `None` if the type of `shape` isn’t registered, or if it doesn’t have any
+    /// serialization function.
+    pub fn serialize_shape(&self, shape: &dyn Shape) -> Option<CustomShapeData> {
+        let id = self.custom_shape_id(shape)?;
+        let serialize = self.entries.get(&id)?.serialize.as_ref()?;
+        Some(CustomShapeData {
+            id,
+            data: serialize(shape)?,
+        })
+    }
+
+    /// Deserializes a custom shape with the functions registered for its identifier.
+    ///
+    /// Returns `None` if the identifier isn’t registered, if it doesn’t have any
+    /// deserialization function, or if the deserialization failed.
+    pub fn deserialize_shape(&self, shape: &CustomShapeData) -> Option<SharedShape> {
+        let deserialize = self.entries.get(&shape.id)?.deserialize.as_ref()?;
+        deserialize(&shape.data)
+    }
+
+    fn entry_mut<S: Shape>(
+        &mut self,
+    ) -> Result<&mut CustomShapeEntry<ManifoldData, ContactData>, CustomShapeRegistryError> {
+        self.ids
+            .get(&TypeId::of::<S>())
+            .and_then(|id| self.entries.get_mut(id))
+            .ok_or(CustomShapeRegistryError::UnregisteredType)
+    }
+
+    fn dispatchers<'a>(
+        &'a self,
+        g1: &dyn Shape,
+        g2: &dyn Shape,
+    ) -> impl Iterator<Item = &'a dyn PersistentQueryDispatcher<ManifoldData, ContactData>> {
+        let id1 = self.custom_shape_id(g1);
+        let id2 = self.custom_shape_id(g2).filter(|id2| Some(*id2) != id1);
+        [id1, id2]
+            .into_iter()
+            .flatten()
+            .filter_map(|id| self.entries.get(&id)?.dispatcher.as_deref())
+    }
+}
+
+macro_rules! dispatch_method {
+    ($name:ident ( $( $arg:ident : $ty:ty,)*) -> $result:ty, $g1:ident, $g2:ident) => {
+        fn $name(&self, $($arg : $ty,)*
+        ) -> Result<$result, Unsupported> {
+            for dispatcher in self.dispatchers($g1, $g2) {
+                if let Ok(result) = dispatcher.$name($($arg,)*) {
+                    return Ok(result);
+                }
+            }
+
+            Err(Unsupported)
+        }
+    }
+}
+
+impl<ManifoldData, ContactData> QueryDispatcher for CustomShapeRegistry<ManifoldData, ContactData> {
+    dispatch_method!(intersection_test(
+        pos12: &Isometry<Real>,
+        g1: &dyn Shape,
+        g2: &dyn Shape,
+    ) -> bool, g1, g2);
+
+    dispatch_method!(distance(pos12: &Isometry<Real>, g1: &dyn Shape, g2: &dyn Shape,) -> Real, g1, g2);
+
+    dispatch_method!(contact(
+        pos12: &Isometry<Real>,
+        g1: &dyn Shape,
+        g2: &dyn Shape,
+        prediction: Real,
+    ) -> Option<Contact>, g1, g2);
+
+    dispatch_method!(closest_points(
+        pos12: &Isometry<Real>,
+        g1: &dyn Shape,
+        g2: &dyn Shape,
+        max_dist: Real,
+    ) -> ClosestPoints, g1, g2);
+
+    dispatch_method!(cast_shapes(
+        pos12: &Isometry<Real>,
+        vel12: &Vector<Real>,
+        g1: &dyn Shape,
+        g2: &dyn Shape,
+        options: ShapeCastOptions,
+    ) -> Option<ShapeCastHit>, g1, g2);
+
+    dispatch_method!(cast_shapes_nonlinear(
+        motion1: &NonlinearRigidMotion,
+        g1: &dyn Shape,
+        motion2: &NonlinearRigidMotion,
+        g2: &dyn Shape,
+        start_time: Real,
+        end_time: Real,
+        stop_at_penetration: bool,
+    ) -> Option<ShapeCastHit>, g1, g2);
+}
+
+impl<ManifoldData, ContactData> PersistentQueryDispatcher<ManifoldData, ContactData>
+    for CustomShapeRegistry<ManifoldData, ContactData>
+{
+    dispatch_method!(contact_manifolds(
+        pos12: &Isometry<Real>,
+        g1: &dyn Shape,
+        g2: &dyn Shape,
+        prediction: Real,
+        manifolds: &mut Vec<ContactManifold<ManifoldData, ContactData>>,
+        workspace: &mut Option<ContactManifoldsWorkspace>,
+    ) -> (), g1, g2);
+
+    dispatch_method!(contact_manifold_convex_convex(
+        pos12: &Isometry<Real>,
+        g1: &dyn Shape,
+        g2: &dyn Shape,
+        normal_constraints1: Option<&dyn NormalConstraints>,
+        normal_constraints2: Option<&dyn NormalConstraints>,
+        prediction: Real,
+        manifold: &mut ContactManifold<ManifoldData, ContactData>,
+    ) -> (), g1, g2);
+}
+
+/// Serializes a custom shape with the global [`CustomShapeRegistry`].
+#[cfg(feature = "serde-serialize")]
+pub(crate) fn serialize_custom_shape<S: serde::Serializer>(
+    shape: &&dyn Shape,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    use crate::serde::ser::Error;
+
+    #[cfg(feature = "std")]
+    if let Some(data) =
+        CustomShapeRegistry::global().and_then(|registry| registry.serialize_shape(*shape))
+    {
+        return serde::Serialize::serialize(&data, serializer);
+    }
+
+    Err(S::Error::custom(
+        "Cannot serialize a custom shape that isn’t registered in the global `CustomShapeRegistry`.",
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CustomShapeRegistry, CustomShapeRegistryError};
+    use crate::bounding_volume::{Aabb, BoundingSphere};
+    use crate::mass_properties::MassProperties;
+    use crate::math::{Isometry, Point, Real, Vector};
+    use crate::query::details::NormalConstraints;
+    use crate::query::{
+        self, ContactManifold, ContactManifoldsWorkspace, DefaultQueryDispatcher,
+        PersistentQueryDispatcher, PointProjection, PointQuery, QueryDispatcher, Ray, RayCast,
+        RayIntersection, TrackedContact, Unsupported,
+    };
+    use crate::shape::{Ball, FeatureId, PackedFeatureId, Shape, ShapeType, TypedShape};
+    use alloc::boxed::Box;
+    use alloc::vec::Vec;
+
+    /// A point-like custom shape.
+    #[derive(Clone, Debug, PartialEq)]
+    struct Dot(Real);
+
+    impl PointQuery for Dot {
+        fn project_local_point(&self, _: &Point<Real>, _: bool) -> PointProjection {
+            PointProjection::new(false, Point::origin())
+        }
+
+        fn project_local_point_and_get_feature(
+            &self,
+            pt: &Point<Real>,
+        ) -> (PointProjection, FeatureId) {
+            (self.project_local_point(pt, false), FeatureId::Vertex(0))
+        }
+    }
+
+    impl RayCast for Dot {
+        fn cast_local_ray_and_get_normal(
+            &self,
+            _: &Ray,
+            _: Real,
+            _: bool,
+        ) -> Option<RayIntersection> {
+            None
+        }
+    }
+
+    impl Shape for Dot {
+        fn compute_local_aabb(&self) -> Aabb {
+            Aabb::new(Point::origin(), Point::origin())
+        }
+
+        fn compute_local_bounding_sphere(&self) -> BoundingSphere {
+            BoundingSphere::new(Point::origin(), 0.0)
+        }
+
+        fn clone_dyn(&self) -> Box<dyn Shape> {
+            Box::new(self.clone())
+        }
+
+        fn scale_dyn(&self, _: &Vector<Real>, _: u32) -> Option<Box<dyn Shape>> {
+            Some(Box::new(self.clone()))
+        }
+
+        fn mass_properties(&self, _: Real) -> MassProperties {
+            MassProperties::from_ball(1.0, 0.0)
+        }
+
+        fn shape_type(&self) -> ShapeType {
+            ShapeType::Custom
+        }
+
+        fn as_typed_shape(&self) -> TypedShape {
+            TypedShape::Custom(self)
+        }
+
+        fn ccd_thickness(&self) -> Real {
+            0.0
+        }
+
+        fn ccd_angular_thickness(&self) -> Real {
+            0.0
+        }
+    }
+
+    /// Handles the distances between dots, and between a dot and a ball, and the contact
+    /// manifolds between a dot and a ball.
+    struct DotDispatcher;
+
+    impl QueryDispatcher for DotDispatcher {
+        fn intersection_test(
+            &self,
+            _: &Isometry<Real>,
+            _: &dyn Shape,
+            _: &dyn Shape,
+        ) -> Result<bool, Unsupported> {
+            Err(Unsupported)
+        }
+
+        fn distance(
+            &self,
+            pos12: &Isometry<Real>,
+            g1: &dyn Shape,
+            g2: &dyn Shape,
+        ) -> Result<Real, Unsupported> {
+            let dist = pos12.translation.vector.norm();
+            match (g1.as_ball(), g2.as_ball()) {
+                (Some(ball), None) | (None, Some(ball)) => Ok((dist - ball.radius).max(0.0)),
+                (None, None) => Ok(dist),
+                _ => Err(Unsupported),
+            }
+        }
+
+        fn contact(
+            &self,
+            _: &Isometry<Real>,
+            _: &dyn Shape,
+            _: &dyn Shape,
+            _: Real,
+        ) -> Result<Option<query::Contact>, Unsupported> {
+            Err(Unsupported)
+        }
+
+        fn closest_points(
+            &self,
+            _: &Isometry<Real>,
+            _: &dyn Shape,
+            _: &dyn Shape,
+            _: Real,
+        ) -> Result<query::ClosestPoints, Unsupported> {
+            Err(Unsupported)
+        }
+
+        fn cast_shapes(
+            &self,
+            _: &Isometry<Real>,
+            _: &Vector<Real>,
+            _: &dyn Shape,
+            _: &dyn Shape,
+            _: query::ShapeCastOptions,
+        ) -> Result<Option<query::ShapeCastHit>, Unsupported> {
+            Err(Unsupported)
+        }
+
+        fn cast_shapes_nonlinear(
+            &self,
+            _: &query::NonlinearRigidMotion,
+            _: &dyn Shape,
+            _: &query::NonlinearRigidMotion,
+            _: &dyn Shape,
+            _: Real,
+            _: Real,
+            _: bool,
+        ) -> Result<Option<query::ShapeCastHit>, Unsupported> {
+            Err(Unsupported)
+        }
+    }
+
+    impl PersistentQueryDispatcher for DotDispatcher {
+        fn contact_manifolds(
+            &self,
+            pos12: &Isometry<Real>,
+            g1: &dyn Shape,
+            g2: &dyn Shape,
+            prediction: Real,
+            manifolds: &mut Vec<ContactManifold<(), ()>>,
+            _: &mut Option<ContactManifoldsWorkspace>,
+        ) -> Result<(), Unsupported> {
+            let (Some(_), Some(ball)) = (g1.downcast_ref::<Dot>(), g2.as_ball()) else {
+                return Err(Unsupported);
+            };
+
+            manifolds.clear();
+            let center2 = Point::from(pos12.translation.vector);
+            let dist = center2.coords.norm() - ball.radius;
+
+            if dist <= prediction {
+                let mut manifold = ContactManifold::new();
+                manifold.local_n1 = center2.coords.normalize();
+                manifold.local_n2 = pos12.inverse_transform_vector(&-manifold.local_n1);
+                let local_p2 = Point::from(manifold.local_n2 * ball.radius);
+                let fid1 = PackedFeatureId::vertex(42);
+                let fid2 = PackedFeatureId::UNKNOWN;
+                manifold.points.push(TrackedContact::new(
+                    Point::origin(),
+                    local_p2,
+                    fid1,
+                    fid2,
+                    dist,
+                ));
+                manifolds.push(manifold);
+            }
+
+            Ok(())
+        }
+
+        fn contact_manifold_convex_convex(
+            &self,
+            _: &Isometry<Real>,
+            _: &dyn Shape,
+            _: &dyn Shape,
+            _: Option<&dyn NormalConstraints>,
+            _: Option<&dyn NormalConstraints>,
+            _: Real,
+            _: &mut ContactManifold<(), ()>,
+        ) -> Result<(), Unsupported> {
+            Err(Unsupported)
+        }
+    }
+
+    fn dot_registry() -> CustomShapeRegistry {
+        let mut registry = CustomShapeRegistry::new();
+        registry.register::<Dot>(42).unwrap();
+        registry
+            .set_serialization::<Dot>(
+                |dot| dot.0.to_le_bytes().to_vec(),
+                |data| Some(Dot(Real::from_le_bytes(data.try_into().ok()?))),
+            )
+            .unwrap();
+        registry.set_dispatcher::<Dot>(DotDispatcher).unwrap();
+        registry
+    }
+
+    #[test]
+    fn custom_shape_registration_and_dispatch() {
+        let mut registry = dot_registry();
+        assert_eq!(
+            registry.register::<Dot>(1),
+            Err(CustomShapeRegistryError::TypeAlreadyRegistered(42))
+        );
+        assert_eq!(
+            registry.register::<Ball>(42),
+            Err(CustomShapeRegistryError::IdAlreadyRegistered(42))
+        );
+        assert_eq!(
+            registry.set_dispatcher::<Ball>(DotDispatcher),
+            Err(CustomShapeRegistryError::UnregisteredType)
+        );
+
+        let dot = Dot(2.0);
+        let ball = Ball::new(1.0);
+        assert_eq!(registry.custom_shape_id(&dot), Some(42));
+        assert_eq!(registry.custom_shape_id(&ball), None);
+
+        let data = registry.serialize_shape(&dot).unwrap();
+        assert_eq!(data.id, 42);
+        let shape = registry.deserialize_shape(&data).unwrap();
+        assert_eq!(shape.downcast_ref::<Dot>(), Some(&dot));
+
+        let pos12 = Isometry::from_parts((Vector::x() * 3.0).into(), Default::default());
+        let dispatcher = registry.clone().chain(DefaultQueryDispatcher);
+        assert_eq!(dispatcher.distance(&pos12, &dot, &ball), Ok(2.0));
+        assert_eq!(dispatcher.distance(&pos12, &dot, &dot), Ok(3.0));
+        assert_eq!(
+            DefaultQueryDispatcher.distance(&pos12, &dot, &dot),
+            Err(Unsupported)
+        );
+        assert!(registry.contact(&pos12, &ball, &dot, 0.0).is_err());
+
+        // Contact manifolds are dispatched too, e.g., when stepping a physics simulation.
+        let mut manifolds = Vec::new();
+        dispatcher
+            .contact_manifolds(&pos12, &dot, &ball, 2.5, &mut manifolds, &mut None)
+            .unwrap();
+        assert_eq!(manifolds.len(), 1);
+        assert_eq!(manifolds[0].points[0].dist, 2.0);
+        assert_eq!(manifolds[0].local_n1, Vector::x());
+        assert_eq!(manifolds[0].points[0].fid1, PackedFeatureId::vertex(42));
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde-serialize", feature = "std"))]
+    fn custom_shape_serialization() {
+        use crate::shape::SharedShape;
+
+        let shape = SharedShape::new(Dot(2.0));
+        CustomShapeRegistry::set_global(&dot_registry());
+
+        let json = serde_json::to_string(&shape).unwrap();
+        let deserialized: SharedShape = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.downcast_ref::<Dot>(), Some(&Dot(2.0)));
+
+        // Shapes of other types are still serialized as usual.
+        let ball = SharedShape::ball(1.0);
+        let json = serde_json::to_string(&ball).unwrap();
+        let deserialized: SharedShape = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.as_ball(), Some(&Ball::new(1.0)));
+    }
+}
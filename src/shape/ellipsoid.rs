@@ -0,0 +1,180 @@
+//! Support mapping based Ellipsoid shape.
+
+use crate::math::{Point, Real, Vector};
+use crate::shape::SupportMap;
+use na::Unit;
+
+#[cfg(feature = "rkyv")]
+use rkyv::{bytecheck, CheckBytes};
+
+/// An ellipsoid (or an ellipse in 2D) centered at the origin, with its principal axes aligned
+/// with the local coordinate axes.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize, CheckBytes),
+    archive(as = "Self")
+)]
+#[derive(PartialEq, Debug, Copy, Clone)]
+#[repr(C)]
+pub struct Ellipsoid {
+    /// The semi-axes lengths of the ellipsoid, along each local coordinate axis.
+    pub radii: Vector<Real>,
+}
+
+impl Ellipsoid {
+    /// Creates a new ellipsoid from its semi-axes lengths along each local coordinate axis.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the `radii` isn’t positive.
+    #[inline]
+    pub fn new(radii: Vector<Real>) -> Ellipsoid {
+        assert!(
+            radii.iter().all(|r| *r > 0.0),
+            "The radii of an ellipsoid must be positive."
+        );
+        Ellipsoid { radii }
+    }
+
+    /// Computes a scaled version of this ellipsoid.
+    ///
+    /// Ellipsoids are closed under non-uniform scaling along their principal axes so the result
+    /// is always an ellipsoid. Returns `None` if one of the scaling factors is zero.
+    #[inline]
+    pub fn scaled(self, scale: &Vector<Real>) -> Option<Self> {
+        let radii = self.radii.component_mul(&scale.abs());
+        radii.iter().all(|r| *r > 0.0).then_some(Self { radii })
+    }
+
+    /// Is the given point inside of this ellipsoid (or on its boundary)?
+    #[inline]
+    pub fn contains_local_point(&self, pt: &Point<Real>) -> bool {
+        pt.coords.component_div(&self.radii).norm_squared() <= 1.0
+    }
+
+    /// The outward normal of this ellipsoid’s boundary at the given point.
+    ///
+    /// The point is assumed to be located on the ellipsoid’s boundary. Returns `None` if `pt` is
+    /// the ellipsoid’s center.
+    #[inline]
+    pub fn normal_at_point(&self, pt: &Point<Real>) -> Option<Unit<Vector<Real>>> {
+        let gradient = pt
+            .coords
+            .component_div(&self.radii.component_mul(&self.radii));
+        Unit::try_new(gradient, crate::math::DEFAULT_EPSILON)
+    }
+}
+
+impl SupportMap for Ellipsoid {
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<Real>) -> Point<Real> {
+        let scaled_dir = dir.component_mul(&self.radii);
+        let norm = scaled_dir.norm();
+
+        if norm == 0.0 {
+            Point::origin()
+        } else {
+            Point::from(scaled_dir.component_mul(&self.radii) / norm)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::math::{Isometry, Point, Vector, DIM};
+    use crate::query::{self, ContactManifold, DefaultQueryDispatcher, PersistentQueryDispatcher};
+    use crate::shape::{Ball, Cuboid, Ellipsoid, Shape, SupportMap};
+    use alloc::vec::Vec;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn ellipsoid_support_and_bounding_volumes() {
+        let mut radii = Vector::repeat(1.0);
+        radii.x = 3.0;
+        let ellipsoid = Ellipsoid::new(radii);
+
+        assert_relative_eq!(
+            ellipsoid.local_support_point(&Vector::x()),
+            Point::from(Vector::x() * 3.0)
+        );
+        assert_relative_eq!(
+            ellipsoid.local_support_point(&-Vector::y()),
+            Point::from(-Vector::y())
+        );
+
+        // The exact Aabb of the rotated ellipsoid matches the one based on its support function.
+        #[cfg(feature = "dim2")]
+        let pos = Isometry::new(Vector::new(1.0, 2.0), 0.7);
+        #[cfg(feature = "dim3")]
+        let pos = Isometry::new(Vector::new(1.0, 2.0, 3.0), Vector::new(0.7, -0.2, 0.3));
+        let aabb = ellipsoid.compute_aabb(&pos);
+        for i in 0..DIM {
+            let axis = Vector::ith(i, 1.0);
+            let maxs = ellipsoid.support_point(&pos, &axis)[i];
+            let mins = ellipsoid.support_point(&pos, &-axis)[i];
+            assert_relative_eq!(aabb.maxs[i], maxs, epsilon = 1.0e-5);
+            assert_relative_eq!(aabb.mins[i], mins, epsilon = 1.0e-5);
+        }
+
+        #[cfg(feature = "dim2")]
+        let vertices = ellipsoid.to_polyline(10);
+        #[cfg(feature = "dim3")]
+        let vertices = ellipsoid.to_trimesh(10, 10).0;
+        for pt in vertices {
+            assert_relative_eq!(
+                pt.coords.component_div(&radii).norm(),
+                1.0,
+                epsilon = 1.0e-5
+            );
+        }
+
+        assert_eq!(
+            ellipsoid.scaled(&-Vector::repeat(2.0)),
+            Some(Ellipsoid::new(radii * 2.0))
+        );
+        assert!(ellipsoid.scaled(&Vector::zeros()).is_none());
+    }
+
+    #[test]
+    fn ellipsoid_contacts() {
+        let mut radii = Vector::repeat(0.5);
+        radii.x = 2.0;
+        let ellipsoid = Ellipsoid::new(radii);
+        let cuboid = Cuboid::new(Vector::repeat(1.0));
+        let ball = Ball::new(1.0);
+        // The ellipsoid is slightly above the top face of the cuboid.
+        #[cfg(feature = "dim2")]
+        let pos12 = Isometry::translation(0.0, 1.6);
+        #[cfg(feature = "dim3")]
+        let pos12 = Isometry::translation(0.0, 1.6, 0.0);
+
+        for other in [&cuboid as &dyn Shape, &ball as &dyn Shape] {
+            let contact = query::contact(&Isometry::identity(), other, &pos12, &ellipsoid, 0.2)
+                .unwrap()
+                .unwrap();
+            assert_relative_eq!(contact.dist, 0.1, epsilon = 1.0e-4);
+            assert_relative_eq!(contact.normal1.into_inner(), Vector::y(), epsilon = 1.0e-4);
+
+            let mut manifolds: Vec<ContactManifold<(), ()>> = Vec::new();
+            let mut workspace = None;
+            DefaultQueryDispatcher
+                .contact_manifolds(
+                    &pos12,
+                    other,
+                    &ellipsoid,
+                    0.2,
+                    &mut manifolds,
+                    &mut workspace,
+                )
+                .unwrap();
+            assert_eq!(manifolds.len(), 1);
+            assert_eq!(manifolds[0].points.len(), 1);
+            assert_relative_eq!(manifolds[0].points[0].dist, 0.1, epsilon = 1.0e-4);
+            // NOTE: the ellipsoid is very flat near its bottom so GJK only locates the contact
+            //       point approximately along `x`.
+            assert_relative_eq!(manifolds[0].points[0].local_p2.y, -0.5, epsilon = 1.0e-3);
+        }
+    }
+}
@@ -9,7 +9,7 @@ use na::Point2;
 use crate::bounding_volume::Aabb;
 use crate::math::{Real, Vector};
 
-use crate::shape::Segment;
+use crate::shape::{FeatureId, Segment};
 
 /// Indicates if a cell of a heightfield is removed or not. Set this to `false` for
 /// a removed cell.
@@ -31,6 +31,9 @@ pub struct HeightField {
 
     scale: Vector<Real>,
     aabb: Aabb,
+    // The per-cell tags. Empty if the heightfield isn’t tagged, in which case all the tags are zero.
+    #[cfg_attr(feature = "serde", serde(default))]
+    tags: DVector<u32>,
 }
 
 #[cfg(feature = "alloc")]
@@ -56,6 +59,7 @@ impl HeightField {
             status: DVector::repeat(num_segments, true),
             scale,
             aabb,
+            tags: DVector::zeros(0),
         }
     }
 }
@@ -201,6 +205,61 @@ impl HeightField {
         !self.status[i]
     }
 
+    /// The tag of the `i`-th cell.
+    ///
+    /// Tags are arbitrary `u32` values attached to each cell (e.g. material identifiers). Since
+    /// the sub-shape ids of contact manifolds involving a heightfield are segment ids, this can be
+    /// used to retrieve the tag of the segment involved in a contact. Returns zero if this
+    /// heightfield isn’t tagged.
+    pub fn cell_tag(&self, i: usize) -> u32 {
+        self.tags.get(i).copied().unwrap_or_default()
+    }
+
+    /// Sets the tag of the `i`-th cell.
+    ///
+    /// If this heightfield wasn’t tagged, all its other cells are given a zero tag.
+    pub fn set_cell_tag(&mut self, i: usize, tag: u32) {
+        if self.tags.is_empty() {
+            self.tags = DVector::zeros(self.num_cells());
+        }
+
+        self.tags[i] = tag;
+    }
+
+    /// The tags of all the cells of this heightfield, or an empty vector if it isn’t tagged.
+    pub fn cells_tags(&self) -> &DVector<u32> {
+        &self.tags
+    }
+
+    /// Sets the tags of all the cells of this heightfield.
+    ///
+    /// An empty vector removes all the tags of this heightfield.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tags` isn’t empty and its length doesn’t match [`Self::num_cells`].
+    pub fn set_cells_tags(&mut self, tags: DVector<u32>) {
+        assert!(
+            tags.is_empty() || tags.len() == self.num_cells(),
+            "The length of the tags must match the number of cells of the heightfield."
+        );
+        self.tags = tags;
+    }
+
+    /// Returns the tag of the cell identified by the given feature.
+    ///
+    /// The feature must be a [`FeatureId::Face`], as reported by the ray-casts and
+    /// point-projections on this heightfield (including backfaces). Returns `None` for any other
+    /// feature.
+    pub fn feature_tag(&self, feature: FeatureId) -> Option<u32> {
+        match feature {
+            FeatureId::Face(i) if (i as usize) < self.num_cells() * 2 => {
+                Some(self.cell_tag(i as usize % self.num_cells()))
+            }
+            _ => None,
+        }
+    }
+
     /// The range of segment ids that may intersect the given local Aabb.
     pub fn unclamped_elements_range_in_local_aabb(&self, aabb: &Aabb) -> Range<isize> {
         let ref_mins = aabb.mins.coords.component_div(&self.scale);
@@ -23,6 +23,14 @@ pub struct HeightFieldCellStatus(u8);
 bitflags::bitflags! {
     impl HeightFieldCellStatus: u8 {
         /// If this bit is set, the concerned heightfield cell is subdivided using a Z pattern.
+        ///
+        /// By default, the cell `(i, j)` is split along the diagonal joining the vertices
+        /// `(i + 1, j)` and `(i, j + 1)`. If this bit is set, it is split along the diagonal
+        /// joining the vertices `(i, j)` and `(i + 1, j + 1)` instead. Picking, for each cell,
+        /// the diagonal following the ridges and valleys of the terrain avoids collision
+        /// artifacts. This choice is taken into account by all the geometric queries, by the
+        /// pseudo-normals of [`HeightField::triangle_normal_constraints`], and by
+        /// `HeightField::to_trimesh`.
         const ZIGZAG_SUBDIVISION = 0b00000001;
         /// If this bit is set, the leftmost triangle of the concerned heightfield cell is removed.
         const LEFT_TRIANGLE_REMOVED = 0b00000010;
@@ -74,6 +82,9 @@ pub struct HeightField {
     aabb: Aabb,
     num_triangles: usize,
     flags: HeightFieldFlags,
+    // The per-cell tags. Empty if the heightfield isn’t tagged, in which case all the tags are zero.
+    #[cfg_attr(feature = "serde", serde(default))]
+    tags: DMatrix<u32>,
 }
 
 #[cfg(feature = "alloc")]
@@ -114,6 +125,7 @@ impl HeightField {
             num_triangles,
             status,
             flags,
+            tags: DMatrix::zeros(0, 0),
         }
     }
 }
@@ -435,65 +447,77 @@ impl HeightField {
                 adj.map(|adj| adj.normal().map(|n| *n).unwrap_or(tri_normal))
             };
 
-            let diag_dir = if status.contains(HeightFieldCellStatus::ZIGZAG_SUBDIVISION) {
-                Vector::new(-1.0, 0.0, 1.0)
+            let zigzag = status.contains(HeightFieldCellStatus::ZIGZAG_SUBDIVISION);
+            // The three edges of each triangle are: the diagonal of the cell, one of its
+            // edges along `x` (the side at `j` for the left triangle, `j + 1` for the right one),
+            // and one of its edges along `z` (the side at `i` or `i + 1`, depending on the
+            // subdivision scheme).
+            //
+            // Without zigzag:     With zigzag:
+            //    ___                 ___
+            //   | /|                |\ |
+            //   |/_|                |_\|
+            //
+            // The direction pointing outward of the left triangle, across the diagonal.
+            let diag_dir = if zigzag {
+                Vector::new(1.0, 0.0, -1.0)
             } else {
                 Vector::new(1.0, 0.0, 1.0)
             };
 
-            let (left_pseudo_normal, right_pseudo_normal) = if left {
-                let adj_left = adj_pseudo_normal(self.triangles_at(i.overflowing_sub(1).0, j).1)
-                    .unwrap_or_else(|| bivector(-Vector::z()));
-                let adj_right = adj_pseudo_normal(tri_right).unwrap_or_else(|| bivector(diag_dir));
-                (adj_left, adj_right)
+            let (diag_pseudo_normal, x_pseudo_normal) = if left {
+                let adj_diag = adj_pseudo_normal(tri_right).unwrap_or_else(|| bivector(diag_dir));
+                // The neighbor along `-x` is the right triangle of the cell at `j - 1`,
+                // whatever its subdivision scheme.
+                let adj_x = adj_pseudo_normal(self.triangles_at(i, j.overflowing_sub(1).0).1)
+                    .unwrap_or_else(|| bivector(-Vector::x()));
+                (adj_diag, adj_x)
             } else {
-                let adj_left = adj_pseudo_normal(tri_left).unwrap_or_else(|| bivector(-diag_dir));
-                let adj_right = adj_pseudo_normal(self.triangles_at(i + 1, j).0)
-                    .unwrap_or_else(|| bivector(Vector::z()));
-                (adj_left, adj_right)
+                let adj_diag = adj_pseudo_normal(tri_left).unwrap_or_else(|| bivector(-diag_dir));
+                // The neighbor along `+x` is the left triangle of the cell at `j + 1`,
+                // whatever its subdivision scheme.
+                let adj_x = adj_pseudo_normal(self.triangles_at(i, j + 1).0)
+                    .unwrap_or_else(|| bivector(Vector::x()));
+                (adj_diag, adj_x)
             };
 
-            // The third neighbor depends on the combination of zigzag scheme
-            // and right/left position.
-            let top_or_bottom_pseudo_normal = if left
-                != status.contains(HeightFieldCellStatus::ZIGZAG_SUBDIVISION)
-            {
-                // The neighbor is below.
-                let ((bot_left, bot_right), bot_status) = if j > 0 {
-                    (self.triangles_at(i, j - 1), self.status[(i, j - 1)])
-                } else {
-                    ((None, None), HeightFieldCellStatus::empty())
-                };
-
-                let bot_tri = if bot_status.contains(HeightFieldCellStatus::ZIGZAG_SUBDIVISION) {
-                    bot_left
+            // The neighbor along `z` depends on the subdivision schemes of both cells.
+            let z_pseudo_normal = if left != zigzag {
+                // The neighbor is the cell at `i - 1`. Its triangle touching this cell is the
+                // one touching its side at `i`.
+                let neighbor_tri = if i > 0 {
+                    let (adj_left, adj_right) = self.triangles_at(i - 1, j);
+                    if self.status[(i - 1, j)].contains(HeightFieldCellStatus::ZIGZAG_SUBDIVISION) {
+                        adj_left
+                    } else {
+                        adj_right
+                    }
                 } else {
-                    bot_right
+                    None
                 };
 
-                adj_pseudo_normal(bot_tri).unwrap_or_else(|| bivector(-Vector::x()))
+                adj_pseudo_normal(neighbor_tri).unwrap_or_else(|| bivector(-Vector::z()))
             } else {
-                // The neighbor is above.
-                let ((top_left, top_right), top_status) = if j < self.heights.ncols() - 2 {
-                    (self.triangles_at(i, j + 1), self.status[(i, j + 1)])
-                } else {
-                    ((None, None), HeightFieldCellStatus::empty())
-                };
-
-                let top_tri = if top_status.contains(HeightFieldCellStatus::ZIGZAG_SUBDIVISION) {
-                    top_right
+                // The neighbor is the cell at `i + 1`. Its triangle touching this cell is the
+                // one touching its side at `i + 1`.
+                let neighbor_tri = if i < self.heights.nrows() - 2 {
+                    let (adj_left, adj_right) = self.triangles_at(i + 1, j);
+                    if self.status[(i + 1, j)].contains(HeightFieldCellStatus::ZIGZAG_SUBDIVISION) {
+                        adj_right
+                    } else {
+                        adj_left
+                    }
                 } else {
-                    top_left
+                    None
                 };
 
-                adj_pseudo_normal(top_tri).unwrap_or_else(|| bivector(Vector::x()))
+                adj_pseudo_normal(neighbor_tri).unwrap_or_else(|| bivector(Vector::z()))
             };
 
             // NOTE: the normalization can only succeed due to the heightfield’s definition.
-            let pseudo_normal1 = Unit::new_normalize((tri_normal + left_pseudo_normal) / 2.0);
-            let pseudo_normal2 = Unit::new_normalize((tri_normal + right_pseudo_normal) / 2.0);
-            let pseudo_normal3 =
-                Unit::new_normalize((tri_normal + top_or_bottom_pseudo_normal) / 2.0);
+            let pseudo_normal1 = Unit::new_normalize((tri_normal + diag_pseudo_normal) / 2.0);
+            let pseudo_normal2 = Unit::new_normalize((tri_normal + x_pseudo_normal) / 2.0);
+            let pseudo_normal3 = Unit::new_normalize((tri_normal + z_pseudo_normal) / 2.0);
 
             Some(TrianglePseudoNormals {
                 face: Unit::new_unchecked(tri_normal), // No need to re-normalize.
@@ -531,6 +555,68 @@ impl HeightField {
         &mut self.status
     }
 
+    /// The tag of the `(i, j)`-th cell.
+    ///
+    /// Tags are arbitrary `u32` values attached to each cell (e.g. material identifiers), shared
+    /// by both triangles of the cell. Returns zero if this heightfield isn’t tagged.
+    pub fn cell_tag(&self, i: usize, j: usize) -> u32 {
+        self.tags.get((i, j)).copied().unwrap_or_default()
+    }
+
+    /// Sets the tag of the `(i, j)`-th cell.
+    ///
+    /// If this heightfield wasn’t tagged, all its other cells are given a zero tag.
+    pub fn set_cell_tag(&mut self, i: usize, j: usize, tag: u32) {
+        if self.tags.is_empty() {
+            self.tags = DMatrix::zeros(self.nrows(), self.ncols());
+        }
+
+        self.tags[(i, j)] = tag;
+    }
+
+    /// The tags of all the cells of this heightfield, or an empty matrix if it isn’t tagged.
+    pub fn cells_tags(&self) -> &DMatrix<u32> {
+        &self.tags
+    }
+
+    /// Sets the tags of all the cells of this heightfield.
+    ///
+    /// An empty matrix removes all the tags of this heightfield.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tags` isn’t empty and its dimensions don’t match [`Self::num_cells_ij`].
+    pub fn set_cells_tags(&mut self, tags: DMatrix<u32>) {
+        assert!(
+            tags.is_empty() || tags.shape() == self.num_cells_ij(),
+            "The dimensions of the tags must match the number of cells of the heightfield."
+        );
+        self.tags = tags;
+    }
+
+    /// The tag of the cell containing the triangle identified by `id`.
+    ///
+    /// Since the sub-shape ids of contact manifolds involving a heightfield are triangle ids,
+    /// this can be used to retrieve the tag of the triangle involved in a contact.
+    pub fn triangle_tag(&self, id: u32) -> u32 {
+        let (i, j, _) = self.split_triangle_id(id);
+        self.cell_tag(i, j)
+    }
+
+    /// Returns the tag of the cell containing the triangle identified by the given feature.
+    ///
+    /// The feature must be a [`FeatureId::Face`], as reported by the ray-casts and
+    /// point-projections on this heightfield (including backfaces). Returns `None` for any
+    /// other feature.
+    pub fn feature_tag(&self, feature: FeatureId) -> Option<u32> {
+        match feature {
+            FeatureId::Face(id) if (id as usize) < self.num_triangles * 2 => {
+                Some(self.triangle_tag(id % self.num_triangles as u32))
+            }
+            _ => None,
+        }
+    }
+
     /// The heightfield’s flags controlling internal-edges handling.
     pub fn flags(&self) -> HeightFieldFlags {
         self.flags
@@ -546,6 +632,71 @@ impl HeightField {
         &self.heights
     }
 
+    /// Replaces the heights of the vertices in the given region of this heightfield.
+    ///
+    /// The vertex at row `i_range.start + i` and column `j_range.start + j` is given the
+    /// height `values[(i, j)]` (before application of the scale factor). The root [`Aabb`] of
+    /// the heightfield is updated accordingly. Cell statuses are left unchanged, and the
+    /// pseudo-normals of the triangles (see [`Self::triangle_normal_constraints`]) are computed
+    /// from the current heights so they remain consistent with the edit.
+    ///
+    /// Returns the local [`Aabb`] of all the triangles affected by this modification, enclosing
+    /// them both before and after the modification. It can be used to refresh the contacts
+    /// with this heightfield in that region. If the region is empty, nothing is modified and
+    /// an invalid [`Aabb`] is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the region is out of the bounds of the heightfield’s heights, or if the
+    /// dimensions of `values` don’t match the dimensions of the region.
+    pub fn set_heights_in_region(
+        &mut self,
+        i_range: Range<usize>,
+        j_range: Range<usize>,
+        values: &DMatrix<Real>,
+    ) -> Aabb {
+        assert!(
+            i_range.end <= self.heights.nrows() && j_range.end <= self.heights.ncols(),
+            "The region must be within the bounds of the heightfield heights."
+        );
+        assert_eq!(
+            (i_range.len(), j_range.len()),
+            values.shape(),
+            "The dimensions of the new values must match the dimensions of the region."
+        );
+
+        if i_range.is_empty() || j_range.is_empty() {
+            return Aabb::new_invalid();
+        }
+
+        let mut region = self
+            .heights
+            .view_range_mut(i_range.clone(), j_range.clone());
+        let (old_min, old_max) = (region.min(), region.max());
+        region.copy_from(values);
+
+        // The triangles touching a modified vertex belong to the cells surrounding it, so they
+        // also involve the unmodified vertices adjacent to the region.
+        let i_start = i_range.start.saturating_sub(1);
+        let i_end = (i_range.end + 1).min(self.heights.nrows());
+        let j_start = j_range.start.saturating_sub(1);
+        let j_end = (j_range.end + 1).min(self.heights.ncols());
+        let affected = self.heights.view_range(i_start..i_end, j_start..j_end);
+        let min = affected.min().min(old_min) * self.scale.y;
+        let max = affected.max().max(old_max) * self.scale.y;
+
+        let root_min = self.heights.min() * self.scale.y;
+        let root_max = self.heights.max() * self.scale.y;
+        self.aabb.mins.y = root_min.min(root_max);
+        self.aabb.maxs.y = root_min.max(root_max);
+
+        // Account for negative scale factors.
+        Aabb::from_points(&[
+            Point3::new(self.x_at(j_start), min, self.z_at(i_start)),
+            Point3::new(self.x_at(j_end - 1), max, self.z_at(i_end - 1)),
+        ])
+    }
+
     /// The scale factor applied to this heightfield.
     pub fn scale(&self) -> &Vector<Real> {
         &self.scale
@@ -907,3 +1058,199 @@ impl HeightFieldRadialTriangles<'_> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::math::{Real, Vector};
+    use crate::query::{Ray, RayCast};
+    use crate::shape::{HeightField, HeightFieldCellStatus};
+    use approx::assert_relative_eq;
+    use na::{DMatrix, Point3};
+
+    #[test]
+    fn heightfield_set_heights_in_region() {
+        // A flat 4x4 cells heightfield with cells of size 1.
+        let mut heightfield = HeightField::new(DMatrix::zeros(5, 5), Vector::new(4.0, 1.0, 4.0));
+        heightfield.set_cell_status(0, 0, HeightFieldCellStatus::CELL_REMOVED);
+
+        // Dig a crater around the center vertex.
+        let crater = DMatrix::from_element(1, 1, -2.0);
+        let affected = heightfield.set_heights_in_region(2..3, 2..3, &crater);
+        assert_relative_eq!(affected.mins, Point3::new(-1.0, -2.0, -1.0));
+        assert_relative_eq!(affected.maxs, Point3::new(1.0, 0.0, 1.0));
+        assert_relative_eq!(heightfield.root_aabb().mins.y, -2.0);
+        assert_relative_eq!(heightfield.root_aabb().maxs.y, 0.0);
+        assert_eq!(
+            heightfield.cell_status(0, 0),
+            HeightFieldCellStatus::CELL_REMOVED
+        );
+
+        let ray = Ray::new(Point3::new(0.0, 1.0, 0.0), -Vector::y());
+        let toi = heightfield.cast_local_ray(&ray, Real::MAX, true).unwrap();
+        assert_relative_eq!(toi, 3.0, epsilon = 1.0e-5);
+
+        // Filling the crater back shrinks the root Aabb.
+        let affected = heightfield.set_heights_in_region(1..4, 2..3, &DMatrix::zeros(3, 1));
+        assert_relative_eq!(affected.mins, Point3::new(-1.0, -2.0, -2.0));
+        assert_relative_eq!(affected.maxs, Point3::new(1.0, 0.0, 2.0));
+        assert_relative_eq!(heightfield.root_aabb().mins.y, 0.0);
+        assert!(heightfield
+            .triangles()
+            .all(|tri| tri.a.y == 0.0 && tri.b.y == 0.0 && tri.c.y == 0.0));
+    }
+
+    #[test]
+    fn heightfield_cell_tags() {
+        use crate::math::Isometry;
+        use crate::query::{
+            ContactManifold, DefaultQueryDispatcher, PersistentQueryDispatcher, PointQuery,
+        };
+        use crate::shape::{Ball, FeatureId, TriMesh};
+        use alloc::vec::Vec;
+
+        // A flat 4x4 cells heightfield with cells of size 1, where the cell `(i, j)` is tagged
+        // with `1 + i + 4 * j`.
+        let mut heightfield = HeightField::new(DMatrix::zeros(5, 5), Vector::new(4.0, 1.0, 4.0));
+        assert_eq!(heightfield.cell_tag(1, 2), 0);
+        heightfield.set_cell_tag(1, 2, 7);
+        assert_eq!(heightfield.cell_tag(1, 2), 7);
+        assert_eq!(heightfield.cell_tag(2, 1), 0);
+        heightfield.set_cells_tags(DMatrix::from_fn(4, 4, |i, j| 1 + i as u32 + 4 * j as u32));
+        heightfield.set_cell_status(0, 0, HeightFieldCellStatus::LEFT_TRIANGLE_REMOVED);
+
+        // The cell `(1, 2)` spans `[0, 1]` along `x` and `[-1, 0]` along `z`.
+        let expected = 1 + 1 + 4 * 2;
+        let ray = Ray::new(Point3::new(0.2, 1.0, -0.7), -Vector::y());
+        let hit = heightfield
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+            .unwrap();
+        assert_eq!(heightfield.feature_tag(hit.feature), Some(expected));
+        let ray = Ray::new(Point3::new(0.7, -1.0, -0.2), Vector::y());
+        let hit = heightfield
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+            .unwrap();
+        assert_eq!(heightfield.feature_tag(hit.feature), Some(expected));
+
+        let (proj, feature) =
+            heightfield.project_local_point_and_get_feature(&Point3::new(0.7, 0.5, -0.2));
+        assert_relative_eq!(proj.point, Point3::new(0.7, 0.0, -0.2));
+        assert_eq!(heightfield.feature_tag(feature), Some(expected));
+        assert_eq!(heightfield.feature_tag(FeatureId::Vertex(0)), None);
+
+        let pos12 = Isometry::translation(0.5, 0.4, -0.5);
+        let mut manifolds: Vec<ContactManifold<(), ()>> = Vec::new();
+        DefaultQueryDispatcher
+            .contact_manifolds(
+                &pos12,
+                &heightfield,
+                &Ball::new(0.45),
+                0.0,
+                &mut manifolds,
+                &mut None,
+            )
+            .unwrap();
+        manifolds.retain(|m| !m.points.is_empty());
+        assert!(!manifolds.is_empty());
+        assert!(manifolds
+            .iter()
+            .all(|m| heightfield.triangle_tag(m.subshape1) == expected));
+
+        // The tags are preserved by the conversion to a triangle mesh.
+        let mesh = TriMesh::from(heightfield.clone());
+        assert_eq!(mesh.num_triangles(), 31);
+        assert!((0..mesh.num_triangles() as u32).all(|id| {
+            let center = mesh.triangle(id).center();
+            let (i, j) = heightfield.cell_at_point(&center).unwrap();
+            mesh.triangle_tag(id) == heightfield.cell_tag(i, j)
+        }));
+    }
+
+    #[test]
+    fn heightfield_zigzag_subdivision() {
+        use crate::math::Isometry;
+        use crate::query::{ContactManifold, DefaultQueryDispatcher, PersistentQueryDispatcher};
+        use crate::shape::{Ball, HeightFieldFlags, TriMesh, TriMeshFlags};
+        use alloc::vec::Vec;
+        use approx::relative_eq;
+
+        // A 4x4 cells heightfield with cells of size 1, and a ridge along its diagonal. The
+        // zigzag subdivision follows that ridge, and is applied to the cells in a checkerboard
+        // pattern so that neighbor cells have different subdivisions.
+        let heights = DMatrix::from_fn(5, 5, |i, j| if i == j { 1.0 } else { 0.0 });
+        let mut heightfield = HeightField::with_flags(
+            heights,
+            Vector::new(4.0, 1.0, 4.0),
+            HeightFieldFlags::FIX_INTERNAL_EDGES,
+        );
+        for i in 0..4 {
+            for j in 0..4 {
+                if (i + j) % 2 == 0 {
+                    heightfield.set_cell_status(i, j, HeightFieldCellStatus::ZIGZAG_SUBDIVISION);
+                }
+            }
+        }
+
+        // The center of the cell `(1, 1)` is on the ridge.
+        let ray = Ray::new(Point3::new(-0.5, 5.0, -0.5), -Vector::y());
+        let toi = heightfield.cast_local_ray(&ray, Real::MAX, true).unwrap();
+        assert_relative_eq!(toi, 4.0, epsilon = 1.0e-5);
+
+        // Ray-casts and contacts agree with the triangle mesh conversion.
+        let (vertices, indices) = heightfield.to_trimesh();
+        let flags = TriMeshFlags::MERGE_DUPLICATE_VERTICES | TriMeshFlags::FIX_INTERNAL_EDGES;
+        let mesh = TriMesh::with_flags(vertices, indices, flags).unwrap();
+
+        for k in 0..49 {
+            let (x, z) = ((k % 7) as Real * 0.6 - 1.7, (k / 7) as Real * 0.6 - 1.9);
+            let ray = Ray::new(Point3::new(x, 5.0, z), -Vector::y());
+            let toi1 = heightfield.cast_local_ray(&ray, Real::MAX, true).unwrap();
+            let toi2 = mesh.cast_local_ray(&ray, Real::MAX, true).unwrap();
+            assert_relative_eq!(toi1, toi2, epsilon = 1.0e-5);
+
+            let pos12 = Isometry::translation(x, 1.0, z);
+            let deepest = |manifolds: &[ContactManifold<(), ()>]| {
+                manifolds
+                    .iter()
+                    .flat_map(|m| m.points.iter())
+                    .map(|pt| pt.dist)
+                    .fold(Real::MAX, Real::min)
+            };
+            let mut manifolds1 = Vec::new();
+            let mut manifolds2 = Vec::new();
+            let ball = Ball::new(0.8);
+            DefaultQueryDispatcher
+                .contact_manifolds(&pos12, &heightfield, &ball, 0.0, &mut manifolds1, &mut None)
+                .unwrap();
+            DefaultQueryDispatcher
+                .contact_manifolds(&pos12, &mesh, &ball, 0.0, &mut manifolds2, &mut None)
+                .unwrap();
+            assert_relative_eq!(deepest(&manifolds1), deepest(&manifolds2), epsilon = 1.0e-4);
+        }
+
+        // The pseudo-normal of each internal edge is the average of the normals of the two
+        // triangles sharing it.
+        let triangles: Vec<_> = (0..heightfield.num_triangles as u32)
+            .map(|id| heightfield.triangle_at_id(id).unwrap())
+            .collect();
+        for (id, tri) in triangles.iter().enumerate() {
+            let pseudo_normals = heightfield.triangle_normal_constraints(id as u32).unwrap();
+            let normal = tri.normal().unwrap();
+
+            for [a, b] in [[tri.a, tri.b], [tri.b, tri.c], [tri.c, tri.a]] {
+                let Some(adj) = triangles.iter().enumerate().find_map(|(adj_id, adj)| {
+                    let vtx = adj.vertices();
+                    (adj_id != id && vtx.contains(&a) && vtx.contains(&b)).then_some(adj)
+                }) else {
+                    continue;
+                };
+
+                let expected = (*normal + *adj.normal().unwrap()).normalize();
+                assert!(pseudo_normals.edges.iter().any(|n| relative_eq!(
+                    **n,
+                    expected,
+                    epsilon = 1.0e-5
+                )));
+            }
+        }
+    }
+}
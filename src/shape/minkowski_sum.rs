@@ -0,0 +1,109 @@
+use crate::math::{Isometry, Point, Real, Vector};
+use crate::shape::SupportMap;
+use na::Unit;
+
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug)]
+/// The Minkowski sum of two support-mapped shapes, each with its own local position.
+///
+/// This is the set of all the points `a + b` where `a` is a point of `shape1` (transformed by
+/// `pos1`) and `b` is a point of `shape2` (transformed by `pos2`). Because both shapes are
+/// convex, their Minkowski sum is convex too and its support function is simply the sum of the
+/// support functions of both shapes.
+///
+/// For example, the Minkowski sum of any convex shape with a ball is that shape with a rounded
+/// border (just like [`RoundShape`](crate::shape::RoundShape)), and the Minkowski sum of a shape
+/// with a segment is the volume swept by that shape along the segment.
+pub struct MinkowskiSum<S1, S2> {
+    /// The position of the first shape, relative to the Minkowski sum’s local-space.
+    pub pos1: Isometry<Real>,
+    /// The first shape of the Minkowski sum.
+    pub shape1: S1,
+    /// The position of the second shape, relative to the Minkowski sum’s local-space.
+    pub pos2: Isometry<Real>,
+    /// The second shape of the Minkowski sum.
+    pub shape2: S2,
+}
+
+impl<S1, S2> MinkowskiSum<S1, S2> {
+    /// Creates the Minkowski sum of `shape1` transformed by `pos1`, and `shape2` transformed
+    /// by `pos2`.
+    pub fn new(pos1: Isometry<Real>, shape1: S1, pos2: Isometry<Real>, shape2: S2) -> Self {
+        Self {
+            pos1,
+            shape1,
+            pos2,
+            shape2,
+        }
+    }
+}
+
+impl<S1: SupportMap, S2: SupportMap> SupportMap for MinkowskiSum<S1, S2> {
+    fn local_support_point(&self, dir: &Vector<Real>) -> Point<Real> {
+        self.shape1.support_point(&self.pos1, dir)
+            + self.shape2.support_point(&self.pos2, dir).coords
+    }
+
+    fn local_support_point_toward(&self, dir: &Unit<Vector<Real>>) -> Point<Real> {
+        self.shape1.support_point_toward(&self.pos1, dir)
+            + self.shape2.support_point_toward(&self.pos2, dir).coords
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::math::{Isometry, Point, Real, Vector};
+    use crate::query::{PointQuery, Ray, RayCast};
+    use crate::shape::{Ball, Cuboid, MinkowskiSum, RoundShape, SupportMap};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn minkowski_sum_matches_round_shape() {
+        let cuboid = Cuboid::new(Vector::repeat(1.0));
+        let ball = Ball::new(0.5);
+        let shift = Vector::repeat(0.5);
+        let sum = MinkowskiSum::new(
+            Isometry::identity(),
+            cuboid,
+            Isometry::new(shift, na::zero()),
+            ball,
+        );
+        let round_cuboid = RoundShape {
+            inner_shape: cuboid,
+            border_radius: 0.5,
+        };
+
+        // Support points.
+        let dir = Vector::repeat(1.0);
+        assert_relative_eq!(
+            sum.local_support_point(&dir),
+            round_cuboid.local_support_point(&dir) + shift
+        );
+
+        // Ray-casts.
+        let ray = Ray::new(Point::from(Vector::repeat(-5.0)), Vector::x());
+        assert!(sum.cast_local_ray(&ray, Real::MAX, true).is_none());
+        let ray = Ray::new(Point::from(-Vector::x() * 5.0 + shift), Vector::x());
+        let hit = sum
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+            .unwrap();
+        assert_relative_eq!(hit.time_of_impact, 3.5, epsilon = 1.0e-5);
+        assert_relative_eq!(hit.normal, -Vector::x(), epsilon = 1.0e-5);
+
+        // Point queries.
+        let pt = Point::from(Vector::x() * 3.0 + shift);
+        assert_relative_eq!(
+            sum.distance_to_local_point(&pt, true),
+            1.5,
+            epsilon = 1.0e-3
+        );
+        assert!(sum.contains_local_point(&Point::from(shift)));
+        assert!(!sum.contains_local_point(&Point::from(-Vector::repeat(1.2))));
+        assert!(round_cuboid.contains_local_point(&Point::from(-Vector::repeat(1.2))));
+
+        // Bounding volumes.
+        let aabb = sum.local_aabb();
+        assert_relative_eq!(aabb.mins, Point::from(Vector::repeat(-1.0)));
+        assert_relative_eq!(aabb.maxs, Point::from(Vector::repeat(2.0)));
+    }
+}
@@ -3,10 +3,19 @@
 pub use self::ball::Ball;
 pub use self::capsule::Capsule;
 pub use self::cuboid::Cuboid;
+#[cfg(feature = "alloc")]
+pub use self::custom_shape_registry::{
+    CustomShapeData, CustomShapeRegistry, CustomShapeRegistryError,
+};
+pub use self::ellipsoid::Ellipsoid;
 pub use self::feature_id::{FeatureId, PackedFeatureId};
 pub use self::half_space::HalfSpace;
+pub use self::minkowski_sum::MinkowskiSum;
 pub use self::polygonal_feature_map::PolygonalFeatureMap;
 pub use self::round_shape::RoundShape;
+#[cfg(feature = "alloc")]
+pub use self::round_trimesh::RoundTriMesh;
+pub use self::scaled::Scaled;
 pub use self::segment::{Segment, SegmentPointLocation};
 #[cfg(feature = "serde-serialize")]
 pub(crate) use self::shape::DeserializableTypedShape;
@@ -14,6 +23,7 @@ pub(crate) use self::shape::DeserializableTypedShape;
 pub use self::shape::{Shape, ShapeType, TypedShape};
 #[doc(inline)]
 pub use self::support_map::SupportMap;
+pub use self::tapered_capsule::TaperedCapsule;
 pub use self::triangle::{Triangle, TriangleOrientation, TrianglePointLocation};
 
 #[cfg(feature = "alloc")]
@@ -21,12 +31,18 @@ pub use self::{
     composite_shape::{SimdCompositeShape, TypedSimdCompositeShape},
     compound::Compound,
     polyline::Polyline,
+    sdf::Sdf,
     shared_shape::SharedShape,
+    sphere_set::{SphereSet, SphereSetBuilderError},
+    swept_shape::SweptShape,
     voxels::{
         AxisMask, OctantPattern, VoxelData, VoxelPrimitiveGeometry, VoxelState, VoxelType, Voxels,
     },
 };
 
+#[cfg(feature = "dim2")]
+#[cfg(feature = "alloc")]
+pub use self::bezier_spline::{BezierSpline, BezierSplineBuilderError};
 #[cfg(feature = "dim2")]
 #[cfg(feature = "alloc")]
 pub use self::convex_polygon::ConvexPolygon;
@@ -34,6 +50,9 @@ pub use self::convex_polygon::ConvexPolygon;
 #[cfg(feature = "alloc")]
 pub use self::heightfield2::*;
 #[cfg(feature = "dim2")]
+#[cfg(feature = "alloc")]
+pub use self::polygon::Polygon;
+#[cfg(feature = "dim2")]
 pub use self::polygonal_feature2d::PolygonalFeature;
 
 #[cfg(feature = "dim3")]
@@ -49,7 +68,15 @@ pub use self::heightfield3::*;
 #[cfg(feature = "dim3")]
 pub use self::polygonal_feature3d::PolygonalFeature;
 #[cfg(feature = "dim3")]
+#[cfg(feature = "alloc")]
+pub use self::round_polyline::{CapsulePseudoNormals, RoundPolyline};
+#[cfg(feature = "dim3")]
+#[cfg(feature = "alloc")]
+pub use self::tetmesh::{TetMesh, TetMeshBuilderError};
+#[cfg(feature = "dim3")]
 pub use self::tetrahedron::{Tetrahedron, TetrahedronPointLocation};
+#[cfg(feature = "dim3")]
+pub use self::torus::Torus;
 pub use self::triangle_pseudo_normals::TrianglePseudoNormals;
 #[cfg(feature = "alloc")]
 pub use self::trimesh::*;
@@ -64,6 +91,10 @@ pub type RoundCone = RoundShape<Cone>;
 pub type RoundCuboid = RoundShape<Cuboid>;
 /// A triangle dilated by a sphere (so it has round corners).
 pub type RoundTriangle = RoundShape<Triangle>;
+/// A heightfield dilated by a sphere (so each of its triangles, or segments in 2D, has round
+/// corners).
+#[cfg(feature = "alloc")]
+pub type RoundHeightField = RoundShape<HeightField>;
 /// A convex polyhedron dilated by a sphere (so it has round corners).
 #[cfg(feature = "dim3")]
 #[cfg(feature = "alloc")]
@@ -83,23 +114,43 @@ pub mod composite_shape;
 #[cfg(feature = "alloc")]
 mod compound;
 mod cuboid;
+#[cfg(feature = "alloc")]
+mod custom_shape_registry;
+mod ellipsoid;
 mod half_space;
+mod minkowski_sum;
 #[cfg(feature = "alloc")]
 mod polyline;
 mod round_shape;
+#[cfg(feature = "alloc")]
+mod round_trimesh;
+mod scaled;
+#[cfg(feature = "alloc")]
+mod sdf;
 mod segment;
 #[doc(hidden)]
 pub mod shape;
+#[cfg(feature = "alloc")]
+mod sphere_set;
 #[doc(hidden)]
 pub mod support_map;
+#[cfg(feature = "alloc")]
+mod swept_shape;
+mod tapered_capsule;
 mod triangle;
 
+#[cfg(feature = "dim2")]
+#[cfg(feature = "alloc")]
+pub(crate) mod bezier_spline;
 #[cfg(feature = "dim2")]
 #[cfg(feature = "alloc")]
 mod convex_polygon;
 #[cfg(feature = "dim2")]
 #[cfg(feature = "alloc")]
 mod heightfield2;
+#[cfg(feature = "dim2")]
+#[cfg(feature = "alloc")]
+mod polygon;
 
 #[cfg(feature = "dim3")]
 mod cone;
@@ -115,7 +166,15 @@ mod heightfield3;
 mod polygonal_feature3d;
 mod polygonal_feature_map;
 #[cfg(feature = "dim3")]
+#[cfg(feature = "alloc")]
+mod round_polyline;
+#[cfg(feature = "dim3")]
+#[cfg(feature = "alloc")]
+mod tetmesh;
+#[cfg(feature = "dim3")]
 mod tetrahedron;
+#[cfg(feature = "dim3")]
+mod torus;
 #[cfg(feature = "alloc")]
 pub(crate) mod trimesh;
 // TODO: move this elsewhere?
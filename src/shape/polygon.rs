@@ -1,83 +1,253 @@
-#![allow(dead_code)] // TODO: remove this once we support polygons.
-
+use crate::bounding_volume::{Aabb, BoundingSphere};
 use crate::math::{Isometry, Point, Real, Vector};
-use parry::bounding_volume::Aabb;
+use crate::shape::{Compound, ConvexPolygon, Polyline, SharedShape, Triangle};
+use crate::transformation::ear_clipping::triangulate_ear_clipping_with_holes;
+use crate::transformation::hertel_mehlhorn;
+use alloc::vec::Vec;
+use core::ops::Range;
 
-#[derive(Clone)]
+/// A simple (possibly non-convex) polygon, optionally with holes.
+///
+/// Its interior is solid: point projections and ray-casts take into account the fact that
+/// points inside of the polygon (but outside of its holes) are part of the shape. Contacts and
+/// distances are computed by the [`Compound`] of convex parts resulting from the automatic
+/// convex decomposition of the polygon.
+#[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
-#[cfg_attr(
-    feature = "rkyv",
-    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
-    archive(check_bytes)
-)]
-/// A convex planar polygon.
 pub struct Polygon {
-    pub(crate) vertices: Vec<Point<Real>>,
-    pub(crate) normals: Vec<Vector<Real>>,
+    vertices: Vec<Point<Real>>,
+    rings: Vec<[u32; 2]>,
+    boundary: Polyline,
+    decomposition: Compound,
 }
 
 impl Polygon {
-    /// Builds a new polygon from a set of vertices and normals.
+    /// Builds a new polygon from its outer boundary and its holes.
     ///
-    /// The vertices must be ordered in such a way that two consecutive
-    /// vertices determines an edge of the polygon. For example `vertices[0], vertices[1]`
-    /// is an edge, `vertices[1], vertices[2]` is the next edge, etc. The last edge will
-    /// be `vertices[vertices.len() - 1], vertices[0]`.
-    /// The vertices must be given in counter-clockwise order.
-    /// The vertices must form a convex polygon.
+    /// The outer boundary and each hole are closed loops of vertices: two consecutive vertices
+    /// determine an edge, and the last vertex is connected to the first one. Their orientation
+    /// doesn’t matter: the outer boundary is stored in counter-clockwise order and the holes in
+    /// clockwise order. The holes must be strictly inside of the outer boundary, and none of
+    /// the loops may intersect each other or self-intersect.
     ///
-    /// One normal must be provided per edge and mut point towards the outside of the polygon.
-    pub fn new(vertices: Vec<Point<Real>>, normals: Vec<Vector<Real>>) -> Self {
-        Self { vertices, normals }
-    }
+    /// Returns `None` if one of the loops has less than three vertices or a zero area, or if the
+    /// polygon couldn’t be decomposed into convex parts (e.g. because of self-intersections).
+    pub fn new(outer_boundary: Vec<Point<Real>>, holes: Vec<Vec<Point<Real>>>) -> Option<Self> {
+        let mut vertices = Vec::new();
+        let mut rings = Vec::new();
 
-    /// Compute the axis-aligned bounding box of the polygon.
-    pub fn aabb(&self, pos: &Isometry<Real>) -> Aabb {
-        let p0 = pos * self.vertices[0];
-        let mut mins = p0;
-        let mut maxs = p0;
-
-        for pt in &self.vertices[1..] {
-            let pt = pos * pt;
-            mins = mins.inf(&pt);
-            maxs = maxs.sup(&pt);
+        for (i, mut ring) in core::iter::once(outer_boundary).chain(holes).enumerate() {
+            let area = signed_area(&ring);
+
+            if ring.len() < 3 || area == 0.0 {
+                return None;
+            }
+
+            // The outer boundary must be counter-clockwise, and the holes clockwise.
+            if (area > 0.0) != (i == 0) {
+                ring.reverse();
+            }
+
+            let start = vertices.len() as u32;
+            vertices.extend_from_slice(&ring);
+            rings.push([start, vertices.len() as u32]);
         }
 
-        Aabb::new(mins.into(), maxs.into())
+        let ranges: Vec<Range<u32>> = rings.iter().map(|r| r[0]..r[1]).collect();
+        let triangles = triangulate_ear_clipping_with_holes(&vertices, &ranges)?;
+        let parts = hertel_mehlhorn(&vertices, &triangles)
+            .into_iter()
+            .map(|points| {
+                match points.len() {
+                    3 => {
+                        let triangle = Triangle::new(points[0], points[1], points[2]);
+                        Some(SharedShape::new(triangle))
+                    }
+                    _ => ConvexPolygon::from_convex_polyline(points).map(SharedShape::new),
+                }
+                .map(|shape| (Isometry::identity(), shape))
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let segments = ranges
+            .iter()
+            .flat_map(|r| {
+                r.clone()
+                    .map(move |i| [i, if i + 1 == r.end { r.start } else { i + 1 }])
+            })
+            .collect();
+        let boundary = Polyline::new(vertices.clone(), Some(segments));
+
+        Some(Self {
+            vertices,
+            rings,
+            boundary,
+            decomposition: Compound::new(parts),
+        })
+    }
+
+    /// Computes a scaled version of this polygon.
+    ///
+    /// Returns `None` if the scaled polygon is degenerate (for example if the scaling factor
+    /// along one axis is zero).
+    pub fn scaled(&self, scale: &Vector<Real>) -> Option<Self> {
+        let scaled_ring = |r: &[u32; 2]| -> Vec<_> {
+            self.vertices[r[0] as usize..r[1] as usize]
+                .iter()
+                .map(|pt| pt.coords.component_mul(scale).into())
+                .collect()
+        };
+        let holes = self.rings[1..].iter().map(scaled_ring).collect();
+        Self::new(scaled_ring(&self.rings[0]), holes)
     }
 
-    /// The vertices of this polygon.
+    /// All the vertices of this polygon: the vertices of its outer boundary, followed by the
+    /// vertices of each of its holes.
     pub fn vertices(&self) -> &[Point<Real>] {
         &self.vertices
     }
 
-    pub(crate) fn support_point(&self, dir: &Vector<Real>) -> usize {
-        let mut best_dot = -Real::MAX;
-        let mut best_i = 0;
+    /// The vertices of the outer boundary of this polygon, in counter-clockwise order.
+    pub fn outer_boundary(&self) -> &[Point<Real>] {
+        self.ring(0)
+    }
 
-        for (i, pt) in self.vertices.iter().enumerate() {
-            let dot = pt.coords.dot(&dir);
-            if dot > best_dot {
-                best_dot = dot;
-                best_i = i;
-            }
-        }
+    /// The number of holes of this polygon.
+    pub fn num_holes(&self) -> usize {
+        self.rings.len() - 1
+    }
+
+    /// The vertices of the `i`-th hole of this polygon, in clockwise order.
+    pub fn hole(&self, i: usize) -> &[Point<Real>] {
+        self.ring(i + 1)
+    }
 
-        best_i
+    /// The boundary of this polygon (including the boundary of its holes), as a polyline.
+    ///
+    /// The segments of the polyline are oriented such that their normals point outward.
+    pub fn boundary(&self) -> &Polyline {
+        &self.boundary
     }
 
-    pub(crate) fn support_face(&self, dir: &Vector<Real>) -> usize {
-        let mut max_dot = -Real::MAX;
-        let mut max_dot_i = 0;
+    /// The convex decomposition of this polygon.
+    pub fn convex_decomposition(&self) -> &Compound {
+        &self.decomposition
+    }
 
-        for (i, normal) in self.normals.iter().enumerate() {
-            let dot = normal.dot(dir);
-            if dot > max_dot {
-                max_dot = dot;
-                max_dot_i = i;
-            }
-        }
+    /// Compute the axis-aligned bounding box of this polygon.
+    pub fn aabb(&self, pos: &Isometry<Real>) -> Aabb {
+        self.local_aabb().transform_by(pos)
+    }
+
+    /// Gets the local axis-aligned bounding box of this polygon.
+    pub fn local_aabb(&self) -> &Aabb {
+        self.boundary.local_aabb()
+    }
+
+    /// The bounding-sphere of this polygon in its local-space.
+    pub fn local_bounding_sphere(&self) -> BoundingSphere {
+        self.local_aabb().bounding_sphere()
+    }
+
+    fn ring(&self, i: usize) -> &[Point<Real>] {
+        let [start, end] = self.rings[i];
+        &self.vertices[start as usize..end as usize]
+    }
+}
+
+/// The signed area of the closed loop of vertices, positive if it is counter-clockwise.
+fn signed_area(ring: &[Point<Real>]) -> Real {
+    let mut area = 0.0;
+
+    for (i, a) in ring.iter().enumerate() {
+        let b = ring[(i + 1) % ring.len()];
+        area += a.coords.perp(&b.coords);
+    }
+
+    area / 2.0
+}
+
+#[cfg(test)]
+mod test {
+    use crate::math::{Isometry, Point, Real, Vector};
+    use crate::query::{self, PointQuery};
+    use crate::shape::{Ball, Polygon, Shape};
+    use alloc::{vec, vec::Vec};
+    use approx::assert_relative_eq;
+
+    fn square(center: Point<Real>, half_extent: Real) -> Vec<Point<Real>> {
+        vec![
+            center + Vector::new(-half_extent, -half_extent),
+            center + Vector::new(half_extent, -half_extent),
+            center + Vector::new(half_extent, half_extent),
+            center + Vector::new(-half_extent, half_extent),
+        ]
+    }
+
+    #[test]
+    fn polygon_with_hole() {
+        // A clockwise "U" shape with a square hole in its base.
+        let mut outer = vec![
+            Point::new(0.0, 0.0),
+            Point::new(6.0, 0.0),
+            Point::new(6.0, 6.0),
+            Point::new(4.0, 6.0),
+            Point::new(4.0, 3.0),
+            Point::new(2.0, 3.0),
+            Point::new(2.0, 6.0),
+            Point::new(0.0, 6.0),
+        ];
+        outer.reverse();
+        let hole = square(Point::new(3.0, 1.5), 0.5);
+        let polygon = Polygon::new(outer, vec![hole]).unwrap();
+
+        assert_eq!(polygon.num_holes(), 1);
+        assert_relative_eq!(polygon.outer_boundary()[0], Point::new(0.0, 0.0));
+
+        // Mass properties.
+        let area = 36.0 - 6.0 - 1.0;
+        assert_relative_eq!(
+            polygon.mass_properties(2.0).mass(),
+            area * 2.0,
+            epsilon = 1.0e-4
+        );
+
+        // Containment.
+        assert!(polygon.contains_local_point(&Point::new(1.0, 5.0)));
+        assert!(!polygon.contains_local_point(&Point::new(3.0, 5.0)));
+        assert!(!polygon.contains_local_point(&Point::new(3.0, 1.5)));
+
+        // Contacts go through the convex decomposition.
+        let ball = Ball::new(0.5);
+        let contact = query::contact(
+            &Isometry::identity(),
+            &polygon,
+            &Isometry::translation(3.0, 3.2),
+            &ball,
+            0.0,
+        )
+        .unwrap()
+        .unwrap();
+        assert_relative_eq!(contact.dist, -0.3, epsilon = 1.0e-5);
+        assert_relative_eq!(*contact.normal1, Vector::y(), epsilon = 1.0e-5);
+
+        let dist = query::distance(
+            &Isometry::identity(),
+            &polygon,
+            &Isometry::translation(3.0, 1.5),
+            &Ball::new(0.25),
+        )
+        .unwrap();
+        assert_relative_eq!(dist, 0.25, epsilon = 1.0e-5);
+    }
 
-        max_dot_i
+    #[test]
+    fn polygon_rejects_degenerate_input() {
+        assert!(Polygon::new(vec![Point::origin(), Point::new(1.0, 0.0)], vec![]).is_none());
+        assert!(Polygon::new(
+            vec![Point::origin(), Point::new(1.0, 0.0), Point::new(2.0, 0.0)],
+            vec![]
+        )
+        .is_none());
     }
 }
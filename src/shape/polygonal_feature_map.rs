@@ -5,7 +5,7 @@ use na::Unit;
 use {
     crate::{
         math::Point,
-        shape::{Cone, Cylinder, PackedFeatureId},
+        shape::{Cone, Cylinder, PackedFeatureId, Tetrahedron},
     },
     approx::AbsDiffEq,
 };
@@ -39,6 +39,13 @@ impl PolygonalFeatureMap for Triangle {
     }
 }
 
+#[cfg(feature = "dim3")]
+impl PolygonalFeatureMap for Tetrahedron {
+    fn local_support_feature(&self, dir: &Unit<Vector<Real>>, out_feature: &mut PolygonalFeature) {
+        *out_feature = self.support_face(**dir);
+    }
+}
+
 impl PolygonalFeatureMap for Cuboid {
     fn local_support_feature(&self, dir: &Unit<Vector<Real>>, out_feature: &mut PolygonalFeature) {
         *out_feature = self.support_face(**dir);
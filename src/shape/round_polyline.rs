@@ -0,0 +1,414 @@
+use crate::bounding_volume::{Aabb, BoundingSphere, BoundingVolume};
+use crate::mass_properties::MassProperties;
+use crate::math::{Isometry, Real, UnitVector, Vector, DEFAULT_EPSILON};
+use crate::partitioning::Qbvh;
+use crate::query::details::NormalConstraints;
+use crate::shape::composite_shape::SimdCompositeShape;
+use crate::shape::{Capsule, Polyline, Shape, TypedSimdCompositeShape};
+use alloc::{vec, vec::Vec};
+#[cfg(not(feature = "std"))]
+use na::ComplexField;
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+/// A polyline dilated by a sphere, i.e., a chain of capsules sharing the same radius.
+///
+/// This is a composite shape where each part is a [`Capsule`]. It is well suited for modeling
+/// cables, hoses, or ropes: it uses much less memory than a [`Compound`](crate::shape::Compound)
+/// of capsules, and the joints between consecutive capsules are smoothed by
+/// [`CapsulePseudoNormals`] so that objects sliding along it don't snag on them.
+pub struct RoundPolyline {
+    qbvh: Qbvh<u32>,
+    polyline: Polyline,
+    radius: Real,
+    pseudo_normals: Vec<CapsulePseudoNormals>,
+}
+
+impl RoundPolyline {
+    /// Creates a new polyline with round corners.
+    ///
+    /// Every segment of `polyline` is dilated by a sphere with radius `radius`.
+    pub fn new(polyline: Polyline, radius: Real) -> Self {
+        // The bounding volumes of the segments are enlarged by the radius
+        // so that the tree can be traversed directly by the composite-shape queries.
+        let data = polyline.segments().enumerate().map(|(i, seg)| {
+            let aabb = seg.local_aabb().loosened(radius);
+            (i as u32, aabb)
+        });
+
+        let mut qbvh = Qbvh::new();
+        // NOTE: we apply no dilation factor because we won't
+        // update this tree dynamically.
+        qbvh.clear_and_rebuild(data, 0.0);
+        let pseudo_normals = CapsulePseudoNormals::from_polyline(&polyline);
+
+        Self {
+            qbvh,
+            polyline,
+            radius,
+            pseudo_normals,
+        }
+    }
+
+    /// Returns a scaled version of this polyline.
+    ///
+    /// The radius is left unchanged.
+    pub fn scaled(self, scale: &Vector<Real>) -> Self {
+        Self::new(self.polyline.scaled(scale), self.radius)
+    }
+
+    /// The polyline dilated by this shape.
+    pub fn polyline(&self) -> &Polyline {
+        &self.polyline
+    }
+
+    /// The radius of the sphere dilating each segment of this polyline.
+    pub fn radius(&self) -> Real {
+        self.radius
+    }
+
+    /// The number of capsules (i.e. of segments) of this shape.
+    pub fn num_capsules(&self) -> usize {
+        self.polyline.num_segments()
+    }
+
+    /// Compute the axis-aligned bounding box of this polyline.
+    pub fn aabb(&self, pos: &Isometry<Real>) -> Aabb {
+        self.qbvh.root_aabb().transform_by(pos)
+    }
+
+    /// Gets the local axis-aligned bounding box of this polyline.
+    pub fn local_aabb(&self) -> &Aabb {
+        self.qbvh.root_aabb()
+    }
+
+    /// The bounding-sphere of this polyline in its local-space.
+    pub fn local_bounding_sphere(&self) -> BoundingSphere {
+        self.polyline.local_bounding_sphere().loosened(self.radius)
+    }
+
+    /// The acceleration structure used by this polyline.
+    ///
+    /// Its leaves are the bounding boxes of the capsules.
+    pub fn qbvh(&self) -> &Qbvh<u32> {
+        &self.qbvh
+    }
+
+    /// Get the `i`-th capsule of this shape, i.e., its `i`-th segment dilated by its radius.
+    pub fn capsule(&self, i: u32) -> Capsule {
+        let segment = self.polyline.segment(i);
+        Capsule::new(segment.a, segment.b, self.radius)
+    }
+
+    /// The pseudo-normals smoothing the joints of the `i`-th capsule with its neighbors.
+    pub fn capsule_pseudo_normals(&self, i: u32) -> Option<&CapsulePseudoNormals> {
+        self.pseudo_normals.get(i as usize)
+    }
+
+    /// Computes the mass properties of this shape.
+    ///
+    /// This sums the mass properties of every capsule, and removes the balls counted twice at
+    /// the joints. This is exact if the joints are straight, and a close approximation if they
+    /// are bent.
+    pub fn mass_properties(&self, density: Real) -> MassProperties {
+        let vertices = self.polyline.vertices();
+        let mut valences = vec![0u32; vertices.len()];
+        let capsules: MassProperties = self
+            .polyline
+            .indices()
+            .iter()
+            .map(|idx| {
+                valences[idx[0] as usize] += 1;
+                valences[idx[1] as usize] += 1;
+                let (a, b) = (vertices[idx[0] as usize], vertices[idx[1] as usize]);
+                MassProperties::from_capsule(density, a, b, self.radius)
+            })
+            .sum();
+        // A vertex shared by `n` segments is covered by `n` balls but only one is needed.
+        let joints: MassProperties = vertices
+            .iter()
+            .zip(valences)
+            .filter(|(_, valence)| *valence > 1)
+            .map(|(pt, valence)| {
+                let excess_density = density * (valence - 1) as Real;
+                MassProperties::from_ball(excess_density, self.radius)
+                    .transform_by(&Isometry::translation(pt.x, pt.y, pt.z))
+            })
+            .sum();
+
+        capsules - joints
+    }
+}
+
+impl SimdCompositeShape for RoundPolyline {
+    fn map_part_at(
+        &self,
+        i: u32,
+        f: &mut dyn FnMut(Option<&Isometry<Real>>, &dyn Shape, Option<&dyn NormalConstraints>),
+    ) {
+        let capsule = self.capsule(i);
+        let normals = self.capsule_pseudo_normals(i);
+        f(None, &capsule, normals.map(|n| n as &dyn NormalConstraints))
+    }
+
+    fn qbvh(&self) -> &Qbvh<u32> {
+        &self.qbvh
+    }
+}
+
+impl TypedSimdCompositeShape for RoundPolyline {
+    type PartShape = Capsule;
+    type PartNormalConstraints = CapsulePseudoNormals;
+    type PartId = u32;
+
+    #[inline(always)]
+    fn map_typed_part_at(
+        &self,
+        i: u32,
+        mut f: impl FnMut(
+            Option<&Isometry<Real>>,
+            &Self::PartShape,
+            Option<&Self::PartNormalConstraints>,
+        ),
+    ) {
+        let capsule = self.capsule(i);
+        f(None, &capsule, self.capsule_pseudo_normals(i))
+    }
+
+    #[inline(always)]
+    fn map_untyped_part_at(
+        &self,
+        i: u32,
+        mut f: impl FnMut(Option<&Isometry<Real>>, &dyn Shape, Option<&dyn NormalConstraints>),
+    ) {
+        let capsule = self.capsule(i);
+        let normals = self.capsule_pseudo_normals(i);
+        f(None, &capsule, normals.map(|n| n as &dyn NormalConstraints))
+    }
+
+    fn typed_qbvh(&self) -> &Qbvh<u32> {
+        &self.qbvh
+    }
+}
+
+// NOTE: just like the `TrianglePseudoNormals`, this only relies on the normal direction, not on
+//       the contact point. This is exact as long as the neighbor segments are longer than the
+//       radius of the capsules.
+/// The pseudo-normals of a capsule that is part of a chain of capsules, used to discard the
+/// contacts on its caps that are covered by its neighbors.
+///
+/// A contact normal pointing to the cap of a capsule and toward the segment adjacent to that cap
+/// is rejected: the contact point lies inside the neighbor capsule, which generates the correct
+/// contact instead.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct CapsulePseudoNormals {
+    /// The unit direction of the capsule's segment, from its first to its second endpoint.
+    pub axis: UnitVector<Real>,
+    /// The unit direction from the first endpoint toward the other endpoint of the segment
+    /// attached to it, if there is exactly one such segment.
+    pub start: Option<UnitVector<Real>>,
+    /// The unit direction from the second endpoint toward the other endpoint of the segment
+    /// attached to it, if there is exactly one such segment.
+    pub end: Option<UnitVector<Real>>,
+}
+
+impl CapsulePseudoNormals {
+    /// Computes the pseudo-normals of every segment of `polyline`.
+    pub fn from_polyline(polyline: &Polyline) -> Vec<Self> {
+        let vertices = polyline.vertices();
+        // For each vertex, the number of segments attached to it, and the other endpoints of
+        // the first two of them.
+        let mut valences = vec![0usize; vertices.len()];
+        let mut neighbors = vec![[u32::MAX; 2]; vertices.len()];
+
+        for idx in polyline.indices() {
+            for k in 0..2 {
+                let vid = idx[k] as usize;
+                if valences[vid] < 2 {
+                    neighbors[vid][valences[vid]] = idx[1 - k];
+                }
+                valences[vid] += 1;
+            }
+        }
+
+        let joint_dir = |vid: u32, other: u32| {
+            let vid = vid as usize;
+            if valences[vid] != 2 {
+                return None;
+            }
+
+            let next = if neighbors[vid][0] == other {
+                neighbors[vid][1]
+            } else {
+                neighbors[vid][0]
+            };
+            UnitVector::try_new(vertices[next as usize] - vertices[vid], DEFAULT_EPSILON)
+        };
+
+        polyline
+            .indices()
+            .iter()
+            .map(|idx| Self {
+                axis: UnitVector::try_new(
+                    vertices[idx[1] as usize] - vertices[idx[0] as usize],
+                    DEFAULT_EPSILON,
+                )
+                .unwrap_or_else(Vector::y_axis),
+                start: joint_dir(idx[0], idx[1]),
+                end: joint_dir(idx[1], idx[0]),
+            })
+            .collect()
+    }
+}
+
+impl NormalConstraints for CapsulePseudoNormals {
+    fn project_local_normal_mut(&self, dir: &mut Vector<Real>) -> bool {
+        let eps = DEFAULT_EPSILON.sqrt();
+        let dot_axis = dir.dot(&self.axis);
+
+        if dot_axis > eps {
+            !self.end.is_some_and(|end| dir.dot(&end) > eps)
+        } else if dot_axis < -eps {
+            !self.start.is_some_and(|start| dir.dot(&start) > eps)
+        } else {
+            true
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::math::{Isometry, Point, Real, Vector};
+    use crate::query::{
+        self, ContactManifold, DefaultQueryDispatcher, PersistentQueryDispatcher, PointQuery, Ray,
+        RayCast, ShapeCastOptions,
+    };
+    use crate::shape::{Ball, Capsule, Polyline, RoundPolyline, Shape};
+    use alloc::{vec, vec::Vec};
+    use approx::assert_relative_eq;
+
+    /// A straight rope along `x`, from `-2` to `2`, made of four segments of radius `0.1`.
+    fn straight_rope() -> RoundPolyline {
+        let vertices = (-2..=2).map(|i| Point::new(i as Real, 0.0, 0.0)).collect();
+        RoundPolyline::new(Polyline::new(vertices, None), 0.1)
+    }
+
+    #[test]
+    fn round_polyline_ray_and_point_queries() {
+        let rope = straight_rope();
+        assert_eq!(rope.num_capsules(), 4);
+        assert_relative_eq!(rope.local_aabb().mins, Point::new(-2.1, -0.1, -0.1));
+        assert_relative_eq!(rope.local_aabb().maxs, Point::new(2.1, 0.1, 0.1));
+
+        let ray = Ray::new(Point::new(0.5, 5.0, 0.0), -Vector::y());
+        let hit = rope
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+            .unwrap();
+        assert_relative_eq!(hit.time_of_impact, 4.9, epsilon = 1.0e-3);
+        assert_relative_eq!(hit.normal, Vector::y(), epsilon = 1.0e-3);
+
+        let ray = Ray::new(Point::new(-5.0, 0.0, 0.0), Vector::x());
+        let toi = rope.cast_local_ray(&ray, Real::MAX, true).unwrap();
+        assert_relative_eq!(toi, 2.9, epsilon = 1.0e-3);
+        let ray = Ray::new(Point::new(0.0, 0.15, 5.0), -Vector::z());
+        assert_eq!(rope.cast_local_ray(&ray, Real::MAX, true), None);
+
+        let proj = rope.project_local_point(&Point::new(1.0, 0.0, 0.5), true);
+        assert!(!proj.is_inside);
+        assert_relative_eq!(proj.point, Point::new(1.0, 0.0, 0.1), epsilon = 1.0e-5);
+        assert!(rope.contains_local_point(&Point::new(-1.5, 0.05, 0.0)));
+        assert!(!rope.contains_local_point(&Point::new(2.15, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn round_polyline_joints_dont_snag() {
+        let rope = straight_rope();
+        let ball = Ball::new(0.5);
+        let dispatcher = DefaultQueryDispatcher;
+
+        // A ball resting on the rope slightly past the joint at `x = 0`. Without pseudo-normals,
+        // the cap of the first capsule would push the ball along the rope.
+        for x in [-0.05, -0.001, 0.0, 0.001, 0.05] {
+            let pos12 = Isometry::translation(x, 0.59, 0.0);
+            let mut manifolds: Vec<ContactManifold<(), ()>> = Vec::new();
+            dispatcher
+                .contact_manifolds(&pos12, &rope, &ball, 0.0, &mut manifolds, &mut None)
+                .unwrap();
+            manifolds.retain(|m| !m.points.is_empty());
+
+            assert!(!manifolds.is_empty());
+            for manifold in &manifolds {
+                assert_relative_eq!(manifold.local_n1, Vector::y(), epsilon = 1.0e-5);
+                assert_relative_eq!(manifold.points[0].dist, -0.01, epsilon = 1.0e-5);
+            }
+        }
+
+        // The free ends of the rope are still round.
+        let pos12 = Isometry::translation(2.55, 0.0, 0.0);
+        let contact = query::contact(&Isometry::identity(), &rope, &pos12, &ball, 0.0)
+            .unwrap()
+            .unwrap();
+        assert_relative_eq!(contact.dist, -0.05, epsilon = 1.0e-5);
+        assert_relative_eq!(*contact.normal1, Vector::x(), epsilon = 1.0e-5);
+    }
+
+    #[test]
+    fn round_polyline_bent_joint_keeps_outer_contacts() {
+        // An L-shaped rope: a ball touching the outer side of its corner only touches the caps.
+        let vertices = vec![
+            Point::new(-1.0, 0.0, 0.0),
+            Point::origin(),
+            Point::new(0.0, 0.0, -1.0),
+        ];
+        let rope = RoundPolyline::new(Polyline::new(vertices, None), 0.1);
+        let normal = Vector::new(1.0, 0.0, 1.0).normalize();
+        let pos12 = Isometry::from(normal * 0.59);
+        let mut manifolds: Vec<ContactManifold<(), ()>> = Vec::new();
+        DefaultQueryDispatcher
+            .contact_manifolds(
+                &pos12,
+                &rope,
+                &Ball::new(0.5),
+                0.0,
+                &mut manifolds,
+                &mut None,
+            )
+            .unwrap();
+        manifolds.retain(|m| !m.points.is_empty());
+
+        assert_eq!(manifolds.len(), 2);
+        for manifold in &manifolds {
+            assert_relative_eq!(manifold.local_n1, normal, epsilon = 1.0e-5);
+        }
+    }
+
+    #[test]
+    fn round_polyline_shape_cast_and_mass_properties() {
+        let rope = straight_rope();
+        let hit = query::cast_shapes(
+            &Isometry::identity(),
+            &Vector::zeros(),
+            &rope,
+            &Isometry::translation(1.0, 3.0, 0.0),
+            &-Vector::y(),
+            &Ball::new(0.5),
+            ShapeCastOptions::default(),
+        )
+        .unwrap()
+        .unwrap();
+        assert_relative_eq!(hit.time_of_impact, 2.4, epsilon = 1.0e-3);
+
+        // A straight rope has the same mass properties as a single capsule.
+        let capsule = Capsule::new(Point::new(-2.0, 0.0, 0.0), Point::new(2.0, 0.0, 0.0), 0.1);
+        let expected = capsule.mass_properties(2.0);
+        let mprops = rope.mass_properties(2.0);
+        assert_relative_eq!(mprops.mass(), expected.mass(), max_relative = 1.0e-5);
+        assert_relative_eq!(mprops.local_com, expected.local_com, epsilon = 1.0e-5);
+        assert_relative_eq!(
+            mprops.reconstruct_inertia_matrix(),
+            expected.reconstruct_inertia_matrix(),
+            max_relative = 1.0e-4
+        );
+    }
+}
@@ -0,0 +1,247 @@
+use crate::bounding_volume::{Aabb, BoundingSphere, BoundingVolume};
+use crate::math::{Isometry, Real, Vector};
+use crate::partitioning::Qbvh;
+use crate::query::details::NormalConstraints;
+use crate::shape::composite_shape::SimdCompositeShape;
+use crate::shape::{
+    RoundShape, RoundTriangle, Shape, TriMesh, TrianglePseudoNormals, TypedSimdCompositeShape,
+};
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+/// A triangle mesh dilated by a sphere (so each of its triangles has round corners).
+///
+/// This is a composite shape where each part is a [`RoundTriangle`]. Giving a thickness to a
+/// mesh prevents fast-moving objects from tunnelling through it, and yields smooth contact
+/// normals at its edges and vertices.
+pub struct RoundTriMesh {
+    qbvh: Qbvh<u32>,
+    mesh: TriMesh,
+    border_radius: Real,
+}
+
+impl RoundTriMesh {
+    /// Creates a new triangle mesh with round corners.
+    ///
+    /// Every triangle of `mesh` is dilated by a sphere with radius `border_radius`.
+    pub fn new(mesh: TriMesh, border_radius: Real) -> Self {
+        // The bounding volumes of the triangles are enlarged by the border radius
+        // so that the tree can be traversed directly by the composite-shape queries.
+        let data = (0..mesh.num_triangles() as u32).map(|i| {
+            let aabb = mesh.triangle(i).local_aabb().loosened(border_radius);
+            (i, aabb)
+        });
+
+        let mut qbvh = Qbvh::new();
+        // NOTE: we apply no dilation factor because we won't
+        // update this tree dynamically.
+        qbvh.clear_and_rebuild(data, 0.0);
+
+        Self {
+            qbvh,
+            mesh,
+            border_radius,
+        }
+    }
+
+    /// Returns a scaled version of this triangle mesh.
+    ///
+    /// The border radius is left unchanged.
+    pub fn scaled(self, scale: &Vector<Real>) -> Self {
+        Self::new(self.mesh.scaled(scale), self.border_radius)
+    }
+
+    /// The triangle mesh dilated by this shape.
+    pub fn mesh(&self) -> &TriMesh {
+        &self.mesh
+    }
+
+    /// The radius of the sphere dilating each triangle of this mesh.
+    pub fn border_radius(&self) -> Real {
+        self.border_radius
+    }
+
+    /// Compute the axis-aligned bounding box of this triangle mesh.
+    pub fn aabb(&self, pos: &Isometry<Real>) -> Aabb {
+        self.qbvh.root_aabb().transform_by(pos)
+    }
+
+    /// Gets the local axis-aligned bounding box of this triangle mesh.
+    pub fn local_aabb(&self) -> &Aabb {
+        self.qbvh.root_aabb()
+    }
+
+    /// The bounding-sphere of this triangle mesh in its local-space.
+    pub fn local_bounding_sphere(&self) -> BoundingSphere {
+        self.mesh
+            .local_bounding_sphere()
+            .loosened(self.border_radius)
+    }
+
+    /// The acceleration structure used by this triangle mesh.
+    ///
+    /// Its leaves are the bounding boxes of the rounded triangles.
+    pub fn qbvh(&self) -> &Qbvh<u32> {
+        &self.qbvh
+    }
+
+    /// Get the `i`-th triangle of this mesh, with its round corners.
+    pub fn triangle(&self, i: u32) -> RoundTriangle {
+        RoundShape {
+            inner_shape: self.mesh.triangle(i),
+            border_radius: self.border_radius,
+        }
+    }
+}
+
+impl SimdCompositeShape for RoundTriMesh {
+    fn map_part_at(
+        &self,
+        i: u32,
+        f: &mut dyn FnMut(Option<&Isometry<Real>>, &dyn Shape, Option<&dyn NormalConstraints>),
+    ) {
+        let tri = self.triangle(i);
+        let normals = self.mesh.triangle_normal_constraints(i);
+        f(
+            None,
+            &tri,
+            normals.as_ref().map(|n| n as &dyn NormalConstraints),
+        )
+    }
+
+    fn qbvh(&self) -> &Qbvh<u32> {
+        &self.qbvh
+    }
+}
+
+impl TypedSimdCompositeShape for RoundTriMesh {
+    type PartShape = RoundTriangle;
+    type PartNormalConstraints = TrianglePseudoNormals;
+    type PartId = u32;
+
+    #[inline(always)]
+    fn map_typed_part_at(
+        &self,
+        i: u32,
+        mut f: impl FnMut(
+            Option<&Isometry<Real>>,
+            &Self::PartShape,
+            Option<&Self::PartNormalConstraints>,
+        ),
+    ) {
+        let tri = self.triangle(i);
+        let pseudo_normals = self.mesh.triangle_normal_constraints(i);
+        f(None, &tri, pseudo_normals.as_ref())
+    }
+
+    #[inline(always)]
+    fn map_untyped_part_at(
+        &self,
+        i: u32,
+        mut f: impl FnMut(Option<&Isometry<Real>>, &dyn Shape, Option<&dyn NormalConstraints>),
+    ) {
+        let tri = self.triangle(i);
+        let pseudo_normals = self.mesh.triangle_normal_constraints(i);
+        f(
+            None,
+            &tri,
+            pseudo_normals.as_ref().map(|n| n as &dyn NormalConstraints),
+        )
+    }
+
+    fn typed_qbvh(&self) -> &Qbvh<u32> {
+        &self.qbvh
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "dim3")]
+mod test {
+    use crate::math::{Isometry, Point, Real, Vector};
+    use crate::query::{
+        self, ContactManifold, DefaultQueryDispatcher, PersistentQueryDispatcher, PointQuery, Ray,
+        RayCast, ShapeCastOptions,
+    };
+    use crate::shape::{Ball, FeatureId, RoundTriMesh, TriMesh};
+    use alloc::{vec, vec::Vec};
+    use approx::assert_relative_eq;
+
+    /// A square of half-extent 1 on the `xz` plane, with a border radius of `0.1`.
+    fn round_square() -> RoundTriMesh {
+        let vertices = vec![
+            Point::new(-1.0, 0.0, -1.0),
+            Point::new(1.0, 0.0, -1.0),
+            Point::new(1.0, 0.0, 1.0),
+            Point::new(-1.0, 0.0, 1.0),
+        ];
+        let mesh = TriMesh::new(vertices, vec![[0, 2, 1], [0, 3, 2]]).unwrap();
+        RoundTriMesh::new(mesh, 0.1)
+    }
+
+    #[test]
+    fn round_trimesh_ray_and_point_queries() {
+        let mesh = round_square();
+        assert_relative_eq!(mesh.local_aabb().maxs, Point::new(1.1, 0.1, 1.1));
+
+        let ray = Ray::new(Point::new(0.2, 5.0, 0.3), -Vector::y());
+        let hit = mesh
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+            .unwrap();
+        assert_relative_eq!(hit.time_of_impact, 4.9, epsilon = 1.0e-3);
+        assert_relative_eq!(hit.normal, Vector::y(), epsilon = 1.0e-3);
+        assert!(matches!(hit.feature, FeatureId::Face(_)));
+
+        // Hitting the rounded border, outside of the triangles.
+        let ray = Ray::new(Point::new(1.05, 5.0, 0.0), -Vector::y());
+        let toi = mesh.cast_local_ray(&ray, Real::MAX, true).unwrap();
+        assert_relative_eq!(toi, 5.0 - Real::sqrt(0.0075), epsilon = 1.0e-3);
+        let ray = Ray::new(Point::new(1.15, 5.0, 0.0), -Vector::y());
+        assert_eq!(mesh.cast_local_ray(&ray, Real::MAX, true), None);
+
+        let proj = mesh.project_local_point(&Point::new(0.0, 0.5, 0.0), true);
+        assert!(!proj.is_inside);
+        assert_relative_eq!(proj.point, Point::new(0.0, 0.1, 0.0), epsilon = 1.0e-5);
+        assert!(mesh.contains_local_point(&Point::new(0.0, -0.05, 0.0)));
+        assert!(!mesh.contains_local_point(&Point::new(0.0, 0.15, 0.0)));
+    }
+
+    #[test]
+    fn round_trimesh_contacts_and_shape_casts() {
+        let mesh = round_square();
+        let ball = Ball::new(0.5);
+
+        let pos12 = Isometry::translation(0.3, 0.55, 0.0);
+        let dispatcher = DefaultQueryDispatcher;
+        let mut manifolds: Vec<ContactManifold<(), ()>> = Vec::new();
+        dispatcher
+            .contact_manifolds(&pos12, &mesh, &ball, 0.0, &mut manifolds, &mut None)
+            .unwrap();
+        let deepest = manifolds
+            .iter()
+            .flat_map(|m| m.points.iter())
+            .map(|pt| pt.dist)
+            .fold(Real::MAX, Real::min);
+        assert_relative_eq!(deepest, -0.05, epsilon = 1.0e-4);
+
+        // A ball next to the edge of the mesh, only touching its rounded border.
+        let pos12 = Isometry::translation(1.55, 0.0, 0.0);
+        let contact = query::contact(&Isometry::identity(), &mesh, &pos12, &ball, 0.0)
+            .unwrap()
+            .unwrap();
+        assert_relative_eq!(contact.dist, -0.05, epsilon = 1.0e-4);
+        assert_relative_eq!(*contact.normal1, Vector::x(), epsilon = 1.0e-4);
+
+        let hit = query::cast_shapes(
+            &Isometry::identity(),
+            &Vector::zeros(),
+            &mesh,
+            &Isometry::translation(0.0, 3.0, 0.0),
+            &-Vector::y(),
+            &ball,
+            ShapeCastOptions::default(),
+        )
+        .unwrap()
+        .unwrap();
+        assert_relative_eq!(hit.time_of_impact, 2.4, epsilon = 1.0e-3);
+    }
+}
@@ -0,0 +1,166 @@
+use crate::math::{Point, Real, Vector};
+use crate::shape::SupportMap;
+use na::Unit;
+
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug)]
+/// A shape scaled non-uniformly along each local coordinate axis.
+///
+/// Unlike [`Shape::scale_dyn`](crate::shape::Shape::scale_dyn), which approximates non-uniformly
+/// scaled balls, cylinders, and cones with polyhedra, the scaling is applied lazily to the support
+/// function, ray-casts, and point-containment tests of the inner shape. This keeps the scaled
+/// shape exact for GJK/EPA, and doesn’t allocate.
+pub struct Scaled<S> {
+    inner_shape: S,
+    scale: Vector<Real>,
+}
+
+impl<S> Scaled<S> {
+    /// Scales `inner_shape` by `scale`, along each local coordinate axis.
+    ///
+    /// Returns `None` if one of the components of `scale` is zero.
+    pub fn new(inner_shape: S, scale: Vector<Real>) -> Option<Self> {
+        if scale.iter().any(|s| *s == 0.0) {
+            return None;
+        }
+
+        Some(Self { inner_shape, scale })
+    }
+
+    /// The shape being scaled.
+    pub fn inner_shape(&self) -> &S {
+        &self.inner_shape
+    }
+
+    /// The scaling factor along each local coordinate axis.
+    pub fn scale(&self) -> &Vector<Real> {
+        &self.scale
+    }
+
+    /// Computes a scaled version of this shape by combining `scale` with its current scaling
+    /// factor.
+    ///
+    /// Returns `None` if one of the components of `scale` is zero.
+    pub fn scaled(self, scale: &Vector<Real>) -> Option<Self> {
+        let scale = self.scale.component_mul(scale);
+        Self::new(self.inner_shape, scale)
+    }
+
+    /// Transforms a point from the local-space of this shape to the local-space of the inner
+    /// shape.
+    #[inline]
+    pub fn unscale_point(&self, pt: &Point<Real>) -> Point<Real> {
+        pt.coords.component_div(&self.scale).into()
+    }
+
+    /// Transforms a normal of the inner shape into a normal of this scaled shape.
+    ///
+    /// Returns `None` if `normal` is zero.
+    #[inline]
+    pub fn scale_normal(&self, normal: &Vector<Real>) -> Option<Unit<Vector<Real>>> {
+        // Normals are transformed by the inverse-transpose of the (diagonal) scaling matrix.
+        Unit::try_new(normal.component_div(&self.scale), 0.0)
+    }
+}
+
+impl<S: SupportMap> SupportMap for Scaled<S> {
+    fn local_support_point(&self, dir: &Vector<Real>) -> Point<Real> {
+        // The scaling matrix is diagonal, so it is its own transpose.
+        let local_dir = dir.component_mul(&self.scale);
+        let pt = self.inner_shape.local_support_point(&local_dir);
+        pt.coords.component_mul(&self.scale).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::mass_properties::MassProperties;
+    use crate::math::{Point, Real, Vector};
+    use crate::query::{PointQuery, Ray, RayCast};
+    use crate::shape::{Ball, Cuboid, Ellipsoid, Scaled, Shape, SupportMap};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn scaled_ball_is_an_ellipsoid() {
+        #[cfg(feature = "dim2")]
+        let radii = Vector::new(2.0, 0.5);
+        #[cfg(feature = "dim3")]
+        let radii = Vector::new(2.0, 0.5, 1.5);
+        let scaled = Scaled::new(Ball::new(1.0), radii).unwrap();
+        let ellipsoid = Ellipsoid::new(radii);
+        assert!(Scaled::new(Ball::new(1.0), Vector::x()).is_none());
+
+        // Support points.
+        let dir = Vector::repeat(1.0);
+        assert_relative_eq!(
+            scaled.local_support_point(&dir),
+            ellipsoid.local_support_point(&dir),
+            epsilon = 1.0e-5
+        );
+
+        // Ray-casts are computed exactly, in the inner shape’s local-space.
+        let ray = Ray::new(Point::from(Vector::repeat(-3.0)), Vector::repeat(1.0));
+        let hit1 = scaled
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+            .unwrap();
+        let hit2 = ellipsoid
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+            .unwrap();
+        assert_relative_eq!(hit1.time_of_impact, hit2.time_of_impact, epsilon = 1.0e-5);
+        assert_relative_eq!(hit1.normal, hit2.normal, epsilon = 1.0e-5);
+
+        // Point queries.
+        let pt = Point::from(Vector::x() * 1.9 + Vector::y() * 0.1);
+        assert!(scaled.contains_local_point(&pt));
+        let pt = Point::from(Vector::x() * 3.0);
+        assert_relative_eq!(
+            scaled.project_local_point(&pt, true).point,
+            Point::from(Vector::x() * 2.0),
+            epsilon = 1.0e-4
+        );
+
+        // Bounding volume.
+        let aabb = scaled.compute_local_aabb();
+        assert_relative_eq!(aabb.maxs, Point::from(radii), epsilon = 1.0e-5);
+    }
+
+    #[test]
+    fn scaled_mass_properties() {
+        let scale = Vector::repeat(2.0) - Vector::x() * 3.0;
+        let cuboid = Scaled::new(Cuboid::new(Vector::repeat(1.0)), scale).unwrap();
+        let mprops = cuboid.mass_properties(2.0);
+        let expected = Cuboid::new(scale.abs()).mass_properties(2.0);
+        assert_relative_eq!(mprops.mass(), expected.mass(), epsilon = 1.0e-4);
+        assert_relative_eq!(mprops.local_com, Point::origin(), epsilon = 1.0e-4);
+        #[cfg(feature = "dim2")]
+        assert_relative_eq!(
+            mprops.principal_inertia(),
+            expected.principal_inertia(),
+            epsilon = 1.0e-3
+        );
+        #[cfg(feature = "dim3")]
+        assert_relative_eq!(
+            mprops.reconstruct_inertia_matrix(),
+            expected.reconstruct_inertia_matrix(),
+            epsilon = 1.0e-3
+        );
+
+        // Mass properties of curved shapes are exact in 3D, and approximated in 2D.
+        #[cfg(feature = "dim2")]
+        let radii = Vector::new(2.0, 0.5);
+        #[cfg(feature = "dim3")]
+        let radii = Vector::new(2.0, 0.5, 1.5);
+        let ball = Scaled::new(Ball::new(1.0), radii).unwrap();
+        assert_relative_eq!(
+            ball.mass_properties(2.0).mass(),
+            MassProperties::from_ellipsoid(2.0, radii).mass(),
+            max_relative = 1.0e-2
+        );
+        #[cfg(feature = "dim3")]
+        assert_relative_eq!(
+            ball.mass_properties(2.0),
+            MassProperties::from_ellipsoid(2.0, radii),
+            epsilon = 1.0e-4
+        );
+    }
+}
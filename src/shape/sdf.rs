@@ -0,0 +1,398 @@
+use crate::bounding_volume::Aabb;
+use crate::math::{Point, Real, Vector, DIM};
+use alloc::vec::Vec;
+use na::Unit;
+
+#[cfg(not(feature = "std"))]
+use na::ComplexField;
+
+/// A shape described by signed distances sampled on a regular grid.
+///
+/// The samples are located at `origin + key * cell_size` where each component of `key` ranges
+/// from `0` to `dimensions - 1`. The distance at any point of the grid’s domain is obtained by
+/// bilinear (in 2D) or trilinear (in 3D) interpolation of the samples of the cell containing it.
+///
+/// The shape is the set of points of the grid’s domain with a negative (or zero) interpolated
+/// distance. Anything outside the grid’s domain is considered outside the shape. Because the
+/// distance field is only known at the samples, point projections and ray-casts on this shape are
+/// approximate: their accuracy depends on the grid resolution and on how closely the samples
+/// match an actual distance field (in particular, distances should not vary faster than the
+/// distance to the surface).
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct Sdf {
+    origin: Point<Real>,
+    cell_size: Vector<Real>,
+    dimensions: Vector<u32>,
+    // The samples, with `x` as the fastest-varying coordinate.
+    values: Vec<Real>,
+}
+
+impl Sdf {
+    /// Initializes a signed distance field from its samples.
+    ///
+    /// The sample with the grid coordinates `key` is located at `origin + key * cell_size` and is
+    /// read from `values[key.x + key.y * dimensions.x (+ key.z * dimensions.x * dimensions.y)]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dimensions` is smaller than `2` along any axis, if `cell_size` isn’t positive,
+    /// or if the number of values doesn’t match `dimensions`.
+    pub fn new(
+        origin: Point<Real>,
+        cell_size: Vector<Real>,
+        dimensions: Vector<u32>,
+        values: Vec<Real>,
+    ) -> Self {
+        assert!(
+            dimensions.iter().all(|d| *d >= 2),
+            "A signed distance field needs at least two samples along each axis."
+        );
+        assert!(
+            cell_size.iter().all(|s| *s > 0.0),
+            "The cell size of a signed distance field must be positive."
+        );
+        assert_eq!(
+            values.len(),
+            dimensions.iter().map(|d| *d as usize).product::<usize>(),
+            "The number of samples doesn’t match the signed distance field dimensions."
+        );
+
+        Self {
+            origin,
+            cell_size,
+            dimensions,
+            values,
+        }
+    }
+
+    /// Initializes a signed distance field by sampling the given function at each grid point.
+    ///
+    /// See [`Self::new`] for details on the location of the samples.
+    pub fn from_fn(
+        origin: Point<Real>,
+        cell_size: Vector<Real>,
+        dimensions: Vector<u32>,
+        mut distance: impl FnMut(Point<Real>) -> Real,
+    ) -> Self {
+        let len = dimensions.iter().map(|d| *d as usize).product::<usize>();
+        let mut values = Vec::with_capacity(len);
+
+        for i in 0..len {
+            let key = Self::key_from_index(dimensions, i);
+            values.push(distance(
+                origin + key.cast::<Real>().coords.component_mul(&cell_size),
+            ));
+        }
+
+        Self::new(origin, cell_size, dimensions, values)
+    }
+
+    /// The location of the sample with grid coordinates `[0, 0(, 0)]`.
+    pub fn origin(&self) -> Point<Real> {
+        self.origin
+    }
+
+    /// The distance between two consecutive samples along each axis.
+    pub fn cell_size(&self) -> Vector<Real> {
+        self.cell_size
+    }
+
+    /// The number of samples along each axis.
+    pub fn dimensions(&self) -> Vector<u32> {
+        self.dimensions
+    }
+
+    /// All the samples of this signed distance field.
+    ///
+    /// See [`Self::new`] for details on their layout.
+    pub fn values(&self) -> &[Real] {
+        &self.values
+    }
+
+    /// The signed distance sampled at the grid point with coordinates `key`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` is outside of the grid.
+    pub fn sample(&self, key: Point<u32>) -> Real {
+        self.values[self.linear_index(key)]
+    }
+
+    /// Sets the signed distance sampled at the grid point with coordinates `key`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` is outside of the grid.
+    pub fn set_sample(&mut self, key: Point<u32>, value: Real) {
+        let id = self.linear_index(key);
+        self.values[id] = value;
+    }
+
+    /// The location of the grid point with coordinates `key`.
+    pub fn sample_point(&self, key: Point<u32>) -> Point<Real> {
+        self.origin + key.cast::<Real>().coords.component_mul(&self.cell_size)
+    }
+
+    /// The domain covered by the grid samples.
+    pub fn domain(&self) -> Aabb {
+        let extents = (self.dimensions - Vector::repeat(1))
+            .cast::<Real>()
+            .component_mul(&self.cell_size);
+        Aabb::new(self.origin, self.origin + extents)
+    }
+
+    /// Scales this signed distance field.
+    ///
+    /// Returns `None` if the scaling factor isn’t uniform and positive, since scaling the samples
+    /// non-uniformly wouldn’t preserve their meaning as distances.
+    pub fn scaled(mut self, scale: &Vector<Real>) -> Option<Self> {
+        if scale.x <= 0.0 || scale.iter().any(|s| *s != scale.x) {
+            return None;
+        }
+
+        self.origin *= scale.x;
+        self.cell_size *= scale.x;
+        self.values.iter_mut().for_each(|v| *v *= scale.x);
+        Some(self)
+    }
+
+    /// The interpolated signed distance at the given point.
+    ///
+    /// Outside of the grid’s domain, this is approximated by the distance to the domain, plus the
+    /// interpolated distance at the closest point of the domain if it is positive.
+    pub fn distance_at_point(&self, pt: &Point<Real>) -> Real {
+        let clamped = self.clamp_to_domain(pt);
+        let (value, _) = self.interpolate(&clamped);
+
+        if clamped == *pt {
+            value
+        } else {
+            value.max(0.0) + na::distance(pt, &clamped)
+        }
+    }
+
+    /// The gradient of the interpolated signed distance at the given point.
+    ///
+    /// Points outside of the grid’s domain are projected on its boundary first.
+    pub fn gradient_at_point(&self, pt: &Point<Real>) -> Vector<Real> {
+        self.interpolate(&self.clamp_to_domain(pt)).1
+    }
+
+    /// The outward normal of the isosurfaces of this signed distance field at the given point.
+    ///
+    /// This is the normalized gradient at `pt`, or `None` if the gradient is zero.
+    pub fn normal_at_point(&self, pt: &Point<Real>) -> Option<Unit<Vector<Real>>> {
+        Unit::try_new(self.gradient_at_point(pt), crate::math::DEFAULT_EPSILON)
+    }
+
+    /// The point of the grid’s domain closest to `pt`.
+    pub(crate) fn clamp_to_domain(&self, pt: &Point<Real>) -> Point<Real> {
+        let domain = self.domain();
+        pt.coords
+            .sup(&domain.mins.coords)
+            .inf(&domain.maxs.coords)
+            .into()
+    }
+
+    fn linear_index(&self, key: Point<u32>) -> usize {
+        assert!(
+            key.coords
+                .iter()
+                .zip(self.dimensions.iter())
+                .all(|(k, d)| k < d),
+            "Signed distance field sample out of bounds."
+        );
+
+        let mut id = 0;
+        let mut stride = 1;
+
+        for k in 0..DIM {
+            id += key[k] as usize * stride;
+            stride *= self.dimensions[k] as usize;
+        }
+
+        id
+    }
+
+    fn key_from_index(dimensions: Vector<u32>, mut id: usize) -> Point<u32> {
+        let mut key = Point::origin();
+
+        for k in 0..DIM {
+            key[k] = (id % dimensions[k] as usize) as u32;
+            id /= dimensions[k] as usize;
+        }
+
+        key
+    }
+
+    /// The interpolated value and gradient at a point of the grid’s domain.
+    fn interpolate(&self, pt: &Point<Real>) -> (Real, Vector<Real>) {
+        let local = (pt - self.origin).component_div(&self.cell_size);
+        let mut cell = Point::<u32>::origin();
+        let mut t = Vector::zeros();
+
+        for k in 0..DIM {
+            let max_cell = self.dimensions[k] - 2;
+            let ik = local[k].floor().max(0.0).min(max_cell as Real);
+            cell[k] = ik as u32;
+            t[k] = local[k] - ik;
+        }
+
+        let mut value = 0.0;
+        let mut gradient = Vector::zeros();
+
+        // Iterate on the cell’s corners, each bit of `corner` selecting the upper sample along
+        // the corresponding axis.
+        for corner in 0..(1 << DIM) {
+            let mut key = cell;
+            let mut weights = Vector::zeros();
+            let mut signs = Vector::zeros();
+
+            for k in 0..DIM {
+                if corner & (1 << k) != 0 {
+                    key[k] += 1;
+                    weights[k] = t[k];
+                    signs[k] = 1.0;
+                } else {
+                    weights[k] = 1.0 - t[k];
+                    signs[k] = -1.0;
+                }
+            }
+
+            let sample = self.sample(key);
+            value += weights.product() * sample;
+
+            for k in 0..DIM {
+                let mut partial = signs[k] * sample;
+                for j in (0..DIM).filter(|j| *j != k) {
+                    partial *= weights[j];
+                }
+                gradient[k] += partial;
+            }
+        }
+
+        (value, gradient.component_div(&self.cell_size))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::math::{Isometry, Point, Real, Vector, DIM};
+    use crate::query::{
+        ContactManifold, DefaultQueryDispatcher, PersistentQueryDispatcher, PointQuery,
+        QueryDispatcher, Ray, RayCast,
+    };
+    use crate::shape::{Cuboid, Sdf};
+    use alloc::vec::Vec;
+    use approx::assert_relative_eq;
+
+    fn ball_sdf(radius: Real) -> Sdf {
+        Sdf::from_fn(
+            Point::from(Vector::repeat(-2.0)),
+            Vector::repeat(0.1),
+            Vector::repeat(41),
+            |pt| pt.coords.norm() - radius,
+        )
+    }
+
+    #[test]
+    fn sdf_interpolation() {
+        // Distances that are linear wrt. the coordinates are interpolated exactly.
+        let sdf = Sdf::from_fn(
+            Point::origin(),
+            Vector::repeat(0.5),
+            Vector::repeat(5),
+            |pt| pt.y - 1.2,
+        );
+        let pt = Point::from(Vector::repeat(0.73));
+        assert_relative_eq!(sdf.distance_at_point(&pt), 0.73 - 1.2, epsilon = 1.0e-5);
+        assert_relative_eq!(sdf.gradient_at_point(&pt), Vector::y(), epsilon = 1.0e-5);
+
+        let mut far = pt;
+        far.x = -3.0;
+        assert_relative_eq!(sdf.distance_at_point(&far), 3.0, epsilon = 1.0e-5);
+    }
+
+    #[test]
+    fn sdf_point_and_ray_queries() {
+        let sdf = ball_sdf(1.0);
+
+        let pt = Point::from(Vector::repeat(1.0));
+        let proj = sdf.project_local_point(&pt, true);
+        assert!(!proj.is_inside);
+        assert_relative_eq!(proj.point, pt / pt.coords.norm(), epsilon = 1.0e-2);
+        assert!(sdf.contains_local_point(&Point::from(Vector::repeat(0.2))));
+
+        let ray = Ray::new(Point::from(Vector::x() * -3.0), Vector::x());
+        let hit = sdf
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+            .unwrap();
+        assert_relative_eq!(hit.time_of_impact, 2.0, epsilon = 1.0e-2);
+        assert_relative_eq!(hit.normal, -Vector::x(), epsilon = 1.0e-1);
+
+        // From the inside, solid and non-solid.
+        let ray = Ray::new(Point::origin(), Vector::x());
+        let hit = sdf.cast_local_ray(&ray, Real::MAX, true).unwrap();
+        assert_eq!(hit, 0.0);
+        let hit = sdf
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, false)
+            .unwrap();
+        assert_relative_eq!(hit.time_of_impact, 1.0, epsilon = 1.0e-2);
+        assert_relative_eq!(hit.normal, -Vector::x(), epsilon = 1.0e-1);
+
+        let ray = Ray::new(Point::from(Vector::y() * -3.0), Vector::x());
+        assert!(sdf.cast_local_ray(&ray, Real::MAX, true).is_none());
+    }
+
+    #[test]
+    fn sdf_cuboid_contacts() {
+        // A cuboid slightly penetrating the flat ground.
+        let ground = Sdf::from_fn(
+            Point::from(Vector::repeat(-4.0)),
+            Vector::repeat(0.5),
+            Vector::repeat(17),
+            |pt| pt.y,
+        );
+        let cuboid = Cuboid::new(Vector::repeat(0.5));
+        #[cfg(feature = "dim2")]
+        let pos12 = Isometry::translation(0.3, 0.45);
+        #[cfg(feature = "dim3")]
+        let pos12 = Isometry::translation(0.3, 0.45, -0.2);
+
+        let contact = DefaultQueryDispatcher
+            .contact(&pos12, &ground, &cuboid, 0.0)
+            .unwrap()
+            .unwrap();
+        assert_relative_eq!(contact.dist, -0.05, epsilon = 1.0e-4);
+        assert_relative_eq!(contact.normal1.into_inner(), Vector::y(), epsilon = 1.0e-4);
+
+        let mut manifolds: Vec<ContactManifold<(), ()>> = Vec::new();
+        let mut workspace = None;
+        DefaultQueryDispatcher
+            .contact_manifolds(
+                &pos12,
+                &ground,
+                &cuboid,
+                0.0,
+                &mut manifolds,
+                &mut workspace,
+            )
+            .unwrap();
+        // One contact per vertex of the cuboid’s bottom face.
+        assert_eq!(manifolds.len(), 1);
+        assert_eq!(manifolds[0].points.len(), 1 << (DIM - 1));
+        for pt in &manifolds[0].points {
+            assert_relative_eq!(pt.dist, -0.05, epsilon = 1.0e-4);
+            assert_relative_eq!(pt.local_p1.y, 0.0, epsilon = 1.0e-4);
+        }
+
+        // Flipped.
+        let contact = DefaultQueryDispatcher
+            .contact(&pos12.inverse(), &cuboid, &ground, 0.0)
+            .unwrap()
+            .unwrap();
+        assert_relative_eq!(contact.dist, -0.05, epsilon = 1.0e-4);
+        assert_relative_eq!(contact.normal2.into_inner(), Vector::y(), epsilon = 1.0e-4);
+    }
+}
@@ -1,5 +1,5 @@
 #[cfg(feature = "alloc")]
-use alloc::{boxed::Box, vec::Vec};
+use alloc::boxed::Box;
 use core::fmt::Debug;
 
 use crate::bounding_volume::{Aabb, BoundingSphere, BoundingVolume};
@@ -8,24 +8,29 @@ use crate::math::{Isometry, Point, Real, Vector};
 #[cfg(not(feature = "alloc"))]
 use crate::num::Float;
 use crate::query::{PointQuery, RayCast};
+#[cfg(all(feature = "serde-serialize", feature = "alloc"))]
+use crate::shape::CustomShapeData;
 #[cfg(feature = "serde-serialize")]
 use crate::shape::SharedShape;
 #[cfg(feature = "alloc")]
-use crate::shape::{composite_shape::SimdCompositeShape, Compound, HeightField, Polyline, TriMesh};
 use crate::shape::{
-    Ball, Capsule, Cuboid, FeatureId, HalfSpace, PolygonalFeatureMap, RoundCuboid, RoundShape,
-    RoundTriangle, Segment, SupportMap, Triangle,
+    composite_shape::SimdCompositeShape, Compound, HeightField, Polyline, RoundHeightField,
+    RoundTriMesh, Sdf, SphereSet, SweptShape, TriMesh,
+};
+use crate::shape::{
+    Ball, Capsule, Cuboid, Ellipsoid, FeatureId, HalfSpace, PolygonalFeatureMap, RoundCuboid,
+    RoundShape, RoundTriangle, Scaled, Segment, SupportMap, TaperedCapsule, Triangle,
 };
 #[cfg(feature = "dim3")]
-use crate::shape::{Cone, Cylinder, RoundCone, RoundCylinder};
+use crate::shape::{Cone, Cylinder, RoundCone, RoundCylinder, Tetrahedron, Torus};
 
 #[cfg(feature = "dim3")]
 #[cfg(feature = "alloc")]
-use crate::shape::{ConvexPolyhedron, RoundConvexPolyhedron, Voxels};
+use crate::shape::{ConvexPolyhedron, RoundConvexPolyhedron, RoundPolyline, TetMesh, Voxels};
 
 #[cfg(feature = "dim2")]
 #[cfg(feature = "alloc")]
-use crate::shape::{ConvexPolygon, RoundConvexPolygon, Voxels};
+use crate::shape::{BezierSpline, ConvexPolygon, Polygon, RoundConvexPolygon, Voxels};
 use downcast_rs::{impl_downcast, DowncastSync};
 use na::{RealField, Unit};
 use num::Zero;
@@ -67,16 +72,10 @@ pub enum ShapeType {
     #[cfg(feature = "dim3")]
     /// A cone shape.
     Cone,
-    // /// A custom shape type.
-    // Custom(u8),
     /// A cuboid with rounded corners.
     RoundCuboid,
     /// A triangle with rounded corners.
     RoundTriangle,
-    // /// A triangle-mesh with rounded corners.
-    // RoundedTriMesh,
-    // /// An heightfield with rounded corners.
-    // RoundedHeightField,
     /// A cylinder with rounded corners.
     #[cfg(feature = "dim3")]
     RoundCylinder,
@@ -89,6 +88,38 @@ pub enum ShapeType {
     /// A convex polygon with rounded corners.
     #[cfg(feature = "dim2")]
     RoundConvexPolygon,
+    /// A shape defined by a grid of sampled signed distances.
+    Sdf,
+    /// An ellipsoid shape (or an ellipse in 2D).
+    Ellipsoid,
+    /// A torus shape.
+    #[cfg(feature = "dim3")]
+    Torus,
+    /// A simple polygon, possibly non-convex and with holes.
+    #[cfg(feature = "dim2")]
+    Polygon,
+    /// The convex hull of a shape at two different positions.
+    SweptShape,
+    /// A tetrahedron.
+    #[cfg(feature = "dim3")]
+    Tetrahedron,
+    /// A tetrahedral mesh.
+    #[cfg(feature = "dim3")]
+    TetMesh,
+    /// A set of balls with individual radii.
+    SphereSet,
+    /// A triangle mesh with rounded corners.
+    RoundTriMesh,
+    /// A heightfield with rounded corners.
+    RoundHeightField,
+    /// A chain of cubic Bézier curves.
+    #[cfg(feature = "dim2")]
+    BezierSpline,
+    /// A capsule with different radii at its two endpoints.
+    TaperedCapsule,
+    /// A polyline with rounded corners, i.e., a chain of capsules.
+    #[cfg(feature = "dim3")]
+    RoundPolyline,
     /// A custom user-defined shape.
     Custom,
 }
@@ -141,10 +172,6 @@ pub enum TypedShape<'a> {
     RoundCuboid(&'a RoundCuboid),
     /// A triangle with rounded corners.
     RoundTriangle(&'a RoundTriangle),
-    // /// A triangle-mesh with rounded corners.
-    // RoundedTriMesh,
-    // /// An heightfield with rounded corners.
-    // RoundedHeightField,
     /// A cylinder with rounded corners.
     #[cfg(feature = "dim3")]
     RoundCylinder(&'a RoundCylinder),
@@ -159,8 +186,57 @@ pub enum TypedShape<'a> {
     #[cfg(feature = "dim2")]
     #[cfg(feature = "alloc")]
     RoundConvexPolygon(&'a RoundConvexPolygon),
+    /// A shape defined by a grid of sampled signed distances.
+    #[cfg(feature = "alloc")]
+    Sdf(&'a Sdf),
+    /// An ellipsoid shape (or an ellipse in 2D).
+    Ellipsoid(&'a Ellipsoid),
+    /// A torus shape.
+    #[cfg(feature = "dim3")]
+    Torus(&'a Torus),
+    /// A simple polygon, possibly non-convex and with holes.
+    #[cfg(feature = "dim2")]
+    #[cfg(feature = "alloc")]
+    Polygon(&'a Polygon),
+    /// The convex hull of a shape at two different positions.
+    #[cfg(feature = "alloc")]
+    SweptShape(&'a SweptShape),
+    /// A tetrahedron.
+    #[cfg(feature = "dim3")]
+    Tetrahedron(&'a Tetrahedron),
+    /// A tetrahedral mesh.
+    #[cfg(feature = "dim3")]
+    #[cfg(feature = "alloc")]
+    TetMesh(&'a TetMesh),
+    /// A set of balls with individual radii.
+    #[cfg(feature = "alloc")]
+    SphereSet(&'a SphereSet),
+    /// A triangle mesh with rounded corners.
+    #[cfg(feature = "alloc")]
+    RoundTriMesh(&'a RoundTriMesh),
+    /// A heightfield with rounded corners.
+    #[cfg(feature = "alloc")]
+    RoundHeightField(&'a RoundHeightField),
+    /// A chain of cubic Bézier curves.
+    #[cfg(feature = "dim2")]
+    #[cfg(feature = "alloc")]
+    BezierSpline(&'a BezierSpline),
+    /// A capsule with different radii at its two endpoints.
+    TaperedCapsule(&'a TaperedCapsule),
+    /// A polyline with rounded corners, i.e., a chain of capsules.
+    #[cfg(feature = "dim3")]
+    #[cfg(feature = "alloc")]
+    RoundPolyline(&'a RoundPolyline),
     /// A custom user-defined shape.
-    #[cfg_attr(feature = "serde-serialize", serde(skip))]
+    ///
+    /// It can only be serialized if its type is registered in the global
+    /// [`CustomShapeRegistry`](crate::shape::CustomShapeRegistry), which requires the `std`
+    /// feature.
+    #[cfg_attr(
+        all(feature = "serde-serialize", feature = "alloc"),
+        serde(serialize_with = "crate::shape::custom_shape_registry::serialize_custom_shape")
+    )]
+    #[cfg_attr(all(feature = "serde-serialize", not(feature = "alloc")), serde(skip))]
     Custom(&'a dyn Shape),
 }
 impl Debug for TypedShape<'_> {
@@ -208,6 +284,34 @@ impl Debug for TypedShape<'_> {
             Self::RoundConvexPolygon(arg0) => {
                 f.debug_tuple("RoundConvexPolygon").field(arg0).finish()
             }
+            #[cfg(feature = "alloc")]
+            Self::Sdf(arg0) => f.debug_tuple("Sdf").field(arg0).finish(),
+            Self::Ellipsoid(arg0) => f.debug_tuple("Ellipsoid").field(arg0).finish(),
+            #[cfg(feature = "dim3")]
+            Self::Torus(arg0) => f.debug_tuple("Torus").field(arg0).finish(),
+            #[cfg(feature = "dim2")]
+            #[cfg(feature = "alloc")]
+            Self::Polygon(arg0) => f.debug_tuple("Polygon").field(arg0).finish(),
+            #[cfg(feature = "alloc")]
+            Self::SweptShape(arg0) => f.debug_tuple("SweptShape").field(arg0).finish(),
+            #[cfg(feature = "dim3")]
+            Self::Tetrahedron(arg0) => f.debug_tuple("Tetrahedron").field(arg0).finish(),
+            #[cfg(feature = "dim3")]
+            #[cfg(feature = "alloc")]
+            Self::TetMesh(arg0) => f.debug_tuple("TetMesh").field(arg0).finish(),
+            #[cfg(feature = "alloc")]
+            Self::SphereSet(arg0) => f.debug_tuple("SphereSet").field(arg0).finish(),
+            #[cfg(feature = "alloc")]
+            Self::RoundTriMesh(arg0) => f.debug_tuple("RoundTriMesh").field(arg0).finish(),
+            #[cfg(feature = "alloc")]
+            Self::RoundHeightField(arg0) => f.debug_tuple("RoundHeightField").field(arg0).finish(),
+            #[cfg(feature = "dim2")]
+            #[cfg(feature = "alloc")]
+            Self::BezierSpline(arg0) => f.debug_tuple("BezierSpline").field(arg0).finish(),
+            Self::TaperedCapsule(arg0) => f.debug_tuple("TaperedCapsule").field(arg0).finish(),
+            #[cfg(feature = "dim3")]
+            #[cfg(feature = "alloc")]
+            Self::RoundPolyline(arg0) => f.debug_tuple("RoundPolyline").field(arg0).finish(),
             Self::Custom(_) => f.debug_tuple("Custom").finish(),
         }
     }
@@ -258,16 +362,10 @@ pub(crate) enum DeserializableTypedShape {
     #[cfg(feature = "dim3")]
     /// A cone shape.
     Cone(Cone),
-    // /// A custom shape type.
-    // Custom(u8),
     /// A cuboid with rounded corners.
     RoundCuboid(RoundCuboid),
     /// A triangle with rounded corners.
     RoundTriangle(RoundTriangle),
-    // /// A triangle-mesh with rounded corners.
-    // RoundedTriMesh,
-    // /// An heightfield with rounded corners.
-    // RoundedHeightField,
     /// A cylinder with rounded corners.
     #[cfg(feature = "dim3")]
     RoundCylinder(RoundCylinder),
@@ -282,14 +380,58 @@ pub(crate) enum DeserializableTypedShape {
     #[cfg(feature = "dim2")]
     #[cfg(feature = "alloc")]
     RoundConvexPolygon(RoundConvexPolygon),
+    /// A shape defined by a grid of sampled signed distances.
+    #[cfg(feature = "alloc")]
+    Sdf(Sdf),
+    /// An ellipsoid shape (or an ellipse in 2D).
+    Ellipsoid(Ellipsoid),
+    /// A torus shape.
+    #[cfg(feature = "dim3")]
+    Torus(Torus),
+    /// A simple polygon, possibly non-convex and with holes.
+    #[cfg(feature = "dim2")]
+    #[cfg(feature = "alloc")]
+    Polygon(Polygon),
+    /// The convex hull of a shape at two different positions.
+    #[cfg(feature = "alloc")]
+    SweptShape(SweptShape),
+    /// A tetrahedron.
+    #[cfg(feature = "dim3")]
+    Tetrahedron(Tetrahedron),
+    /// A tetrahedral mesh.
+    #[cfg(feature = "dim3")]
+    #[cfg(feature = "alloc")]
+    TetMesh(TetMesh),
+    /// A set of balls with individual radii.
+    #[cfg(feature = "alloc")]
+    SphereSet(SphereSet),
+    /// A triangle mesh with rounded corners.
+    #[cfg(feature = "alloc")]
+    RoundTriMesh(RoundTriMesh),
+    /// A heightfield with rounded corners.
+    #[cfg(feature = "alloc")]
+    RoundHeightField(RoundHeightField),
+    /// A chain of cubic Bézier curves.
+    #[cfg(feature = "dim2")]
+    #[cfg(feature = "alloc")]
+    BezierSpline(BezierSpline),
+    /// A capsule with different radii at its two endpoints.
+    TaperedCapsule(TaperedCapsule),
+    /// A polyline with rounded corners, i.e., a chain of capsules.
+    #[cfg(feature = "dim3")]
+    #[cfg(feature = "alloc")]
+    RoundPolyline(RoundPolyline),
     /// A custom user-defined shape.
-    #[allow(dead_code)]
-    Custom,
+    #[cfg(feature = "alloc")]
+    Custom(CustomShapeData),
 }
 
 #[cfg(feature = "serde-serialize")]
 impl DeserializableTypedShape {
-    /// Converts `self` to a `SharedShape` if `self` isn't `Custom`.
+    /// Converts `self` to a `SharedShape`.
+    ///
+    /// Custom shapes are deserialized with the global [`CustomShapeRegistry`](crate::shape::CustomShapeRegistry).
+    /// Returns `None` if their type isn’t registered, or if the `std` feature isn’t enabled.
     pub fn into_shared_shape(self) -> Option<SharedShape> {
         match self {
             DeserializableTypedShape::Ball(s) => Some(SharedShape::new(s)),
@@ -330,7 +472,40 @@ impl DeserializableTypedShape {
             #[cfg(feature = "dim2")]
             #[cfg(feature = "alloc")]
             DeserializableTypedShape::RoundConvexPolygon(s) => Some(SharedShape::new(s)),
-            DeserializableTypedShape::Custom => None,
+            #[cfg(feature = "alloc")]
+            DeserializableTypedShape::Sdf(s) => Some(SharedShape::new(s)),
+            DeserializableTypedShape::Ellipsoid(s) => Some(SharedShape::new(s)),
+            #[cfg(feature = "dim3")]
+            DeserializableTypedShape::Torus(s) => Some(SharedShape::new(s)),
+            #[cfg(feature = "dim2")]
+            #[cfg(feature = "alloc")]
+            DeserializableTypedShape::Polygon(s) => Some(SharedShape::new(s)),
+            #[cfg(feature = "alloc")]
+            DeserializableTypedShape::SweptShape(s) => Some(SharedShape::new(s)),
+            #[cfg(feature = "dim3")]
+            DeserializableTypedShape::Tetrahedron(s) => Some(SharedShape::new(s)),
+            #[cfg(feature = "dim3")]
+            #[cfg(feature = "alloc")]
+            DeserializableTypedShape::TetMesh(s) => Some(SharedShape::new(s)),
+            #[cfg(feature = "alloc")]
+            DeserializableTypedShape::SphereSet(s) => Some(SharedShape::new(s)),
+            #[cfg(feature = "alloc")]
+            DeserializableTypedShape::RoundTriMesh(s) => Some(SharedShape::new(s)),
+            #[cfg(feature = "alloc")]
+            DeserializableTypedShape::RoundHeightField(s) => Some(SharedShape::new(s)),
+            #[cfg(feature = "dim2")]
+            #[cfg(feature = "alloc")]
+            DeserializableTypedShape::BezierSpline(s) => Some(SharedShape::new(s)),
+            DeserializableTypedShape::TaperedCapsule(s) => Some(SharedShape::new(s)),
+            #[cfg(feature = "dim3")]
+            #[cfg(feature = "alloc")]
+            DeserializableTypedShape::RoundPolyline(s) => Some(SharedShape::new(s)),
+            #[cfg(all(feature = "alloc", feature = "std"))]
+            DeserializableTypedShape::Custom(s) => {
+                crate::shape::CustomShapeRegistry::global()?.deserialize_shape(&s)
+            }
+            #[cfg(all(feature = "alloc", not(feature = "std")))]
+            DeserializableTypedShape::Custom(_) => None,
         }
     }
 }
@@ -496,6 +671,24 @@ impl dyn Shape {
         self.downcast_mut()
     }
 
+    /// Converts this abstract shape to a tapered capsule, if it is one.
+    pub fn as_tapered_capsule(&self) -> Option<&TaperedCapsule> {
+        self.downcast_ref()
+    }
+    /// Converts this abstract shape to a mutable tapered capsule, if it is one.
+    pub fn as_tapered_capsule_mut(&mut self) -> Option<&mut TaperedCapsule> {
+        self.downcast_mut()
+    }
+
+    /// Converts this abstract shape to an ellipsoid, if it is one.
+    pub fn as_ellipsoid(&self) -> Option<&Ellipsoid> {
+        self.downcast_ref()
+    }
+    /// Converts this abstract shape to a mutable ellipsoid, if it is one.
+    pub fn as_ellipsoid_mut(&mut self) -> Option<&mut Ellipsoid> {
+        self.downcast_mut()
+    }
+
     /// Converts this abstract shape to a triangle, if it is one.
     pub fn as_triangle(&self) -> Option<&Triangle> {
         self.downcast_ref()
@@ -516,6 +709,28 @@ impl dyn Shape {
         self.downcast_mut()
     }
 
+    /// Converts this abstract shape to a signed distance field, if it is one.
+    #[cfg(feature = "alloc")]
+    pub fn as_sdf(&self) -> Option<&Sdf> {
+        self.downcast_ref()
+    }
+    /// Converts this abstract shape to a mutable signed distance field, if it is one.
+    #[cfg(feature = "alloc")]
+    pub fn as_sdf_mut(&mut self) -> Option<&mut Sdf> {
+        self.downcast_mut()
+    }
+
+    /// Converts this abstract shape to a swept shape, if it is one.
+    #[cfg(feature = "alloc")]
+    pub fn as_swept_shape(&self) -> Option<&SweptShape> {
+        self.downcast_ref()
+    }
+    /// Converts this abstract shape to a mutable swept shape, if it is one.
+    #[cfg(feature = "alloc")]
+    pub fn as_swept_shape_mut(&mut self) -> Option<&mut SweptShape> {
+        self.downcast_mut()
+    }
+
     /// Converts this abstract shape to a compound shape, if it is one.
     #[cfg(feature = "alloc")]
     pub fn as_compound(&self) -> Option<&Compound> {
@@ -527,6 +742,17 @@ impl dyn Shape {
         self.downcast_mut()
     }
 
+    /// Converts this abstract shape to a sphere set, if it is one.
+    #[cfg(feature = "alloc")]
+    pub fn as_sphere_set(&self) -> Option<&SphereSet> {
+        self.downcast_ref()
+    }
+    /// Converts this abstract shape to a mutable sphere set, if it is one.
+    #[cfg(feature = "alloc")]
+    pub fn as_sphere_set_mut(&mut self) -> Option<&mut SphereSet> {
+        self.downcast_mut()
+    }
+
     /// Converts this abstract shape to a triangle mesh, if it is one.
     #[cfg(feature = "alloc")]
     pub fn as_trimesh(&self) -> Option<&TriMesh> {
@@ -560,6 +786,41 @@ impl dyn Shape {
         self.downcast_mut()
     }
 
+    /// Converts this abstract shape to a round triangle mesh, if it is one.
+    #[cfg(feature = "alloc")]
+    pub fn as_round_trimesh(&self) -> Option<&RoundTriMesh> {
+        self.downcast_ref()
+    }
+    /// Converts this abstract shape to a mutable round triangle mesh, if it is one.
+    #[cfg(feature = "alloc")]
+    pub fn as_round_trimesh_mut(&mut self) -> Option<&mut RoundTriMesh> {
+        self.downcast_mut()
+    }
+
+    /// Converts this abstract shape to a round polyline, if it is one.
+    #[cfg(feature = "dim3")]
+    #[cfg(feature = "alloc")]
+    pub fn as_round_polyline(&self) -> Option<&RoundPolyline> {
+        self.downcast_ref()
+    }
+    /// Converts this abstract shape to a mutable round polyline, if it is one.
+    #[cfg(feature = "dim3")]
+    #[cfg(feature = "alloc")]
+    pub fn as_round_polyline_mut(&mut self) -> Option<&mut RoundPolyline> {
+        self.downcast_mut()
+    }
+
+    /// Converts this abstract shape to a round heightfield, if it is one.
+    #[cfg(feature = "alloc")]
+    pub fn as_round_heightfield(&self) -> Option<&RoundHeightField> {
+        self.downcast_ref()
+    }
+    /// Converts this abstract shape to a mutable round heightfield, if it is one.
+    #[cfg(feature = "alloc")]
+    pub fn as_round_heightfield_mut(&mut self) -> Option<&mut RoundHeightField> {
+        self.downcast_mut()
+    }
+
     /// Converts this abstract shape to a round cuboid, if it is one.
     pub fn as_round_cuboid(&self) -> Option<&RoundCuboid> {
         self.downcast_ref()
@@ -591,6 +852,32 @@ impl dyn Shape {
         self.downcast_mut()
     }
 
+    /// Converts this abstract shape to a polygon, if it is one.
+    #[cfg(feature = "dim2")]
+    #[cfg(feature = "alloc")]
+    pub fn as_polygon(&self) -> Option<&Polygon> {
+        self.downcast_ref()
+    }
+    /// Converts this abstract shape to a mutable polygon, if it is one.
+    #[cfg(feature = "dim2")]
+    #[cfg(feature = "alloc")]
+    pub fn as_polygon_mut(&mut self) -> Option<&mut Polygon> {
+        self.downcast_mut()
+    }
+
+    /// Converts this abstract shape to a Bézier spline, if it is one.
+    #[cfg(feature = "dim2")]
+    #[cfg(feature = "alloc")]
+    pub fn as_bezier_spline(&self) -> Option<&BezierSpline> {
+        self.downcast_ref()
+    }
+    /// Converts this abstract shape to a mutable Bézier spline, if it is one.
+    #[cfg(feature = "dim2")]
+    #[cfg(feature = "alloc")]
+    pub fn as_bezier_spline_mut(&mut self) -> Option<&mut BezierSpline> {
+        self.downcast_mut()
+    }
+
     /// Converts this abstract shape to a round convex polygon, if it is one.
     #[cfg(feature = "dim2")]
     #[cfg(feature = "alloc")]
@@ -626,6 +913,41 @@ impl dyn Shape {
         self.downcast_mut()
     }
 
+    /// Converts this abstract shape to a torus, if it is one.
+    #[cfg(feature = "dim3")]
+    pub fn as_torus(&self) -> Option<&Torus> {
+        self.downcast_ref()
+    }
+    /// Converts this abstract shape to a mutable torus, if it is one.
+    #[cfg(feature = "dim3")]
+    pub fn as_torus_mut(&mut self) -> Option<&mut Torus> {
+        self.downcast_mut()
+    }
+
+    /// Converts this abstract shape to a tetrahedron, if it is one.
+    #[cfg(feature = "dim3")]
+    pub fn as_tetrahedron(&self) -> Option<&Tetrahedron> {
+        self.downcast_ref()
+    }
+    /// Converts this abstract shape to a mutable tetrahedron, if it is one.
+    #[cfg(feature = "dim3")]
+    pub fn as_tetrahedron_mut(&mut self) -> Option<&mut Tetrahedron> {
+        self.downcast_mut()
+    }
+
+    /// Converts this abstract shape to a tetrahedral mesh, if it is one.
+    #[cfg(feature = "dim3")]
+    #[cfg(feature = "alloc")]
+    pub fn as_tetmesh(&self) -> Option<&TetMesh> {
+        self.downcast_ref()
+    }
+    /// Converts this abstract shape to a mutable tetrahedral mesh, if it is one.
+    #[cfg(feature = "dim3")]
+    #[cfg(feature = "alloc")]
+    pub fn as_tetmesh_mut(&mut self) -> Option<&mut TetMesh> {
+        self.downcast_mut()
+    }
+
     /// Converts this abstract shape to a cone, if it is one.
     #[cfg(feature = "dim3")]
     pub fn as_cone(&self) -> Option<&Cone> {
@@ -857,15 +1179,16 @@ impl Shape for Capsule {
     }
 }
 
-impl Shape for Triangle {
+impl Shape for TaperedCapsule {
     #[cfg(feature = "alloc")]
     fn clone_dyn(&self) -> Box<dyn Shape> {
         Box::new(*self)
     }
 
     #[cfg(feature = "alloc")]
-    fn scale_dyn(&self, scale: &Vector<Real>, _num_subdivisions: u32) -> Option<Box<dyn Shape>> {
-        Some(Box::new(self.scaled(scale)))
+    fn scale_dyn(&self, scale: &Vector<Real>, num_subdivisions: u32) -> Option<Box<dyn Shape>> {
+        let scaled = self.scaled(scale, num_subdivisions)?;
+        Some(scaled.either::<_, _, Box<dyn Shape>>(|x| Box::new(x), |x| Box::new(x)))
     }
 
     fn compute_local_aabb(&self) -> Aabb {
@@ -880,11 +1203,14 @@ impl Shape for Triangle {
         self.aabb(position)
     }
 
-    fn mass_properties(&self, _density: Real) -> MassProperties {
-        #[cfg(feature = "dim2")]
-        return MassProperties::from_triangle(_density, &self.a, &self.b, &self.c);
-        #[cfg(feature = "dim3")]
-        return MassProperties::zero();
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        MassProperties::from_tapered_capsule(
+            density,
+            self.segment.a,
+            self.segment.b,
+            self.radius_a,
+            self.radius_b,
+        )
     }
 
     fn is_convex(&self) -> bool {
@@ -892,16 +1218,15 @@ impl Shape for Triangle {
     }
 
     fn shape_type(&self) -> ShapeType {
-        ShapeType::Triangle
+        ShapeType::TaperedCapsule
     }
 
     fn as_typed_shape(&self) -> TypedShape {
-        TypedShape::Triangle(self)
+        TypedShape::TaperedCapsule(self)
     }
 
     fn ccd_thickness(&self) -> Real {
-        // TODO: in 2D use the smallest height of the triangle.
-        0.0
+        self.radius_a.min(self.radius_b)
     }
 
     fn ccd_angular_thickness(&self) -> Real {
@@ -911,24 +1236,9 @@ impl Shape for Triangle {
     fn as_support_map(&self) -> Option<&dyn SupportMap> {
         Some(self as &dyn SupportMap)
     }
-
-    fn as_polygonal_feature_map(&self) -> Option<(&dyn PolygonalFeatureMap, Real)> {
-        Some((self as &dyn PolygonalFeatureMap, 0.0))
-    }
-
-    fn feature_normal_at_point(
-        &self,
-        _feature: FeatureId,
-        _point: &Point<Real>,
-    ) -> Option<Unit<Vector<Real>>> {
-        #[cfg(feature = "dim2")]
-        return None;
-        #[cfg(feature = "dim3")]
-        return self.feature_normal(_feature);
-    }
 }
 
-impl Shape for Segment {
+impl Shape for Ellipsoid {
     #[cfg(feature = "alloc")]
     fn clone_dyn(&self) -> Box<dyn Shape> {
         Box::new(*self)
@@ -936,7 +1246,7 @@ impl Shape for Segment {
 
     #[cfg(feature = "alloc")]
     fn scale_dyn(&self, scale: &Vector<Real>, _num_subdivisions: u32) -> Option<Box<dyn Shape>> {
-        Some(Box::new(self.scaled(scale)))
+        Some(Box::new(self.scaled(scale)?))
     }
 
     fn compute_local_aabb(&self) -> Aabb {
@@ -951,75 +1261,56 @@ impl Shape for Segment {
         self.aabb(position)
     }
 
-    fn mass_properties(&self, _density: Real) -> MassProperties {
-        MassProperties::zero()
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        MassProperties::from_ellipsoid(density, self.radii)
     }
 
     fn is_convex(&self) -> bool {
         true
     }
 
-    fn ccd_thickness(&self) -> Real {
-        0.0
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::Ellipsoid
     }
 
-    fn ccd_angular_thickness(&self) -> Real {
-        Real::frac_pi_2()
+    fn as_typed_shape(&self) -> TypedShape {
+        TypedShape::Ellipsoid(self)
     }
 
-    fn shape_type(&self) -> ShapeType {
-        ShapeType::Segment
+    fn ccd_thickness(&self) -> Real {
+        self.radii.min()
     }
 
-    fn as_typed_shape(&self) -> TypedShape {
-        TypedShape::Segment(self)
+    fn ccd_angular_thickness(&self) -> Real {
+        Real::frac_pi_2()
     }
 
     fn as_support_map(&self) -> Option<&dyn SupportMap> {
         Some(self as &dyn SupportMap)
     }
 
-    fn as_polygonal_feature_map(&self) -> Option<(&dyn PolygonalFeatureMap, Real)> {
-        Some((self as &dyn PolygonalFeatureMap, 0.0))
-    }
-
     fn feature_normal_at_point(
         &self,
-        feature: FeatureId,
-        _point: &Point<Real>,
+        _: FeatureId,
+        point: &Point<Real>,
     ) -> Option<Unit<Vector<Real>>> {
-        self.feature_normal(feature)
+        self.normal_at_point(point)
     }
 }
 
-#[cfg(feature = "alloc")]
-impl Shape for Compound {
+impl Shape for Triangle {
+    #[cfg(feature = "alloc")]
     fn clone_dyn(&self) -> Box<dyn Shape> {
-        Box::new(self.clone())
+        Box::new(*self)
     }
 
-    fn scale_dyn(&self, scale: &Vector<Real>, num_subdivisions: u32) -> Option<Box<dyn Shape>> {
-        use super::SharedShape;
-
-        let scaled: Vec<_> = self
-            .shapes()
-            .iter()
-            .map(|(pos, shape)| {
-                let scaled_shape = shape.scale_dyn(scale, num_subdivisions)?;
-                Some((
-                    Isometry::from_parts(
-                        (pos.translation.vector.component_mul(scale)).into(),
-                        pos.rotation,
-                    ),
-                    SharedShape(scaled_shape.into()),
-                ))
-            })
-            .collect::<Option<Vec<_>>>()?;
-        Some(Box::new(Compound::new(scaled)))
+    #[cfg(feature = "alloc")]
+    fn scale_dyn(&self, scale: &Vector<Real>, _num_subdivisions: u32) -> Option<Box<dyn Shape>> {
+        Some(Box::new(self.scaled(scale)))
     }
 
     fn compute_local_aabb(&self) -> Aabb {
-        *self.local_aabb()
+        self.local_aabb()
     }
 
     fn compute_local_bounding_sphere(&self) -> BoundingSphere {
@@ -1027,18 +1318,152 @@ impl Shape for Compound {
     }
 
     fn compute_aabb(&self, position: &Isometry<Real>) -> Aabb {
-        self.local_aabb().transform_by(position)
+        self.aabb(position)
     }
 
-    fn mass_properties(&self, density: Real) -> MassProperties {
-        MassProperties::from_compound(density, self.shapes())
+    fn mass_properties(&self, _density: Real) -> MassProperties {
+        #[cfg(feature = "dim2")]
+        return MassProperties::from_triangle(_density, &self.a, &self.b, &self.c);
+        #[cfg(feature = "dim3")]
+        return MassProperties::zero();
     }
 
-    fn shape_type(&self) -> ShapeType {
-        ShapeType::Compound
+    fn is_convex(&self) -> bool {
+        true
     }
 
-    fn as_typed_shape(&self) -> TypedShape {
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::Triangle
+    }
+
+    fn as_typed_shape(&self) -> TypedShape {
+        TypedShape::Triangle(self)
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        // TODO: in 2D use the smallest height of the triangle.
+        0.0
+    }
+
+    fn ccd_angular_thickness(&self) -> Real {
+        Real::frac_pi_2()
+    }
+
+    fn as_support_map(&self) -> Option<&dyn SupportMap> {
+        Some(self as &dyn SupportMap)
+    }
+
+    fn as_polygonal_feature_map(&self) -> Option<(&dyn PolygonalFeatureMap, Real)> {
+        Some((self as &dyn PolygonalFeatureMap, 0.0))
+    }
+
+    fn feature_normal_at_point(
+        &self,
+        _feature: FeatureId,
+        _point: &Point<Real>,
+    ) -> Option<Unit<Vector<Real>>> {
+        #[cfg(feature = "dim2")]
+        return None;
+        #[cfg(feature = "dim3")]
+        return self.feature_normal(_feature);
+    }
+}
+
+impl Shape for Segment {
+    #[cfg(feature = "alloc")]
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(*self)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn scale_dyn(&self, scale: &Vector<Real>, _num_subdivisions: u32) -> Option<Box<dyn Shape>> {
+        Some(Box::new(self.scaled(scale)))
+    }
+
+    fn compute_local_aabb(&self) -> Aabb {
+        self.local_aabb()
+    }
+
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere {
+        self.local_bounding_sphere()
+    }
+
+    fn compute_aabb(&self, position: &Isometry<Real>) -> Aabb {
+        self.aabb(position)
+    }
+
+    fn mass_properties(&self, _density: Real) -> MassProperties {
+        MassProperties::zero()
+    }
+
+    fn is_convex(&self) -> bool {
+        true
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        0.0
+    }
+
+    fn ccd_angular_thickness(&self) -> Real {
+        Real::frac_pi_2()
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::Segment
+    }
+
+    fn as_typed_shape(&self) -> TypedShape {
+        TypedShape::Segment(self)
+    }
+
+    fn as_support_map(&self) -> Option<&dyn SupportMap> {
+        Some(self as &dyn SupportMap)
+    }
+
+    fn as_polygonal_feature_map(&self) -> Option<(&dyn PolygonalFeatureMap, Real)> {
+        Some((self as &dyn PolygonalFeatureMap, 0.0))
+    }
+
+    fn feature_normal_at_point(
+        &self,
+        feature: FeatureId,
+        _point: &Point<Real>,
+    ) -> Option<Unit<Vector<Real>>> {
+        self.feature_normal(feature)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Shape for Compound {
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    fn scale_dyn(&self, scale: &Vector<Real>, num_subdivisions: u32) -> Option<Box<dyn Shape>> {
+        Some(Box::new(self.scaled(scale, num_subdivisions)?))
+    }
+
+    fn compute_local_aabb(&self) -> Aabb {
+        *self.local_aabb()
+    }
+
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere {
+        self.local_bounding_sphere()
+    }
+
+    fn compute_aabb(&self, position: &Isometry<Real>) -> Aabb {
+        self.local_aabb().transform_by(position)
+    }
+
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        MassProperties::from_compound(density, self.shapes())
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::Compound
+    }
+
+    fn as_typed_shape(&self) -> TypedShape {
         TypedShape::Compound(self)
     }
 
@@ -1060,6 +1485,54 @@ impl Shape for Compound {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl Shape for SphereSet {
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    fn scale_dyn(&self, scale: &Vector<Real>, num_subdivisions: u32) -> Option<Box<dyn Shape>> {
+        let scaled = self.clone().scaled(scale, num_subdivisions)?;
+        Some(scaled.either::<_, _, Box<dyn Shape>>(|x| Box::new(x), |x| Box::new(x)))
+    }
+
+    fn compute_local_aabb(&self) -> Aabb {
+        *self.local_aabb()
+    }
+
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere {
+        self.local_bounding_sphere()
+    }
+
+    fn compute_aabb(&self, position: &Isometry<Real>) -> Aabb {
+        self.aabb(position)
+    }
+
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        MassProperties::from_sphere_set(density, self.centers(), self.radii())
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::SphereSet
+    }
+
+    fn as_typed_shape(&self) -> TypedShape {
+        TypedShape::SphereSet(self)
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        self.radii().iter().fold(Real::MAX, |curr, r| curr.min(*r))
+    }
+
+    fn ccd_angular_thickness(&self) -> Real {
+        Real::pi()
+    }
+
+    fn as_composite_shape(&self) -> Option<&dyn SimdCompositeShape> {
+        Some(self as &dyn SimdCompositeShape)
+    }
+}
+
 #[cfg(feature = "alloc")]
 impl Shape for Polyline {
     fn clone_dyn(&self) -> Box<dyn Shape> {
@@ -1195,38 +1668,503 @@ impl Shape for HeightField {
         self.aabb(position)
     }
 
-    fn mass_properties(&self, _density: Real) -> MassProperties {
-        MassProperties::zero()
+    fn mass_properties(&self, _density: Real) -> MassProperties {
+        MassProperties::zero()
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::HeightField
+    }
+
+    fn as_typed_shape(&self) -> TypedShape {
+        TypedShape::HeightField(self)
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        0.0
+    }
+
+    fn ccd_angular_thickness(&self) -> Real {
+        // TODO: the value should depend on the angles between
+        // adjacent triangles of the heightfield.
+        Real::frac_pi_4()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Shape for RoundTriMesh {
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    fn scale_dyn(&self, scale: &Vector<Real>, _num_subdivisions: u32) -> Option<Box<dyn Shape>> {
+        Some(Box::new(self.clone().scaled(scale)))
+    }
+
+    fn compute_local_aabb(&self) -> Aabb {
+        *self.local_aabb()
+    }
+
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere {
+        self.local_bounding_sphere()
+    }
+
+    fn compute_aabb(&self, position: &Isometry<Real>) -> Aabb {
+        self.aabb(position)
+    }
+
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        self.mesh().mass_properties(density)
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::RoundTriMesh
+    }
+
+    fn as_typed_shape(&self) -> TypedShape {
+        TypedShape::RoundTriMesh(self)
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        self.border_radius()
+    }
+
+    fn ccd_angular_thickness(&self) -> Real {
+        self.mesh().ccd_angular_thickness()
+    }
+
+    fn as_composite_shape(&self) -> Option<&dyn SimdCompositeShape> {
+        Some(self as &dyn SimdCompositeShape)
+    }
+}
+
+#[cfg(feature = "dim3")]
+#[cfg(feature = "alloc")]
+impl Shape for RoundPolyline {
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    fn scale_dyn(&self, scale: &Vector<Real>, _num_subdivisions: u32) -> Option<Box<dyn Shape>> {
+        Some(Box::new(self.clone().scaled(scale)))
+    }
+
+    fn compute_local_aabb(&self) -> Aabb {
+        *self.local_aabb()
+    }
+
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere {
+        self.local_bounding_sphere()
+    }
+
+    fn compute_aabb(&self, position: &Isometry<Real>) -> Aabb {
+        self.aabb(position)
+    }
+
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        self.mass_properties(density)
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::RoundPolyline
+    }
+
+    fn as_typed_shape(&self) -> TypedShape {
+        TypedShape::RoundPolyline(self)
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        self.radius()
+    }
+
+    fn ccd_angular_thickness(&self) -> Real {
+        self.polyline().ccd_angular_thickness()
+    }
+
+    fn as_composite_shape(&self) -> Option<&dyn SimdCompositeShape> {
+        Some(self as &dyn SimdCompositeShape)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Shape for RoundHeightField {
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    fn scale_dyn(&self, scale: &Vector<Real>, _num_subdivisions: u32) -> Option<Box<dyn Shape>> {
+        Some(Box::new(RoundShape {
+            inner_shape: self.inner_shape.clone().scaled(scale),
+            border_radius: self.border_radius,
+        }))
+    }
+
+    fn compute_local_aabb(&self) -> Aabb {
+        self.inner_shape.local_aabb().loosened(self.border_radius)
+    }
+
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere {
+        self.inner_shape
+            .local_bounding_sphere()
+            .loosened(self.border_radius)
+    }
+
+    fn compute_aabb(&self, position: &Isometry<Real>) -> Aabb {
+        self.inner_shape.aabb(position).loosened(self.border_radius)
+    }
+
+    fn mass_properties(&self, _density: Real) -> MassProperties {
+        MassProperties::zero()
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::RoundHeightField
+    }
+
+    fn as_typed_shape(&self) -> TypedShape {
+        TypedShape::RoundHeightField(self)
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        self.border_radius
+    }
+
+    fn ccd_angular_thickness(&self) -> Real {
+        self.inner_shape.ccd_angular_thickness()
+    }
+}
+
+#[cfg(feature = "dim2")]
+#[cfg(feature = "alloc")]
+impl Shape for Polygon {
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    fn scale_dyn(&self, scale: &Vector<Real>, _num_subdivisions: u32) -> Option<Box<dyn Shape>> {
+        Some(Box::new(self.scaled(scale)?))
+    }
+
+    fn compute_local_aabb(&self) -> Aabb {
+        *self.local_aabb()
+    }
+
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere {
+        self.local_bounding_sphere()
+    }
+
+    fn compute_aabb(&self, position: &Isometry<Real>) -> Aabb {
+        self.aabb(position)
+    }
+
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        MassProperties::from_compound(density, self.convex_decomposition().shapes())
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::Polygon
+    }
+
+    fn as_typed_shape(&self) -> TypedShape {
+        TypedShape::Polygon(self)
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        self.convex_decomposition().ccd_thickness()
+    }
+
+    fn ccd_angular_thickness(&self) -> Real {
+        // TODO: the value should depend on the angles between
+        // adjacent segments of the polygon.
+        Real::frac_pi_4()
+    }
+
+    fn as_composite_shape(&self) -> Option<&dyn SimdCompositeShape> {
+        // Contacts and distances are computed with the convex decomposition.
+        Some(self.convex_decomposition() as &dyn SimdCompositeShape)
+    }
+
+    fn feature_normal_at_point(
+        &self,
+        feature: FeatureId,
+        _point: &Point<Real>,
+    ) -> Option<Unit<Vector<Real>>> {
+        match feature {
+            FeatureId::Face(i) if (i as usize) < self.boundary().num_segments() => {
+                self.boundary().segment(i).normal()
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "dim2")]
+#[cfg(feature = "alloc")]
+impl Shape for BezierSpline {
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    fn scale_dyn(&self, scale: &Vector<Real>, _num_subdivisions: u32) -> Option<Box<dyn Shape>> {
+        Some(Box::new(self.clone().scaled(scale)))
+    }
+
+    fn compute_local_aabb(&self) -> Aabb {
+        *self.local_aabb()
+    }
+
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere {
+        self.local_bounding_sphere()
+    }
+
+    fn compute_aabb(&self, position: &Isometry<Real>) -> Aabb {
+        self.aabb(position)
+    }
+
+    fn mass_properties(&self, _density: Real) -> MassProperties {
+        MassProperties::zero()
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::BezierSpline
+    }
+
+    fn as_typed_shape(&self) -> TypedShape {
+        TypedShape::BezierSpline(self)
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        0.0
+    }
+
+    fn ccd_angular_thickness(&self) -> Real {
+        // TODO: the value should depend on the curvature of the curve.
+        Real::frac_pi_4()
+    }
+
+    fn feature_normal_at_point(
+        &self,
+        feature: FeatureId,
+        point: &Point<Real>,
+    ) -> Option<Unit<Vector<Real>>> {
+        match feature {
+            FeatureId::Face(i) if (i as usize) < self.num_segments() => {
+                let (segment, t) = self.closest_parameter(point);
+                let tangent = self.tangent_at(segment, t);
+                Unit::try_new(
+                    Vector::new(-tangent.y, tangent.x),
+                    crate::math::DEFAULT_EPSILON,
+                )
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "dim2")]
+#[cfg(feature = "alloc")]
+impl Shape for ConvexPolygon {
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    fn scale_dyn(&self, scale: &Vector<Real>, _num_subdivisions: u32) -> Option<Box<dyn Shape>> {
+        Some(Box::new(self.clone().scaled(scale)?))
+    }
+
+    fn compute_local_aabb(&self) -> Aabb {
+        self.local_aabb()
+    }
+
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere {
+        self.local_bounding_sphere()
+    }
+
+    fn compute_aabb(&self, position: &Isometry<Real>) -> Aabb {
+        self.aabb(position)
+    }
+
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        MassProperties::from_convex_polygon(density, self.points())
+    }
+
+    fn is_convex(&self) -> bool {
+        true
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::ConvexPolygon
+    }
+
+    fn as_typed_shape(&self) -> TypedShape {
+        TypedShape::ConvexPolygon(self)
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        // TODO: we should use the OBB instead.
+        self.compute_local_aabb().half_extents().min()
+    }
+
+    fn ccd_angular_thickness(&self) -> Real {
+        // TODO: the value should depend on the angles between
+        // adjacent segments of the convex polygon.
+        Real::frac_pi_4()
+    }
+
+    fn as_support_map(&self) -> Option<&dyn SupportMap> {
+        Some(self as &dyn SupportMap)
+    }
+
+    fn as_polygonal_feature_map(&self) -> Option<(&dyn PolygonalFeatureMap, Real)> {
+        Some((self as &dyn PolygonalFeatureMap, 0.0))
+    }
+
+    fn feature_normal_at_point(
+        &self,
+        feature: FeatureId,
+        _point: &Point<Real>,
+    ) -> Option<Unit<Vector<Real>>> {
+        self.feature_normal(feature)
+    }
+}
+
+#[cfg(feature = "dim3")]
+#[cfg(feature = "alloc")]
+impl Shape for ConvexPolyhedron {
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    fn scale_dyn(&self, scale: &Vector<Real>, _num_subdivisions: u32) -> Option<Box<dyn Shape>> {
+        Some(Box::new(self.clone().scaled(scale)?))
+    }
+
+    fn compute_local_aabb(&self) -> Aabb {
+        self.local_aabb()
+    }
+
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere {
+        self.local_bounding_sphere()
+    }
+
+    fn compute_aabb(&self, position: &Isometry<Real>) -> Aabb {
+        self.aabb(position)
+    }
+
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        let (vertices, indices) = self.to_trimesh();
+        MassProperties::from_convex_polyhedron(density, &vertices, &indices)
+    }
+
+    fn is_convex(&self) -> bool {
+        true
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::ConvexPolyhedron
+    }
+
+    fn as_typed_shape(&self) -> TypedShape {
+        TypedShape::ConvexPolyhedron(self)
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        // TODO: we should use the OBB instead.
+        self.compute_local_aabb().half_extents().min()
+    }
+
+    fn ccd_angular_thickness(&self) -> Real {
+        // TODO: the value should depend on the angles between
+        // adjacent segments of the convex polyhedron.
+        Real::frac_pi_4()
+    }
+
+    fn as_support_map(&self) -> Option<&dyn SupportMap> {
+        Some(self as &dyn SupportMap)
+    }
+
+    fn as_polygonal_feature_map(&self) -> Option<(&dyn PolygonalFeatureMap, Real)> {
+        Some((self as &dyn PolygonalFeatureMap, 0.0))
+    }
+
+    fn feature_normal_at_point(
+        &self,
+        feature: FeatureId,
+        _point: &Point<Real>,
+    ) -> Option<Unit<Vector<Real>>> {
+        self.feature_normal(feature)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl Shape for Cylinder {
+    #[cfg(feature = "alloc")]
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(*self)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn scale_dyn(&self, scale: &Vector<Real>, num_subdivisions: u32) -> Option<Box<dyn Shape>> {
+        let scaled = self.scaled(scale, num_subdivisions)?;
+        Some(scaled.either::<_, _, Box<dyn Shape>>(|x| Box::new(x), |x| Box::new(x)))
+    }
+
+    fn compute_local_aabb(&self) -> Aabb {
+        self.local_aabb()
+    }
+
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere {
+        self.local_bounding_sphere()
+    }
+
+    fn compute_aabb(&self, position: &Isometry<Real>) -> Aabb {
+        self.aabb(position)
+    }
+
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        MassProperties::from_cylinder(density, self.half_height, self.radius)
+    }
+
+    fn is_convex(&self) -> bool {
+        true
     }
 
     fn shape_type(&self) -> ShapeType {
-        ShapeType::HeightField
+        ShapeType::Cylinder
     }
 
     fn as_typed_shape(&self) -> TypedShape {
-        TypedShape::HeightField(self)
+        TypedShape::Cylinder(self)
     }
 
     fn ccd_thickness(&self) -> Real {
-        0.0
+        self.radius
     }
 
     fn ccd_angular_thickness(&self) -> Real {
-        // TODO: the value should depend on the angles between
-        // adjacent triangles of the heightfield.
-        Real::frac_pi_4()
+        Real::frac_pi_2()
+    }
+
+    fn as_support_map(&self) -> Option<&dyn SupportMap> {
+        Some(self as &dyn SupportMap)
+    }
+
+    fn as_polygonal_feature_map(&self) -> Option<(&dyn PolygonalFeatureMap, Real)> {
+        Some((self as &dyn PolygonalFeatureMap, 0.0))
     }
 }
 
-#[cfg(feature = "dim2")]
-#[cfg(feature = "alloc")]
-impl Shape for ConvexPolygon {
+#[cfg(feature = "dim3")]
+impl Shape for Torus {
+    #[cfg(feature = "alloc")]
     fn clone_dyn(&self) -> Box<dyn Shape> {
-        Box::new(self.clone())
+        Box::new(*self)
     }
 
-    fn scale_dyn(&self, scale: &Vector<Real>, _num_subdivisions: u32) -> Option<Box<dyn Shape>> {
-        Some(Box::new(self.clone().scaled(scale)?))
+    #[cfg(feature = "alloc")]
+    fn scale_dyn(&self, scale: &Vector<Real>, num_subdivisions: u32) -> Option<Box<dyn Shape>> {
+        let scaled = self.scaled(scale, num_subdivisions)?;
+        Some(scaled.either::<_, _, Box<dyn Shape>>(|x| Box::new(x), |x| Box::new(x)))
     }
 
     fn compute_local_aabb(&self) -> Aabb {
@@ -1242,58 +2180,48 @@ impl Shape for ConvexPolygon {
     }
 
     fn mass_properties(&self, density: Real) -> MassProperties {
-        MassProperties::from_convex_polygon(density, self.points())
+        MassProperties::from_torus(density, self.major_radius, self.minor_radius)
     }
 
     fn is_convex(&self) -> bool {
-        true
+        false
     }
 
     fn shape_type(&self) -> ShapeType {
-        ShapeType::ConvexPolygon
+        ShapeType::Torus
     }
 
     fn as_typed_shape(&self) -> TypedShape {
-        TypedShape::ConvexPolygon(self)
+        TypedShape::Torus(self)
     }
 
     fn ccd_thickness(&self) -> Real {
-        // TODO: we should use the OBB instead.
-        self.compute_local_aabb().half_extents().min()
+        self.minor_radius
     }
 
     fn ccd_angular_thickness(&self) -> Real {
-        // TODO: the value should depend on the angles between
-        // adjacent segments of the convex polygon.
-        Real::frac_pi_4()
-    }
-
-    fn as_support_map(&self) -> Option<&dyn SupportMap> {
-        Some(self as &dyn SupportMap)
-    }
-
-    fn as_polygonal_feature_map(&self) -> Option<(&dyn PolygonalFeatureMap, Real)> {
-        Some((self as &dyn PolygonalFeatureMap, 0.0))
+        Real::frac_pi_2()
     }
 
     fn feature_normal_at_point(
         &self,
-        feature: FeatureId,
-        _point: &Point<Real>,
+        _: FeatureId,
+        point: &Point<Real>,
     ) -> Option<Unit<Vector<Real>>> {
-        self.feature_normal(feature)
+        self.normal_at_point(point)
     }
 }
 
 #[cfg(feature = "dim3")]
-#[cfg(feature = "alloc")]
-impl Shape for ConvexPolyhedron {
+impl Shape for Tetrahedron {
+    #[cfg(feature = "alloc")]
     fn clone_dyn(&self) -> Box<dyn Shape> {
-        Box::new(self.clone())
+        Box::new(*self)
     }
 
+    #[cfg(feature = "alloc")]
     fn scale_dyn(&self, scale: &Vector<Real>, _num_subdivisions: u32) -> Option<Box<dyn Shape>> {
-        Some(Box::new(self.clone().scaled(scale)?))
+        Some(Box::new(self.scaled(scale)))
     }
 
     fn compute_local_aabb(&self) -> Aabb {
@@ -1309,8 +2237,7 @@ impl Shape for ConvexPolyhedron {
     }
 
     fn mass_properties(&self, density: Real) -> MassProperties {
-        let (vertices, indices) = self.to_trimesh();
-        MassProperties::from_convex_polyhedron(density, &vertices, &indices)
+        MassProperties::from_tetrahedron(density, &self.a, &self.b, &self.c, &self.d)
     }
 
     fn is_convex(&self) -> bool {
@@ -1318,22 +2245,20 @@ impl Shape for ConvexPolyhedron {
     }
 
     fn shape_type(&self) -> ShapeType {
-        ShapeType::ConvexPolyhedron
+        ShapeType::Tetrahedron
     }
 
     fn as_typed_shape(&self) -> TypedShape {
-        TypedShape::ConvexPolyhedron(self)
+        TypedShape::Tetrahedron(self)
     }
 
     fn ccd_thickness(&self) -> Real {
-        // TODO: we should use the OBB instead.
-        self.compute_local_aabb().half_extents().min()
+        // TODO: use the smallest height of the tetrahedron.
+        0.0
     }
 
     fn ccd_angular_thickness(&self) -> Real {
-        // TODO: the value should depend on the angles between
-        // adjacent segments of the convex polyhedron.
-        Real::frac_pi_4()
+        Real::frac_pi_2()
     }
 
     fn as_support_map(&self) -> Option<&dyn SupportMap> {
@@ -1354,20 +2279,18 @@ impl Shape for ConvexPolyhedron {
 }
 
 #[cfg(feature = "dim3")]
-impl Shape for Cylinder {
-    #[cfg(feature = "alloc")]
+#[cfg(feature = "alloc")]
+impl Shape for TetMesh {
     fn clone_dyn(&self) -> Box<dyn Shape> {
-        Box::new(*self)
+        Box::new(self.clone())
     }
 
-    #[cfg(feature = "alloc")]
-    fn scale_dyn(&self, scale: &Vector<Real>, num_subdivisions: u32) -> Option<Box<dyn Shape>> {
-        let scaled = self.scaled(scale, num_subdivisions)?;
-        Some(scaled.either::<_, _, Box<dyn Shape>>(|x| Box::new(x), |x| Box::new(x)))
+    fn scale_dyn(&self, scale: &Vector<Real>, _num_subdivisions: u32) -> Option<Box<dyn Shape>> {
+        Some(Box::new(self.clone().scaled(scale)))
     }
 
     fn compute_local_aabb(&self) -> Aabb {
-        self.local_aabb()
+        *self.local_aabb()
     }
 
     fn compute_local_bounding_sphere(&self) -> BoundingSphere {
@@ -1379,35 +2302,27 @@ impl Shape for Cylinder {
     }
 
     fn mass_properties(&self, density: Real) -> MassProperties {
-        MassProperties::from_cylinder(density, self.half_height, self.radius)
-    }
-
-    fn is_convex(&self) -> bool {
-        true
+        MassProperties::from_tetmesh(density, self.vertices(), self.indices())
     }
 
     fn shape_type(&self) -> ShapeType {
-        ShapeType::Cylinder
+        ShapeType::TetMesh
     }
 
     fn as_typed_shape(&self) -> TypedShape {
-        TypedShape::Cylinder(self)
+        TypedShape::TetMesh(self)
     }
 
     fn ccd_thickness(&self) -> Real {
-        self.radius
+        0.0
     }
 
     fn ccd_angular_thickness(&self) -> Real {
-        Real::frac_pi_2()
-    }
-
-    fn as_support_map(&self) -> Option<&dyn SupportMap> {
-        Some(self as &dyn SupportMap)
+        Real::frac_pi_4()
     }
 
-    fn as_polygonal_feature_map(&self) -> Option<(&dyn PolygonalFeatureMap, Real)> {
-        Some((self as &dyn PolygonalFeatureMap, 0.0))
+    fn as_composite_shape(&self) -> Option<&dyn SimdCompositeShape> {
+        Some(self as &dyn SimdCompositeShape)
     }
 }
 
@@ -1537,12 +2452,12 @@ impl Shape for Voxels {
         Box::new(self.clone())
     }
 
-    fn scale_dyn(&self, _scale: &Vector<Real>, _num_subdivisions: u32) -> Option<Box<dyn Shape>> {
-        todo!()
+    fn scale_dyn(&self, scale: &Vector<Real>, _num_subdivisions: u32) -> Option<Box<dyn Shape>> {
+        Some(Box::new(self.clone().scaled(scale)?))
     }
 
-    fn mass_properties(&self, _density: Real) -> MassProperties {
-        MassProperties::default()
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        MassProperties::from_voxels(density, self)
     }
 
     fn shape_type(&self) -> ShapeType {
@@ -1562,6 +2477,176 @@ impl Shape for Voxels {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl Shape for Sdf {
+    fn compute_local_aabb(&self) -> Aabb {
+        self.local_aabb()
+    }
+
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere {
+        self.local_bounding_sphere()
+    }
+
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    fn scale_dyn(&self, scale: &Vector<Real>, _num_subdivisions: u32) -> Option<Box<dyn Shape>> {
+        Some(Box::new(self.clone().scaled(scale)?))
+    }
+
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        MassProperties::from_sdf(density, self)
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::Sdf
+    }
+
+    fn as_typed_shape(&self) -> TypedShape {
+        TypedShape::Sdf(self)
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        self.cell_size().min()
+    }
+
+    fn ccd_angular_thickness(&self) -> Real {
+        Real::frac_pi_4()
+    }
+
+    fn feature_normal_at_point(
+        &self,
+        _feature: FeatureId,
+        point: &Point<Real>,
+    ) -> Option<Unit<Vector<Real>>> {
+        self.normal_at_point(point)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Shape for SweptShape {
+    fn compute_local_aabb(&self) -> Aabb {
+        self.shape()
+            .compute_swept_aabb(self.start_pos(), self.end_pos())
+    }
+
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere {
+        self.shape()
+            .compute_bounding_sphere(self.start_pos())
+            .merged(&self.shape().compute_bounding_sphere(self.end_pos()))
+    }
+
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    fn scale_dyn(&self, scale: &Vector<Real>, num_subdivisions: u32) -> Option<Box<dyn Shape>> {
+        Some(Box::new(self.scaled(scale, num_subdivisions)?))
+    }
+
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        // The exact convex hull isn’t known, so it is approximated from sampled support points.
+        let points = self.sample_support_points();
+        #[cfg(feature = "dim2")]
+        let hull = ConvexPolygon::from_convex_hull(&points);
+        #[cfg(feature = "dim3")]
+        let hull = ConvexPolyhedron::from_convex_hull(&points);
+        hull.map(|hull| hull.mass_properties(density))
+            .unwrap_or_default()
+    }
+
+    fn is_convex(&self) -> bool {
+        true
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::SweptShape
+    }
+
+    fn as_typed_shape(&self) -> TypedShape {
+        TypedShape::SweptShape(self)
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        self.shape().ccd_thickness()
+    }
+
+    fn ccd_angular_thickness(&self) -> Real {
+        self.shape().ccd_angular_thickness()
+    }
+
+    fn as_support_map(&self) -> Option<&dyn SupportMap> {
+        Some(self as &dyn SupportMap)
+    }
+}
+
+#[cfg(any(feature = "dim3", feature = "alloc"))]
+impl<S: Shape + SupportMap + Clone> Shape for Scaled<S> {
+    fn compute_local_aabb(&self) -> Aabb {
+        self.local_aabb()
+    }
+
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere {
+        self.local_bounding_sphere()
+    }
+
+    fn compute_aabb(&self, position: &Isometry<Real>) -> Aabb {
+        self.aabb(position)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn clone_dyn(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn scale_dyn(&self, scale: &Vector<Real>, _num_subdivisions: u32) -> Option<Box<dyn Shape>> {
+        Some(Box::new(self.clone().scaled(scale)?))
+    }
+
+    fn mass_properties(&self, density: Real) -> MassProperties {
+        MassProperties::from_scaled(density, self)
+    }
+
+    fn is_convex(&self) -> bool {
+        self.inner_shape().is_convex()
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        // Scaled shapes are reported as custom shapes since their
+        // type depends on the type of the inner shape.
+        ShapeType::Custom
+    }
+
+    fn as_typed_shape(&self) -> TypedShape {
+        TypedShape::Custom(self)
+    }
+
+    fn ccd_thickness(&self) -> Real {
+        self.inner_shape().ccd_thickness() * self.scale().abs().min()
+    }
+
+    fn ccd_angular_thickness(&self) -> Real {
+        self.inner_shape().ccd_angular_thickness()
+    }
+
+    fn as_support_map(&self) -> Option<&dyn SupportMap> {
+        Some(self as &dyn SupportMap)
+    }
+
+    fn feature_normal_at_point(
+        &self,
+        feature: FeatureId,
+        point: &Point<Real>,
+    ) -> Option<Unit<Vector<Real>>> {
+        let normal = self
+            .inner_shape()
+            .feature_normal_at_point(feature, &self.unscale_point(point))?;
+        self.scale_normal(&normal)
+    }
+}
+
 macro_rules! impl_shape_for_round_shape(
     ($S: ty, $Tag: ident, $t: tt) => {
         impl Shape for RoundShape<$S> {
@@ -1,18 +1,22 @@
 use super::TriMeshBuilderError;
 use crate::math::{Isometry, Point, Real, Vector, DIM};
 use crate::shape::voxels::VoxelPrimitiveGeometry;
-#[cfg(feature = "dim2")]
-use crate::shape::ConvexPolygon;
 #[cfg(feature = "serde-serialize")]
 use crate::shape::DeserializableTypedShape;
 #[cfg(feature = "dim3")]
 use crate::shape::HeightFieldFlags;
 use crate::shape::{
-    Ball, Capsule, Compound, Cuboid, HalfSpace, HeightField, Polyline, RoundShape, Segment, Shape,
-    TriMesh, TriMeshFlags, Triangle, TypedShape, Voxels,
+    Ball, Capsule, Compound, Cuboid, Ellipsoid, HalfSpace, HeightField, Polyline, RoundShape,
+    RoundTriMesh, Sdf, Segment, Shape, SphereSet, SphereSetBuilderError, SweptShape,
+    TaperedCapsule, TriMesh, TriMeshFlags, Triangle, TypedShape, Voxels,
 };
+#[cfg(feature = "dim2")]
+use crate::shape::{BezierSpline, BezierSplineBuilderError, ConvexPolygon, Polygon};
 #[cfg(feature = "dim3")]
-use crate::shape::{Cone, ConvexPolyhedron, Cylinder};
+use crate::shape::{
+    Cone, ConvexPolyhedron, Cylinder, RoundPolyline, TetMesh, TetMeshBuilderError, Tetrahedron,
+    Torus,
+};
 use crate::transformation::vhacd::{VHACDParameters, VHACD};
 use crate::transformation::voxelization::{FillMode, VoxelSet};
 use alloc::sync::Arc;
@@ -73,6 +77,11 @@ impl SharedShape {
         SharedShape(Arc::new(Ball::new(radius)))
     }
 
+    /// Initialize an ellipsoid (or an ellipse in 2D) defined by its semi-axes lengths.
+    pub fn ellipsoid(radii: Vector<Real>) -> Self {
+        SharedShape(Arc::new(Ellipsoid::new(radii)))
+    }
+
     /// Initialize a plane shape defined by its outward normal.
     pub fn halfspace(outward_normal: Unit<Vector<Real>>) -> Self {
         SharedShape(Arc::new(HalfSpace::new(outward_normal)))
@@ -85,6 +94,13 @@ impl SharedShape {
         SharedShape(Arc::new(Cylinder::new(half_height, radius)))
     }
 
+    /// Initialize a torus shape defined by its major radius (the radius of its core
+    /// circle, lying on the `xz` plane) and its minor radius (the radius of its tube).
+    #[cfg(feature = "dim3")]
+    pub fn torus(major_radius: Real, minor_radius: Real) -> Self {
+        SharedShape(Arc::new(Torus::new(major_radius, minor_radius)))
+    }
+
     /// Initialize a rounded cylindrical shape defined by its half-height
     /// (along along the y axis), its radius, and its roundedness (the
     /// radius of the sphere used for dilating the cylinder).
@@ -168,6 +184,11 @@ impl SharedShape {
         Self::capsule(-p, p, radius)
     }
 
+    /// Initialize a tapered capsule shape from its endpoints and the radii at these endpoints.
+    pub fn tapered_capsule(a: Point<Real>, b: Point<Real>, radius_a: Real, radius_b: Real) -> Self {
+        SharedShape(Arc::new(TaperedCapsule::new(a, b, radius_a, radius_b)))
+    }
+
     /// Initialize a segment shape from its endpoints.
     pub fn segment(a: Point<Real>, b: Point<Real>) -> Self {
         SharedShape(Arc::new(Segment::new(a, b)))
@@ -177,6 +198,11 @@ impl SharedShape {
     pub fn triangle(a: Point<Real>, b: Point<Real>, c: Point<Real>) -> Self {
         SharedShape(Arc::new(Triangle::new(a, b, c)))
     }
+    /// Initializes a tetrahedron shape.
+    #[cfg(feature = "dim3")]
+    pub fn tetrahedron(a: Point<Real>, b: Point<Real>, c: Point<Real>, d: Point<Real>) -> Self {
+        SharedShape(Arc::new(Tetrahedron::new(a, b, c, d)))
+    }
     /// Initializes a triangle shape with round corners.
     pub fn round_triangle(
         a: Point<Real>,
@@ -197,6 +223,58 @@ impl SharedShape {
         SharedShape(Arc::new(Polyline::new(vertices, indices)))
     }
 
+    /// Initializes a shape that is the convex hull of `shape` at `start_pos` and `end_pos`.
+    ///
+    /// Returns `None` if `shape` isn’t a support-mapped shape.
+    pub fn swept(
+        shape: SharedShape,
+        start_pos: Isometry<Real>,
+        end_pos: Isometry<Real>,
+    ) -> Option<Self> {
+        SweptShape::new(shape, start_pos, end_pos).map(|swept| SharedShape(Arc::new(swept)))
+    }
+
+    /// Initializes a simple polygon shape, possibly non-convex and with holes.
+    ///
+    /// Returns `None` if one of the loops is degenerate or if the polygon couldn’t be decomposed
+    /// into convex parts. See [`Polygon::new`] for details.
+    #[cfg(feature = "dim2")]
+    pub fn polygon(outer_boundary: Vec<Point<Real>>, holes: Vec<Vec<Point<Real>>>) -> Option<Self> {
+        Polygon::new(outer_boundary, holes).map(|polygon| SharedShape(Arc::new(polygon)))
+    }
+
+    /// Initializes a chain of cubic Bézier curves defined by its control points.
+    ///
+    /// See [`BezierSpline::new`] for details.
+    #[cfg(feature = "dim2")]
+    pub fn bezier_spline(
+        control_points: Vec<Point<Real>>,
+    ) -> Result<Self, BezierSplineBuilderError> {
+        Ok(SharedShape(Arc::new(BezierSpline::new(control_points)?)))
+    }
+
+    /// Initializes a sphere set shape defined by the centers and radii of its spheres.
+    pub fn sphere_set(
+        centers: Vec<Point<Real>>,
+        radii: Vec<Real>,
+    ) -> Result<Self, SphereSetBuilderError> {
+        Ok(SharedShape(Arc::new(SphereSet::new(centers, radii)?)))
+    }
+
+    /// Initializes a polyline with rounded corners, i.e., a chain of capsules sharing the same
+    /// radius, defined by its vertex and index buffers.
+    ///
+    /// If no index buffer is provided, the polyline is assumed to describe a line strip.
+    #[cfg(feature = "dim3")]
+    pub fn round_polyline(
+        vertices: Vec<Point<Real>>,
+        indices: Option<Vec<[u32; 2]>>,
+        radius: Real,
+    ) -> Self {
+        let polyline = Polyline::new(vertices, indices);
+        SharedShape(Arc::new(RoundPolyline::new(polyline, radius)))
+    }
+
     /// Initializes a triangle mesh shape defined by its vertex and index buffers.
     pub fn trimesh(
         vertices: Vec<Point<Real>>,
@@ -205,6 +283,20 @@ impl SharedShape {
         Ok(SharedShape(Arc::new(TriMesh::new(vertices, indices)?)))
     }
 
+    /// Initializes a triangle mesh shape with round corners, defined by its vertex and index
+    /// buffers.
+    pub fn round_trimesh(
+        vertices: Vec<Point<Real>>,
+        indices: Vec<[u32; 3]>,
+        border_radius: Real,
+    ) -> Result<Self, TriMeshBuilderError> {
+        let mesh = TriMesh::new(vertices, indices)?;
+        Ok(SharedShape(Arc::new(RoundTriMesh::new(
+            mesh,
+            border_radius,
+        ))))
+    }
+
     /// Initializes a triangle mesh shape defined by its vertex and index buffers and
     /// pre-processing flags.
     pub fn trimesh_with_flags(
@@ -217,6 +309,27 @@ impl SharedShape {
         )?)))
     }
 
+    /// Initializes a tetrahedral mesh shape defined by its vertex and index buffers.
+    #[cfg(feature = "dim3")]
+    pub fn tetmesh(
+        vertices: Vec<Point<Real>>,
+        indices: Vec<[u32; 4]>,
+    ) -> Result<Self, TetMeshBuilderError> {
+        Ok(SharedShape(Arc::new(TetMesh::new(vertices, indices)?)))
+    }
+
+    /// Initializes a signed distance field shape from its samples.
+    ///
+    /// See [`Sdf::new`] for details on the samples’ layout.
+    pub fn sdf(
+        origin: Point<Real>,
+        cell_size: Vector<Real>,
+        dimensions: Vector<u32>,
+        values: Vec<Real>,
+    ) -> Self {
+        SharedShape::new(Sdf::new(origin, cell_size, dimensions, values))
+    }
+
     /// Initializes a shape made of voxels.
     ///
     /// Each voxel has the size `voxel_size` and grid coordinate given by `grid_coords`.
@@ -500,6 +613,34 @@ impl SharedShape {
     ) -> Self {
         SharedShape(Arc::new(HeightField::with_flags(heights, scale, flags)))
     }
+
+    /// Initializes a heightfield shape with round corners, defined by its set of height and a
+    /// scale factor along each coordinate axis.
+    #[cfg(feature = "dim2")]
+    pub fn round_heightfield(
+        heights: na::DVector<Real>,
+        scale: Vector<Real>,
+        border_radius: Real,
+    ) -> Self {
+        SharedShape(Arc::new(RoundShape {
+            inner_shape: HeightField::new(heights, scale),
+            border_radius,
+        }))
+    }
+
+    /// Initializes a heightfield shape on the x-z plane with round corners, defined by its set
+    /// of height and a scale factor along each coordinate axis.
+    #[cfg(feature = "dim3")]
+    pub fn round_heightfield(
+        heights: na::DMatrix<Real>,
+        scale: Vector<Real>,
+        border_radius: Real,
+    ) -> Self {
+        SharedShape(Arc::new(RoundShape {
+            inner_shape: HeightField::new(heights, scale),
+            border_radius,
+        }))
+    }
 }
 
 #[cfg(feature = "serde-serialize")]
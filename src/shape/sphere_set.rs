@@ -0,0 +1,330 @@
+use crate::bounding_volume::{Aabb, BoundingSphere};
+use crate::math::{Isometry, Point, Real, Vector};
+use crate::partitioning::Qbvh;
+use crate::query::details::NormalConstraints;
+use crate::shape::composite_shape::SimdCompositeShape;
+use crate::shape::{Ball, Compound, Shape, SharedShape, TypedSimdCompositeShape};
+use alloc::vec::Vec;
+use either::Either;
+
+/// Indicated an inconsistency while building a sphere set.
+#[derive(thiserror::Error, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SphereSetBuilderError {
+    /// A sphere set must contain at least one sphere.
+    #[error("A sphere set must contain at least one sphere.")]
+    EmptySpheres,
+    /// The number of radii doesn’t match the number of sphere centers.
+    #[error("The number of radii doesn’t match the number of sphere centers.")]
+    MismatchedRadii,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+/// A set of balls (or disks in 2D), each with its own center and radius.
+///
+/// This is a composite shape where each part is a [`Ball`]. It is a lightweight alternative
+/// to a [`Compound`] of balls, typically used for particle-based objects or collision proxies
+/// generated from point clouds: the spheres are stored contiguously instead of requiring one
+/// [`SharedShape`] per sphere.
+pub struct SphereSet {
+    qbvh: Qbvh<u32>,
+    centers: Vec<Point<Real>>,
+    radii: Vec<Real>,
+}
+
+impl SphereSet {
+    /// Creates a new sphere set from the centers and radii of its spheres.
+    pub fn new(centers: Vec<Point<Real>>, radii: Vec<Real>) -> Result<Self, SphereSetBuilderError> {
+        if centers.is_empty() {
+            return Err(SphereSetBuilderError::EmptySpheres);
+        }
+
+        if centers.len() != radii.len() {
+            return Err(SphereSetBuilderError::MismatchedRadii);
+        }
+
+        let data = centers
+            .iter()
+            .zip(radii.iter())
+            .enumerate()
+            .map(|(i, (center, radius))| {
+                let aabb = Aabb::from_half_extents(*center, Vector::repeat(*radius));
+                (i as u32, aabb)
+            });
+
+        let mut qbvh = Qbvh::new();
+        // NOTE: we apply no dilation factor because we won't
+        // update this tree dynamically.
+        qbvh.clear_and_rebuild(data, 0.0);
+
+        Ok(Self {
+            qbvh,
+            centers,
+            radii,
+        })
+    }
+
+    /// Creates a new sphere set where all the spheres have the same radius.
+    pub fn with_uniform_radius(
+        centers: Vec<Point<Real>>,
+        radius: Real,
+    ) -> Result<Self, SphereSetBuilderError> {
+        let radii = alloc::vec![radius; centers.len()];
+        Self::new(centers, radii)
+    }
+
+    /// Computes a scaled version of this sphere set.
+    ///
+    /// If the scaling factor is non-uniform, then the scaled spheres can’t be represented as
+    /// balls. Instead, a [`Compound`] of their convex approximations (with `nsubdivs`
+    /// subdivisions) is returned. Returns `None` if one of these approximations is degenerate
+    /// (for example if the scaling factor along one axis is zero).
+    pub fn scaled(self, scale: &Vector<Real>, nsubdivs: u32) -> Option<Either<Self, Compound>> {
+        let centers = self
+            .centers
+            .iter()
+            .map(|pt| pt.coords.component_mul(scale).into());
+
+        if scale.iter().all(|s| *s == scale.x) {
+            let uniform_scale = scale.x.abs();
+            let radii = self.radii.iter().map(|r| r * uniform_scale).collect();
+            return Self::new(centers.collect(), radii).ok().map(Either::Left);
+        }
+
+        let parts = centers
+            .zip(self.radii.iter())
+            .map(|(center, radius): (Point<Real>, _)| {
+                let ball = Ball::new(*radius).scaled(scale, nsubdivs)?;
+                let shape = ball.either(SharedShape::new, SharedShape::new);
+                Some((
+                    Isometry::from_parts(center.coords.into(), Default::default()),
+                    shape,
+                ))
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Either::Right(Compound::new(parts)))
+    }
+
+    /// Compute the axis-aligned bounding box of this sphere set.
+    pub fn aabb(&self, pos: &Isometry<Real>) -> Aabb {
+        self.qbvh.root_aabb().transform_by(pos)
+    }
+
+    /// Gets the local axis-aligned bounding box of this sphere set.
+    pub fn local_aabb(&self) -> &Aabb {
+        self.qbvh.root_aabb()
+    }
+
+    /// The bounding-sphere of this sphere set in its local-space.
+    pub fn local_bounding_sphere(&self) -> BoundingSphere {
+        let center = self.local_aabb().center();
+        let radius = self
+            .centers
+            .iter()
+            .zip(self.radii.iter())
+            .fold(0.0, |max: Real, (pt, radius)| {
+                max.max(na::distance(&center, pt) + radius)
+            });
+        BoundingSphere::new(center, radius)
+    }
+
+    /// The acceleration structure used by this sphere set.
+    pub fn qbvh(&self) -> &Qbvh<u32> {
+        &self.qbvh
+    }
+
+    /// The number of spheres in this set.
+    pub fn num_spheres(&self) -> usize {
+        self.centers.len()
+    }
+
+    /// The centers of the spheres of this set.
+    pub fn centers(&self) -> &[Point<Real>] {
+        &self.centers
+    }
+
+    /// The radii of the spheres of this set.
+    pub fn radii(&self) -> &[Real] {
+        &self.radii
+    }
+
+    /// The `i`-th sphere of this set, with the position of its center.
+    pub fn sphere(&self, i: u32) -> (Isometry<Real>, Ball) {
+        let i = i as usize;
+        let pos = Isometry::from_parts(self.centers[i].coords.into(), Default::default());
+        (pos, Ball::new(self.radii[i]))
+    }
+}
+
+impl SimdCompositeShape for SphereSet {
+    fn map_part_at(
+        &self,
+        i: u32,
+        f: &mut dyn FnMut(Option<&Isometry<Real>>, &dyn Shape, Option<&dyn NormalConstraints>),
+    ) {
+        let (pos, ball) = self.sphere(i);
+        f(Some(&pos), &ball, None)
+    }
+
+    fn qbvh(&self) -> &Qbvh<u32> {
+        &self.qbvh
+    }
+}
+
+impl TypedSimdCompositeShape for SphereSet {
+    type PartShape = Ball;
+    type PartNormalConstraints = ();
+    type PartId = u32;
+
+    #[inline(always)]
+    fn map_typed_part_at(
+        &self,
+        i: u32,
+        mut f: impl FnMut(
+            Option<&Isometry<Real>>,
+            &Self::PartShape,
+            Option<&Self::PartNormalConstraints>,
+        ),
+    ) {
+        let (pos, ball) = self.sphere(i);
+        f(Some(&pos), &ball, None)
+    }
+
+    #[inline(always)]
+    fn map_untyped_part_at(
+        &self,
+        i: u32,
+        mut f: impl FnMut(Option<&Isometry<Real>>, &dyn Shape, Option<&dyn NormalConstraints>),
+    ) {
+        let (pos, ball) = self.sphere(i);
+        f(Some(&pos), &ball, None)
+    }
+
+    fn typed_qbvh(&self) -> &Qbvh<u32> {
+        &self.qbvh
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::mass_properties::MassProperties;
+    use crate::math::{Isometry, Point, Real, Vector};
+    use crate::query::{
+        self, ContactManifold, DefaultQueryDispatcher, PersistentQueryDispatcher, PointQuery, Ray,
+        RayCast,
+    };
+    use crate::shape::{Ball, Cuboid, FeatureId, Shape, SphereSet, SphereSetBuilderError};
+    use alloc::{vec, vec::Vec};
+    use approx::assert_relative_eq;
+
+    /// Three spheres aligned along the `x` axis.
+    fn aligned_spheres() -> SphereSet {
+        let centers = vec![
+            Point::origin(),
+            Point::origin() + Vector::x() * 3.0,
+            Point::origin() + Vector::x() * 6.0,
+        ];
+        SphereSet::new(centers, vec![1.0, 0.5, 1.0]).unwrap()
+    }
+
+    #[test]
+    fn sphere_set_construction_and_mass_properties() {
+        assert_eq!(
+            SphereSet::new(Vec::new(), Vec::new()).err(),
+            Some(SphereSetBuilderError::EmptySpheres)
+        );
+        assert_eq!(
+            SphereSet::new(vec![Point::origin()], vec![1.0, 2.0]).err(),
+            Some(SphereSetBuilderError::MismatchedRadii)
+        );
+
+        let spheres = aligned_spheres();
+        assert_eq!(spheres.num_spheres(), 3);
+
+        let mprops = spheres.mass_properties(2.0);
+        let big = MassProperties::from_ball(2.0, 1.0).mass();
+        let small = MassProperties::from_ball(2.0, 0.5).mass();
+        assert_relative_eq!(mprops.mass(), 2.0 * big + small, epsilon = 1.0e-4);
+        assert_relative_eq!(
+            mprops.local_com,
+            Point::origin() + Vector::x() * 3.0,
+            epsilon = 1.0e-5
+        );
+
+        let aabb = spheres.local_aabb();
+        assert_relative_eq!(aabb.mins, Point::origin() - Vector::repeat(1.0));
+        assert_relative_eq!(
+            aabb.maxs,
+            Point::origin() + Vector::x() * 6.0 + Vector::repeat(1.0)
+        );
+
+        assert!(spheres
+            .clone()
+            .scaled(&Vector::repeat(2.0), 10)
+            .unwrap()
+            .is_left());
+        let mut scale = Vector::repeat(1.0);
+        scale.x = 2.0;
+        let compound = spheres.scaled(&scale, 10).unwrap().unwrap_right();
+        assert_eq!(compound.shapes().len(), 3);
+    }
+
+    #[test]
+    fn sphere_set_point_and_ray_queries() {
+        let spheres = aligned_spheres();
+        let pt = Point::origin() + Vector::x() * 3.2;
+        assert!(spheres.contains_local_point(&pt));
+        assert!(!spheres.contains_local_point(&(Point::origin() + Vector::x() * 1.5)));
+
+        let (proj, feature) =
+            spheres.project_local_point_and_get_feature(&(Point::origin() + Vector::x() * 4.5));
+        assert!(!proj.is_inside);
+        assert_relative_eq!(proj.point, Point::origin() + Vector::x() * 5.0);
+        assert_eq!(feature, FeatureId::Face(2));
+
+        let ray = Ray::new(Point::origin() + Vector::x() * 10.0, -Vector::x());
+        let hit = spheres
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+            .unwrap();
+        assert_relative_eq!(hit.time_of_impact, 3.0, epsilon = 1.0e-5);
+        assert_relative_eq!(hit.normal, Vector::x(), epsilon = 1.0e-5);
+        assert_eq!(hit.feature, FeatureId::Face(2));
+        assert_eq!(spheres.cast_local_ray(&ray, 2.0, true), None);
+    }
+
+    #[test]
+    fn sphere_set_distance_and_contacts() {
+        let spheres = aligned_spheres();
+        let ball = Ball::new(0.5);
+        let pos12 = Isometry::from_parts(
+            (Vector::x() * 3.0 + Vector::y() * 2.0).into(),
+            Default::default(),
+        );
+        let dist = query::distance(&Isometry::identity(), &spheres, &pos12, &ball).unwrap();
+        assert_relative_eq!(dist, 1.0, epsilon = 1.0e-5);
+
+        // A flat box lying under all the spheres.
+        let cuboid = Cuboid::new(Vector::repeat(10.0));
+        let pos12 = Isometry::from_parts(
+            (Vector::x() * 3.0 - Vector::y() * 10.9).into(),
+            Default::default(),
+        );
+        let dispatcher = DefaultQueryDispatcher;
+        let mut manifolds: Vec<ContactManifold<(), ()>> = Vec::new();
+        dispatcher
+            .contact_manifolds(&pos12, &spheres, &cuboid, 0.0, &mut manifolds, &mut None)
+            .unwrap();
+        manifolds.sort_by_key(|m| m.subshape1);
+
+        // The small sphere doesn’t touch the box.
+        assert_eq!(manifolds.len(), 2);
+        assert_eq!(manifolds[0].subshape1, 0);
+        assert_eq!(manifolds[1].subshape1, 2);
+
+        for manifold in &manifolds {
+            assert_eq!(manifold.points.len(), 1);
+            assert_relative_eq!(manifold.points[0].dist, -0.1, epsilon = 1.0e-5);
+        }
+    }
+}
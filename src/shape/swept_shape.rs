@@ -0,0 +1,177 @@
+use crate::math::{Isometry, Point, Real, Vector};
+use crate::shape::{Ball, SharedShape, SupportMap};
+use alloc::vec::Vec;
+use na::Unit;
+
+/// The number of subdivisions of the unit sphere (or circle) used to sample the directions
+/// of the support points approximating the convex hull of a swept shape.
+const NUM_DIRECTION_SUBDIVISIONS: u32 = 32;
+
+/// The convex hull of a support-mapped shape at two different positions.
+///
+/// This is a convex shape containing the volume swept by the shape moving from `start_pos`
+/// to `end_pos` with a linear motion. If the motion includes a rotation, the convex hull is
+/// only an approximation of the actual swept volume. It is much tighter than the swept
+/// [`Aabb`](crate::bounding_volume::Aabb) for thin, fast-moving objects, and can be used with
+/// the intersection, distance, and contact queries like any other convex shape.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct SweptShape {
+    shape: SharedShape,
+    start_pos: Isometry<Real>,
+    end_pos: Isometry<Real>,
+}
+
+impl SweptShape {
+    /// Creates the convex hull of `shape` at `start_pos` and `end_pos`.
+    ///
+    /// Returns `None` if `shape` isn’t a support-mapped shape.
+    pub fn new(
+        shape: SharedShape,
+        start_pos: Isometry<Real>,
+        end_pos: Isometry<Real>,
+    ) -> Option<Self> {
+        let _ = shape.as_support_map()?;
+
+        Some(Self {
+            shape,
+            start_pos,
+            end_pos,
+        })
+    }
+
+    /// The shape being swept.
+    pub fn shape(&self) -> &SharedShape {
+        &self.shape
+    }
+
+    /// The position of the swept shape at the start of its motion.
+    pub fn start_pos(&self) -> &Isometry<Real> {
+        &self.start_pos
+    }
+
+    /// The position of the swept shape at the end of its motion.
+    pub fn end_pos(&self) -> &Isometry<Real> {
+        &self.end_pos
+    }
+
+    /// Computes a scaled version of this swept shape.
+    ///
+    /// Returns `None` if the swept shape can’t be scaled or if the scaled shape isn’t a
+    /// support-mapped shape. A non-uniform scaling can only be applied if neither position
+    /// contains a rotation.
+    pub fn scaled(&self, scale: &Vector<Real>, num_subdivisions: u32) -> Option<Self> {
+        let is_uniform = scale.iter().all(|s| *s == scale[0]);
+        let is_translation = |pos: &Isometry<Real>| pos.rotation == na::one();
+
+        let is_pure_translation = is_translation(&self.start_pos) && is_translation(&self.end_pos);
+
+        if !is_uniform && !is_pure_translation {
+            return None;
+        }
+
+        let shape = SharedShape(self.shape.scale_dyn(scale, num_subdivisions)?.into());
+        let scale_pos = |pos: &Isometry<Real>| {
+            Isometry::from_parts(
+                pos.translation.vector.component_mul(scale).into(),
+                pos.rotation,
+            )
+        };
+
+        Self::new(shape, scale_pos(&self.start_pos), scale_pos(&self.end_pos))
+    }
+
+    /// Samples points of the boundary of this swept shape, for approximating its convex hull.
+    pub(crate) fn sample_support_points(&self) -> Vec<Point<Real>> {
+        #[cfg(feature = "dim2")]
+        let dirs = Ball::new(1.0).to_polyline(NUM_DIRECTION_SUBDIVISIONS);
+        #[cfg(feature = "dim3")]
+        let dirs = Ball::new(1.0)
+            .to_trimesh(NUM_DIRECTION_SUBDIVISIONS, NUM_DIRECTION_SUBDIVISIONS / 2)
+            .0;
+
+        dirs.iter()
+            .map(|dir| self.local_support_point(&dir.coords))
+            .collect()
+    }
+
+    fn support_map(&self) -> &dyn SupportMap {
+        self.shape
+            .as_support_map()
+            .expect("The swept shape must be a support map.")
+    }
+}
+
+impl SupportMap for SweptShape {
+    fn local_support_point(&self, dir: &Vector<Real>) -> Point<Real> {
+        let shape = self.support_map();
+        let pt1 = shape.support_point(&self.start_pos, dir);
+        let pt2 = shape.support_point(&self.end_pos, dir);
+
+        if pt1.coords.dot(dir) >= pt2.coords.dot(dir) {
+            pt1
+        } else {
+            pt2
+        }
+    }
+
+    fn local_support_point_toward(&self, dir: &Unit<Vector<Real>>) -> Point<Real> {
+        let shape = self.support_map();
+        let pt1 = shape.support_point_toward(&self.start_pos, dir);
+        let pt2 = shape.support_point_toward(&self.end_pos, dir);
+
+        if pt1.coords.dot(dir) >= pt2.coords.dot(dir) {
+            pt1
+        } else {
+            pt2
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::math::{Isometry, Real, Vector};
+    use crate::query;
+    use crate::shape::{Ball, Cuboid, Shape, SharedShape, SweptShape};
+    use approx::assert_relative_eq;
+    use na::RealField;
+
+    #[test]
+    fn swept_ball_queries() {
+        let start = Isometry::identity();
+        let end = Isometry::new(Vector::x() * 10.0, na::zero());
+        let swept = SweptShape::new(SharedShape::ball(0.5), start, end).unwrap();
+        assert!(SweptShape::new(SharedShape::halfspace(Vector::y_axis()), start, end).is_none());
+
+        // A thin wall in the middle of the motion is hit, even though it doesn’t touch the
+        // ball at either position.
+        let wall = Cuboid::new(Vector::repeat(0.1));
+        let wall_pos = Isometry::new(Vector::x() * 5.0 + Vector::y() * 0.55, na::zero());
+        assert!(query::intersection_test(&Isometry::identity(), &swept, &wall_pos, &wall).unwrap());
+        let dist = query::distance(
+            &Isometry::identity(),
+            &swept,
+            &Isometry::new(Vector::x() * 5.0 + Vector::y() * 2.0, na::zero()),
+            &Ball::new(0.5),
+        )
+        .unwrap();
+        assert_relative_eq!(dist, 1.0, epsilon = 1.0e-3);
+        let contact = query::contact(&Isometry::identity(), &swept, &wall_pos, &wall, 0.0)
+            .unwrap()
+            .unwrap();
+        assert_relative_eq!(contact.dist, -0.05, epsilon = 1.0e-3);
+        assert_relative_eq!(*contact.normal1, Vector::y(), epsilon = 1.0e-3);
+
+        // Bounding volume and mass properties (the latter is an approximation).
+        let aabb = swept.compute_local_aabb();
+        assert_relative_eq!(aabb.mins.x, -0.5);
+        assert_relative_eq!(aabb.maxs.x, 10.5);
+        #[cfg(feature = "dim2")]
+        let expected_mass = 10.0 + Real::pi() * 0.25;
+        #[cfg(feature = "dim3")]
+        let expected_mass = 10.0 * Real::pi() * 0.25 + Real::pi() * 0.5 / 3.0;
+        let mprops = swept.mass_properties(1.0);
+        assert_relative_eq!(mprops.mass(), expected_mass, max_relative = 0.05);
+        assert_relative_eq!(mprops.local_com.x, 5.0, epsilon = 0.05);
+    }
+}
@@ -0,0 +1,360 @@
+use crate::math::{Isometry, Point, Real, Rotation, Vector};
+use crate::shape::{Segment, SupportMap};
+use na::Unit;
+
+#[cfg(not(feature = "std"))]
+use na::ComplexField;
+#[cfg(feature = "dim3")]
+use na::RealField;
+
+#[cfg(feature = "alloc")]
+use either::Either;
+
+#[cfg(feature = "rkyv")]
+use rkyv::{bytecheck, CheckBytes};
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize, CheckBytes),
+    archive(as = "Self")
+)]
+#[repr(C)]
+/// A tapered capsule: the convex hull of two balls with different radii.
+///
+/// It can be seen as a segment dilated by a radius varying linearly from `radius_a` at its
+/// first endpoint to `radius_b` at its second endpoint. If both radii are equal, this is
+/// a [`Capsule`](crate::shape::Capsule).
+pub struct TaperedCapsule {
+    /// The endpoints of the tapered capsule’s principal axis.
+    pub segment: Segment,
+    /// The radius of the ball centered at `segment.a`.
+    pub radius_a: Real,
+    /// The radius of the ball centered at `segment.b`.
+    pub radius_b: Real,
+}
+
+impl TaperedCapsule {
+    /// Creates a new tapered capsule aligned with the `x` axis.
+    ///
+    /// The ball with radius `radius_a` is centered at `-half_height` and the ball with radius
+    /// `radius_b` is centered at `half_height`.
+    pub fn new_x(half_height: Real, radius_a: Real, radius_b: Real) -> Self {
+        let b = Point::from(Vector::x() * half_height);
+        Self::new(-b, b, radius_a, radius_b)
+    }
+
+    /// Creates a new tapered capsule aligned with the `y` axis.
+    ///
+    /// The ball with radius `radius_a` is centered at `-half_height` and the ball with radius
+    /// `radius_b` is centered at `half_height`.
+    pub fn new_y(half_height: Real, radius_a: Real, radius_b: Real) -> Self {
+        let b = Point::from(Vector::y() * half_height);
+        Self::new(-b, b, radius_a, radius_b)
+    }
+
+    /// Creates a new tapered capsule aligned with the `z` axis.
+    ///
+    /// The ball with radius `radius_a` is centered at `-half_height` and the ball with radius
+    /// `radius_b` is centered at `half_height`.
+    #[cfg(feature = "dim3")]
+    pub fn new_z(half_height: Real, radius_a: Real, radius_b: Real) -> Self {
+        let b = Point::from(Vector::z() * half_height);
+        Self::new(-b, b, radius_a, radius_b)
+    }
+
+    /// Creates a new tapered capsule defined as the convex hull of the ball centered at `a` with
+    /// radius `radius_a` and of the ball centered at `b` with radius `radius_b`.
+    pub fn new(a: Point<Real>, b: Point<Real>, radius_a: Real, radius_b: Real) -> Self {
+        let segment = Segment::new(a, b);
+        Self {
+            segment,
+            radius_a,
+            radius_b,
+        }
+    }
+
+    /// The distance between the centers of the two balls of this tapered capsule.
+    pub fn height(&self) -> Real {
+        (self.segment.b - self.segment.a).norm()
+    }
+
+    /// The half-height of this tapered capsule.
+    pub fn half_height(&self) -> Real {
+        self.height() / 2.0
+    }
+
+    /// The center of the principal axis of this tapered capsule.
+    pub fn center(&self) -> Point<Real> {
+        na::center(&self.segment.a, &self.segment.b)
+    }
+
+    /// Is one of the balls of this tapered capsule entirely contained by the other?
+    ///
+    /// In that case the tapered capsule is just the biggest ball.
+    pub fn is_degenerate(&self) -> bool {
+        self.height() <= (self.radius_a - self.radius_b).abs()
+    }
+
+    /// The sine and cosine of the angle between the lateral boundary of this tapered capsule
+    /// and its principal axis.
+    ///
+    /// The sine is positive if `radius_a > radius_b`. If this tapered capsule is degenerate,
+    /// the sine is `1` or `-1` (depending on which ball is the biggest) and the cosine is zero.
+    pub fn slope(&self) -> (Real, Real) {
+        let height = self.height();
+        let dr = self.radius_a - self.radius_b;
+
+        if height <= dr.abs() {
+            (if dr >= 0.0 { 1.0 } else { -1.0 }, 0.0)
+        } else {
+            let sin = dr / height;
+            (sin, (1.0 - sin * sin).sqrt())
+        }
+    }
+
+    /// Creates a new tapered capsule equal to `self` with all its endpoints transformed by `pos`.
+    pub fn transform_by(&self, pos: &Isometry<Real>) -> Self {
+        Self::new(
+            pos * self.segment.a,
+            pos * self.segment.b,
+            self.radius_a,
+            self.radius_b,
+        )
+    }
+
+    /// The transformation such that `t * Y` points from `a` toward `b`, and `t * origin` equals
+    /// the center of the tapered capsule's principal axis.
+    pub fn canonical_transform(&self) -> Isometry<Real> {
+        Isometry::from_parts(self.center().coords.into(), self.rotation_wrt_y())
+    }
+
+    /// The rotation `r` such that `r * Y` points from `a` toward `b`.
+    ///
+    /// Unlike [`Capsule::rotation_wrt_y`](crate::shape::Capsule::rotation_wrt_y), the
+    /// direction is never flipped since both endpoints don’t play the same role.
+    pub fn rotation_wrt_y(&self) -> Rotation<Real> {
+        let dir = self.segment.b - self.segment.a;
+
+        #[cfg(feature = "dim2")]
+        {
+            Rotation::rotation_between(&Vector::y(), &dir)
+        }
+
+        #[cfg(feature = "dim3")]
+        {
+            Rotation::rotation_between(&Vector::y(), &dir).unwrap_or_else(|| {
+                if dir.y < 0.0 {
+                    Rotation::from_axis_angle(&Vector::x_axis(), Real::pi())
+                } else {
+                    Rotation::identity()
+                }
+            })
+        }
+    }
+
+    /// Computes a scaled version of this tapered capsule.
+    ///
+    /// If the scaling factor is non-uniform, then it can’t be represented as
+    /// tapered capsule. Instead, a convex polygon approximation (with `nsubdivs`
+    /// subdivisions) is returned. Returns `None` if that approximation had degenerate
+    /// normals (for example if the scaling factor along one axis is zero).
+    #[cfg(all(feature = "dim2", feature = "alloc"))]
+    pub fn scaled(
+        self,
+        scale: &Vector<Real>,
+        nsubdivs: u32,
+    ) -> Option<Either<Self, super::ConvexPolygon>> {
+        if scale.x != scale.y {
+            // The scaled shape is not a tapered capsule.
+            let mut vtx = self.to_polyline(nsubdivs);
+            vtx.iter_mut()
+                .for_each(|pt| pt.coords = pt.coords.component_mul(scale));
+            Some(Either::Right(super::ConvexPolygon::from_convex_polyline(
+                vtx,
+            )?))
+        } else {
+            let uniform_scale = scale.x;
+            Some(Either::Left(Self::new(
+                self.segment.a * uniform_scale,
+                self.segment.b * uniform_scale,
+                self.radius_a * uniform_scale.abs(),
+                self.radius_b * uniform_scale.abs(),
+            )))
+        }
+    }
+
+    /// Computes a scaled version of this tapered capsule.
+    ///
+    /// If the scaling factor is non-uniform, then it can’t be represented as
+    /// tapered capsule. Instead, a convex polyhedron approximation (with `nsubdivs`
+    /// subdivisions) is returned. Returns `None` if that approximation had degenerate
+    /// normals (for example if the scaling factor along one axis is zero).
+    #[cfg(all(feature = "dim3", feature = "alloc"))]
+    pub fn scaled(
+        self,
+        scale: &Vector<Real>,
+        nsubdivs: u32,
+    ) -> Option<Either<Self, super::ConvexPolyhedron>> {
+        if scale.x != scale.y || scale.x != scale.z || scale.y != scale.z {
+            // The scaled shape is not a tapered capsule.
+            let (mut vtx, idx) = self.to_trimesh(nsubdivs, nsubdivs);
+            vtx.iter_mut()
+                .for_each(|pt| pt.coords = pt.coords.component_mul(scale));
+            Some(Either::Right(super::ConvexPolyhedron::from_convex_mesh(
+                vtx, &idx,
+            )?))
+        } else {
+            let uniform_scale = scale.x;
+            Some(Either::Left(Self::new(
+                self.segment.a * uniform_scale,
+                self.segment.b * uniform_scale,
+                self.radius_a * uniform_scale.abs(),
+                self.radius_b * uniform_scale.abs(),
+            )))
+        }
+    }
+}
+
+impl SupportMap for TaperedCapsule {
+    fn local_support_point(&self, dir: &Vector<Real>) -> Point<Real> {
+        let dir = Unit::try_new(*dir, 0.0).unwrap_or(Vector::y_axis());
+        self.local_support_point_toward(&dir)
+    }
+
+    fn local_support_point_toward(&self, dir: &Unit<Vector<Real>>) -> Point<Real> {
+        if dir.dot(&self.segment.a.coords) + self.radius_a
+            > dir.dot(&self.segment.b.coords) + self.radius_b
+        {
+            self.segment.a + **dir * self.radius_a
+        } else {
+            self.segment.b + **dir * self.radius_b
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[cfg(feature = "dim3")]
+    use crate::mass_properties::MassProperties;
+    use crate::math::{Isometry, Point, Real, Vector};
+    use crate::query::{PointQuery, Ray, RayCast};
+    use crate::shape::{Ball, Capsule, Shape, SupportMap, TaperedCapsule};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn tapered_capsule_with_equal_radii_is_a_capsule() {
+        let a = Point::from(Vector::repeat(-0.3));
+        let b = Point::from(Vector::repeat(0.7));
+        let capsule = Capsule::new(a, b, 0.4);
+        let tapered = TaperedCapsule::new(a, b, 0.4, 0.4);
+
+        // NOTE: the 2D capsule inertia uses the centroid of 3D hemispheres for its caps, so
+        //       the 2D mass properties are only checked against a discretization.
+        #[cfg(feature = "dim3")]
+        assert_relative_eq!(
+            MassProperties::from_tapered_capsule(2.0, a, b, 0.4, 0.4),
+            MassProperties::from_capsule(2.0, a, b, 0.4),
+            epsilon = 1.0e-4
+        );
+
+        for k in 0..20 {
+            let angle = k as Real * 0.7;
+            let mut dir = Vector::repeat(angle.cos());
+            dir[1] = angle.sin();
+            let pt = Point::from(dir * (0.2 + k as Real * 0.1));
+
+            assert_relative_eq!(
+                tapered.local_support_point(&dir),
+                capsule.local_support_point(&dir),
+                epsilon = 1.0e-5
+            );
+            let proj1 = tapered.project_local_point(&pt, false);
+            let proj2 = capsule.project_local_point(&pt, false);
+            assert_eq!(proj1.is_inside, proj2.is_inside);
+            assert_relative_eq!(proj1.point, proj2.point, epsilon = 1.0e-5);
+        }
+    }
+
+    #[test]
+    fn tapered_capsule_point_and_ray_queries() {
+        // Balls of radii 2 and 1 centered at `-y * 2` and `y * 2`, so the sine of the angle
+        // between the lateral boundary and the principal axis is `(2 - 1) / 4`.
+        let shape = TaperedCapsule::new_y(2.0, 2.0, 1.0);
+        let (sin, cos) = shape.slope();
+        assert_relative_eq!(sin, 0.25);
+
+        // Point in front of the lateral boundary.
+        let pt = Point::from(Vector::x() * 5.0);
+        let proj = shape.project_local_point(&pt, true);
+        assert!(!proj.is_inside);
+        let dist = 5.0 * cos + 2.0 * sin - 2.0;
+        assert_relative_eq!(
+            shape.distance_to_local_point(&pt, true),
+            dist,
+            epsilon = 1.0e-5
+        );
+        let mut normal = Vector::y() * sin;
+        normal.x = cos;
+        assert_relative_eq!(proj.point, pt - normal * dist, epsilon = 1.0e-5);
+
+        // Points in front of the balls.
+        let pt = Point::from(Vector::y() * 5.0);
+        assert_relative_eq!(
+            shape.project_local_point(&pt, true).point,
+            Point::from(Vector::y() * 3.0)
+        );
+        let pt = Point::from(-Vector::y() * 5.0);
+        assert_relative_eq!(
+            shape.project_local_point(&pt, true).point,
+            Point::from(-Vector::y() * 4.0)
+        );
+
+        // Points inside.
+        let pt = Point::from(Vector::y() * 2.5);
+        assert!(shape.contains_local_point(&pt));
+        let proj = shape.project_local_point(&pt, false);
+        assert!(proj.is_inside);
+        assert_relative_eq!(proj.point, Point::from(Vector::y() * 3.0), epsilon = 1.0e-5);
+        let pt = Point::origin();
+        let proj = shape.project_local_point(&pt, false);
+        assert!(proj.is_inside);
+        assert_relative_eq!(na::distance(&pt, &proj.point), 1.5, epsilon = 1.0e-5);
+
+        // Ray-casts.
+        let ray = Ray::new(Point::from(Vector::y() * 10.0), -Vector::y());
+        assert_relative_eq!(
+            shape.cast_local_ray(&ray, Real::MAX, true).unwrap(),
+            7.0,
+            epsilon = 1.0e-4
+        );
+        let ray = Ray::new(Point::from(Vector::x() * 10.0), -Vector::x());
+        let hit = shape
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+            .unwrap();
+        assert_relative_eq!(hit.time_of_impact, 10.0 - 1.5 / cos, epsilon = 1.0e-4);
+        assert_relative_eq!(hit.normal, normal, epsilon = 1.0e-4);
+    }
+
+    #[test]
+    fn degenerate_tapered_capsule_is_a_ball() {
+        let shape = TaperedCapsule::new_x(0.5, 0.5, 2.0);
+        let ball = Ball::new(2.0);
+        let pos = Isometry::new(Vector::x() * 0.5, na::zero());
+        assert!(shape.is_degenerate());
+
+        let pt = Point::from(Vector::x() * 2.0 + Vector::y() * 3.0);
+        assert_relative_eq!(
+            shape.project_local_point(&pt, true).point,
+            ball.project_point(&pos, &pt, true).point,
+            epsilon = 1.0e-5
+        );
+        assert_relative_eq!(
+            shape.mass_properties(1.0),
+            ball.mass_properties(1.0).transform_by(&pos),
+            epsilon = 1.0e-4
+        );
+    }
+}
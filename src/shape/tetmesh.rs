@@ -0,0 +1,321 @@
+use crate::bounding_volume::{Aabb, BoundingSphere};
+use crate::math::{Isometry, Point, Real, Vector};
+use crate::partitioning::Qbvh;
+use crate::query::details::NormalConstraints;
+use crate::shape::composite_shape::SimdCompositeShape;
+use crate::shape::{Shape, Tetrahedron, TriMesh, TypedSimdCompositeShape};
+use crate::utils::hashmap::{Entry, HashMap};
+use alloc::vec::Vec;
+
+/// Indicated an inconsistency while building a tetrahedral mesh.
+#[derive(thiserror::Error, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TetMeshBuilderError {
+    /// A tetrahedral mesh must contain at least one tetrahedron.
+    #[error("A tetrahedral mesh must contain at least one tetrahedron.")]
+    EmptyIndices,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+/// A tetrahedral mesh.
+///
+/// Unlike a [`TriMesh`], which only represents a surface, a tetrahedral mesh is a solid volume:
+/// point containment tests and solid ray-casts take its interior into account. Contacts are
+/// computed with each of its tetrahedra, like any other composite shape.
+pub struct TetMesh {
+    qbvh: Qbvh<u32>,
+    vertices: Vec<Point<Real>>,
+    indices: Vec<[u32; 4]>,
+    boundary: TriMesh,
+}
+
+impl TetMesh {
+    /// Creates a new tetrahedral mesh from a vertex buffer and an index buffer.
+    ///
+    /// The tetrahedra may have any orientation.
+    pub fn new(
+        vertices: Vec<Point<Real>>,
+        indices: Vec<[u32; 4]>,
+    ) -> Result<Self, TetMeshBuilderError> {
+        if indices.is_empty() {
+            return Err(TetMeshBuilderError::EmptyIndices);
+        }
+
+        let boundary_indices = boundary_faces(&vertices, &indices);
+        let boundary = TriMesh::new(vertices.clone(), boundary_indices)
+            .expect("A non-empty tetrahedral mesh always has a non-empty boundary.");
+
+        let data = indices.iter().enumerate().map(|(i, idx)| {
+            let aabb = tetrahedron(&vertices, idx).local_aabb();
+            (i as u32, aabb)
+        });
+
+        let mut qbvh = Qbvh::new();
+        // NOTE: we apply no dilation factor because we won't
+        // update this tree dynamically.
+        qbvh.clear_and_rebuild(data, 0.0);
+
+        Ok(Self {
+            qbvh,
+            vertices,
+            indices,
+            boundary,
+        })
+    }
+
+    /// Computes a scaled version of this tetrahedral mesh.
+    pub fn scaled(self, scale: &Vector<Real>) -> Self {
+        let vertices = self
+            .vertices
+            .into_iter()
+            .map(|pt| pt.coords.component_mul(scale).into())
+            .collect();
+        Self::new(vertices, self.indices).expect("The index buffer isn’t empty.")
+    }
+
+    /// Compute the axis-aligned bounding box of this tetrahedral mesh.
+    pub fn aabb(&self, pos: &Isometry<Real>) -> Aabb {
+        self.qbvh.root_aabb().transform_by(pos)
+    }
+
+    /// Gets the local axis-aligned bounding box of this tetrahedral mesh.
+    pub fn local_aabb(&self) -> &Aabb {
+        self.qbvh.root_aabb()
+    }
+
+    /// The bounding-sphere of this tetrahedral mesh in its local-space.
+    pub fn local_bounding_sphere(&self) -> BoundingSphere {
+        self.boundary.local_bounding_sphere()
+    }
+
+    /// The acceleration structure used by this tetrahedral mesh.
+    pub fn qbvh(&self) -> &Qbvh<u32> {
+        &self.qbvh
+    }
+
+    /// The number of tetrahedra forming this mesh.
+    pub fn num_tetrahedra(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// An iterator through all the tetrahedra of this mesh.
+    pub fn tetrahedra(&self) -> impl ExactSizeIterator<Item = Tetrahedron> + '_ {
+        self.indices
+            .iter()
+            .map(move |idx| tetrahedron(&self.vertices, idx))
+    }
+
+    /// Get the `i`-th tetrahedron of this mesh.
+    pub fn tetrahedron(&self, i: u32) -> Tetrahedron {
+        tetrahedron(&self.vertices, &self.indices[i as usize])
+    }
+
+    /// The vertex buffer of this mesh.
+    pub fn vertices(&self) -> &[Point<Real>] {
+        &self.vertices
+    }
+
+    /// The index buffer of this mesh.
+    pub fn indices(&self) -> &[[u32; 4]] {
+        &self.indices
+    }
+
+    /// The boundary surface of this mesh, i.e., all the faces belonging to only one tetrahedron.
+    ///
+    /// The boundary triangles are oriented such that their normals point outward.
+    pub fn boundary(&self) -> &TriMesh {
+        &self.boundary
+    }
+}
+
+fn tetrahedron(vertices: &[Point<Real>], idx: &[u32; 4]) -> Tetrahedron {
+    Tetrahedron::new(
+        vertices[idx[0] as usize],
+        vertices[idx[1] as usize],
+        vertices[idx[2] as usize],
+        vertices[idx[3] as usize],
+    )
+}
+
+/// Computes the faces belonging to only one tetrahedron, oriented outward.
+fn boundary_faces(vertices: &[Point<Real>], indices: &[[u32; 4]]) -> Vec<[u32; 3]> {
+    let mut faces = Vec::new();
+    let mut face_multiplicity = Vec::new();
+    let mut face_ids = HashMap::default();
+
+    for idx in indices {
+        let tetrahedron = tetrahedron(vertices, idx);
+
+        for i in 0..4 {
+            let face = tetrahedron.outward_face_ids(i).map(|k| idx[k as usize]);
+            let mut key = face;
+            key.sort_unstable();
+
+            match face_ids.entry(key) {
+                Entry::Occupied(e) => face_multiplicity[*e.get()] += 1,
+                Entry::Vacant(e) => {
+                    let _ = e.insert(faces.len());
+                    faces.push(face);
+                    face_multiplicity.push(1);
+                }
+            }
+        }
+    }
+
+    faces
+        .into_iter()
+        .zip(face_multiplicity)
+        .filter(|(_, multiplicity)| *multiplicity == 1)
+        .map(|(face, _)| face)
+        .collect()
+}
+
+impl SimdCompositeShape for TetMesh {
+    fn map_part_at(
+        &self,
+        i: u32,
+        f: &mut dyn FnMut(Option<&Isometry<Real>>, &dyn Shape, Option<&dyn NormalConstraints>),
+    ) {
+        let tetrahedron = self.tetrahedron(i);
+        f(None, &tetrahedron, None)
+    }
+
+    fn qbvh(&self) -> &Qbvh<u32> {
+        &self.qbvh
+    }
+}
+
+impl TypedSimdCompositeShape for TetMesh {
+    type PartShape = Tetrahedron;
+    type PartNormalConstraints = ();
+    type PartId = u32;
+
+    #[inline(always)]
+    fn map_typed_part_at(
+        &self,
+        i: u32,
+        mut f: impl FnMut(
+            Option<&Isometry<Real>>,
+            &Self::PartShape,
+            Option<&Self::PartNormalConstraints>,
+        ),
+    ) {
+        let tetrahedron = self.tetrahedron(i);
+        f(None, &tetrahedron, None)
+    }
+
+    #[inline(always)]
+    fn map_untyped_part_at(
+        &self,
+        i: u32,
+        mut f: impl FnMut(Option<&Isometry<Real>>, &dyn Shape, Option<&dyn NormalConstraints>),
+    ) {
+        let tetrahedron = self.tetrahedron(i);
+        f(None, &tetrahedron, None)
+    }
+
+    fn typed_qbvh(&self) -> &Qbvh<u32> {
+        &self.qbvh
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::mass_properties::MassProperties;
+    use crate::math::{Isometry, Point, Real, Vector};
+    use crate::query::{
+        self, ContactManifold, DefaultQueryDispatcher, PersistentQueryDispatcher, PointQuery, Ray,
+        RayCast,
+    };
+    use crate::shape::{Ball, Shape, TetMesh, TetMeshBuilderError};
+    use alloc::{vec, vec::Vec};
+    use approx::assert_relative_eq;
+
+    /// The unit cube `[0, 1]³` split into five tetrahedra.
+    fn unit_cube() -> TetMesh {
+        let vertices = (0..8)
+            .map(|i| Point::new((i & 1) as Real, ((i >> 1) & 1) as Real, (i >> 2) as Real))
+            .collect();
+        let indices = vec![
+            [0, 1, 2, 4],
+            [3, 2, 1, 7],
+            [5, 1, 4, 7],
+            [6, 4, 2, 7],
+            [1, 2, 4, 7],
+        ];
+        TetMesh::new(vertices, indices).unwrap()
+    }
+
+    #[test]
+    fn tetmesh_boundary_and_mass_properties() {
+        let mesh = unit_cube();
+        assert_eq!(mesh.num_tetrahedra(), 5);
+        assert_eq!(mesh.boundary().indices().len(), 12);
+        assert_eq!(
+            TetMesh::new(Vec::new(), Vec::new()).err(),
+            Some(TetMeshBuilderError::EmptyIndices)
+        );
+
+        let mprops = mesh.mass_properties(2.0);
+        let expected = MassProperties::from_cuboid(2.0, Vector::repeat(0.5));
+        assert_relative_eq!(mprops.mass(), expected.mass(), epsilon = 1.0e-5);
+        assert_relative_eq!(
+            mprops.local_com,
+            Point::new(0.5, 0.5, 0.5),
+            epsilon = 1.0e-5
+        );
+        assert_relative_eq!(
+            mprops.reconstruct_inertia_matrix(),
+            expected.reconstruct_inertia_matrix(),
+            epsilon = 1.0e-5
+        );
+    }
+
+    #[test]
+    fn tetmesh_point_and_ray_queries() {
+        let mesh = unit_cube();
+        let center = Point::new(0.5, 0.6, 0.5);
+        assert!(mesh.contains_local_point(&center));
+        assert!(!mesh.contains_local_point(&Point::new(1.5, 0.5, 0.5)));
+
+        let proj = mesh.project_local_point(&center, true);
+        assert!(proj.is_inside);
+        assert_eq!(proj.point, center);
+        let proj = mesh.project_local_point(&center, false);
+        assert!(proj.is_inside);
+        assert_relative_eq!(proj.point, Point::new(0.5, 1.0, 0.5), epsilon = 1.0e-5);
+
+        let ray = Ray::new(center, Vector::x());
+        assert_eq!(mesh.cast_local_ray(&ray, Real::MAX, true), Some(0.0));
+        let toi = mesh.cast_local_ray(&ray, Real::MAX, false).unwrap();
+        assert_relative_eq!(toi, 0.5, epsilon = 1.0e-5);
+
+        let ray = Ray::new(Point::new(-1.0, 0.5, 0.3), Vector::x());
+        let hit = mesh
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+            .unwrap();
+        assert_relative_eq!(hit.time_of_impact, 1.0, epsilon = 1.0e-5);
+        assert_relative_eq!(hit.normal, -Vector::x(), epsilon = 1.0e-5);
+    }
+
+    #[test]
+    fn tetmesh_contacts() {
+        let mesh = unit_cube();
+        let ball = Ball::new(0.5);
+        let pos12 = Isometry::translation(0.3, 1.4, 0.6);
+
+        let contact = query::contact(&Isometry::identity(), &mesh, &pos12, &ball, 0.0)
+            .unwrap()
+            .unwrap();
+        assert_relative_eq!(contact.dist, -0.1, epsilon = 1.0e-5);
+        assert_relative_eq!(*contact.normal1, Vector::y(), epsilon = 1.0e-5);
+
+        let dispatcher = DefaultQueryDispatcher;
+        let mut manifolds: Vec<ContactManifold<(), ()>> = Vec::new();
+        dispatcher
+            .contact_manifolds(&pos12, &mesh, &ball, 0.0, &mut manifolds, &mut None)
+            .unwrap();
+        assert!(!manifolds.is_empty());
+        assert!(manifolds.iter().all(|m| m.subshape1 < 5));
+    }
+}
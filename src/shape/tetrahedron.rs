@@ -1,10 +1,10 @@
 //! Definition of the tetrahedron shape.
 
-use crate::math::{Matrix, Point, Real};
-use crate::shape::{Segment, Triangle};
+use crate::math::{Isometry, Matrix, Point, Real, Vector};
+use crate::shape::{FeatureId, PackedFeatureId, PolygonalFeature, Segment, SupportMap, Triangle};
 use crate::utils;
 use core::mem;
-use na::Matrix3;
+use na::{Matrix3, Unit};
 
 #[cfg(all(feature = "dim2", not(feature = "std")))]
 use na::ComplexField; // for .abs()
@@ -116,6 +116,29 @@ impl Tetrahedron {
         unsafe { mem::transmute(arr) }
     }
 
+    /// Computes a scaled version of this tetrahedron.
+    #[inline]
+    pub fn scaled(self, scale: &Vector<Real>) -> Self {
+        Self::new(
+            na::Scale::from(*scale) * self.a,
+            na::Scale::from(*scale) * self.b,
+            na::Scale::from(*scale) * self.c,
+            na::Scale::from(*scale) * self.d,
+        )
+    }
+
+    /// Returns a new tetrahedron with vertices transformed by `m`.
+    #[inline]
+    pub fn transformed(&self, m: &Isometry<Real>) -> Self {
+        Self::new(m * self.a, m * self.b, m * self.c, m * self.d)
+    }
+
+    /// The four vertices of this tetrahedron.
+    #[inline]
+    pub fn vertices(&self) -> &[Point<Real>; 4] {
+        unsafe { mem::transmute(self) }
+    }
+
     /// Returns the i-th face of this tetrahedron.
     ///
     /// The 0-th face is the triangle ABC.
@@ -148,6 +171,91 @@ impl Tetrahedron {
         }
     }
 
+    /// Returns the indices of the vertices of the i-th face of this tetrahedron, ordered such
+    /// that the face’s normal (following the right-hand rule) points outward.
+    ///
+    /// The faces are numbered the same way as with [`Tetrahedron::face_ids`].
+    pub fn outward_face_ids(&self, i: u32) -> [u32; 3] {
+        let (i1, i2, i3) = Self::face_ids(i);
+        let pts = self.vertices();
+        let opposite = pts[6 - (i1 + i2 + i3) as usize];
+        let normal =
+            (pts[i2 as usize] - pts[i1 as usize]).cross(&(pts[i3 as usize] - pts[i1 as usize]));
+
+        if normal.dot(&(opposite - pts[i1 as usize])) > 0.0 {
+            [i1, i3, i2]
+        } else {
+            [i1, i2, i3]
+        }
+    }
+
+    /// The outward normal of the i-th face of this tetrahedron.
+    ///
+    /// Returns `None` if the face is degenerate.
+    pub fn face_normal(&self, i: u32) -> Option<Unit<Vector<Real>>> {
+        let [i1, i2, i3] = self.outward_face_ids(i);
+        let pts = self.vertices();
+        Triangle::new(pts[i1 as usize], pts[i2 as usize], pts[i3 as usize]).normal()
+    }
+
+    /// The normal of the given feature of this tetrahedron.
+    ///
+    /// Only face features have a normal.
+    pub fn feature_normal(&self, feature: FeatureId) -> Option<Unit<Vector<Real>>> {
+        match feature {
+            FeatureId::Face(i) if i < 4 => self.face_normal(i),
+            _ => None,
+        }
+    }
+
+    /// Return the face of this tetrahedron with an outward normal that maximizes
+    /// the dot product with `dir`.
+    pub fn support_face(&self, dir: Vector<Real>) -> PolygonalFeature {
+        let mut best = 0;
+        let mut best_dot = -Real::MAX;
+
+        for i in 0..4 {
+            if let Some(normal) = self.face_normal(i) {
+                let dot = normal.dot(&dir);
+                if dot > best_dot {
+                    best = i;
+                    best_dot = dot;
+                }
+            }
+        }
+
+        let ids = self.outward_face_ids(best);
+        let pts = self.vertices();
+        let edge = |i: usize| Self::edge_id(ids[i], ids[(i + 1) % 3]);
+
+        PolygonalFeature {
+            vertices: [
+                pts[ids[0] as usize],
+                pts[ids[1] as usize],
+                pts[ids[2] as usize],
+                pts[ids[2] as usize],
+            ],
+            vids: PackedFeatureId::vertices([ids[0], ids[1], ids[2], ids[2]]),
+            eids: PackedFeatureId::edges([edge(0), edge(1), edge(2), edge(2)]),
+            fid: PackedFeatureId::face(best),
+            num_vertices: 3,
+        }
+    }
+
+    /// The index of the edge joining the vertices with indices `i` and `j`.
+    ///
+    /// The edges are numbered the same way as with [`Tetrahedron::edge_ids`].
+    fn edge_id(i: u32, j: u32) -> u32 {
+        match (i.min(j), i.max(j)) {
+            (0, 1) => 0,
+            (0, 2) => 1,
+            (0, 3) => 2,
+            (1, 2) => 3,
+            (1, 3) => 4,
+            _ => 5,
+        }
+    }
+
     /// Returns the i-th edge of this tetrahedron.
     ///
     /// The 0-th edge is the segment AB.
@@ -237,3 +345,22 @@ impl Tetrahedron {
         utils::center(&[self.a, self.b, self.c, self.d])
     }
 }
+
+impl SupportMap for Tetrahedron {
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<Real>) -> Point<Real> {
+        let pts = self.vertices();
+        let mut best = 0;
+        let mut best_dot = pts[0].coords.dot(dir);
+
+        for (i, pt) in pts.iter().enumerate().skip(1) {
+            let dot = pt.coords.dot(dir);
+            if dot > best_dot {
+                best = i;
+                best_dot = dot;
+            }
+        }
+
+        pts[best]
+    }
+}
@@ -0,0 +1,225 @@
+//! Definition of the torus shape.
+
+use crate::math::{Point, Real, Vector};
+use na::{self, Unit};
+
+#[cfg(feature = "alloc")]
+use either::Either;
+
+#[cfg(feature = "rkyv")]
+use rkyv::{bytecheck, CheckBytes};
+
+/// A torus with its revolution axis aligned with the `y` axis.
+///
+/// The torus is the set of points located at a distance smaller than `minor_radius` from its
+/// core circle, i.e., the circle with radius `major_radius` centered at the origin and lying on
+/// the `xz` plane. Unlike most other primitive shapes, a torus isn’t convex.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize, CheckBytes),
+    archive(as = "Self")
+)]
+#[derive(PartialEq, Debug, Copy, Clone)]
+#[repr(C)]
+pub struct Torus {
+    /// The radius of the torus’ core circle.
+    pub major_radius: Real,
+    /// The radius of the torus’ tube.
+    pub minor_radius: Real,
+}
+
+impl Torus {
+    /// Creates a new torus.
+    ///
+    /// # Arguments:
+    /// * `major_radius` - the distance between the torus’ center and the center of its tube.
+    /// * `minor_radius` - the radius of the torus’ tube.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `minor_radius` isn’t positive, or if it is greater than `major_radius` (in which
+    /// case the torus would self-intersect).
+    pub fn new(major_radius: Real, minor_radius: Real) -> Torus {
+        assert!(
+            minor_radius > 0.0 && minor_radius <= major_radius,
+            "The minor radius of a torus must be positive and not greater than its major radius."
+        );
+
+        Torus {
+            major_radius,
+            minor_radius,
+        }
+    }
+
+    /// Computes a scaled version of this torus.
+    ///
+    /// If the scaling factor is non-uniform, then it can’t be represented as
+    /// torus. Instead, a triangle mesh approximation (with `nsubdivs` subdivisions
+    /// along both the core circle and the tube) is returned. Returns `None` if that
+    /// approximation is degenerate (for example if the scaling factor along one axis is zero).
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn scaled(
+        self,
+        scale: &Vector<Real>,
+        nsubdivs: u32,
+    ) -> Option<Either<Self, super::TriMesh>> {
+        let scale = scale.abs();
+
+        if scale.x != scale.y || scale.x != scale.z {
+            // The scaled shape isn’t a torus.
+            let (mut vtx, idx) = self.to_trimesh(nsubdivs, nsubdivs);
+            vtx.iter_mut()
+                .for_each(|pt| pt.coords = pt.coords.component_mul(&scale));
+            super::TriMesh::new(vtx, idx).ok().map(Either::Right)
+        } else if scale.x == 0.0 {
+            None
+        } else {
+            Some(Either::Left(Self::new(
+                self.major_radius * scale.x,
+                self.minor_radius * scale.x,
+            )))
+        }
+    }
+
+    /// The point of this torus’ core circle that is the closest to `pt`.
+    ///
+    /// If `pt` lies on the revolution axis, all the points of the core circle are equally close
+    /// and the one located on the positive `x` axis is returned.
+    #[inline]
+    pub fn closest_core_point(&self, pt: &Point<Real>) -> Point<Real> {
+        let radial = Vector::new(pt.x, 0.0, pt.z);
+        let radial_norm = radial.norm();
+
+        if radial_norm > crate::math::DEFAULT_EPSILON {
+            Point::from(radial * (self.major_radius / radial_norm))
+        } else {
+            Point::new(self.major_radius, 0.0, 0.0)
+        }
+    }
+
+    /// The signed distance between `pt` and the boundary of this torus.
+    ///
+    /// The distance is negative if `pt` is inside of the torus.
+    #[inline]
+    pub fn signed_distance(&self, pt: &Point<Real>) -> Real {
+        na::distance(pt, &self.closest_core_point(pt)) - self.minor_radius
+    }
+
+    /// The outward normal of this torus’ boundary at the given point.
+    ///
+    /// Returns `None` if `pt` lies on the torus’ core circle.
+    #[inline]
+    pub fn normal_at_point(&self, pt: &Point<Real>) -> Option<Unit<Vector<Real>>> {
+        Unit::try_new(
+            pt - self.closest_core_point(pt),
+            crate::math::DEFAULT_EPSILON,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::bounding_volume::{Aabb, BoundingVolume};
+    use crate::math::{Isometry, Vector};
+    use crate::query::{self, ContactManifold, DefaultQueryDispatcher, PersistentQueryDispatcher};
+    use crate::shape::{Ball, Capsule, Cuboid, Shape, Torus};
+    use alloc::vec::Vec;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn torus_bounding_volumes_and_trimesh() {
+        let torus = Torus::new(2.0, 0.5);
+        let pos = Isometry::new(Vector::new(1.0, 2.0, 3.0), Vector::new(0.7, -0.2, 0.3));
+        let aabb = torus.compute_aabb(&pos);
+        let (vtx, _) = torus.to_trimesh(64, 32);
+        let mut mesh_aabb = Aabb::new_invalid();
+
+        for pt in &vtx {
+            // All the vertices of the discretized torus lie on its boundary.
+            assert_relative_eq!(torus.signed_distance(pt), 0.0, epsilon = 1.0e-5);
+            mesh_aabb.take_point(pos * pt);
+        }
+
+        // The exact Aabb is tight around the (finely) discretized torus.
+        assert!(aabb.contains(&mesh_aabb));
+        assert_relative_eq!(aabb.mins, mesh_aabb.mins, epsilon = 1.0e-2);
+        assert_relative_eq!(aabb.maxs, mesh_aabb.maxs, epsilon = 1.0e-2);
+
+        assert!(torus.scaled(&Vector::repeat(-2.0), 10).unwrap().is_left());
+        assert!(torus
+            .scaled(&Vector::new(1.0, 2.0, 1.0), 10)
+            .unwrap()
+            .is_right());
+    }
+
+    #[test]
+    fn torus_contacts() {
+        let torus = Torus::new(2.0, 0.5);
+        let dispatcher = DefaultQueryDispatcher;
+        let mut manifolds: Vec<ContactManifold<(), ()>> = Vec::new();
+
+        // A ball resting on top of the tube.
+        let ball = Ball::new(0.25);
+        let pos12 = Isometry::translation(-2.0, 0.7, 0.0);
+        let contact = query::contact(&Isometry::identity(), &torus, &pos12, &ball, 0.0)
+            .unwrap()
+            .unwrap();
+        assert_relative_eq!(contact.dist, -0.05, epsilon = 1.0e-5);
+        assert_relative_eq!(*contact.normal1, Vector::y(), epsilon = 1.0e-5);
+
+        // A ball going through the hole of the torus is not in contact.
+        let ball = Ball::new(1.0);
+        let pos12 = Isometry::translation(0.0, 0.0, 0.0);
+        assert!(
+            query::contact(&Isometry::identity(), &torus, &pos12, &ball, 0.0)
+                .unwrap()
+                .is_none()
+        );
+
+        // A capsule lying across the torus touches both sides of the tube.
+        let capsule = Capsule::new_x(3.0, 0.2);
+        let pos12 = Isometry::translation(0.0, 0.6, 0.0);
+        dispatcher
+            .contact_manifolds(&pos12, &torus, &capsule, 0.0, &mut manifolds, &mut None)
+            .unwrap();
+        assert_eq!(manifolds.len(), 2);
+
+        for manifold in &manifolds {
+            assert_eq!(manifold.points.len(), 1);
+            assert_relative_eq!(manifold.points[0].dist, -0.1, epsilon = 1.0e-4);
+            assert_relative_eq!(manifold.local_n1, Vector::y(), epsilon = 1.0e-4);
+            let p1 = manifold.points[0].local_p1;
+            assert_relative_eq!(p1.coords.xz().norm(), 2.0, epsilon = 1.0e-4);
+        }
+
+        // A cuboid (with rotation) sitting inside the torus’ tube.
+        let cuboid = Cuboid::new(Vector::repeat(0.2));
+        let pos12 = Isometry::new(Vector::new(0.0, 0.0, 2.4), Vector::y() * 0.3);
+        let contact = query::contact(&Isometry::identity(), &torus, &pos12, &cuboid, 0.0)
+            .unwrap()
+            .unwrap();
+        assert!(contact.dist < 0.0);
+        dispatcher
+            .contact_manifolds(
+                &pos12.inverse(),
+                &cuboid,
+                &torus,
+                0.0,
+                &mut manifolds,
+                &mut None,
+            )
+            .unwrap();
+        assert_eq!(manifolds.len(), 1);
+        assert_relative_eq!(manifolds[0].points[0].dist, contact.dist, epsilon = 1.0e-4);
+
+        // Far away shapes don’t generate any contact.
+        let pos12 = Isometry::translation(10.0, 0.0, 0.0);
+        dispatcher
+            .contact_manifolds(&pos12, &torus, &cuboid, 0.0, &mut manifolds, &mut None)
+            .unwrap();
+        assert!(manifolds.is_empty());
+    }
+}
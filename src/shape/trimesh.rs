@@ -1,10 +1,11 @@
 use crate::bounding_volume::Aabb;
 use crate::math::{Isometry, Point, Real, Vector};
-use crate::partitioning::Qbvh;
+use crate::partitioning::{Qbvh, QbvhUpdateWorkspace};
 use crate::shape::{FeatureId, Shape, Triangle, TrianglePseudoNormals, TypedSimdCompositeShape};
 use crate::utils::HashablePartialEq;
 use alloc::{vec, vec::Vec};
 use core::fmt;
+use core::ops::Range;
 #[cfg(feature = "dim3")]
 use {crate::shape::Cuboid, crate::utils::SortedPair, na::Unit};
 
@@ -141,15 +142,28 @@ impl TriMeshConnectedComponents {
     /// The `mesh` must be the one used to generate `self`, otherwise it might panic or produce an
     /// unexpected result.
     ///
-    /// All the meshes are constructed with the given `flags`.
+    /// All the meshes are constructed with the given `flags`, and their triangles keep the tags they
+    /// have in `mesh`.
     pub fn to_meshes(
         &self,
         mesh: &TriMesh,
         flags: TriMeshFlags,
     ) -> Vec<Result<TriMesh, TriMeshBuilderError>> {
+        let tags = self.ranges.windows(2).filter(|r| r[1] > r[0]).map(|r| {
+            if mesh.tags.is_empty() {
+                vec![]
+            } else {
+                self.grouped_faces[r[0]..r[1]]
+                    .iter()
+                    .map(|fid| mesh.triangle_tag(*fid))
+                    .collect()
+            }
+        });
+
         self.to_mesh_buffers(mesh)
             .into_iter()
-            .map(|(vtx, idx)| TriMesh::with_flags(vtx, idx, flags))
+            .zip(tags)
+            .map(|((vtx, idx), tags)| TriMesh::with_flags_and_tags(vtx, idx, flags, tags))
             .collect()
     }
 }
@@ -292,6 +306,9 @@ pub struct TriMesh {
     topology: Option<TriMeshTopology>,
     connected_components: Option<TriMeshConnectedComponents>,
     flags: TriMeshFlags,
+    // The per-triangle tags. Empty if the mesh isn’t tagged, in which case all the tags are zero.
+    #[cfg_attr(feature = "serde-serialize", serde(default))]
+    tags: Vec<u32>,
 }
 
 impl fmt::Debug for TriMesh {
@@ -314,11 +331,30 @@ impl TriMesh {
         vertices: Vec<Point<Real>>,
         indices: Vec<[u32; 3]>,
         flags: TriMeshFlags,
+    ) -> Result<Self, TriMeshBuilderError> {
+        Self::with_flags_and_tags(vertices, indices, flags, vec![])
+    }
+
+    /// Creates a new triangle mesh from a vertex buffer, an index buffer, flags controlling
+    /// optional properties, and per-triangle tags.
+    ///
+    /// The `i`-th triangle is given the tag `tags[i]`, or zero if `tags` has less than `i + 1`
+    /// elements. If the `flags` lead to the removal of some triangles, the tags of the
+    /// remaining triangles are preserved. See [`Self::triangle_tag`] for details on tags.
+    pub fn with_flags_and_tags(
+        vertices: Vec<Point<Real>>,
+        indices: Vec<[u32; 3]>,
+        flags: TriMeshFlags,
+        mut tags: Vec<u32>,
     ) -> Result<Self, TriMeshBuilderError> {
         if indices.is_empty() {
             return Err(TriMeshBuilderError::EmptyIndices);
         }
 
+        if !tags.is_empty() {
+            tags.resize(indices.len(), 0);
+        }
+
         let mut result = Self {
             qbvh: Qbvh::new(),
             vertices,
@@ -328,6 +364,7 @@ impl TriMesh {
             topology: None,
             connected_components: None,
             flags: TriMeshFlags::empty(),
+            tags,
         };
 
         let _ = result.set_flags(flags);
@@ -415,6 +452,7 @@ impl TriMesh {
             flags: _,
             #[cfg(feature = "dim3")]
             pseudo_normals,
+            tags,
         } = self;
         let sz_qbvh = qbvh.heap_memory_size();
         let sz_vertices = vertices.capacity() * size_of::<Point<Real>>();
@@ -445,6 +483,7 @@ impl TriMesh {
                     + c.ranges.capacity() * size_of::<usize>()
             })
             .unwrap_or(0);
+        let sz_tags = tags.capacity() * size_of::<u32>();
 
         sz_qbvh
             + sz_vertices
@@ -452,6 +491,7 @@ impl TriMesh {
             + sz_pseudo_normals
             + sz_topology
             + sz_connected_components
+            + sz_tags
     }
 
     /// Transforms in-place the vertices of this triangle mesh.
@@ -508,11 +548,21 @@ impl TriMesh {
             topology: self.topology,
             connected_components: self.connected_components,
             flags: self.flags,
+            tags: self.tags,
         }
     }
 
     /// Appends a second triangle mesh to this triangle mesh.
+    ///
+    /// The triangles keep their tags. If only one of the meshes is tagged, the triangles of the
+    /// other are given a zero tag.
     pub fn append(&mut self, rhs: &TriMesh) {
+        if !self.tags.is_empty() || !rhs.tags.is_empty() {
+            self.tags.resize(self.indices.len(), 0);
+            self.tags
+                .extend((0..rhs.indices.len() as u32).map(|i| rhs.triangle_tag(i)));
+        }
+
         let base_id = self.vertices.len() as u32;
         self.vertices.extend_from_slice(rhs.vertices());
         self.indices.extend(
@@ -523,7 +573,380 @@ impl TriMesh {
 
         let vertices = core::mem::take(&mut self.vertices);
         let indices = core::mem::take(&mut self.indices);
-        *self = TriMesh::with_flags(vertices, indices, self.flags).unwrap();
+        let tags = core::mem::take(&mut self.tags);
+        *self = TriMesh::with_flags_and_tags(vertices, indices, self.flags, tags).unwrap();
+    }
+
+    /// Inserts new triangles into this triangle mesh, without rebuilding it from scratch.
+    ///
+    /// The `new_vertices` are appended to the vertex buffer of this mesh and the `new_indices`
+    /// to its index buffer. Unlike [`TriMesh::append`], the new indices refer to the whole
+    /// vertex buffer (including the new vertices), so the new triangles can share vertices with
+    /// the existing ones. The new triangles are inserted incrementally into the [`Qbvh`], which
+    /// is refitted and rebalanced if `rebalance` is `true`. New leaves are attached close to the
+    /// root of the tree, so it should be rebalanced once in a while if triangles are inserted
+    /// repeatedly (e.g. every frame).
+    ///
+    /// If they were computed, the pseudo-normals of the mesh are updated incrementally. The
+    /// half-edge topology and the connected components are discarded and their flags are
+    /// removed from [`TriMesh::flags`]: they can be recomputed with [`TriMesh::set_flags`].
+    /// Note that the new vertices are not merged with existing duplicates, and no degenerate or
+    /// duplicate triangle is removed. If this mesh is tagged, the new triangles are given a zero
+    /// tag, which can be modified with [`Self::tags_mut`].
+    ///
+    /// Returns the range of ids of the inserted triangles.
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the new indices is out of the bounds of the vertex buffer. The mesh is
+    /// left unmodified in this case.
+    pub fn insert_triangles(
+        &mut self,
+        new_vertices: &[Point<Real>],
+        new_indices: &[[u32; 3]],
+        workspace: &mut QbvhUpdateWorkspace,
+        rebalance: bool,
+    ) -> Range<u32> {
+        let num_vertices = self.vertices.len() + new_vertices.len();
+        assert!(
+            new_indices
+                .iter()
+                .flatten()
+                .all(|i| (*i as usize) < num_vertices),
+            "Triangle index out of the bounds of the vertex buffer."
+        );
+
+        self.vertices.extend_from_slice(new_vertices);
+
+        self.invalidate_topology_and_connected_components();
+
+        #[cfg(feature = "dim3")]
+        if let Some(pseudo_normals) = &mut self.pseudo_normals {
+            pseudo_normals
+                .vertices_pseudo_normal
+                .resize(self.vertices.len(), Vector::zeros());
+        }
+
+        let first_id = self.indices.len() as u32;
+        #[cfg(feature = "dim3")]
+        let mut neighbors = Vec::new();
+
+        for idx in new_indices {
+            let tri_id = self.indices.len() as u32;
+            self.indices.push(*idx);
+
+            #[cfg(feature = "dim3")]
+            if let Some(pseudo_normals) = &mut self.pseudo_normals {
+                pseudo_normals
+                    .edges_pseudo_normal
+                    .push([Vector::zeros(); 3]);
+
+                // The triangles inserted so far aren’t in the Qbvh yet.
+                neighbors.clear();
+                self.qbvh
+                    .intersect_aabb(&self.triangle(tri_id).local_aabb(), &mut neighbors);
+                neighbors.retain(|id| *id < first_id);
+                neighbors.extend(first_id..tri_id);
+                self.update_pseudo_normals(tri_id, &neighbors, 1.0);
+            }
+
+            self.qbvh.pre_update_or_insert(tri_id);
+        }
+
+        if !self.tags.is_empty() {
+            self.tags.resize(self.indices.len(), 0);
+        }
+
+        self.refit_qbvh(workspace, rebalance);
+        first_id..self.indices.len() as u32
+    }
+
+    /// Removes the `i`-th triangle of this triangle mesh, without rebuilding it from scratch.
+    ///
+    /// Like [`Vec::swap_remove`], the last triangle of this mesh is moved to the index `i`, so
+    /// its id changes from `self.num_triangles() - 1` to `i`, and so does its tag. The vertex buffer
+    /// isn’t modified.
+    /// The triangle is removed incrementally from the [`Qbvh`], which is refitted and
+    /// rebalanced if `rebalance` is `true`.
+    ///
+    /// If they were computed, the pseudo-normals of the mesh are updated incrementally. The
+    /// half-edge topology and the connected components are discarded and their flags are
+    /// removed from [`TriMesh::flags`]: they can be recomputed with [`TriMesh::set_flags`].
+    ///
+    /// Returns the indices of the removed triangle.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is out of the bounds of the index buffer.
+    pub fn remove_triangle(
+        &mut self,
+        i: u32,
+        workspace: &mut QbvhUpdateWorkspace,
+        rebalance: bool,
+    ) -> [u32; 3] {
+        let last_id = self.indices.len() as u32 - 1;
+        assert!(i <= last_id, "Triangle index out of bounds.");
+
+        self.invalidate_topology_and_connected_components();
+
+        #[cfg(feature = "dim3")]
+        if self.pseudo_normals.is_some() {
+            let mut neighbors = Vec::new();
+            self.qbvh
+                .intersect_aabb(&self.triangle(i).local_aabb(), &mut neighbors);
+            self.update_pseudo_normals(i, &neighbors, -1.0);
+
+            if let Some(pseudo_normals) = &mut self.pseudo_normals {
+                let _ = pseudo_normals.edges_pseudo_normal.swap_remove(i as usize);
+            }
+        }
+
+        let removed = self.indices.swap_remove(i as usize);
+        if !self.tags.is_empty() {
+            let _ = self.tags.swap_remove(i as usize);
+        }
+        let _ = self.qbvh.remove(last_id);
+
+        if i != last_id {
+            // The last triangle moved to the index `i`.
+            self.qbvh.pre_update_or_insert(i);
+        }
+
+        self.refit_qbvh(workspace, rebalance);
+        removed
+    }
+
+    /// Replaces the vertices of this triangle mesh, typically to deform it.
+    ///
+    /// Only the triangles with at least one moved vertex are updated: their leaves in the
+    /// [`Qbvh`] are refitted (and the tree is rebalanced if `rebalance` is `true`), and the
+    /// pseudo-normals around the moved vertices are recomputed if they were computed (see
+    /// [`TriMeshFlags::ORIENTED`]). The index buffer, half-edge topology, and connected
+    /// components are unaffected.
+    ///
+    /// Rebalancing the tree keeps queries efficient if the mesh deforms significantly, at the
+    /// cost of a slower update.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `vertices` doesn’t have the same length as the vertex buffer of this mesh.
+    pub fn set_vertices(
+        &mut self,
+        vertices: &[Point<Real>],
+        workspace: &mut QbvhUpdateWorkspace,
+        rebalance: bool,
+    ) {
+        assert_eq!(
+            vertices.len(),
+            self.vertices.len(),
+            "The number of vertices must not change."
+        );
+
+        let moved: Vec<bool> = self
+            .vertices
+            .iter()
+            .zip(vertices.iter())
+            .map(|(old, new)| old != new)
+            .collect();
+        self.vertices.copy_from_slice(vertices);
+
+        for (i, idx) in self.indices.iter().enumerate() {
+            if idx.iter().any(|vid| moved[*vid as usize]) {
+                self.qbvh.pre_update_or_insert(i as u32);
+            }
+        }
+
+        #[cfg(feature = "dim3")]
+        self.update_pseudo_normals_around(&moved);
+
+        self.refit_qbvh(workspace, rebalance);
+    }
+
+    /// The mutable vertex buffer of this mesh.
+    ///
+    /// After the vertices are modified, [`TriMesh::refit_bvh`] must be called to update the
+    /// [`Qbvh`] and pseudo-normals of this mesh. If only some of the vertices move, consider
+    /// [`TriMesh::set_vertices`] which only updates the triangles around them.
+    pub fn vertices_mut(&mut self) -> &mut [Point<Real>] {
+        &mut self.vertices
+    }
+
+    /// Updates this triangle mesh after its vertices were modified with
+    /// [`TriMesh::vertices_mut`].
+    ///
+    /// The leaves of the [`Qbvh`] are refitted instead of rebuilding the whole tree, and the
+    /// tree is rebalanced if `rebalance` is `true`. The pseudo-normals are recomputed if they
+    /// were computed (see [`TriMeshFlags::ORIENTED`]).
+    pub fn refit_bvh(&mut self, workspace: &mut QbvhUpdateWorkspace, rebalance: bool) {
+        for i in 0..self.indices.len() as u32 {
+            self.qbvh.pre_update_or_insert(i);
+        }
+
+        #[cfg(feature = "dim3")]
+        if self.pseudo_normals.is_some() {
+            self.compute_pseudo_normals();
+        }
+
+        self.refit_qbvh(workspace, rebalance);
+    }
+
+    /// Discards the half-edge topology and connected components, which can’t be updated
+    /// incrementally, and clears their flags.
+    fn invalidate_topology_and_connected_components(&mut self) {
+        self.topology = None;
+        self.connected_components = None;
+        self.flags
+            .remove(TriMeshFlags::HALF_EDGE_TOPOLOGY | TriMeshFlags::CONNECTED_COMPONENTS);
+    }
+
+    /// Recomputes the pseudo-normals affected by the motion of the vertices flagged in `moved`.
+    ///
+    /// These are the pseudo-normals of the vertices sharing a triangle with a moved vertex,
+    /// and of the edges of the triangles with a moved vertex.
+    #[cfg(feature = "dim3")]
+    fn update_pseudo_normals_around(&mut self, moved: &[bool]) {
+        let vertices = &self.vertices;
+        let indices = &self.indices;
+        let Some(pseudo_normals) = &mut self.pseudo_normals else {
+            return;
+        };
+        let edges = |idx: &[u32; 3]| {
+            [
+                SortedPair::new(idx[0], idx[1]),
+                SortedPair::new(idx[1], idx[2]),
+                SortedPair::new(idx[2], idx[0]),
+            ]
+        };
+
+        let mut affected_vertices = vec![false; vertices.len()];
+        let mut affected_edges = HashMap::default();
+
+        for idx in indices {
+            if idx.iter().any(|vid| moved[*vid as usize]) {
+                for vid in idx {
+                    affected_vertices[*vid as usize] = true;
+                }
+
+                for edge in edges(idx) {
+                    let _ = affected_edges.insert(edge, Vector::zeros());
+                }
+            }
+        }
+
+        for (n, affected) in pseudo_normals
+            .vertices_pseudo_normal
+            .iter_mut()
+            .zip(affected_vertices.iter())
+        {
+            if *affected {
+                *n = Vector::zeros();
+            }
+        }
+
+        // Accumulate the contributions of all the triangles touching an affected vertex.
+        for idx in indices {
+            if !idx.iter().any(|vid| affected_vertices[*vid as usize]) {
+                continue;
+            }
+
+            let tri = Triangle::new(
+                vertices[idx[0] as usize],
+                vertices[idx[1] as usize],
+                vertices[idx[2] as usize],
+            );
+
+            if let Some(n) = tri.normal() {
+                let angles = [
+                    (tri.b - tri.a).angle(&(tri.c - tri.a)),
+                    (tri.a - tri.b).angle(&(tri.c - tri.b)),
+                    (tri.b - tri.c).angle(&(tri.a - tri.c)),
+                ];
+
+                for (vid, angle) in idx.iter().zip(angles.iter()) {
+                    if affected_vertices[*vid as usize] {
+                        pseudo_normals.vertices_pseudo_normal[*vid as usize] += *n * *angle;
+                    }
+                }
+
+                for edge in edges(idx) {
+                    if let Some(edge_n) = affected_edges.get_mut(&edge) {
+                        *edge_n += *n;
+                    }
+                }
+            }
+        }
+
+        for (idx, edges_n) in indices
+            .iter()
+            .zip(pseudo_normals.edges_pseudo_normal.iter_mut())
+        {
+            for (edge, edge_n) in edges(idx).iter().zip(edges_n.iter_mut()) {
+                if let Some(n) = affected_edges.get(edge) {
+                    *edge_n = *n;
+                }
+            }
+        }
+    }
+
+    /// Adds (if `sign` is `1.0`) or subtracts (if `sign` is `-1.0`) the contribution of the
+    /// `tri_id`-th triangle to the pseudo-normals of its vertices and of the edges it shares
+    /// with the `neighbors` triangles.
+    ///
+    /// When adding, the contributions of the `neighbors` to the pseudo-normals of the edges
+    /// of the `tri_id`-th triangle are added too.
+    #[cfg(feature = "dim3")]
+    fn update_pseudo_normals(&mut self, tri_id: u32, neighbors: &[u32], sign: Real) {
+        let vertices = &self.vertices;
+        let indices = &self.indices;
+        let Some(pseudo_normals) = &mut self.pseudo_normals else {
+            return;
+        };
+        let triangle = |idx: &[u32; 3]| {
+            Triangle::new(
+                vertices[idx[0] as usize],
+                vertices[idx[1] as usize],
+                vertices[idx[2] as usize],
+            )
+        };
+
+        let idx = indices[tri_id as usize];
+        let tri = triangle(&idx);
+        let normal = tri.normal().map(|n| *n * sign);
+
+        if let Some(n) = normal {
+            let ang1 = (tri.b - tri.a).angle(&(tri.c - tri.a));
+            let ang2 = (tri.a - tri.b).angle(&(tri.c - tri.b));
+            let ang3 = (tri.b - tri.c).angle(&(tri.a - tri.c));
+
+            pseudo_normals.vertices_pseudo_normal[idx[0] as usize] += n * ang1;
+            pseudo_normals.vertices_pseudo_normal[idx[1] as usize] += n * ang2;
+            pseudo_normals.vertices_pseudo_normal[idx[2] as usize] += n * ang3;
+
+            if sign > 0.0 {
+                pseudo_normals.edges_pseudo_normal[tri_id as usize] = [n; 3];
+            }
+        }
+
+        for neighbor in neighbors.iter().filter(|id| **id != tri_id) {
+            let neighbor_idx = indices[*neighbor as usize];
+            let neighbor_normal = triangle(&neighbor_idx).normal();
+
+            for k in 0..3 {
+                let edge = SortedPair::new(idx[k], idx[(k + 1) % 3]);
+
+                for l in 0..3 {
+                    if SortedPair::new(neighbor_idx[l], neighbor_idx[(l + 1) % 3]) == edge {
+                        if let Some(n) = normal {
+                            pseudo_normals.edges_pseudo_normal[*neighbor as usize][l] += n;
+                        }
+
+                        if let Some(neighbor_n) = neighbor_normal.filter(|_| sign > 0.0) {
+                            pseudo_normals.edges_pseudo_normal[tri_id as usize][k] += *neighbor_n;
+                        }
+                    }
+                }
+            }
+        }
     }
 
     /// Create a `TriMesh` from a set of points assumed to describe a counter-clockwise non-convex polygon.
@@ -554,11 +977,30 @@ impl TriMesh {
             (i as u32, aabb)
         });
 
-        // NOTE: we apply no dilation factor because we won't
-        // update this tree dynamically.
+        // NOTE: we apply no dilation factor because the tree is
+        // refitted exactly whenever it is updated.
         self.qbvh.clear_and_rebuild(data, 0.0);
     }
 
+    /// Updates the [`Qbvh`] leaves modified since its last update, and optionally rebalances it.
+    fn refit_qbvh(&mut self, workspace: &mut QbvhUpdateWorkspace, rebalance: bool) {
+        let vertices = &self.vertices;
+        let indices = &self.indices;
+        let _ = self.qbvh.refit_tight(0.0, workspace, |i| {
+            let idx = indices[*i as usize];
+            Triangle::new(
+                vertices[idx[0] as usize],
+                vertices[idx[1] as usize],
+                vertices[idx[2] as usize],
+            )
+            .local_aabb()
+        });
+
+        if rebalance {
+            self.qbvh.rebalance(0.0, workspace);
+        }
+    }
+
     /// Reverse the orientation of the triangle mesh.
     pub fn reverse(&mut self) {
         self.indices.iter_mut().for_each(|idx| idx.swap(0, 1));
@@ -602,6 +1044,7 @@ impl TriMesh {
         let mut vtx_to_id = HashMap::default();
         let mut new_vertices = Vec::with_capacity(self.vertices.len());
         let mut new_indices = Vec::with_capacity(self.indices.len());
+        let mut new_tags = Vec::with_capacity(self.tags.len());
         let mut triangle_set = HashSet::default();
 
         fn resolve_coord_id(
@@ -622,7 +1065,7 @@ impl TriMesh {
             *id
         }
 
-        for t in self.indices.iter() {
+        for (tri_id, t) in self.indices.iter().enumerate() {
             let va = resolve_coord_id(
                 &self.vertices[t[0] as usize],
                 &mut vtx_to_id,
@@ -644,13 +1087,19 @@ impl TriMesh {
             let is_degenerate = va == vb || va == vc || vb == vc;
 
             if !is_degenerate || !delete_degenerate_triangles {
-                if delete_duplicate_triangles {
+                let is_new = if delete_duplicate_triangles {
                     let (c, b, a) = crate::utils::sort3(&va, &vb, &vc);
-                    if triangle_set.insert((*a, *b, *c)) {
-                        new_indices.push([va, vb, vc])
-                    }
+                    triangle_set.insert((*a, *b, *c))
                 } else {
+                    true
+                };
+
+                if is_new {
                     new_indices.push([va, vb, vc]);
+
+                    if let Some(tag) = self.tags.get(tri_id) {
+                        new_tags.push(*tag);
+                    }
                 }
             }
         }
@@ -659,6 +1108,7 @@ impl TriMesh {
 
         self.vertices = new_vertices;
         self.indices = new_indices;
+        self.tags = new_tags;
 
         // Vertices and indices changed: the pseudo-normals are no longer valid.
         #[cfg(feature = "dim3")]
@@ -755,11 +1205,13 @@ impl TriMesh {
     fn delete_bad_topology_triangles(&mut self) {
         let mut half_edge_set = HashSet::default();
         let mut deleted_any = false;
+        let mut kept = Vec::with_capacity(self.indices.len());
 
         // First, create three half-edges for each face.
         self.indices.retain(|idx| {
             if idx[0] == idx[1] || idx[0] == idx[2] || idx[1] == idx[2] {
                 deleted_any = true;
+                kept.push(false);
                 return false;
             }
 
@@ -767,6 +1219,7 @@ impl TriMesh {
                 let edge_key = (idx[k as usize], idx[(k as usize + 1) % 3]);
                 if half_edge_set.contains(&edge_key) {
                     deleted_any = true;
+                    kept.push(false);
                     return false;
                 }
             }
@@ -776,8 +1229,14 @@ impl TriMesh {
                 let _ = half_edge_set.insert(edge_key);
             }
 
+            kept.push(true);
             true
         });
+
+        if deleted_any && !self.tags.is_empty() {
+            let mut kept = kept.into_iter();
+            self.tags.retain(|_| kept.next().unwrap_or(false));
+        }
     }
 
     /// Computes half-edge topological information for this triangle mesh, based on its index buffer only.
@@ -1096,6 +1555,60 @@ impl TriMesh {
         &self.indices
     }
 
+    /// The per-triangle tags of this mesh, or an empty slice if it isn’t tagged.
+    ///
+    /// See [`Self::triangle_tag`] for details on tags.
+    pub fn tags(&self) -> &[u32] {
+        &self.tags
+    }
+
+    /// The mutable per-triangle tags of this mesh.
+    ///
+    /// If this mesh wasn’t tagged, all its triangles are given a zero tag first.
+    pub fn tags_mut(&mut self) -> &mut [u32] {
+        self.tags.resize(self.indices.len(), 0);
+        &mut self.tags
+    }
+
+    /// Sets the per-triangle tags of this mesh.
+    ///
+    /// The `i`-th triangle is given the tag `tags[i]`, or zero if `tags` has less than `i + 1`
+    /// elements. An empty `tags` removes all the tags of this mesh.
+    pub fn set_tags(&mut self, mut tags: Vec<u32>) {
+        if !tags.is_empty() {
+            tags.resize(self.indices.len(), 0);
+        }
+
+        self.tags = tags;
+    }
+
+    /// The tag of the `i`-th triangle of this mesh.
+    ///
+    /// Tags are arbitrary `u32` values attached to each triangle (e.g. material identifiers).
+    /// They are preserved by the operations building a new mesh from this one, like
+    /// [`Self::append`], [`Self::scaled`], or the mesh splitting and intersection methods.
+    /// Since the sub-shape ids of contact manifolds involving a triangle mesh are triangle ids,
+    /// this can be used to retrieve the tag of the triangle involved in a contact.
+    ///
+    /// Returns zero if this mesh isn’t tagged.
+    pub fn triangle_tag(&self, i: u32) -> u32 {
+        self.tags.get(i as usize).copied().unwrap_or_default()
+    }
+
+    /// Returns the tag of the triangle identified by the given feature.
+    ///
+    /// The feature must be a [`FeatureId::Face`], as reported by the ray-casts and
+    /// point-projections on this mesh (including backfaces). Returns `None` for any other
+    /// feature.
+    pub fn feature_tag(&self, feature: FeatureId) -> Option<u32> {
+        match feature {
+            FeatureId::Face(i) if (i as usize) < self.num_triangles() * 2 => {
+                Some(self.triangle_tag(i % self.num_triangles() as u32))
+            }
+            _ => None,
+        }
+    }
+
     /// Returns the topology information of this trimesh, if it has been computed.
     pub fn topology(&self) -> Option<&TriMeshTopology> {
         self.topology.as_ref()
@@ -1139,7 +1652,21 @@ impl TriMesh {
 impl From<crate::shape::HeightField> for TriMesh {
     fn from(heightfield: crate::shape::HeightField) -> Self {
         let (vtx, idx) = heightfield.to_trimesh();
-        TriMesh::new(vtx, idx).unwrap()
+        let mut tags = vec![];
+
+        if !heightfield.cells_tags().is_empty() {
+            // Same order as the triangles output by `HeightField::to_trimesh`.
+            for j in 0..heightfield.ncols() {
+                for i in 0..heightfield.nrows() {
+                    let (left, right) = heightfield.triangles_at(i, j);
+                    let tag = heightfield.cell_tag(i, j);
+                    tags.extend(left.map(|_| tag));
+                    tags.extend(right.map(|_| tag));
+                }
+            }
+        }
+
+        TriMesh::with_flags_and_tags(vtx, idx, TriMeshFlags::empty(), tags).unwrap()
     }
 }
 
@@ -1253,4 +1780,291 @@ mod test {
             assert_eq!(cc.indices.len(), idx.len());
         }
     }
+
+    #[test]
+    #[cfg(feature = "dim3")]
+    fn insert_and_remove_triangles() {
+        use crate::math::Point;
+        use crate::partitioning::QbvhUpdateWorkspace;
+        use crate::query::PointQuery;
+        use alloc::vec::Vec;
+
+        let (vtx, idx) = Cuboid::new(Vector::repeat(0.5)).to_trimesh();
+        let flags = TriMeshFlags::ORIENTED | TriMeshFlags::HALF_EDGE_TOPOLOGY;
+        let mut mesh = TriMesh::with_flags(vtx.clone(), idx.clone(), flags).unwrap();
+        let mut workspace = QbvhUpdateWorkspace::default();
+
+        // Remove the two triangles of the `+x` face of the cube.
+        let mut face: Vec<u32> = (0..mesh.num_triangles() as u32)
+            .filter(|i| mesh.triangle(*i).vertices().iter().all(|pt| pt.x == 0.5))
+            .collect();
+        face.sort_unstable();
+        let removed = [
+            mesh.remove_triangle(face[1], &mut workspace, false),
+            mesh.remove_triangle(face[0], &mut workspace, false),
+        ];
+        assert_eq!(mesh.num_triangles(), idx.len() - 2);
+        assert!(mesh.topology().is_none());
+        assert!(!mesh.flags().contains(TriMeshFlags::HALF_EDGE_TOPOLOGY));
+
+        // Replace it with a pyramid, built from the boundary edges of the removed face.
+        let apex = vtx.len() as u32;
+        let pyramid: Vec<_> = removed
+            .iter()
+            .flat_map(|tri| (0..3).map(move |k| [tri[k], tri[(k + 1) % 3], apex]))
+            .filter(|tri| {
+                !removed
+                    .iter()
+                    .flat_map(|t| (0..3).map(move |k| [t[(k + 1) % 3], t[k]]))
+                    .any(|edge| edge == [tri[0], tri[1]])
+            })
+            .collect();
+        assert_eq!(pyramid.len(), 4);
+        let apex_pt = Point::new(1.0, 0.0, 0.0);
+        let ids = mesh.insert_triangles(&[apex_pt], &pyramid, &mut workspace, true);
+        assert_eq!(ids, idx.len() as u32 - 2..idx.len() as u32 + 2);
+
+        // The incremental updates match a mesh built from scratch.
+        let expected =
+            TriMesh::with_flags(mesh.vertices().to_vec(), mesh.indices().to_vec(), flags).unwrap();
+        let pn = mesh.pseudo_normals().unwrap();
+        let expected_pn = expected.pseudo_normals().unwrap();
+
+        for (n1, n2) in pn
+            .vertices_pseudo_normal
+            .iter()
+            .zip(expected_pn.vertices_pseudo_normal.iter())
+        {
+            assert!((n1 - n2).norm() < 1.0e-5);
+        }
+
+        for (n1, n2) in pn
+            .edges_pseudo_normal
+            .iter()
+            .zip(expected_pn.edges_pseudo_normal.iter())
+        {
+            for k in 0..3 {
+                assert!((n1[k] - n2[k]).norm() < 1.0e-5);
+            }
+        }
+
+        assert_eq!(mesh.local_aabb(), expected.local_aabb());
+        mesh.qbvh()
+            .check_topology(true, |i| mesh.triangle(*i).local_aabb());
+        assert!(mesh.contains_local_point(&Point::new(0.9, 0.0, 0.0)));
+        assert!(!mesh.contains_local_point(&Point::new(0.8, 0.3, 0.0)));
+
+        mesh.set_flags(flags).unwrap();
+        mesh.assert_half_edge_topology_is_valid();
+    }
+
+    #[test]
+    #[cfg(feature = "dim3")]
+    fn deform_vertices() {
+        use crate::math::Point;
+        use crate::partitioning::QbvhUpdateWorkspace;
+        use crate::query::PointQuery;
+
+        let (vtx, idx) = Cuboid::new(Vector::repeat(0.5)).to_trimesh();
+        let mut mesh =
+            TriMesh::with_flags(vtx.clone(), idx.clone(), TriMeshFlags::ORIENTED).unwrap();
+        let mut workspace = QbvhUpdateWorkspace::default();
+
+        // Pull one corner of the cube outward.
+        let mut deformed = vtx.clone();
+        deformed[0].coords *= 3.0;
+        mesh.set_vertices(&deformed, &mut workspace, false);
+
+        let expected =
+            TriMesh::with_flags(deformed.clone(), idx.clone(), TriMeshFlags::ORIENTED).unwrap();
+        let pn = mesh.pseudo_normals().unwrap();
+        let expected_pn = expected.pseudo_normals().unwrap();
+
+        for (n1, n2) in pn
+            .vertices_pseudo_normal
+            .iter()
+            .zip(expected_pn.vertices_pseudo_normal.iter())
+        {
+            assert!((n1 - n2).norm() < 1.0e-5);
+        }
+
+        for (n1, n2) in pn
+            .edges_pseudo_normal
+            .iter()
+            .zip(expected_pn.edges_pseudo_normal.iter())
+        {
+            for k in 0..3 {
+                assert!((n1[k] - n2[k]).norm() < 1.0e-5);
+            }
+        }
+
+        assert_eq!(mesh.local_aabb(), expected.local_aabb());
+        assert!(mesh.contains_local_point(&(deformed[0] * 0.9)));
+
+        // Shrink the whole mesh in place, with a rebalancing of its Qbvh.
+        mesh.vertices_mut()
+            .iter_mut()
+            .for_each(|pt| pt.coords *= 0.5);
+        mesh.refit_bvh(&mut workspace, true);
+        let expected = TriMesh::new(mesh.vertices().to_vec(), idx).unwrap();
+        assert_eq!(mesh.local_aabb(), expected.local_aabb());
+        mesh.qbvh()
+            .check_topology(true, |i| mesh.triangle(*i).local_aabb());
+        assert!(!mesh.contains_local_point(&Point::new(0.3, 0.0, 0.0)));
+        assert!(mesh.contains_local_point(&Point::new(0.2, 0.0, 0.0)));
+    }
+
+    #[test]
+    #[cfg(feature = "dim3")]
+    fn triangle_tags() {
+        use crate::math::{Isometry, Point};
+        use crate::partitioning::QbvhUpdateWorkspace;
+        use crate::query::{
+            ContactManifold, DefaultQueryDispatcher, PersistentQueryDispatcher, PointQuery, Ray,
+            RayCast, SplitResult,
+        };
+        use crate::shape::Ball;
+        use crate::transformation::intersect_meshes;
+        use alloc::vec::Vec;
+
+        // Tags a triangle of a cuboid with the direction of its normal: `1 + 2 * axis` for
+        // a positive direction, and `2 + 2 * axis` for a negative direction.
+        fn expected_tag(mesh: &TriMesh, i: u32) -> u32 {
+            let n = mesh.triangle(i).normal().unwrap();
+            let axis = n.iamax();
+            1 + 2 * axis as u32 + (n[axis] < 0.0) as u32
+        }
+        fn has_expected_tags(mesh: &TriMesh) -> bool {
+            (0..mesh.num_triangles() as u32).all(|i| mesh.triangle_tag(i) == expected_tag(mesh, i))
+        }
+
+        let (vtx, idx) = Cuboid::new(Vector::repeat(0.5)).to_trimesh();
+        let untagged = TriMesh::new(vtx.clone(), idx.clone()).unwrap();
+        let tags: Vec<_> = (0..idx.len() as u32)
+            .map(|i| expected_tag(&untagged, i))
+            .collect();
+        assert_eq!(untagged.triangle_tag(0), 0);
+        assert!(untagged.tags().is_empty());
+
+        // Tags are preserved when duplicate triangles are removed.
+        let mut dup_idx = idx.clone();
+        dup_idx.insert(0, idx[3]);
+        let mut dup_tags = tags.clone();
+        dup_tags.insert(0, 42);
+        let flags = TriMeshFlags::MERGE_DUPLICATE_VERTICES
+            | TriMeshFlags::DELETE_DUPLICATE_TRIANGLES
+            | TriMeshFlags::HALF_EDGE_TOPOLOGY
+            | TriMeshFlags::ORIENTED;
+        let dedup = TriMesh::with_flags_and_tags(vtx.clone(), dup_idx, flags, dup_tags).unwrap();
+        assert_eq!(dedup.num_triangles(), idx.len());
+        assert_eq!(dedup.triangle_tag(0), 42);
+        assert_eq!(&dedup.tags()[1..], &[&tags[..3], &tags[4..]].concat()[..]);
+
+        let mesh = TriMesh::with_flags_and_tags(vtx.clone(), idx.clone(), flags, tags).unwrap();
+        assert!(has_expected_tags(&mesh));
+        assert!(has_expected_tags(
+            &mesh.clone().scaled(&Vector::new(1.0, 2.0, 3.0))
+        ));
+
+        // Tags are available from ray-casts (on front and back faces), point projections,
+        // and contact manifolds.
+        let ray = Ray::new(Point::new(0.1, 2.0, 0.2), -Vector::y());
+        let hit = mesh
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+            .unwrap();
+        assert_eq!(mesh.feature_tag(hit.feature), Some(3));
+        let ray = Ray::new(Point::new(0.1, 0.0, 0.2), -Vector::y());
+        let hit = mesh
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, false)
+            .unwrap();
+        assert!(mesh.is_backface(hit.feature));
+        assert_eq!(mesh.feature_tag(hit.feature), Some(4));
+        let (_, feature) = mesh.project_local_point_and_get_feature(&Point::new(0.1, 0.2, 0.9));
+        assert_eq!(mesh.feature_tag(feature), Some(5));
+        assert_eq!(mesh.feature_tag(crate::shape::FeatureId::Vertex(0)), None);
+
+        let pos12 = Isometry::translation(0.1, 0.9, 0.0);
+        let mut manifolds: Vec<ContactManifold<(), ()>> = Vec::new();
+        DefaultQueryDispatcher
+            .contact_manifolds(
+                &pos12,
+                &mesh,
+                &Ball::new(0.5),
+                0.0,
+                &mut manifolds,
+                &mut None,
+            )
+            .unwrap();
+        manifolds.retain(|m| !m.points.is_empty());
+        assert!(!manifolds.is_empty());
+        assert!(manifolds
+            .iter()
+            .all(|m| mesh.triangle_tag(m.subshape1) == 3));
+
+        // Tags are preserved by appending (untagged triangles get a zero tag) and removal.
+        let mut appended = mesh.clone();
+        let mut far_away = untagged.clone();
+        far_away.transform_vertices(&Isometry::translation(5.0, 0.0, 0.0));
+        appended.append(&far_away);
+        assert_eq!(appended.tags()[..idx.len()], mesh.tags()[..]);
+        assert!(appended.tags()[idx.len()..].iter().all(|tag| *tag == 0));
+        let last_tag = appended.triangle_tag(appended.num_triangles() as u32 - 1);
+        let first_tag = appended.triangle_tag(0);
+        let mut workspace = QbvhUpdateWorkspace::default();
+        let _ = appended.remove_triangle(0, &mut workspace, false);
+        assert_eq!(appended.triangle_tag(0), last_tag);
+        let _ = appended.insert_triangles(&[], &[idx[0]], &mut workspace, false);
+        assert_eq!(appended.tags().len(), appended.num_triangles());
+        assert_eq!(appended.tags_mut()[idx.len() * 2 - 1], 0);
+        assert_ne!(first_tag, 0);
+
+        // Tags are preserved by connected-components extraction.
+        let mut components = mesh.clone();
+        components.append(&mesh.clone().scaled(&Vector::repeat(0.5)));
+        let mut far_away = mesh.clone();
+        far_away.transform_vertices(&Isometry::translation(5.0, 0.0, 0.0));
+        components.append(&far_away);
+        components
+            .set_flags(TriMeshFlags::CONNECTED_COMPONENTS)
+            .unwrap();
+        let cc_meshes = components
+            .connected_component_meshes(TriMeshFlags::empty())
+            .unwrap();
+        assert_eq!(cc_meshes.len(), 3);
+        assert!(cc_meshes
+            .iter()
+            .all(|cc| has_expected_tags(cc.as_ref().unwrap())));
+
+        // Split triangles keep the tag of the triangle they were part of, while the triangles
+        // closing the split meshes get a zero tag.
+        let SplitResult::Pair(lhs, rhs) = mesh.canonical_split(0, 0.1, 1.0e-5) else {
+            panic!("The mesh must be split into two parts.");
+        };
+        for half in [lhs, rhs] {
+            assert!(half.num_triangles() > idx.len() / 2);
+            assert!((0..half.num_triangles() as u32).all(|i| {
+                let tag = half.triangle_tag(i);
+                tag == expected_tag(&half, i)
+                    || (tag == 0 && (half.triangle(i).center().x - 0.1).abs() < 1.0e-5)
+            }));
+            assert!(half.tags().contains(&0));
+        }
+
+        // Triangles of a mesh intersection keep the tag of the triangle they are part of.
+        let pos2 = Isometry::translation(0.3, 0.2, 0.1);
+        let untagged = TriMesh::with_flags(vtx, idx, flags).unwrap();
+        let inter = intersect_meshes(&Isometry::identity(), &mesh, false, &pos2, &untagged, false)
+            .unwrap()
+            .unwrap();
+        assert_eq!(inter.tags().len(), inter.num_triangles());
+        assert!((0..inter.num_triangles() as u32).all(|i| {
+            let tag = inter.triangle_tag(i);
+            let from_mesh1 = inter.triangle(i).normal().unwrap().sum() > 0.0;
+            tag == if from_mesh1 {
+                expected_tag(&inter, i)
+            } else {
+                0
+            }
+        }));
+    }
 }
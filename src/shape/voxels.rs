@@ -1,5 +1,8 @@
 use crate::bounding_volume::Aabb;
 use crate::math::{Point, Real, Vector, DIM};
+use crate::partitioning::{Qbvh, QbvhUpdateWorkspace};
+use crate::shape::FeatureId;
+use crate::utils::hashmap::HashMap;
 use alloc::{vec, vec::Vec};
 
 /// The primitive shape all voxels from a [`Voxels`] is given.
@@ -186,10 +189,11 @@ impl VoxelState {
 /// Information associated to a voxel.
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct VoxelData {
-    /// The temporary index in the internal voxels’ storage.
+    /// The linearized index of this voxel, also reported as its feature by queries.
     ///
-    /// This index can be invalidated after a call to [`Voxels::set_voxel`], or
-    /// [`Voxels::resize_domain`].
+    /// This index stays valid as long as the chunk containing the voxel isn’t freed, i.e., as
+    /// long as one of its voxels is filled. It is invalidated by [`Voxels::resize_domain`], and
+    /// the indices of freed chunks aren’t reused by the chunks allocated afterward.
     pub linear_id: u32,
     /// The voxel’s integer grid coordinates.
     pub grid_coords: Point<i32>,
@@ -197,6 +201,51 @@ pub struct VoxelData {
     pub center: Point<Real>,
     /// The voxel’s state, indicating if it’s empty or full.
     pub state: VoxelState,
+    /// The user-defined data (e.g. a material identifier) attached to this voxel.
+    ///
+    /// This is always zero for empty voxels.
+    pub user_data: u16,
+}
+
+/// The number of voxels along each coordinate axis of a chunk.
+///
+/// This must match the number of bits of the `Voxels::row_masks` elements.
+const CHUNK_WIDTH: i32 = 16;
+/// The number of distinct chunk handles.
+///
+/// The linearized index of a voxel is `handle * CHUNK_LEN + local_index`, which must fit in
+/// the 30 bits of a [`crate::shape::PackedFeatureId`] code.
+const MAX_CHUNK_HANDLES: u32 = (1 << 30) / CHUNK_LEN as u32;
+/// The number of voxels along each coordinate axis of a brick, i.e., of the blocks of voxels
+/// tracked by `Voxels::brick_masks`.
+const BRICK_WIDTH: i32 = 4;
+/// The number of bricks along each coordinate axis of a chunk.
+const BRICKS_PER_AXIS: i32 = CHUNK_WIDTH / BRICK_WIDTH;
+/// The number of chunks that can be allocated before the chunks BVH gets rebalanced, regardless
+/// of its size.
+const MIN_UNBALANCED_CHUNKS: usize = 16;
+/// The total number of voxels in a chunk.
+#[cfg(feature = "dim2")]
+const CHUNK_LEN: usize = (CHUNK_WIDTH * CHUNK_WIDTH) as usize;
+/// The total number of voxels in a chunk.
+#[cfg(feature = "dim3")]
+const CHUNK_LEN: usize = (CHUNK_WIDTH * CHUNK_WIDTH * CHUNK_WIDTH) as usize;
+
+/// A chunk of `CHUNK_WIDTH^DIM` voxels, with its states stored contiguously in `Voxels::states`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+struct VoxelsChunk {
+    /// The chunk’s integer coordinates, i.e., the grid coordinates of its first voxel divided
+    /// by `CHUNK_WIDTH`.
+    key: Point<i32>,
+    /// The number of non-empty voxels in this chunk. The chunk is free if this is zero.
+    len: u32,
+    /// The identifier of this chunk in the linearized indices of its voxels.
+    ///
+    /// Unlike the position of the chunk in `Voxels::chunks`, it isn’t reused right away by the
+    /// chunks allocated after this one is freed, so that stale linearized indices can be
+    /// detected.
+    handle: u32,
 }
 
 /// A shape made of axis-aligned, uniformly sized, cubes (aka. voxels).
@@ -205,15 +254,52 @@ pub struct VoxelData {
 /// that collision-detection isn’t affected by the so-called "internal edges problem" that can create
 /// artifacts when another objects rolls or slides against a flat voxelized surface.
 ///
-/// The internal storage is compact (but not sparse at the moment), storing only one byte per voxel
-/// in the allowed domain. This has a generally smaller memory footprint than a mesh representation
-/// of the voxels.
+/// The internal storage is sparse: voxels are grouped into chunks of `16` voxels along each
+/// coordinate axis, and only the chunks containing at least one non-empty voxel are allocated.
+/// Each allocated chunk stores one byte per voxel. The chunks are indexed by a hash-map, as well
+/// as by a [`Qbvh`] that is updated incrementally whenever a chunk is allocated or freed. Editing
+/// a voxel only ever touches its own chunk, the chunks of its direct neighbors, and a single leaf
+/// of that [`Qbvh`] (with its ancestors).
+///
+/// Within a chunk, a voxel is found in constant time from its grid coordinates. Each chunk also
+/// tracks which of its rows of voxels, and which of its blocks of `4` voxels along each axis,
+/// contain non-empty voxels. These occupancy masks are the chunk’s acceleration structure:
+/// iterating through the non-empty voxels never reads the empty ones, and ray-casts skip the
+/// empty blocks of the chunks they traverse, the same way the [`Qbvh`] over the chunks lets them
+/// skip the empty space between chunks.
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 pub struct Voxels {
     domain_mins: Point<i32>,
     domain_maxs: Point<i32>,
-    states: Vec<VoxelState>, // Somehow switch to a sparse representation?
+    chunks: Vec<VoxelsChunk>,
+    chunk_ids: HashMap<Point<i32>, u32>,
+    // The id of each allocated chunk, indexed by its handle.
+    chunk_handles: HashMap<u32, u32>,
+    next_chunk_handle: u32,
+    free_chunks: Vec<u32>,
+    // The states of all the voxels of the chunk with id `i` are stored at
+    // `states[i * CHUNK_LEN..(i + 1) * CHUNK_LEN]`.
+    states: Vec<VoxelState>,
+    // For each row of `CHUNK_WIDTH` voxels along the `x` axis, i.e., for each
+    // `states[i * CHUNK_WIDTH..(i + 1) * CHUNK_WIDTH]`, a bit mask of its non-empty voxels.
+    // This lets us iterate through the non-empty voxels without reading the empty ones.
+    row_masks: Vec<u16>,
+    // For each chunk, a bit mask of its bricks (blocks of `BRICK_WIDTH` voxels along each axis)
+    // containing at least one non-empty voxel. This lets ray-casts skip the empty parts of the
+    // chunks they traverse without checking their voxels one by one.
+    brick_masks: Vec<u64>,
+    // The user-data of each voxel, with the same layout as `states`. This is left empty until
+    // any voxel is given a non-zero user-data.
+    user_data: Vec<u16>,
+    // The chunks’ AABBs stored in this BVH are expressed in grid coordinates (i.e., they don’t
+    // account for `voxel_size`) so that it doesn’t need to be rebuilt when the voxel size changes.
+    chunk_bvh: Qbvh<u32>,
+    #[cfg_attr(feature = "serde-serialize", serde(skip))]
+    chunk_bvh_workspace: QbvhUpdateWorkspace,
+    // The number of leaves inserted into `chunk_bvh` since it was last rebalanced.
+    #[cfg_attr(feature = "serde-serialize", serde(skip))]
+    num_unbalanced_chunks: u32,
     primitive_geometry: VoxelPrimitiveGeometry,
     voxel_size: Vector<Real>,
 }
@@ -228,12 +314,19 @@ impl Voxels {
         voxel_size: Vector<Real>,
         grid_coordinates: &[Point<i32>],
     ) -> Self {
-        // Ensure pseudo-balls always use uniform voxel sizes.
-        let voxel_size = match primitive_geometry {
-            VoxelPrimitiveGeometry::PseudoBall => Vector::repeat(voxel_size.x),
-            VoxelPrimitiveGeometry::PseudoCube => voxel_size,
-        };
+        Self::with_user_data(primitive_geometry, voxel_size, grid_coordinates, &[])
+    }
 
+    /// Initializes a voxel shapes from the voxels grid coordinates and their user-data.
+    ///
+    /// The `i`-th voxel is given the user-data `user_data[i]`, or zero if `user_data` has less
+    /// than `i + 1` elements. See [`Self::new`] for details on the voxels’ positions.
+    pub fn with_user_data(
+        primitive_geometry: VoxelPrimitiveGeometry,
+        voxel_size: Vector<Real>,
+        grid_coordinates: &[Point<i32>],
+        user_data: &[u16],
+    ) -> Self {
         let mut domain_mins = grid_coordinates[0];
         let mut domain_maxs = grid_coordinates[0];
 
@@ -243,25 +336,52 @@ impl Voxels {
         }
 
         domain_maxs += Vector::repeat(1);
-        let dimensions = domain_maxs - domain_mins;
-        let voxels_count = dimensions.product();
-        let mut result = Self {
-            domain_mins,
-            domain_maxs,
-            states: vec![VoxelState::EMPTY; voxels_count as usize],
-            primitive_geometry,
-            voxel_size,
-        };
+        let mut result =
+            Self::with_domain(primitive_geometry, voxel_size, domain_mins, domain_maxs);
 
-        for vox in grid_coordinates {
-            let index = result.linear_index(*vox);
-            result.states[index as usize] = VoxelState::INTERIOR;
+        for (i, vox) in grid_coordinates.iter().enumerate() {
+            let data = user_data.get(i).copied().unwrap_or_default();
+            result.set_state_unchecked(*vox, VoxelState::INTERIOR, data);
         }
 
         result.recompute_voxels_data();
+        result.rebuild_chunk_bvh();
         result
     }
 
+    /// Initializes a voxels shape with the given domain, where all the voxels are empty.
+    fn with_domain(
+        primitive_geometry: VoxelPrimitiveGeometry,
+        voxel_size: Vector<Real>,
+        domain_mins: Point<i32>,
+        domain_maxs: Point<i32>,
+    ) -> Self {
+        // Ensure pseudo-balls always use uniform voxel sizes.
+        let voxel_size = match primitive_geometry {
+            VoxelPrimitiveGeometry::PseudoBall => Vector::repeat(voxel_size.x),
+            VoxelPrimitiveGeometry::PseudoCube => voxel_size,
+        };
+
+        Self {
+            domain_mins,
+            domain_maxs,
+            chunks: vec![],
+            chunk_ids: HashMap::default(),
+            chunk_handles: HashMap::default(),
+            next_chunk_handle: 0,
+            free_chunks: vec![],
+            states: vec![],
+            row_masks: vec![],
+            brick_masks: vec![],
+            user_data: vec![],
+            chunk_bvh: Qbvh::new(),
+            chunk_bvh_workspace: QbvhUpdateWorkspace::default(),
+            num_unbalanced_chunks: 0,
+            primitive_geometry,
+            voxel_size,
+        }
+    }
+
     /// Computes a voxels shape from the set of `points`.
     ///
     /// The points are mapped to a regular grid centered at the provided point with smallest
@@ -304,17 +424,35 @@ impl Voxels {
         let Self {
             domain_mins: _,
             domain_maxs: _,
-            states: data,
+            chunks,
+            chunk_ids,
+            chunk_handles,
+            next_chunk_handle: _,
+            free_chunks,
+            states,
+            row_masks,
+            brick_masks,
+            user_data,
+            chunk_bvh,
+            chunk_bvh_workspace: _,
+            num_unbalanced_chunks: _,
             primitive_geometry: _,
             voxel_size: _,
         } = self;
-        data.capacity() * size_of::<VoxelState>()
+        chunks.capacity() * size_of::<VoxelsChunk>()
+            + chunk_ids.capacity() * size_of::<(Point<i32>, u32)>()
+            + chunk_handles.capacity() * size_of::<(u32, u32)>()
+            + free_chunks.capacity() * size_of::<u32>()
+            + states.capacity() * size_of::<VoxelState>()
+            + row_masks.capacity() * size_of::<u16>()
+            + brick_masks.capacity() * size_of::<u64>()
+            + user_data.capacity() * size_of::<u16>()
+            + chunk_bvh.heap_memory_size()
     }
 
     /// The extents of the total axis-aligned volume covered by this [`Voxels`] shape.
     ///
-    /// This accounts for all the voxels reserved in the internal buffer of `self`, including empty
-    /// ones.
+    /// This accounts for all the voxels within [`Self::domain`], including empty ones.
     pub fn extents(&self) -> Vector<Real> {
         self.dimensions()
             .cast::<Real>()
@@ -351,6 +489,9 @@ impl Voxels {
     ///
     /// With `let [mins, maxs] = voxels.domain();` the valid indices along the dimension `i` are
     /// all the indices in the range `mins[i]..maxs[i]` (i.e. `maxs[i]` is excluded).
+    ///
+    /// All the non-empty voxels are guaranteed to lie within this domain. Note that this domain
+    /// isn’t shrunk automatically when voxels are removed.
     pub fn domain(&self) -> [&Point<i32>; 2] {
         [&self.domain_mins, &self.domain_maxs]
     }
@@ -371,47 +512,154 @@ impl Voxels {
     }
 
     fn recompute_voxels_data(&mut self) {
-        for i in 0..self.states.len() {
-            let key = self.voxel_at_id(i as u32);
-            self.states[i] = self.compute_voxel_state(key);
+        let filled_ids: Vec<_> = self.filled_state_indices(0..self.chunks.len()).collect();
+
+        for id in filled_ids {
+            let key = self.voxel_at_state_index(id);
+            self.states[id] = self.compute_voxel_state(key);
         }
     }
 
     /// Scale this shape.
+    ///
+    /// Negative scaling factors mirror the voxels grid along the corresponding axes.
+    ///
+    /// Returns `None` if any scaling factor is zero, or if [`Self::primitive_geometry`] is
+    /// [`VoxelPrimitiveGeometry::PseudoBall`] and the absolute values of the scaling factors
+    /// aren’t all equal: pseudo-balls can only be represented by cubic voxels.
     pub fn scaled(mut self, scale: &Vector<Real>) -> Option<Self> {
-        self.voxel_size.component_mul_assign(scale);
+        if scale.iter().any(|s| *s == 0.0) {
+            return None;
+        }
+
+        let abs_scale = scale.abs();
+        if self.primitive_geometry == VoxelPrimitiveGeometry::PseudoBall
+            && abs_scale.iter().any(|s| *s != abs_scale.x)
+        {
+            return None;
+        }
+
+        self.voxel_size.component_mul_assign(&abs_scale);
+
+        for k in 0..DIM {
+            if scale[k] < 0.0 {
+                self.mirror(k);
+            }
+        }
+
         Some(self)
     }
 
+    /// Mirrors the voxels grid along the given coordinate axis.
+    ///
+    /// The voxel with grid coordinates `key` is moved to the grid coordinates with the
+    /// `axis`-th component set to `-key[axis] - 1`.
+    fn mirror(&mut self, axis: usize) {
+        let pos_bit = 1 << (axis * 2);
+        let neg_bit = 1 << (axis * 2 + 1);
+        let mut domain_mins = self.domain_mins;
+        let mut domain_maxs = self.domain_maxs;
+        domain_mins[axis] = -self.domain_maxs[axis];
+        domain_maxs[axis] = -self.domain_mins[axis];
+        let mut mirrored = Self::with_domain(
+            self.primitive_geometry,
+            self.voxel_size,
+            domain_mins,
+            domain_maxs,
+        );
+        // Keep assigning fresh chunk handles so that the linearized indices computed before
+        // the mirroring don’t alias the new ones.
+        mirrored.next_chunk_handle = self.next_chunk_handle;
+
+        for vox in self.filled_voxels() {
+            let mut key = vox.grid_coords;
+            key[axis] = -key[axis] - 1;
+
+            // The neighbors along the positive and negative directions are swapped.
+            let mut new_state = vox.state;
+            new_state.0 &= !(pos_bit | neg_bit);
+            new_state.0 |= ((vox.state.0 & pos_bit) << 1) | ((vox.state.0 & neg_bit) >> 1);
+            mirrored.set_state_unchecked(key, new_state, vox.user_data);
+        }
+
+        mirrored.rebuild_chunk_bvh();
+        *self = mirrored;
+    }
+
     /// Sets the voxel at the given grid coordinates, returning `None` if it lies outside [`Self::domain`].
     ///
-    /// See [`Self::set_voxel`] for a method that automatically resizes the internal
-    /// storage of `self` if the key is out of the valid bounds.
+    /// A voxel that gets filled by this method keeps its current user-data if it was already
+    /// filled, and gets a zero user-data otherwise. See [`Self::try_set_voxel_with_user_data`]
+    /// for setting the user-data too.
+    ///
+    /// See [`Self::set_voxel`] for a method that automatically grows the domain of `self` if the
+    /// key is out of the valid bounds.
     pub fn try_set_voxel(&mut self, key: Point<i32>, is_filled: bool) -> Option<VoxelState> {
-        if key[0] < self.domain_mins[0]
-            || key[0] >= self.domain_maxs[0]
-            || key[1] < self.domain_mins[1]
-            || key[1] >= self.domain_maxs[1]
-        {
-            return None;
-        }
+        self.try_set_voxel_impl(key, is_filled, None)
+    }
 
-        #[cfg(feature = "dim3")]
-        if key[2] < self.domain_mins[2] || key[2] >= self.domain_maxs[2] {
+    /// Sets the voxel, and its user-data, at the given grid coordinates, returning `None` if it
+    /// lies outside [`Self::domain`].
+    ///
+    /// The `user_data` is ignored if `is_filled` is `false`: empty voxels always have a zero
+    /// user-data.
+    pub fn try_set_voxel_with_user_data(
+        &mut self,
+        key: Point<i32>,
+        is_filled: bool,
+        user_data: u16,
+    ) -> Option<VoxelState> {
+        self.try_set_voxel_impl(key, is_filled, Some(user_data))
+    }
+
+    fn try_set_voxel_impl(
+        &mut self,
+        key: Point<i32>,
+        is_filled: bool,
+        user_data: Option<u16>,
+    ) -> Option<VoxelState> {
+        if !self.is_voxel_in_bounds(key) {
             return None;
         }
 
-        let id = self.linear_index(key);
-        let prev = self.states[id as usize];
-        let new = if is_filled {
-            VoxelState::INTERIOR
-        } else {
-            VoxelState::EMPTY
-        };
+        let prev = self.voxel_state(key);
 
-        if prev.is_empty() ^ new.is_empty() {
-            self.states[id as usize] = new;
+        if prev.is_empty() && is_filled {
+            let (chunk_id, newly_allocated) = self.get_or_alloc_chunk(Self::chunk_key(key));
+            let id = chunk_id * CHUNK_LEN + Self::local_index(key);
+            self.chunks[chunk_id].len += 1;
+            self.states[id] = VoxelState::INTERIOR;
+            self.set_occupancy_bits(id, true);
+            self.set_user_data_at(id, user_data.unwrap_or_default());
             self.update_voxel_and_neighbors_state(key);
+
+            if newly_allocated {
+                self.chunk_bvh.pre_update_or_insert(chunk_id as u32);
+                self.num_unbalanced_chunks += 1;
+                self.refit_chunk_bvh();
+            }
+        } else if !prev.is_empty() && is_filled {
+            if let (Some(id), Some(user_data)) = (self.state_index(key), user_data) {
+                self.set_user_data_at(id, user_data);
+            }
+        } else if !prev.is_empty() && !is_filled {
+            let chunk_key = Self::chunk_key(key);
+            let chunk_id = self.chunk_ids[&chunk_key] as usize;
+            let id = chunk_id * CHUNK_LEN + Self::local_index(key);
+            self.chunks[chunk_id].len -= 1;
+            self.states[id] = VoxelState::EMPTY;
+            self.set_occupancy_bits(id, false);
+            self.set_user_data_at(id, 0);
+            self.update_voxel_and_neighbors_state(key);
+
+            if self.chunks[chunk_id].len == 0 {
+                // The chunk doesn’t contain any filled voxel anymore: free it.
+                let _ = self.chunk_ids.remove(&chunk_key);
+                let _ = self.chunk_handles.remove(&self.chunks[chunk_id].handle);
+                self.free_chunks.push(chunk_id as u32);
+                let _ = self.chunk_bvh.remove(chunk_id as u32);
+                self.refit_chunk_bvh();
+            }
         }
 
         Some(prev)
@@ -419,37 +667,42 @@ impl Voxels {
 
     /// Inserts a voxel at the given key, even if it is out of the bounds of this shape.
     ///
-    /// If `is_filed` is `true` and the key lies out of the bounds on this shape, the internal
-    /// voxels storage will be resized automatically. If a resize happens, the cost of the insertion
-    /// is `O(n)` where `n` is the capacity of `self`. If no resize happens, then the cost of
-    /// insertion is `O(1)`.
+    /// If `is_filed` is `true` and the key lies out of the bounds on this shape, the
+    /// [`Self::domain`] will be grown automatically to include it. Because the internal storage
+    /// is sparse, the cost of the insertion doesn’t depend on the size of the domain: it is
+    /// `O(1)`, plus a partial update of the chunks [`Qbvh`] if a new chunk needs to be allocated
+    /// (see [`Self::rebalance_chunk_bvh`]).
+    ///
+    /// A voxel that gets filled by this method keeps its current user-data if it was already
+    /// filled, and gets a zero user-data otherwise. See [`Self::set_voxel_with_user_data`]
+    /// for setting the user-data too.
     ///
     /// Use [`Self::try_set_voxel`] instead for a version that will be a no-op if the provided
-    /// coordinates are outside the [`Self::domain`], avoiding potential internal reallocations.
+    /// coordinates are outside the [`Self::domain`].
     pub fn set_voxel(&mut self, key: Point<i32>, is_filled: bool) -> Option<VoxelState> {
-        if !self.is_voxel_in_bounds(key) && is_filled {
-            let dims = self.dimensions();
-
-            // Add 10% extra padding.
-            let extra = dims.map(|k| k * 10 / 100);
-            let mut new_domain_mins = self.domain_mins;
-            let mut new_domain_maxs = self.domain_maxs;
-
-            for k in 0..DIM {
-                if key[k] < self.domain_mins[k] {
-                    new_domain_mins[k] = key[k] - extra[k] as i32;
-                }
-
-                if key[k] >= self.domain_maxs[k] {
-                    new_domain_maxs[k] = key[k] + extra[k] as i32 + 1;
-                }
-            }
+        self.grow_domain_for_voxel(key, is_filled);
+        self.try_set_voxel(key, is_filled)
+    }
 
-            self.resize_domain(new_domain_mins, new_domain_maxs);
+    /// Inserts a voxel, and its user-data, at the given key, even if it is out of the bounds of
+    /// this shape.
+    ///
+    /// The `user_data` is ignored if `is_filled` is `false`: empty voxels always have a zero
+    /// user-data. See [`Self::set_voxel`] for additional details.
+    pub fn set_voxel_with_user_data(
+        &mut self,
+        key: Point<i32>,
+        is_filled: bool,
+        user_data: u16,
+    ) -> Option<VoxelState> {
+        self.grow_domain_for_voxel(key, is_filled);
+        self.try_set_voxel_with_user_data(key, is_filled, user_data)
+    }
 
-            self.try_set_voxel(key, is_filled)
-        } else {
-            self.try_set_voxel(key, is_filled)
+    fn grow_domain_for_voxel(&mut self, key: Point<i32>, is_filled: bool) {
+        if !self.is_voxel_in_bounds(key) && is_filled {
+            self.domain_mins = self.domain_mins.inf(&key);
+            self.domain_maxs = self.domain_maxs.sup(&(key + Vector::repeat(1)));
         }
     }
 
@@ -495,29 +748,34 @@ impl Voxels {
             return None;
         }
 
-        let new_len = new_dim.iter().map(|x| *x as usize).product();
-
-        let mut new_shape = Self {
+        let mut new_shape = Self::with_domain(
+            self.primitive_geometry,
+            self.voxel_size,
             domain_mins,
             domain_maxs,
-            states: vec![VoxelState::EMPTY; new_len],
-            primitive_geometry: self.primitive_geometry,
-            voxel_size: self.voxel_size,
-        };
-
-        for i in 0..self.states.len() {
-            let key = self.voxel_at_id(i as u32);
-            let new_i = new_shape.linear_index(key);
-            new_shape.states[new_i as usize] = self.states[i];
+        );
+        // Keep assigning fresh chunk handles so that the linearized indices computed before
+        // the resizing don’t alias the new ones.
+        new_shape.next_chunk_handle = self.next_chunk_handle;
+
+        let chunk_ids = self.chunks_in_range(domain_mins, domain_maxs);
+        for id in self.filled_state_indices(chunk_ids.into_iter().map(|id| id as usize)) {
+            let key = self.voxel_at_state_index(id);
+            if new_shape.is_voxel_in_bounds(key) {
+                new_shape.set_state_unchecked(key, self.states[id], self.user_data_at(id));
+            }
         }
 
+        // Voxels at the boundary of the new domain might have lost some of their neighbors.
+        new_shape.recompute_voxels_data();
+        new_shape.rebuild_chunk_bvh();
         Some(new_shape)
     }
 
     /// Checks if the given key is within [`Self::domain`].
     #[cfg(feature = "dim2")]
     pub fn is_voxel_in_bounds(&self, key: Point<i32>) -> bool {
-        key[0] >= self.domain_mins[1]
+        key[0] >= self.domain_mins[0]
             && key[0] < self.domain_maxs[0]
             && key[1] >= self.domain_mins[1]
             && key[1] < self.domain_maxs[1]
@@ -535,16 +793,15 @@ impl Voxels {
     }
 
     fn update_voxel_and_neighbors_state(&mut self, key: Point<i32>) {
-        let key_id = self.linear_index(key) as usize;
+        let key_id = self.state_index(key);
         let mut key_data = 0;
-        let center_is_empty = self.states[key_id].is_empty();
+        let center_is_empty = key_id.map(|id| self.states[id].is_empty()).unwrap_or(true);
 
         for k in 0..DIM {
-            if key[k] > self.domain_mins[k] {
-                let mut left = key;
-                left[k] -= 1;
-                let left_id = self.linear_index(left) as usize;
+            let mut left = key;
+            left[k] -= 1;
 
+            if let Some(left_id) = self.state_index(left) {
                 if !self.states[left_id].is_empty() {
                     if center_is_empty {
                         self.states[left_id].0 &= !(1 << (k * 2));
@@ -555,11 +812,10 @@ impl Voxels {
                 }
             }
 
-            if key[k] + 1 < self.domain_maxs[k] {
-                let mut right = key;
-                right[k] += 1;
-                let right_id = self.linear_index(right) as usize;
+            let mut right = key;
+            right[k] += 1;
 
+            if let Some(right_id) = self.state_index(right) {
                 if !self.states[right_id].is_empty() {
                     if center_is_empty {
                         self.states[right_id].0 &= !(1 << (k * 2 + 1));
@@ -571,7 +827,7 @@ impl Voxels {
             }
         }
 
-        if !center_is_empty {
+        if let (Some(key_id), false) = (key_id, center_is_empty) {
             self.states[key_id] = VoxelState(key_data);
         }
     }
@@ -585,9 +841,37 @@ impl Voxels {
 
     /// Returns the state of a given voxel.
     ///
-    /// Panics if the key is out of the bounds defined by [`Self::domain`].
+    /// Returns [`VoxelState::EMPTY`] if the voxel isn’t part of any allocated chunk, which is
+    /// always the case if it lies outside [`Self::domain`].
     pub fn voxel_state(&self, key: Point<i32>) -> VoxelState {
-        self.states[self.linear_index(key) as usize]
+        self.state_index(key)
+            .map(|id| self.states[id])
+            .unwrap_or(VoxelState::EMPTY)
+    }
+
+    /// Returns the user-data of a given voxel.
+    ///
+    /// This is zero for empty voxels.
+    pub fn voxel_user_data(&self, key: Point<i32>) -> u16 {
+        self.state_index(key)
+            .map(|id| self.user_data_at(id))
+            .unwrap_or_default()
+    }
+
+    /// Returns the user-data of the voxel identified by the given feature.
+    ///
+    /// The feature must be a [`FeatureId::Face`] holding the linearized index of a voxel,
+    /// as reported by the ray-casts, point-projections, and contact manifolds involving this
+    /// shape. Returns `None` for any other feature, as well as for a linearized index that no
+    /// longer identifies a filled voxel (e.g. because the voxel was cleared since).
+    pub fn feature_user_data(&self, feature: FeatureId) -> Option<u16> {
+        match feature {
+            FeatureId::Face(linear_index) => {
+                let id = self.storage_index_of(linear_index)?;
+                (!self.states[id].is_empty()).then(|| self.user_data_at(id))
+            }
+            _ => None,
+        }
     }
 
     /// Calculates the grid coordinates of the voxel containing the given `point`, regardless
@@ -704,9 +988,30 @@ impl Voxels {
     /// The center point of all the voxels in this shape (including empty ones).
     ///
     /// The voxel data associated to each center is provided to determine what kind of voxel
-    /// it is (and, in particular, if it is empty or full).
+    /// it is (and, in particular, if it is empty or full). Empty voxels that aren’t part of any
+    /// allocated chunk are skipped.
     pub fn voxels(&self) -> impl Iterator<Item = VoxelData> + '_ {
-        self.voxels_in_range(self.domain_mins, self.domain_maxs)
+        self.chunks
+            .iter()
+            .enumerate()
+            .filter(|(_, chunk)| chunk.len != 0)
+            .flat_map(move |(chunk_id, chunk)| {
+                let [chunk_mins, chunk_maxs] = Self::chunk_voxel_range(chunk.key);
+                let mins = Point::from(chunk_mins.coords.sup(&self.domain_mins.coords));
+                let maxs = Point::from(chunk_maxs.coords.inf(&self.domain_maxs.coords));
+                Self::keys_in_range(mins, maxs).map(move |grid_coords| {
+                    self.voxel_data_at(chunk_id * CHUNK_LEN + Self::local_index(grid_coords))
+                })
+            })
+    }
+
+    /// The center point and data of all the non-empty voxels in this shape.
+    ///
+    /// Unlike [`Self::voxels`], this never reads the empty voxels: they are skipped using the
+    /// bit masks of the non-empty voxels maintained by each chunk.
+    pub fn filled_voxels(&self) -> impl Iterator<Item = VoxelData> + '_ {
+        self.filled_state_indices(0..self.chunks.len())
+            .map(|id| self.voxel_data_at(id))
     }
 
     /// Splits this voxels shape into two subshapes.
@@ -715,37 +1020,26 @@ impl Voxels {
     /// The second subshape contains all the remaining voxels.
     pub fn split_with_box(&self, aabb: &Aabb) -> (Option<Self>, Option<Self>) {
         // TODO: optimize this?
-        let mut in_box = vec![];
-        let mut rest = vec![];
-        for vox in self.voxels() {
-            if !vox.state.is_empty() {
-                if aabb.contains_local_point(&vox.center) {
-                    in_box.push(vox.center);
-                } else {
-                    rest.push(vox.center);
-                }
-            }
+        let mut in_box = (vec![], vec![]);
+        let mut rest = (vec![], vec![]);
+        for vox in self.filled_voxels() {
+            let part = if aabb.contains_local_point(&vox.center) {
+                &mut in_box
+            } else {
+                &mut rest
+            };
+            part.0.push(vox.grid_coords);
+            part.1.push(vox.user_data);
         }
 
-        let in_box = if !in_box.is_empty() {
-            Some(Voxels::from_points(
-                self.primitive_geometry,
-                self.voxel_size,
-                &in_box,
-            ))
-        } else {
-            None
+        let to_voxels = |(keys, user_data): (Vec<_>, Vec<_>)| {
+            (!keys.is_empty()).then(|| {
+                Voxels::with_user_data(self.primitive_geometry, self.voxel_size, &keys, &user_data)
+            })
         };
 
-        let rest = if !rest.is_empty() {
-            Some(Voxels::from_points(
-                self.primitive_geometry,
-                self.voxel_size,
-                &rest,
-            ))
-        } else {
-            None
-        };
+        let in_box = to_voxels(in_box);
+        let rest = to_voxels(rest);
 
         (in_box, rest)
     }
@@ -753,99 +1047,185 @@ impl Voxels {
     /// Iterate through the data of all the voxels within the given (semi-open) voxel grid indices.
     ///
     /// Note that this yields both empty and non-empty voxels within the range. This does not
-    /// include any voxel that falls outside [`Self::domain`].
-    #[cfg(feature = "dim2")]
+    /// include any voxel that falls outside [`Self::domain`], nor any (necessarily empty) voxel
+    /// that isn’t part of an allocated chunk.
     pub fn voxels_in_range(
         &self,
         mins: Point<i32>,
         maxs: Point<i32>,
     ) -> impl Iterator<Item = VoxelData> + '_ {
-        let mins = mins.coords.sup(&self.domain_mins.coords);
-        let maxs = maxs.coords.inf(&self.domain_maxs.coords);
-
-        (mins[0]..maxs[0]).flat_map(move |ix| {
-            (mins[1]..maxs[1]).map(move |iy| {
-                let grid_coords = Point::new(ix, iy);
-                let vid = self.linear_index(grid_coords);
-                let center =
-                    Vector::new(ix as Real + 0.5, iy as Real + 0.5).component_mul(&self.voxel_size);
-                VoxelData {
-                    linear_id: vid,
-                    grid_coords,
-                    center: center.into(),
-                    state: self.states[vid as usize],
-                }
+        let mins = Point::from(mins.coords.sup(&self.domain_mins.coords));
+        let maxs = Point::from(maxs.coords.inf(&self.domain_maxs.coords));
+
+        self.chunks_in_range(mins, maxs)
+            .into_iter()
+            .flat_map(move |chunk_id| {
+                let chunk_key = self.chunks[chunk_id as usize].key;
+                let [chunk_mins, chunk_maxs] = Self::chunk_voxel_range(chunk_key);
+                let range_mins = Point::from(mins.coords.sup(&chunk_mins.coords));
+                let range_maxs = Point::from(maxs.coords.inf(&chunk_maxs.coords));
+
+                Self::keys_in_range(range_mins, range_maxs).map(move |grid_coords| {
+                    self.voxel_data_at(
+                        chunk_id as usize * CHUNK_LEN + Self::local_index(grid_coords),
+                    )
+                })
             })
-        })
     }
 
-    /// Iterate through the data of all the voxels within the given (semi-open) voxel grid indices.
+    /// The ids of the allocated chunks intersecting the given (semi-open) voxel grid indices.
+    fn chunks_in_range(&self, mins: Point<i32>, maxs: Point<i32>) -> Vec<u32> {
+        let mut chunk_ids = vec![];
+
+        if mins.iter().zip(maxs.iter()).all(|(a, b)| a < b) {
+            let chunk_mins = Self::chunk_key(mins);
+            let chunk_maxs = Self::chunk_key(maxs - Vector::repeat(1)) + Vector::repeat(1);
+            let num_chunks_in_range = (chunk_maxs - chunk_mins)
+                .iter()
+                .map(|d| *d as u64)
+                .product::<u64>();
+
+            // Pick the cheapest option between looking up every chunk of the range in the
+            // hash-map, or traversing the BVH of allocated chunks.
+            if num_chunks_in_range <= self.chunk_ids.len() as u64 {
+                chunk_ids.extend(
+                    Self::keys_in_range(chunk_mins, chunk_maxs)
+                        .filter_map(|chunk_key| self.chunk_ids.get(&chunk_key).copied()),
+                );
+            } else {
+                let range_aabb = Aabb::new(mins.cast::<Real>(), maxs.cast::<Real>());
+                self.chunk_bvh.intersect_aabb(&range_aabb, &mut chunk_ids);
+            }
+        }
+
+        chunk_ids
+    }
+
+    /// The data of the voxel with the given index in `self.states`.
+    fn voxel_data_at(&self, id: usize) -> VoxelData {
+        let grid_coords = self.voxel_at_state_index(id);
+        VoxelData {
+            linear_id: self.linear_id_at(id),
+            grid_coords,
+            center: self.voxel_center(grid_coords),
+            state: self.states[id],
+            user_data: self.user_data_at(id),
+        }
+    }
+
+    /// Iterates through the indices in `self.states` of all the non-empty voxels of the given
+    /// chunks, skipping the free chunks.
     ///
-    /// Note that this yields both empty and non-empty voxels within the range. This does not
-    /// include any voxel that falls outside [`Self::domain`].
-    #[cfg(feature = "dim3")]
-    pub fn voxels_in_range(
-        &self,
-        mins: Point<i32>,
-        maxs: Point<i32>,
-    ) -> impl Iterator<Item = VoxelData> + '_ {
-        let mins = mins.coords.sup(&self.domain_mins.coords);
-        let maxs = maxs.coords.inf(&self.domain_maxs.coords);
-
-        (mins[0]..maxs[0]).flat_map(move |ix| {
-            (mins[1]..maxs[1]).flat_map(move |iy| {
-                (mins[2]..maxs[2]).map(move |iz| {
-                    let grid_coords = Point::new(ix, iy, iz);
-                    let vid = self.linear_index(grid_coords);
-                    let center = Vector::new(ix as Real + 0.5, iy as Real + 0.5, iz as Real + 0.5)
-                        .component_mul(&self.voxel_size)
-                        .into();
-                    VoxelData {
-                        linear_id: vid,
-                        grid_coords,
-                        center,
-                        state: self.states[vid as usize],
+    /// Only the rows with a non-zero bit mask are visited, and only their set bits are read.
+    fn filled_state_indices<'a>(
+        &'a self,
+        chunk_ids: impl Iterator<Item = usize> + 'a,
+    ) -> impl Iterator<Item = usize> + 'a {
+        let row_len = CHUNK_WIDTH as usize;
+        let rows_per_chunk = CHUNK_LEN / row_len;
+
+        chunk_ids
+            .filter(|chunk_id| self.chunks[*chunk_id].len != 0)
+            .flat_map(move |chunk_id| chunk_id * rows_per_chunk..(chunk_id + 1) * rows_per_chunk)
+            .flat_map(move |row_id| {
+                let mut mask = self.row_masks[row_id];
+                core::iter::from_fn(move || {
+                    if mask == 0 {
+                        return None;
                     }
+
+                    let bit = mask.trailing_zeros() as usize;
+                    mask &= mask - 1;
+                    Some(row_id * row_len + bit)
                 })
             })
-        })
     }
 
-    /// The linearized index associated to the given voxel key.
-    #[cfg(feature = "dim2")]
-    pub fn linear_index(&self, voxel_key: Point<i32>) -> u32 {
-        let dims = self.dimensions();
-        let rel_key = voxel_key - self.domain_mins;
-        (rel_key.x + rel_key.y * dims[0] as i32) as u32
+    /// Iterates through all the maximal runs of contiguous non-empty voxels along the `x` axis
+    /// within each allocated chunk.
+    ///
+    /// Each run is given as the grid coordinates of its first voxel, and the states of all the
+    /// voxels it contains. The empty voxels are never visited: the runs are extracted from the
+    /// bit masks of the non-empty voxels of each row, and the empty rows are skipped.
+    pub(crate) fn filled_runs(&self) -> impl Iterator<Item = (Point<i32>, &[VoxelState])> + '_ {
+        let row_len = CHUNK_WIDTH as usize;
+        let rows_per_chunk = CHUNK_LEN / row_len;
+
+        self.chunks
+            .iter()
+            .enumerate()
+            .filter(|(_, chunk)| chunk.len != 0)
+            .flat_map(move |(chunk_id, _)| {
+                (chunk_id * rows_per_chunk..(chunk_id + 1) * rows_per_chunk)
+                    .filter(move |row_id| self.row_masks[*row_id] != 0)
+            })
+            .flat_map(move |row_id| {
+                let row = &self.states[row_id * row_len..(row_id + 1) * row_len];
+                let row_key = self.voxel_at_state_index(row_id * row_len);
+                let mut mask = self.row_masks[row_id] as u32;
+
+                core::iter::from_fn(move || {
+                    if mask == 0 {
+                        return None;
+                    }
+
+                    // The run starts at the first set bit, and ends at the next unset bit.
+                    let start = mask.trailing_zeros();
+                    let len = (!(mask >> start)).trailing_zeros();
+                    mask &= !(((1 << len) - 1) << start);
+
+                    let mut key = row_key;
+                    key.x += start as i32;
+                    Some((key, &row[start as usize..(start + len) as usize]))
+                })
+            })
     }
 
     /// The linearized index associated to the given voxel key.
-    #[cfg(feature = "dim3")]
-    pub fn linear_index(&self, voxel_key: Point<i32>) -> u32 {
-        let dims = self.dimensions();
-        let rel_key = voxel_key - self.domain_mins;
-        rel_key.x as u32 + rel_key.y as u32 * dims[0] + rel_key.z as u32 * dims[0] * dims[1]
+    ///
+    /// Returns `None` if the voxel isn’t part of any allocated chunk, in which case it is
+    /// necessarily empty. The returned index remains valid as long as the voxel’s chunk stays
+    /// allocated (i.e., as long as any voxel of that chunk is filled).
+    pub fn linear_index(&self, voxel_key: Point<i32>) -> Option<u32> {
+        self.state_index(voxel_key).map(|id| self.linear_id_at(id))
     }
 
     /// The key of the voxel at the given linearized index.
-    #[cfg(feature = "dim2")]
-    pub fn voxel_at_id(&self, linear_index: u32) -> Point<i32> {
-        let dim0 = self.domain_maxs[0] - self.domain_mins[0];
-        let y = linear_index as i32 / dim0;
-        let x = linear_index as i32 % dim0;
-        self.domain_mins + Vector::new(x, y)
+    ///
+    /// Returns `None` if the linearized index doesn’t belong to a chunk that is currently
+    /// allocated, e.g., if the chunk of the voxel it was computed for has been freed since.
+    pub fn voxel_at_id(&self, linear_index: u32) -> Option<Point<i32>> {
+        self.storage_index_of(linear_index)
+            .map(|id| self.voxel_at_state_index(id))
     }
 
-    /// The key of the voxel at the given linearized index.
-    #[cfg(feature = "dim3")]
-    pub fn voxel_at_id(&self, linear_index: u32) -> Point<i32> {
-        let dims = self.dimensions();
+    /// The linearized index of the voxel with the given index in `self.states`.
+    fn linear_id_at(&self, id: usize) -> u32 {
+        self.chunks[id / CHUNK_LEN].handle * CHUNK_LEN as u32 + (id % CHUNK_LEN) as u32
+    }
 
-        let d0d1 = dims[0] * dims[1];
-        let z = linear_index / d0d1;
-        let y = (linear_index - z * d0d1) / dims[0];
-        let x = linear_index % dims[0];
-        self.domain_mins + Vector::new(x as i32, y as i32, z as i32)
+    /// The index in `self.states` of the voxel with the given linearized index, if its chunk is
+    /// still allocated.
+    fn storage_index_of(&self, linear_index: u32) -> Option<usize> {
+        let chunk_id = *self.chunk_handles.get(&(linear_index / CHUNK_LEN as u32))?;
+        Some(chunk_id as usize * CHUNK_LEN + linear_index as usize % CHUNK_LEN)
+    }
+
+    /// The key of the voxel with the given index in `self.states`.
+    fn voxel_at_state_index(&self, id: usize) -> Point<i32> {
+        let local_id = (id % CHUNK_LEN) as i32;
+        let [chunk_mins, _] = Self::chunk_voxel_range(self.chunks[id / CHUNK_LEN].key);
+
+        #[cfg(feature = "dim2")]
+        let local_key = Vector::new(local_id % CHUNK_WIDTH, local_id / CHUNK_WIDTH);
+        #[cfg(feature = "dim3")]
+        let local_key = Vector::new(
+            local_id % CHUNK_WIDTH,
+            (local_id / CHUNK_WIDTH) % CHUNK_WIDTH,
+            local_id / (CHUNK_WIDTH * CHUNK_WIDTH),
+        );
+
+        chunk_mins + local_key
     }
 
     /// The center of the voxel with the given key.
@@ -856,8 +1236,262 @@ impl Voxels {
             .into()
     }
 
+    /// The key of the chunk containing the voxel with the given key.
+    fn chunk_key(voxel_key: Point<i32>) -> Point<i32> {
+        voxel_key.map(|e| e.div_euclid(CHUNK_WIDTH))
+    }
+
+    /// The semi-open range of grid coordinates of the voxels part of the chunk with the given key.
+    fn chunk_voxel_range(chunk_key: Point<i32>) -> [Point<i32>; 2] {
+        let mins = chunk_key * CHUNK_WIDTH;
+        [mins, mins + Vector::repeat(CHUNK_WIDTH)]
+    }
+
+    /// The index of the given voxel within its chunk.
+    fn local_index(voxel_key: Point<i32>) -> usize {
+        let local_key = voxel_key.map(|e| e.rem_euclid(CHUNK_WIDTH) as usize);
+        #[cfg(feature = "dim2")]
+        return local_key.x + local_key.y * CHUNK_WIDTH as usize;
+        #[cfg(feature = "dim3")]
+        return local_key.x
+            + local_key.y * CHUNK_WIDTH as usize
+            + local_key.z * (CHUNK_WIDTH * CHUNK_WIDTH) as usize;
+    }
+
+    /// The index of the given voxel’s state in `self.states`, if it is part of an allocated chunk.
+    fn state_index(&self, voxel_key: Point<i32>) -> Option<usize> {
+        let chunk_id = *self.chunk_ids.get(&Self::chunk_key(voxel_key))?;
+        Some(chunk_id as usize * CHUNK_LEN + Self::local_index(voxel_key))
+    }
+
+    /// Iterates through all the keys in the given semi-open range, with the `x` coordinate
+    /// varying the fastest.
+    #[cfg(feature = "dim2")]
+    fn keys_in_range(mins: Point<i32>, maxs: Point<i32>) -> impl Iterator<Item = Point<i32>> {
+        (mins[1]..maxs[1]).flat_map(move |iy| (mins[0]..maxs[0]).map(move |ix| Point::new(ix, iy)))
+    }
+
+    /// Iterates through all the keys in the given semi-open range, with the `x` coordinate
+    /// varying the fastest.
+    #[cfg(feature = "dim3")]
+    fn keys_in_range(mins: Point<i32>, maxs: Point<i32>) -> impl Iterator<Item = Point<i32>> {
+        (mins[2]..maxs[2]).flat_map(move |iz| {
+            (mins[1]..maxs[1])
+                .flat_map(move |iy| (mins[0]..maxs[0]).map(move |ix| Point::new(ix, iy, iz)))
+        })
+    }
+
+    /// Returns the id of the chunk with the given key, allocating it if it doesn’t exist yet.
+    ///
+    /// The returned boolean is `true` if the chunk was newly allocated. This doesn’t update
+    /// the chunks BVH.
+    fn get_or_alloc_chunk(&mut self, chunk_key: Point<i32>) -> (usize, bool) {
+        if let Some(id) = self.chunk_ids.get(&chunk_key) {
+            return (*id as usize, false);
+        }
+
+        // Pick a handle that isn’t used by any allocated chunk. Handles are assigned in a
+        // round-robin fashion so that the handle of a freed chunk doesn’t get reused before
+        // all the other ones.
+        while self.chunk_handles.contains_key(&self.next_chunk_handle) {
+            self.next_chunk_handle = (self.next_chunk_handle + 1) % MAX_CHUNK_HANDLES;
+        }
+        let handle = self.next_chunk_handle;
+        self.next_chunk_handle = (self.next_chunk_handle + 1) % MAX_CHUNK_HANDLES;
+
+        let chunk = VoxelsChunk {
+            key: chunk_key,
+            len: 0,
+            handle,
+        };
+        let id = if let Some(id) = self.free_chunks.pop() {
+            // NOTE: the states of a free chunk are all empty already.
+            self.chunks[id as usize] = chunk;
+            id as usize
+        } else {
+            self.chunks.push(chunk);
+            self.states
+                .resize(self.states.len() + CHUNK_LEN, VoxelState::EMPTY);
+            self.row_masks
+                .resize(self.states.len() / CHUNK_WIDTH as usize, 0);
+            self.brick_masks.push(0);
+            if !self.user_data.is_empty() {
+                self.user_data.resize(self.states.len(), 0);
+            }
+            self.chunks.len() - 1
+        };
+
+        let _ = self.chunk_ids.insert(chunk_key, id as u32);
+        let _ = self.chunk_handles.insert(handle, id as u32);
+        (id, true)
+    }
+
+    /// Sets the state and user-data of a voxel without updating its neighbors, nor the chunks BVH.
+    fn set_state_unchecked(&mut self, key: Point<i32>, state: VoxelState, user_data: u16) {
+        let (chunk_id, _) = self.get_or_alloc_chunk(Self::chunk_key(key));
+        let id = chunk_id * CHUNK_LEN + Self::local_index(key);
+
+        if self.states[id].is_empty() {
+            self.chunks[chunk_id].len += 1;
+            self.set_occupancy_bits(id, true);
+        }
+
+        self.states[id] = state;
+        self.set_user_data_at(id, user_data);
+    }
+
+    /// Sets whether the voxel with the given index in `self.states` is flagged as non-empty in
+    /// `self.row_masks` and `self.brick_masks`.
+    fn set_occupancy_bits(&mut self, id: usize, filled: bool) {
+        let row_id = id / CHUNK_WIDTH as usize;
+        let bit = 1 << (id % CHUNK_WIDTH as usize);
+        let brick_bit = 1 << Self::brick_index(id % CHUNK_LEN);
+
+        if filled {
+            self.row_masks[row_id] |= bit;
+            self.brick_masks[id / CHUNK_LEN] |= brick_bit;
+        } else {
+            self.row_masks[row_id] &= !bit;
+
+            if !self.is_brick_filled(id) {
+                self.brick_masks[id / CHUNK_LEN] &= !brick_bit;
+            }
+        }
+    }
+
+    /// The index, within its chunk, of the brick containing the voxel with the given index
+    /// within its chunk.
+    fn brick_index(local_id: usize) -> usize {
+        let brick_width = BRICK_WIDTH as usize;
+        let bricks_per_axis = BRICKS_PER_AXIS as usize;
+        let x = local_id % CHUNK_WIDTH as usize / brick_width;
+        let y = local_id / CHUNK_WIDTH as usize % CHUNK_WIDTH as usize / brick_width;
+        #[cfg(feature = "dim2")]
+        return x + y * bricks_per_axis;
+        #[cfg(feature = "dim3")]
+        return x
+            + y * bricks_per_axis
+            + local_id / (CHUNK_WIDTH * CHUNK_WIDTH) as usize / brick_width
+                * bricks_per_axis
+                * bricks_per_axis;
+    }
+
+    /// Checks, from `self.row_masks`, if the brick containing the voxel with the given index in
+    /// `self.states` contains any non-empty voxel.
+    fn is_brick_filled(&self, id: usize) -> bool {
+        let row_len = CHUNK_WIDTH as usize;
+        let brick_width = BRICK_WIDTH as usize;
+        let local_id = id % CHUNK_LEN;
+        let x = local_id % row_len;
+        let bits = ((1u32 << brick_width) - 1) << (x - x % brick_width);
+        // The index, in `self.row_masks`, of the first row of the chunk.
+        let first_row = id / CHUNK_LEN * (CHUNK_LEN / row_len);
+        let y = local_id / row_len % row_len;
+        let y0 = y - y % brick_width;
+
+        // The indices, relative to `first_row`, of the rows of the brick.
+        #[cfg(feature = "dim2")]
+        let mut rows = y0..y0 + brick_width;
+        #[cfg(feature = "dim3")]
+        let mut rows = {
+            let z = local_id / (row_len * row_len);
+            let z0 = z - z % brick_width;
+            (z0..z0 + brick_width)
+                .flat_map(move |z| (y0..y0 + brick_width).map(move |y| y + z * row_len))
+        };
+
+        rows.any(|row| self.row_masks[first_row + row] as u32 & bits != 0)
+    }
+
+    /// The semi-open range of grid coordinates of a block of empty voxels containing the
+    /// voxel with the given key, if any.
+    ///
+    /// This is the range of the voxel’s chunk if it isn’t allocated, or the range of the voxel’s
+    /// brick if it only contains empty voxels. Returns `None` otherwise, in which case the voxel
+    /// itself may or may not be empty.
+    pub(crate) fn empty_range_containing(&self, voxel_key: Point<i32>) -> Option<[Point<i32>; 2]> {
+        let chunk_key = Self::chunk_key(voxel_key);
+        let Some(chunk_id) = self.chunk_ids.get(&chunk_key) else {
+            return Some(Self::chunk_voxel_range(chunk_key));
+        };
+
+        let brick_bit = 1 << Self::brick_index(Self::local_index(voxel_key));
+        if self.brick_masks[*chunk_id as usize] & brick_bit != 0 {
+            return None;
+        }
+
+        let mins = voxel_key.map(|e| e - e.rem_euclid(BRICK_WIDTH));
+        Some([mins, mins + Vector::repeat(BRICK_WIDTH)])
+    }
+
+    fn user_data_at(&self, id: usize) -> u16 {
+        self.user_data.get(id).copied().unwrap_or_default()
+    }
+
+    fn set_user_data_at(&mut self, id: usize, user_data: u16) {
+        if self.user_data.is_empty() {
+            if user_data == 0 {
+                // All the user-data are zero, no need to allocate them.
+                return;
+            }
+
+            self.user_data.resize(self.states.len(), 0);
+        }
+
+        self.user_data[id] = user_data;
+    }
+
+    /// The AABB, in grid coordinates, of the chunk with the given key.
+    fn chunk_grid_aabb(chunk_key: Point<i32>) -> Aabb {
+        let [mins, maxs] = Self::chunk_voxel_range(chunk_key);
+        Aabb::new(mins.cast::<Real>(), maxs.cast::<Real>())
+    }
+
+    fn rebuild_chunk_bvh(&mut self) {
+        self.num_unbalanced_chunks = 0;
+        let chunks = self
+            .chunks
+            .iter()
+            .enumerate()
+            .filter(|(_, chunk)| chunk.len != 0)
+            .map(|(id, chunk)| (id as u32, Self::chunk_grid_aabb(chunk.key)))
+            .collect::<Vec<_>>();
+        self.chunk_bvh.clear_and_rebuild(chunks.into_iter(), 0.0);
+    }
+
+    /// Updates the chunks BVH leaves modified since its last update.
+    ///
+    /// New leaves are attached close to the root of the tree, so it is rebalanced once the
+    /// number of leaves inserted since its last rebalancing exceeds half the number of
+    /// allocated chunks. This keeps the amortized cost of a chunk allocation low, instead of
+    /// rebalancing the whole tree every time.
+    fn refit_chunk_bvh(&mut self) {
+        let chunks = &self.chunks;
+        let _ = self
+            .chunk_bvh
+            .refit(0.0, &mut self.chunk_bvh_workspace, |id| {
+                Self::chunk_grid_aabb(chunks[*id as usize].key)
+            });
+
+        if self.num_unbalanced_chunks as usize
+            > (self.chunk_ids.len() / 2).max(MIN_UNBALANCED_CHUNKS)
+        {
+            self.rebalance_chunk_bvh();
+        }
+    }
+
+    /// Rebalances the [`Qbvh`] indexing the allocated chunks.
+    ///
+    /// This tree is refitted whenever a chunk is allocated or freed, but only rebalanced once
+    /// in a while as chunks get allocated. This can be called after a large batch of edits to
+    /// rebalance it right away.
+    pub fn rebalance_chunk_bvh(&mut self) {
+        self.chunk_bvh.rebalance(0.0, &mut self.chunk_bvh_workspace);
+        self.num_unbalanced_chunks = 0;
+    }
+
     fn compute_voxel_state(&self, key: Point<i32>) -> VoxelState {
-        if self.states[self.linear_index(key) as usize].is_empty() {
+        if self.voxel_state(key).is_empty() {
             return VoxelState::EMPTY;
         }
 
@@ -868,14 +1502,10 @@ impl Voxels {
             prev[k] -= 1;
             next[k] += 1;
 
-            if key[k] + 1 < self.domain_maxs[k]
-                && !self.states[self.linear_index(next) as usize].is_empty()
-            {
+            if !self.voxel_state(next).is_empty() {
                 occupied_faces |= 1 << (k * 2);
             }
-            if key[k] > self.domain_mins[k]
-                && !self.states[self.linear_index(prev) as usize].is_empty()
-            {
+            if !self.voxel_state(prev).is_empty() {
                 occupied_faces |= 1 << (k * 2 + 1);
             }
         }
@@ -1513,8 +2143,346 @@ const FACES_TO_OCTANT_MASKS: [u32; 17] = [
 
 #[cfg(test)]
 mod test {
+    use super::{VoxelPrimitiveGeometry, VoxelState, Voxels, CHUNK_WIDTH};
+    use crate::math::{Isometry, Point, Real, Vector};
+    use crate::query::{
+        ContactManifold, DefaultQueryDispatcher, PersistentQueryDispatcher, PointQuery, Ray,
+        RayCast,
+    };
+    use crate::shape::{Cuboid, FeatureId, Shape};
+    use alloc::vec::Vec;
+
     #[test]
     fn gen_const_tables() {
         super::gen_const_tables();
     }
+
+    #[test]
+    fn voxels_scaled() {
+        let keys = [
+            Point::origin(),
+            Point::from(Vector::repeat(1)),
+            Point::from(Vector::x()),
+        ];
+        let voxels = Voxels::new(
+            VoxelPrimitiveGeometry::PseudoCube,
+            Vector::repeat(1.0),
+            &keys,
+        );
+
+        let mut scale = Vector::repeat(2.0);
+        scale.x = -3.0;
+        let scaled = voxels.clone().scaled(&scale).unwrap();
+        let mut expected_size = Vector::repeat(2.0);
+        expected_size.x = 3.0;
+        assert_eq!(scaled.voxel_size(), expected_size);
+
+        // Mirroring along `x` must preserve the voxels’ states up to the swapped `x` neighbors.
+        for key in keys {
+            let mut mirrored_key = key;
+            mirrored_key.x = -key.x - 1;
+            let state = voxels.voxel_state(key);
+            let mirrored_state = scaled.voxel_state(mirrored_key);
+            let mut expected_state = state.0 & !0b11;
+            expected_state |= ((state.0 & 0b01) << 1) | ((state.0 & 0b10) >> 1);
+            assert_eq!(mirrored_state, VoxelState(expected_state));
+            assert_eq!(
+                scaled.voxel_center(mirrored_key),
+                Point::from(voxels.voxel_center(key).coords.component_mul(&scale))
+            );
+        }
+
+        assert!(voxels.clone().scaled(&Vector::zeros()).is_none());
+
+        // Pseudo-balls only support uniform scaling.
+        let balls = Voxels::new(
+            VoxelPrimitiveGeometry::PseudoBall,
+            Vector::repeat(1.0),
+            &keys,
+        );
+        assert!(balls.clone().scaled(&scale).is_none());
+        assert!(balls.scaled(&-Vector::repeat(2.0)).is_some());
+    }
+
+    #[test]
+    fn voxels_sparse_chunks() {
+        let mut voxels = Voxels::new(
+            VoxelPrimitiveGeometry::PseudoCube,
+            Vector::repeat(1.0),
+            &[Point::origin()],
+        );
+
+        // Two adjacent voxels on both sides of a chunk boundary.
+        let right = Point::origin();
+        let left = Point::from(-Vector::<i32>::x());
+        assert_eq!(voxels.set_voxel(left, true), Some(VoxelState::EMPTY));
+        assert_eq!(voxels.voxel_state(left), VoxelState(0b01));
+        assert_eq!(voxels.voxel_state(right), VoxelState(0b10));
+        assert_eq!(voxels.chunk_ids.len(), 2);
+
+        // A voxel very far away only allocates a single additional chunk.
+        let far = Point::from(Vector::repeat(CHUNK_WIDTH * 1000 + 3));
+        assert_eq!(voxels.set_voxel(far, true), Some(VoxelState::EMPTY));
+        assert_eq!(voxels.voxel_state(far), VoxelState(0));
+        assert_eq!(voxels.chunk_ids.len(), 3);
+        assert_eq!(voxels.voxels().filter(|v| !v.state.is_empty()).count(), 3);
+        let [mins, maxs] = voxels.domain();
+        assert_eq!((*mins, *maxs), (left, far + Vector::repeat(1)));
+
+        // A ray traversing all the empty chunks in-between hits the far voxel.
+        let dir = Vector::repeat(1.0).normalize();
+        let ray = Ray::new(voxels.voxel_center(far) - dir * 100.0, dir);
+        let hit = voxels
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+            .unwrap();
+        assert!(voxels
+            .voxel_aabb(far)
+            .contains_local_point(&ray.point_at(hit.time_of_impact)));
+
+        // Removing the last voxel of a chunk frees it, and the freed chunk gets reused.
+        assert_eq!(voxels.set_voxel(left, false), Some(VoxelState(0b01)));
+        assert_eq!(voxels.voxel_state(right), VoxelState(0));
+        assert_eq!(voxels.chunk_ids.len(), 2);
+        assert_eq!(voxels.set_voxel(far, false), Some(VoxelState(0)));
+        assert_eq!(voxels.chunk_ids.len(), 1);
+        assert!(voxels.set_voxel(left, true).is_some());
+        assert_eq!(voxels.chunks.len(), 3);
+        assert!(voxels
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+            .is_none());
+
+        // Allocating and freeing many chunks one by one keeps the chunks BVH valid.
+        let keys: Vec<_> = (0..300)
+            .map(|i| Point::from(Vector::x() * (i * CHUNK_WIDTH * 2)))
+            .collect();
+        for key in &keys {
+            let _ = voxels.set_voxel(*key, true);
+        }
+        for key in keys.iter().step_by(3) {
+            let _ = voxels.set_voxel(*key, false);
+        }
+        // The tree isn’t rebalanced at every allocation.
+        assert!(voxels.num_unbalanced_chunks > 0);
+        voxels.rebalance_chunk_bvh();
+        voxels.chunk_bvh.check_topology(true, |id| {
+            Voxels::chunk_grid_aabb(voxels.chunks[*id as usize].key)
+        });
+        assert_eq!(voxels.chunk_ids.len(), 201);
+        let ray = Ray::new(
+            voxels.voxel_center(keys[299]) + Vector::y() * 10.0,
+            -Vector::y(),
+        );
+        assert!(voxels
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+            .is_some());
+    }
+
+    #[test]
+    fn voxels_filled_runs() {
+        let keys: Vec<_> = [0, 1, 2, 5, 15, 16, 17]
+            .iter()
+            .map(|x| Point::from(Vector::x() * *x))
+            .collect();
+        let mut voxels = Voxels::new(
+            VoxelPrimitiveGeometry::PseudoCube,
+            Vector::repeat(1.0),
+            &keys,
+        );
+        let _ = voxels.set_voxel(keys[1], false);
+
+        // Runs don’t cross chunk boundaries.
+        let mut runs: Vec<_> = voxels
+            .filled_runs()
+            .map(|(key, states)| (key.x, states.len()))
+            .collect();
+        runs.sort_unstable();
+        assert_eq!(runs, [(0, 1), (2, 1), (5, 1), (15, 1), (16, 2)]);
+        assert!(voxels
+            .filled_runs()
+            .all(|(_, states)| states.iter().all(|state| !state.is_empty())));
+    }
+
+    #[test]
+    fn voxels_user_data() {
+        // A row of voxels along `x`, each with a user-data equal to its `x` coordinate plus one.
+        let keys: Vec<_> = (0..4).map(|x| Point::from(Vector::x() * x)).collect();
+        let user_data: Vec<_> = (1..=4).collect();
+        let mut voxels = Voxels::with_user_data(
+            VoxelPrimitiveGeometry::PseudoCube,
+            Vector::repeat(1.0),
+            &keys,
+            &user_data,
+        );
+        let key = |x: i32| Point::from(Vector::x() * x);
+
+        for vox in voxels.voxels() {
+            let expected = if vox.state.is_empty() {
+                0
+            } else {
+                vox.grid_coords.x as u16 + 1
+            };
+            assert_eq!(vox.user_data, expected);
+        }
+
+        // Filling an already filled voxel only changes its user-data if it is provided.
+        assert!(voxels.set_voxel(key(1), true).is_some());
+        assert_eq!(voxels.voxel_user_data(key(1)), 2);
+        assert!(voxels.set_voxel_with_user_data(key(1), true, 20).is_some());
+        assert_eq!(voxels.voxel_user_data(key(1)), 20);
+
+        // Removed voxels lose their user-data, even if they get filled again.
+        assert!(voxels.set_voxel(key(3), false).is_some());
+        assert_eq!(voxels.voxel_user_data(key(3)), 0);
+        assert!(voxels.set_voxel(key(3), true).is_some());
+        assert_eq!(voxels.voxel_user_data(key(3)), 0);
+        assert!(voxels.set_voxel_with_user_data(key(-40), true, 7).is_some());
+
+        // The user-data are reachable from the features reported by queries.
+        let mut dir = Vector::zeros();
+        dir.y = -1.0;
+        let ray = Ray::new(voxels.voxel_center(key(1)) - dir * 10.0, dir);
+        let hit = voxels
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+            .unwrap();
+        assert_eq!(voxels.feature_user_data(hit.feature), Some(20));
+
+        let (_, feature) =
+            voxels.project_local_point_and_get_feature(&(voxels.voxel_center(key(-40)) - dir));
+        assert_eq!(voxels.feature_user_data(feature), Some(7));
+
+        // A cuboid slightly penetrating the voxels at `x = 0` and `x = 1`.
+        let dispatcher = DefaultQueryDispatcher;
+        let cuboid = Cuboid::new(Vector::repeat(0.5));
+        let mut pos12 = Vector::repeat(0.5);
+        pos12.x = 1.0;
+        pos12.y = 1.45;
+        let pos12 = Isometry::from(pos12);
+        let cases = [
+            (&voxels as &dyn Shape, &cuboid as &dyn Shape, pos12, false),
+            (&cuboid, &voxels, pos12.inverse(), true),
+        ];
+
+        for (g1, g2, pos12, flipped) in cases {
+            let mut manifolds: Vec<ContactManifold<(), ()>> = Vec::new();
+            let mut workspace = None;
+
+            // Run twice to check that the features are still reported properly once cached.
+            for _ in 0..2 {
+                dispatcher
+                    .contact_manifolds(&pos12, g1, g2, 0.1, &mut manifolds, &mut workspace)
+                    .unwrap();
+                let user_data: Vec<_> = manifolds
+                    .iter()
+                    .flat_map(|m| &m.points)
+                    .map(|pt| {
+                        let fid = if flipped { pt.fid2 } else { pt.fid1 };
+                        voxels.feature_user_data(fid.unpack()).unwrap()
+                    })
+                    .collect();
+                assert!(!user_data.is_empty());
+                assert!(user_data.iter().all(|data| [1, 20].contains(data)));
+            }
+        }
+    }
+
+    #[test]
+    fn voxels_stale_features() {
+        let key = Point::origin();
+        let far = Point::from(Vector::repeat(CHUNK_WIDTH * 10 + 3));
+        let mut voxels = Voxels::with_user_data(
+            VoxelPrimitiveGeometry::PseudoCube,
+            Vector::repeat(1.0),
+            &[key],
+            &[5],
+        );
+
+        let mut dir = Vector::zeros();
+        dir.y = -1.0;
+        let ray = Ray::new(voxels.voxel_center(key) - dir * 10.0, dir);
+        let old_feature = voxels
+            .cast_local_ray_and_get_normal(&ray, Real::MAX, true)
+            .unwrap()
+            .feature;
+        assert_eq!(voxels.feature_user_data(old_feature), Some(5));
+
+        // Free the voxel’s chunk, and allocate another chunk elsewhere in its slot.
+        assert!(voxels.set_voxel(key, false).is_some());
+        assert_eq!(voxels.feature_user_data(old_feature), None);
+        assert!(voxels.set_voxel_with_user_data(far, true, 8).is_some());
+        assert_eq!(voxels.chunks.len(), 1);
+
+        // The old feature doesn’t resolve to the voxel that now occupies the same storage.
+        assert_eq!(voxels.feature_user_data(old_feature), None);
+        let old_id = match old_feature {
+            FeatureId::Face(id) => id,
+            _ => unreachable!(),
+        };
+        assert_eq!(voxels.voxel_at_id(old_id), None);
+
+        let new_id = voxels.linear_index(far).unwrap();
+        assert_ne!(new_id, old_id);
+        assert_eq!(voxels.voxel_at_id(new_id), Some(far));
+        assert_eq!(voxels.feature_user_data(FeatureId::Face(new_id)), Some(8));
+    }
+
+    #[test]
+    fn voxels_brick_masks() {
+        // A sparse pseudo-random pattern within a few chunks, then with some voxels removed.
+        let keys: Vec<_> = (0..CHUNK_WIDTH * 3)
+            .flat_map(|i| {
+                (0..CHUNK_WIDTH * 2).filter_map(move |j| {
+                    let mut key = Point::from(Vector::repeat(j - CHUNK_WIDTH));
+                    key.x = i - CHUNK_WIDTH;
+                    ((i * 7 + j * 13) % 23 == 0).then_some(key)
+                })
+            })
+            .collect();
+        let mut voxels = Voxels::new(
+            VoxelPrimitiveGeometry::PseudoCube,
+            Vector::repeat(1.0),
+            &keys,
+        );
+        for key in keys.iter().step_by(2) {
+            let _ = voxels.set_voxel(*key, false);
+        }
+
+        // The occupancy masks match the voxel states.
+        let filled: Vec<_> = voxels.filled_voxels().map(|vox| vox.grid_coords).collect();
+        let expected: Vec<_> = keys.iter().skip(1).step_by(2).copied().collect();
+        assert_eq!(filled.len(), expected.len());
+        assert!(expected.iter().all(|key| filled.contains(key)));
+
+        let mut num_empty_bricks = 0;
+        for vox in voxels.voxels() {
+            if let Some([mins, maxs]) = voxels.empty_range_containing(vox.grid_coords) {
+                num_empty_bricks += 1;
+                assert!(
+                    Voxels::keys_in_range(mins, maxs).all(|key| voxels.voxel_state(key).is_empty())
+                );
+            }
+        }
+        assert!(num_empty_bricks > 0);
+
+        // Ray-casts skipping the empty bricks hit the same voxels as brute-force ray-casts.
+        let [mins, maxs] = voxels.domain();
+        let (mins, maxs) = (*mins, *maxs);
+        let mut num_hits = 0;
+        for i in 0..CHUNK_WIDTH * 3 {
+            let mut origin = voxels.voxel_center(mins + Vector::repeat(i % (maxs.y - mins.y)));
+            origin.x = mins.x as Real - 1.0;
+            let ray = Ray::new(origin, Vector::repeat(0.1) + Vector::x());
+            let hit = voxels.cast_local_ray(&ray, Real::MAX, true);
+            let expected = voxels
+                .filled_voxels()
+                .filter_map(|vox| {
+                    voxels
+                        .voxel_aabb(vox.grid_coords)
+                        .cast_local_ray(&ray, Real::MAX, true)
+                })
+                .min_by(|a, b| a.partial_cmp(b).unwrap());
+            assert_eq!(hit, expected);
+            num_hits += hit.is_some() as usize;
+        }
+        assert!(num_hits > 0);
+    }
 }
@@ -6,6 +6,8 @@ use crate::{
     utils::point_in_triangle::{corner_direction, is_point_in_triangle, Orientation},
 };
 use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+use core::ops::Range;
 
 /// The information stored for each vertex in the ear clipping algorithm.
 #[derive(Clone, Default)]
@@ -39,10 +41,13 @@ fn update_vertex(idx: usize, vertex_info: &mut VertexInfo, points: &[Point<Real>
 
     // A point is considered an ear when it is convex and no other points are
     // inside the triangle spanned by it and its two neighbors.
+    // Points coinciding with one of the ear’s corners (e.g. the duplicated endpoints of the bridges
+    // created by `triangulate_ear_clipping_with_holes`) are ignored.
     let mut error = false;
     vertex_info.is_ear = corner_direction(&p1, &p, &p3) == Orientation::Ccw
         && (0..points.len())
             .filter(|&i| i != vertex_info.p_prev && i != idx && i != vertex_info.p_next)
+            .filter(|&i| points[i] != p1 && points[i] != p && points[i] != p3)
             .all(|i| {
                 if let Some(is) = is_point_in_triangle(&points[i], &p1, &p, &p3) {
                     !is
@@ -131,6 +136,172 @@ pub(crate) fn triangulate_ear_clipping(vertices: &[Point<Real>]) -> Option<Vec<[
     Some(output_indices)
 }
 
+/// Ear clipping triangulation algorithm for a polygon with holes.
+///
+/// The first ring of `rings` is the outer boundary of the polygon and must be given in
+/// counter-clockwise order. The other rings are the holes and must be given in clockwise order.
+/// Each ring is a range of indices into `vertices`, and the returned triangles index `vertices`
+/// too.
+///
+/// The holes are merged into the outer boundary by connecting each of them to a visible vertex
+/// of the boundary with a bridge (a pair of opposite edges). The resulting weakly-simple polygon
+/// is then triangulated by [`triangulate_ear_clipping`].
+pub(crate) fn triangulate_ear_clipping_with_holes(
+    vertices: &[Point<Real>],
+    rings: &[Range<u32>],
+) -> Option<Vec<[u32; 3]>> {
+    let (outer, holes) = rings.split_first()?;
+    let mut boundary: Vec<u32> = outer.clone().collect();
+    let mut holes: Vec<_> = holes.iter().filter(|hole| !hole.is_empty()).collect();
+
+    // Merge the holes from the right-most one to the left-most one, so that the bridge of a
+    // hole never crosses a hole that isn’t merged yet.
+    let max_x = |hole: &Range<u32>| {
+        hole.clone()
+            .map(|i| vertices[i as usize].x)
+            .fold(-Real::MAX, Real::max)
+    };
+    holes.sort_by(|a, b| max_x(b).partial_cmp(&max_x(a)).unwrap_or(Ordering::Equal));
+
+    for hole in holes {
+        let hole_pt_id = hole
+            .clone()
+            .max_by(|a, b| {
+                vertices[*a as usize]
+                    .x
+                    .partial_cmp(&vertices[*b as usize].x)
+                    .unwrap_or(Ordering::Equal)
+            })
+            .unwrap();
+        let bridge_start = find_bridge_target(vertices, &boundary, &vertices[hole_pt_id as usize])?;
+
+        // Splice the hole (starting and ending at `hole_pt_id`) after `bridge_start`.
+        let hole_len = hole.len() as u32;
+        let spliced = (0..=hole_len)
+            .map(|k| hole.start + (hole_pt_id - hole.start + k) % hole_len)
+            .chain(core::iter::once(boundary[bridge_start]));
+        let _ = boundary.splice(bridge_start + 1..bridge_start + 1, spliced);
+    }
+
+    let points: Vec<_> = boundary.iter().map(|i| vertices[*i as usize]).collect();
+    let triangles = triangulate_ear_clipping(&points)?;
+    Some(
+        triangles
+            .into_iter()
+            .map(|tri| tri.map(|i| boundary[i as usize]))
+            .collect(),
+    )
+}
+
+/// Finds the position, in the counter-clockwise `boundary`, of a vertex visible from `pt`, where
+/// `pt` is the right-most point of a hole located inside of the boundary.
+fn find_bridge_target(
+    vertices: &[Point<Real>],
+    boundary: &[u32],
+    pt: &Point<Real>,
+) -> Option<usize> {
+    // Cast a ray from `pt` toward +X and find the closest boundary edge it intersects.
+    let mut closest: Option<(Real, usize)> = None;
+
+    for i in 0..boundary.len() {
+        let a = vertices[boundary[i] as usize];
+        let b = vertices[boundary[(i + 1) % boundary.len()] as usize];
+
+        if (a.y > pt.y) == (b.y > pt.y) && a.y != pt.y && b.y != pt.y {
+            continue;
+        }
+
+        let x = if a.y == b.y {
+            a.x.max(b.x)
+        } else {
+            a.x + (pt.y - a.y) * (b.x - a.x) / (b.y - a.y)
+        };
+
+        if x >= pt.x && closest.map(|(best_x, _)| x < best_x).unwrap_or(true) {
+            closest = Some((x, i));
+        }
+    }
+
+    let (hit_x, edge) = closest?;
+    let edge_end = (edge + 1) % boundary.len();
+    let a = vertices[boundary[edge] as usize];
+    let b = vertices[boundary[edge_end] as usize];
+    let candidate = if a.x > b.x { edge } else { edge_end };
+    let candidate_pt = vertices[boundary[candidate] as usize];
+    let hit = Point::new(hit_x, pt.y);
+
+    if candidate_pt == hit {
+        return pick_visible_occurrence(vertices, boundary, candidate, pt);
+    }
+
+    // Vertices inside of the triangle formed by `pt`, the hit point, and the candidate may hide
+    // the candidate from `pt`. In that case, pick the one with the smallest angle with the ray.
+    let mut best = candidate;
+    let mut best_key = ray_angle_key(pt, &candidate_pt);
+
+    for (i, id) in boundary.iter().enumerate() {
+        let v = vertices[*id as usize];
+
+        if v == candidate_pt || v == *pt {
+            continue;
+        }
+
+        if is_point_in_triangle(&v, pt, &hit, &candidate_pt) == Some(true) {
+            let key = ray_angle_key(pt, &v);
+            if key < best_key {
+                best = i;
+                best_key = key;
+            }
+        }
+    }
+
+    pick_visible_occurrence(vertices, boundary, best, pt)
+}
+
+/// A sorting key for picking the boundary vertex making the smallest angle with the +X ray
+/// starting at `pt`, breaking ties with the distance.
+fn ray_angle_key(pt: &Point<Real>, v: &Point<Real>) -> (Real, Real) {
+    let dir = v - pt;
+    let dist = dir.norm();
+    (-dir.x / dist, dist)
+}
+
+/// A vertex may appear several times in the boundary if it is the endpoint of a bridge. Picks
+/// the occurrence such that the direction toward `pt` lies inside of the boundary’s interior
+/// angle, so that the new bridge doesn’t cross the existing ones.
+fn pick_visible_occurrence(
+    vertices: &[Point<Real>],
+    boundary: &[u32],
+    position: usize,
+    pt: &Point<Real>,
+) -> Option<usize> {
+    let target = vertices[boundary[position] as usize];
+    let n = boundary.len();
+    let occurrences = (0..n).filter(|i| vertices[boundary[*i] as usize] == target);
+
+    for i in occurrences {
+        let prev = vertices[boundary[(i + n - 1) % n] as usize];
+        let next = vertices[boundary[(i + 1) % n] as usize];
+        let to_prev = prev - target;
+        let to_next = next - target;
+        let dir = pt - target;
+
+        let inside = if to_next.perp(&to_prev) >= 0.0 {
+            // Convex corner.
+            to_next.perp(&dir) >= 0.0 && dir.perp(&to_prev) >= 0.0
+        } else {
+            // Reflex corner.
+            to_next.perp(&dir) >= 0.0 || dir.perp(&to_prev) >= 0.0
+        };
+
+        if inside {
+            return Some(i);
+        }
+    }
+
+    Some(position)
+}
+
 // --- Unit tests ----------------------------------------------------------------------------
 #[cfg(test)]
 mod tests {
@@ -215,4 +386,38 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn square_with_holes() {
+        let vertices = vec![
+            // Outer boundary, counter-clockwise.
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 4.0),
+            Point::new(0.0, 4.0),
+            // First hole, clockwise.
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 3.0),
+            Point::new(2.0, 3.0),
+            Point::new(2.0, 1.0),
+            // Second hole, clockwise, aligned with the first one.
+            Point::new(2.5, 1.0),
+            Point::new(2.5, 3.0),
+            Point::new(3.0, 3.0),
+            Point::new(3.0, 1.0),
+        ];
+        let triangles =
+            triangulate_ear_clipping_with_holes(&vertices, &[0..4, 4..8, 8..12]).unwrap();
+
+        // A polygon with `n` vertices and `h` holes is split into `n + 2h - 2` triangles.
+        assert_eq!(triangles.len(), 14);
+        let area: Real = triangles
+            .iter()
+            .map(|t| {
+                let [a, b, c] = t.map(|i| vertices[i as usize]);
+                (b - a).perp(&(c - a)) / 2.0
+            })
+            .sum();
+        assert_eq!(area, 16.0 - 2.0 - 1.0);
+    }
 }
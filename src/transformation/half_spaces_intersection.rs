@@ -0,0 +1,814 @@
+use crate::math::{Point, Real, Vector, DEFAULT_EPSILON, DIM};
+#[cfg(feature = "dim3")]
+use crate::utils::hashmap::HashMap;
+use crate::utils::WBasis;
+use alloc::{vec, vec::Vec};
+#[cfg(not(feature = "std"))]
+use na::ComplexField;
+use na::Unit;
+#[cfg(feature = "dim3")]
+use na::{Point2, Vector2};
+
+/// Errors generated when computing the intersection of a set of half-spaces.
+#[derive(thiserror::Error, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HalfSpacesIntersectionError {
+    /// The intersection of the half-spaces is empty.
+    #[error("The intersection of the half-spaces is empty.")]
+    Empty,
+    /// The intersection of the half-spaces is not bounded.
+    #[error("The intersection of the half-spaces is unbounded.")]
+    Unbounded,
+    /// The intersection of the half-spaces is bounded and not empty, but has no interior (e.g.
+    /// it is flat).
+    #[error("The intersection of the half-spaces has no interior.")]
+    Degenerate,
+}
+
+/// Computes the vertices of the convex region at the intersection of a set of half-spaces.
+///
+/// Each half-space is given as a pair `(normal, offset)` and contains the points `x` such that
+/// `normal.dot(x) <= offset`. The normal points outward and doesn't have to be normalized.
+///
+/// The region is first found by clipping the plane bounding each half-space by all the other
+/// half-spaces, which takes about `O(n²)` time for `n` half-spaces. Its faces are then read
+/// from the convex hull of the points dual to the planes. Redundant half-spaces don't generate
+/// any vertex, and the vertices that nearly coincide (for example because some planes are
+/// almost parallel) are merged. The returned points are not ordered.
+///
+/// Returns an error if the intersection is empty, unbounded, or flat.
+pub fn half_spaces_intersection_points(
+    half_spaces: &[(Vector<Real>, Real)],
+) -> Result<Vec<Point<Real>>, HalfSpacesIntersectionError> {
+    half_spaces_intersection(half_spaces).map(|(points, _)| points)
+}
+
+/// Computes the vertices and the faces of the convex region at the intersection of a set of
+/// half-spaces.
+///
+/// Each face is given by the unit normal of the half-space supporting it, and the indices of
+/// its vertices. In 3D, these vertices are ordered counterclockwise around the normal. In 2D,
+/// the faces are the edges of a polygon: their vertices are their two endpoints, and the faces
+/// themselves are ordered counterclockwise.
+pub(crate) fn half_spaces_intersection(
+    half_spaces: &[(Vector<Real>, Real)],
+) -> Result<(Vec<Point<Real>>, Vec<(Unit<Vector<Real>>, Vec<u32>)>), HalfSpacesIntersectionError> {
+    let angular_eps = DEFAULT_EPSILON.sqrt();
+    let mut planes: Vec<(Vector<Real>, Real)> = Vec::with_capacity(half_spaces.len());
+
+    for (normal, offset) in half_spaces {
+        let norm = normal.norm();
+
+        if norm > DEFAULT_EPSILON {
+            let (normal, offset) = (normal / norm, offset / norm);
+            // Among the half-spaces with (almost) the same normal, only the tightest matters.
+            match planes
+                .iter_mut()
+                .find(|p| p.0.dot(&normal) > 0.0 && sin_angle(&p.0, &normal) <= angular_eps)
+            {
+                Some(plane) => plane.1 = plane.1.min(offset),
+                None => planes.push((normal, offset)),
+            }
+        } else if *offset < 0.0 {
+            // No point satisfies `0 <= offset`.
+            return Err(HalfSpacesIntersectionError::Empty);
+        }
+        // Otherwise, the half-space contains the whole space and can be ignored.
+    }
+
+    let scale = planes
+        .iter()
+        .fold(1.0, |scale: Real, p| scale.max(p.1.abs()));
+    let tolerance = DEFAULT_EPSILON.sqrt() * scale;
+    // The faces extending farther than this from the origin are considered unbounded. Rounding
+    // errors on points this far are still about as small as the tolerance.
+    let extent = scale / DEFAULT_EPSILON.sqrt();
+    let null_space = normals_null_space(&planes);
+
+    if !null_space.is_empty() {
+        // The region is invariant along the directions orthogonal to all the normals, so it
+        // is either empty or unbounded. It is empty iff its slice orthogonal to these
+        // directions is empty.
+        for dir in null_space {
+            planes.push((dir, 0.0));
+            planes.push((-dir, 0.0));
+        }
+
+        return if (0..planes.len())
+            .all(|i| matches!(clip_face(&planes, i, tolerance, extent), FaceClip::Empty))
+        {
+            Err(HalfSpacesIntersectionError::Empty)
+        } else {
+            Err(HalfSpacesIntersectionError::Unbounded)
+        };
+    }
+
+    // Since the normals span the whole space, the region is unbounded iff one of its faces is,
+    // and it is empty iff all its faces are.
+    let mut is_empty = true;
+    let mut face_points = vec![];
+
+    for i in 0..planes.len() {
+        match clip_face(&planes, i, tolerance, extent) {
+            FaceClip::Empty => {}
+            FaceClip::Unbounded => return Err(HalfSpacesIntersectionError::Unbounded),
+            FaceClip::Bounded(points) => {
+                is_empty = false;
+                face_points.extend(points);
+            }
+        }
+    }
+
+    if is_empty {
+        return Err(HalfSpacesIntersectionError::Empty);
+    }
+
+    // Because of the tolerances, the faces clipped independently may disagree on their tiny
+    // edges, so they are only used to find a point inside of the region. The region is then
+    // the polar dual of the convex hull of the points `n / (o - n.dot(center))`: each of its
+    // faces is dual to a vertex of this hull, and each of its vertices to a facet of this hull.
+    let center =
+        interior_point(&face_points, tolerance).ok_or(HalfSpacesIntersectionError::Degenerate)?;
+    let dual_points = planes
+        .iter()
+        .map(|(normal, offset)| {
+            let dist = offset - normal.dot(&center.coords);
+            if dist > 0.0 {
+                Ok(Point::from(normal / dist))
+            } else {
+                Err(HalfSpacesIntersectionError::Degenerate)
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    dual_hull_faces(&planes, &dual_points, &center, tolerance)
+}
+
+/// Reads the faces of the region bounded by `planes` from the convex hull of their dual points.
+#[cfg(feature = "dim2")]
+fn dual_hull_faces(
+    planes: &[(Vector<Real>, Real)],
+    dual_points: &[Point<Real>],
+    center: &Point<Real>,
+    tolerance: Real,
+) -> Result<(Vec<Point<Real>>, Vec<(Unit<Vector<Real>>, Vec<u32>)>), HalfSpacesIntersectionError> {
+    let hull = crate::transformation::convex_hull_idx(dual_points);
+    let n = hull.len();
+
+    if n < 3 {
+        return Err(HalfSpacesIntersectionError::Degenerate);
+    }
+
+    // The vertex `k` of the region is dual to the hull edge between `hull[k]` and `hull[k + 1]`.
+    let mut positions = Vec::with_capacity(n);
+    for k in 0..n {
+        let (qa, qb) = (dual_points[hull[k]], dual_points[hull[(k + 1) % n]]);
+        let normal = Vector::new(qb.y - qa.y, qa.x - qb.x);
+        let offset = normal.dot(&qa.coords);
+        if offset <= 0.0 {
+            return Err(HalfSpacesIntersectionError::Degenerate);
+        }
+        positions.push(center + normal / offset);
+    }
+
+    // Merge the ends of the edges shorter than the tolerance.
+    let mut parents: Vec<usize> = (0..n).collect();
+    for k in 0..n {
+        let prev = (k + n - 1) % n;
+        if na::distance_squared(&positions[prev], &positions[k]) <= tolerance * tolerance {
+            let root1 = find_root(&mut parents, prev);
+            let root2 = find_root(&mut parents, k);
+            parents[root1] = root2;
+        }
+    }
+
+    // The edge supported by `hull[k]` goes from the vertex `k - 1` to the vertex `k`.
+    let mut new_ids = vec![u32::MAX; n];
+    let mut points = vec![];
+    let mut faces = vec![];
+
+    for k in 0..n {
+        let ends = [(k + n - 1) % n, k].map(|vertex| {
+            let root = find_root(&mut parents, vertex);
+            if new_ids[root] == u32::MAX {
+                new_ids[root] = points.len() as u32;
+                points.push(positions[root]);
+            }
+            new_ids[root]
+        });
+
+        if ends[0] != ends[1] {
+            faces.push((Unit::new_unchecked(planes[hull[k]].0), ends.to_vec()));
+        }
+    }
+
+    if faces.len() < 3 {
+        return Err(HalfSpacesIntersectionError::Degenerate);
+    }
+
+    Ok((points, faces))
+}
+
+/// Reads the faces of the region bounded by `planes` from the convex hull of their dual points.
+#[cfg(feature = "dim3")]
+fn dual_hull_faces(
+    planes: &[(Vector<Real>, Real)],
+    dual_points: &[Point<Real>],
+    center: &Point<Real>,
+    tolerance: Real,
+) -> Result<(Vec<Point<Real>>, Vec<(Unit<Vector<Real>>, Vec<u32>)>), HalfSpacesIntersectionError> {
+    let (hull_points, hull_triangles) = crate::transformation::try_convex_hull(dual_points)
+        .map_err(|_| HalfSpacesIntersectionError::Degenerate)?;
+
+    // The convex hull only keeps the points it uses, so find the planes they come from.
+    let bits = |pt: &Point<Real>| pt.coords.map(|x| x.to_bits());
+    let plane_ids: HashMap<_, _> = dual_points
+        .iter()
+        .enumerate()
+        .map(|(i, pt)| (bits(pt), i))
+        .collect();
+    let triangles: Vec<[usize; 3]> = hull_triangles
+        .iter()
+        .map(|tri| tri.map(|id| plane_ids[&bits(&hull_points[id as usize])]))
+        .collect();
+
+    // Each triangle of the hull is dual to a vertex of the region.
+    let mut positions = Vec::with_capacity(triangles.len());
+    let mut triangle_with_edge = HashMap::default();
+
+    for (t, tri) in triangles.iter().enumerate() {
+        let [qa, qb, qc] = tri.map(|id| dual_points[id]);
+        let normal = (qb - qa).cross(&(qc - qa));
+        let offset = normal.dot(&qa.coords);
+        if tri[0] == tri[1] || tri[0] == tri[2] || tri[1] == tri[2] || offset <= 0.0 {
+            return Err(HalfSpacesIntersectionError::Degenerate);
+        }
+        positions.push(center + normal / offset);
+
+        for k in 0..3 {
+            let _ = triangle_with_edge.insert((tri[k], tri[(k + 1) % 3]), t);
+        }
+    }
+
+    // Merge the ends of the edges shorter than the tolerance.
+    let mut parents: Vec<usize> = (0..triangles.len()).collect();
+    for (t1, tri) in triangles.iter().enumerate() {
+        for k in 0..3 {
+            let t2 = *triangle_with_edge
+                .get(&(tri[(k + 1) % 3], tri[k]))
+                .ok_or(HalfSpacesIntersectionError::Degenerate)?;
+            if na::distance_squared(&positions[t1], &positions[t2]) <= tolerance * tolerance {
+                let root1 = find_root(&mut parents, t1);
+                let root2 = find_root(&mut parents, t2);
+                parents[root1] = root2;
+            }
+        }
+    }
+
+    // Each face of the region is dual to a vertex of the hull: its vertices are dual to the
+    // triangles around this hull vertex, in counterclockwise order.
+    let mut first_triangle = vec![usize::MAX; planes.len()];
+    for (t, tri) in triangles.iter().enumerate() {
+        for id in tri {
+            if first_triangle[*id] == usize::MAX {
+                first_triangle[*id] = t;
+            }
+        }
+    }
+
+    let mut new_ids = vec![u32::MAX; triangles.len()];
+    let mut points = vec![];
+    let mut faces = vec![];
+
+    for (plane_id, start) in first_triangle.into_iter().enumerate() {
+        if start == usize::MAX {
+            // The half-space is redundant.
+            continue;
+        }
+
+        let mut roots = vec![];
+        let mut t = start;
+        loop {
+            let root = find_root(&mut parents, t);
+            if roots.last() != Some(&root) {
+                roots.push(root);
+            }
+
+            // Step to the triangle sharing the edge between `plane_id` and the last vertex of `t`.
+            let tri = triangles[t];
+            let k = tri
+                .iter()
+                .position(|id| *id == plane_id)
+                .unwrap_or_default();
+            t = triangle_with_edge[&(plane_id, tri[(k + 2) % 3])];
+
+            if t == start {
+                break;
+            } else if roots.len() > triangles.len() {
+                // The hull isn't manifold around this vertex.
+                return Err(HalfSpacesIntersectionError::Degenerate);
+            }
+        }
+
+        if roots.len() > 1 && roots.first() == roots.last() {
+            let _ = roots.pop();
+        }
+
+        // The half-spaces only touching the region don't generate any face.
+        if roots.len() >= 3 {
+            let vertices = roots
+                .into_iter()
+                .map(|root| {
+                    if new_ids[root] == u32::MAX {
+                        new_ids[root] = points.len() as u32;
+                        points.push(positions[root]);
+                    }
+                    new_ids[root]
+                })
+                .collect();
+            faces.push((Unit::new_unchecked(planes[plane_id].0), vertices));
+        }
+    }
+
+    if faces.len() < 4 {
+        return Err(HalfSpacesIntersectionError::Degenerate);
+    }
+
+    Ok((points, faces))
+}
+
+/// The sine of the angle between two unit vectors.
+fn sin_angle(a: &Vector<Real>, b: &Vector<Real>) -> Real {
+    #[cfg(feature = "dim2")]
+    return a.perp(b).abs();
+    #[cfg(feature = "dim3")]
+    return a.cross(b).norm();
+}
+
+/// The root of the set containing `i` in a union-find forest.
+fn find_root(parents: &mut [usize], mut i: usize) -> usize {
+    while parents[i] != i {
+        parents[i] = parents[parents[i]];
+        i = parents[i];
+    }
+    i
+}
+
+/// The intersection of the plane bounding a half-space with all the other half-spaces.
+enum FaceClip {
+    /// The plane doesn't intersect the region.
+    Empty,
+    /// The plane intersects the region along an infinite set.
+    Unbounded,
+    /// The vertices of the intersection.
+    Bounded(Vec<Point<Real>>),
+}
+
+/// Intersects the line bounding the `i`-th half-plane with all the other half-planes.
+#[cfg(feature = "dim2")]
+fn clip_face(planes: &[(Vector<Real>, Real)], i: usize, tolerance: Real, extent: Real) -> FaceClip {
+    let angular_eps = DEFAULT_EPSILON.sqrt();
+    let (normal, offset) = planes[i];
+    let dir = Vector::new(-normal.y, normal.x);
+    let origin = normal * offset;
+    // The range of parameters `t` such that `origin + dir * t` is in the other half-planes,
+    // and whether each of its ends is set by one of them.
+    let (mut min, mut max) = ((-extent, false), (extent, false));
+
+    for (j, (n, o)) in planes.iter().enumerate() {
+        if j == i {
+            continue;
+        }
+
+        let a = n.dot(&dir);
+        let b = o - n.dot(&origin);
+
+        if a.abs() <= angular_eps {
+            if b < -tolerance {
+                return FaceClip::Empty;
+            }
+        } else if a > 0.0 {
+            if b / a < max.0 {
+                max = (b / a, true);
+            }
+        } else if b / a > min.0 {
+            min = (b / a, true);
+        }
+    }
+
+    if min.0 > max.0 + tolerance {
+        FaceClip::Empty
+    } else if !min.1 || !max.1 {
+        FaceClip::Unbounded
+    } else {
+        FaceClip::Bounded(vec![
+            Point::from(origin + dir * min.0),
+            Point::from(origin + dir * max.0),
+        ])
+    }
+}
+
+/// Intersects the plane bounding the `i`-th half-space with all the other half-spaces.
+#[cfg(feature = "dim3")]
+fn clip_face(planes: &[(Vector<Real>, Real)], i: usize, tolerance: Real, extent: Real) -> FaceClip {
+    let angular_eps = DEFAULT_EPSILON.sqrt();
+    let (normal, offset) = planes[i];
+    let u = normal.orthonormal_basis()[0];
+    let v = normal.cross(&u);
+    let origin = normal * offset;
+    // The lines `(a, b)` containing the points `p` of the plane (in the basis `u, v`) such that
+    // `a.dot(p) <= b`, starting with a square larger than any bounded face.
+    let mut lines = vec![
+        (Vector2::x(), extent),
+        (Vector2::y(), extent),
+        (-Vector2::x(), extent),
+        (-Vector2::y(), extent),
+    ];
+    // The lines supporting the edges of the face, in counterclockwise order.
+    let mut edges: Vec<usize> = (0..lines.len()).collect();
+
+    for (j, (n, o)) in planes.iter().enumerate() {
+        if j == i {
+            continue;
+        }
+
+        let a = Vector2::new(n.dot(&u), n.dot(&v));
+        let b = o - n.dot(&origin);
+        let norm = a.norm();
+
+        if norm <= angular_eps {
+            if b < -tolerance {
+                return FaceClip::Empty;
+            }
+        } else {
+            lines.push((a / norm, b / norm));
+            if !clip_polygon(&mut edges, &lines, tolerance) {
+                return FaceClip::Empty;
+            }
+        }
+    }
+
+    if edges.iter().any(|e| *e < 4) {
+        // One of the edges of the initial square is left.
+        return FaceClip::Unbounded;
+    }
+
+    FaceClip::Bounded(
+        (0..edges.len())
+            .map(|k| {
+                let pt = lines_intersection(lines[edges[k]], lines[edges[(k + 1) % edges.len()]]);
+                Point::from(origin + u * pt.x + v * pt.y)
+            })
+            .collect(),
+    )
+}
+
+/// Clips the convex polygon bounded by the given `edges` by the half-plane bounded by the last
+/// of the `lines`.
+///
+/// The vertex `k` of the polygon is the intersection of the lines of its edges `k` and `k + 1`.
+/// Returns `false` if the clipped polygon is empty.
+#[cfg(feature = "dim3")]
+fn clip_polygon(edges: &mut Vec<usize>, lines: &[(Vector2<Real>, Real)], tolerance: Real) -> bool {
+    let angular_eps = DEFAULT_EPSILON.sqrt();
+    let new_edge = lines.len() - 1;
+    let (a, b) = lines[new_edge];
+
+    // Rounding errors on the vertices far from the origin could make the new line look like
+    // it cuts them if it (nearly) coincides with the line of their edge, so skip it directly.
+    if edges.iter().any(|e| {
+        let (edge_a, edge_b) = lines[*e];
+        edge_a.dot(&a) > 0.0 && edge_a.perp(&a).abs() <= angular_eps && b >= edge_b - tolerance
+    }) {
+        return true;
+    }
+
+    let n = edges.len();
+    // How far each vertex is outside of the half-plane.
+    let dists: Vec<Real> = (0..n)
+        .map(|k| {
+            let pt = lines_intersection(lines[edges[k]], lines[edges[(k + 1) % n]]);
+            a.dot(&pt.coords) - b
+        })
+        .collect();
+
+    if dists.iter().all(|d| *d > tolerance) {
+        return false;
+    }
+
+    let farthest = (0..n)
+        .max_by(|i, j| dists[*i].total_cmp(&dists[*j]))
+        .unwrap();
+    if dists[farthest] <= tolerance {
+        // The half-plane contains the whole polygon.
+        return true;
+    }
+
+    // The vertices outside of the half-plane are contiguous. Remove the edges between them, and
+    // insert the new edge in their place.
+    let (mut first, mut last) = (farthest, farthest);
+    while dists[(first + n - 1) % n] > tolerance {
+        first = (first + n - 1) % n;
+    }
+    while dists[(last + 1) % n] > tolerance {
+        last = (last + 1) % n;
+    }
+
+    let mut clipped = Vec::with_capacity(n + 1);
+    let mut k = (last + 1) % n;
+    loop {
+        clipped.push(edges[k]);
+        if k == first {
+            break;
+        }
+        k = (k + 1) % n;
+    }
+    clipped.push(new_edge);
+    *edges = clipped;
+    true
+}
+
+/// The intersection point of two lines `(a, b)` made of the points `p` such that
+/// `a.dot(p) == b`.
+#[cfg(feature = "dim3")]
+fn lines_intersection(l1: (Vector2<Real>, Real), l2: (Vector2<Real>, Real)) -> Point2<Real> {
+    let det = l1.0.perp(&l2.0);
+
+    if det.abs() <= DEFAULT_EPSILON {
+        // Only happens on polygons flattened by numerical errors: any point of `l1` will do.
+        return Point2::from(l1.0 * l1.1);
+    }
+
+    Point2::new(
+        (l1.1 * l2.0.y - l2.1 * l1.0.y) / det,
+        (l1.0.x * l2.1 - l2.0.x * l1.1) / det,
+    )
+}
+
+/// An orthonormal basis of the directions orthogonal to all the (unit) normals of `planes`.
+fn normals_null_space(planes: &[(Vector<Real>, Real)]) -> Vec<Vector<Real>> {
+    let angular_eps = DEFAULT_EPSILON.sqrt();
+    let Some(first) = planes.first() else {
+        return (0..DIM).map(|i| Vector::ith(i, 1.0)).collect();
+    };
+
+    #[cfg(feature = "dim2")]
+    {
+        let dir = first.0.orthonormal_basis()[0];
+        if planes.iter().all(|p| p.0.dot(&dir).abs() <= angular_eps) {
+            vec![dir]
+        } else {
+            vec![]
+        }
+    }
+
+    #[cfg(feature = "dim3")]
+    {
+        // Find the most orthogonal pair of normals.
+        let mut best_cross = Vector::zeros();
+        for (i, p1) in planes.iter().enumerate() {
+            for p2 in &planes[i + 1..] {
+                let cross = p1.0.cross(&p2.0);
+                if cross.norm_squared() > best_cross.norm_squared() {
+                    best_cross = cross;
+                }
+            }
+        }
+
+        if best_cross.norm() <= angular_eps {
+            // All the normals are parallel.
+            first.0.orthonormal_basis().to_vec()
+        } else {
+            let dir = best_cross.normalize();
+            if planes.iter().all(|p| p.0.dot(&dir).abs() <= angular_eps) {
+                vec![dir]
+            } else {
+                vec![]
+            }
+        }
+    }
+}
+
+/// Finds a point strictly inside of the convex hull of `vertices`, or returns `None` if this
+/// convex hull is flat, i.e., if its vertices all lie on the same hyperplane (up to
+/// `tolerance`).
+fn interior_point(vertices: &[Point<Real>], tolerance: Real) -> Option<Point<Real>> {
+    let farthest = |dist: &dyn Fn(&Point<Real>) -> Real| {
+        vertices
+            .iter()
+            .map(|pt| (dist(pt), *pt))
+            .max_by(|a, b| a.0.total_cmp(&b.0))
+            .filter(|(max_dist, _)| *max_dist > tolerance)
+            .map(|(_, pt)| pt)
+    };
+
+    let v0 = *vertices.first()?;
+    let v1 = farthest(&|pt| na::distance(&v0, pt))?;
+    let dir1 = (v1 - v0).normalize();
+    let dist_to_line = |pt: &Point<Real>| {
+        let dpt = pt - v0;
+        (dpt - dir1 * dpt.dot(&dir1)).norm()
+    };
+    let v2 = farthest(&dist_to_line)?;
+
+    // The center of a simplex with vertices far from the hyperplanes of the others.
+    #[cfg(feature = "dim2")]
+    return Some(Point::from((v0.coords + v1.coords + v2.coords) / 3.0));
+
+    #[cfg(feature = "dim3")]
+    {
+        let normal = dir1.cross(&(v2 - v0)).normalize();
+        let v3 = farthest(&|pt| (pt - v0).dot(&normal).abs())?;
+        Some(Point::from(
+            (v0.coords + v1.coords + v2.coords + v3.coords) / 4.0,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{half_spaces_intersection_points, HalfSpacesIntersectionError};
+    use crate::math::{Real, Vector};
+    use alloc::vec::Vec;
+
+    /// The half-spaces bounding the unit cube (the unit square in 2D) centered at the origin.
+    fn unit_cube() -> Vec<(Vector<Real>, Real)> {
+        (0..crate::math::DIM)
+            .flat_map(|i| {
+                let axis = Vector::ith(i, 1.0);
+                [(axis, 0.5), (-axis, 0.5)]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn half_spaces_intersection_with_redundant_planes() {
+        let mut half_spaces = unit_cube();
+        // A duplicate plane with a non-unit normal.
+        half_spaces.push((Vector::x() * 2.0, 1.0));
+        // A plane that doesn't touch the cube.
+        half_spaces.push((Vector::repeat(1.0), 10.0));
+        // A plane almost parallel to one of the cube's faces.
+        let mut near_parallel = Vector::x();
+        near_parallel[1] = 1.0e-10;
+        half_spaces.push((near_parallel, 0.5));
+
+        let points = half_spaces_intersection_points(&half_spaces).unwrap();
+        assert_eq!(points.len(), 1 << crate::math::DIM);
+        for pt in &points {
+            assert!(pt.iter().all(|x| (x.abs() - 0.5).abs() < 1.0e-5));
+        }
+    }
+
+    #[test]
+    fn half_spaces_intersection_errors() {
+        // Two disjoint half-spaces.
+        let disjoint = [(Vector::x(), -1.0), (-Vector::x(), -1.0)];
+        assert_eq!(
+            half_spaces_intersection_points(&disjoint),
+            Err(HalfSpacesIntersectionError::Empty)
+        );
+        let mut disjoint_cube = unit_cube();
+        disjoint_cube.push((Vector::repeat(-1.0), -10.0));
+        assert_eq!(
+            half_spaces_intersection_points(&disjoint_cube),
+            Err(HalfSpacesIntersectionError::Empty)
+        );
+        assert_eq!(
+            half_spaces_intersection_points(&[(Vector::zeros(), -1.0)]),
+            Err(HalfSpacesIntersectionError::Empty)
+        );
+
+        // A slab.
+        let slab = [(Vector::x(), 1.0), (-Vector::x(), 1.0)];
+        assert_eq!(
+            half_spaces_intersection_points(&slab),
+            Err(HalfSpacesIntersectionError::Unbounded)
+        );
+        assert_eq!(
+            half_spaces_intersection_points(&[]),
+            Err(HalfSpacesIntersectionError::Unbounded)
+        );
+
+        // A cube with one missing face, and a corner.
+        let open_cube = &unit_cube()[1..];
+        assert_eq!(
+            half_spaces_intersection_points(open_cube),
+            Err(HalfSpacesIntersectionError::Unbounded)
+        );
+        let corner: Vec<_> = (0..crate::math::DIM)
+            .map(|i| (Vector::ith(i, 1.0), 0.0))
+            .collect();
+        assert_eq!(
+            half_spaces_intersection_points(&corner),
+            Err(HalfSpacesIntersectionError::Unbounded)
+        );
+    }
+
+    #[test]
+    fn convex_shape_from_half_spaces() {
+        // A cube cut by a plane through four of its vertices, i.e., a triangular prism.
+        let mut half_spaces = unit_cube();
+        let mut cut = Vector::zeros();
+        cut[0] = 1.0;
+        cut[1] = 1.0;
+        half_spaces.push((cut, 0.0));
+
+        #[cfg(feature = "dim2")]
+        {
+            let triangle = crate::shape::ConvexPolygon::from_half_spaces(&half_spaces).unwrap();
+            assert_eq!(triangle.points().len(), 3);
+            assert_eq!(triangle.normals().len(), 3);
+        }
+
+        #[cfg(feature = "dim3")]
+        {
+            let prism = crate::shape::ConvexPolyhedron::from_half_spaces(&half_spaces).unwrap();
+            assert_eq!(prism.points().len(), 6);
+            assert_eq!(prism.faces().len(), 5);
+        }
+
+        // A pyramid, with four planes through its apex.
+        #[cfg(feature = "dim3")]
+        {
+            let pyramid = [
+                (-Vector::z(), 0.0),
+                (Vector::new(1.0, 0.0, 1.0), 1.0),
+                (Vector::new(-1.0, 0.0, 1.0), 1.0),
+                (Vector::new(0.0, 1.0, 1.0), 1.0),
+                (Vector::new(0.0, -1.0, 1.0), 1.0),
+            ];
+            let pyramid = crate::shape::ConvexPolyhedron::from_half_spaces(&pyramid).unwrap();
+            assert_eq!(pyramid.points().len(), 5);
+            assert_eq!(pyramid.faces().len(), 5);
+        }
+
+        // A flat region.
+        let mut flat = unit_cube();
+        flat.push((-Vector::x(), -0.5));
+
+        #[cfg(feature = "dim2")]
+        let result = crate::shape::ConvexPolygon::from_half_spaces(&flat).map(|_| ());
+        #[cfg(feature = "dim3")]
+        let result = crate::shape::ConvexPolyhedron::from_half_spaces(&flat).map(|_| ());
+        assert_eq!(result, Err(HalfSpacesIntersectionError::Degenerate));
+    }
+
+    #[test]
+    fn convex_shape_from_many_half_spaces() {
+        // Planes tangent to the unit sphere (the unit circle in 2D), none of them redundant.
+        let n = 200;
+        let normals: Vec<Vector<Real>> = (0..n)
+            .map(|i| {
+                #[cfg(feature = "dim2")]
+                {
+                    let angle = i as Real * <Real as na::RealField>::two_pi() / n as Real;
+                    Vector::new(angle.cos(), angle.sin())
+                }
+                #[cfg(feature = "dim3")]
+                {
+                    // Fibonacci sphere.
+                    let z = 1.0 - (2 * i + 1) as Real / n as Real;
+                    let angle = i as Real * 2.399_963;
+                    let r = (1.0 - z * z).sqrt();
+                    Vector::new(r * angle.cos(), r * angle.sin(), z)
+                }
+            })
+            .collect();
+        let half_spaces: Vec<_> = normals.iter().map(|n| (*n * 2.0, 2.0)).collect();
+        let is_input_normal =
+            |normal: &Vector<Real>| normals.iter().any(|n| (normal - n).norm() < 1.0e-6);
+
+        #[cfg(feature = "dim2")]
+        {
+            let polygon = crate::shape::ConvexPolygon::from_half_spaces(&half_spaces).unwrap();
+            assert_eq!(polygon.points().len(), n);
+            // The edges keep the normals of the half-planes, in counterclockwise order.
+            let edges = polygon.normals();
+            for (i, normal) in edges.iter().enumerate() {
+                assert!(is_input_normal(normal));
+                assert!(normal.perp(&*edges[(i + 1) % n]) > 0.0);
+            }
+        }
+
+        #[cfg(feature = "dim3")]
+        {
+            let polyhedron =
+                crate::shape::ConvexPolyhedron::from_half_spaces(&half_spaces).unwrap();
+            let points = polyhedron.points();
+            let face_vertices = polyhedron.vertices_adj_to_face();
+            assert_eq!(polyhedron.faces().len(), n);
+
+            // Each face keeps the normal of its half-space, and its vertices are counterclockwise
+            // around it.
+            for face in polyhedron.faces() {
+                assert!(is_input_normal(&face.normal));
+                let first = face.first_vertex_or_edge as usize;
+                let [a, b, c] = [0, 1, 2].map(|k| points[face_vertices[first + k] as usize]);
+                assert!(face.normal.dot(&(b - a).cross(&(c - a))) > 0.0);
+            }
+            for pt in points {
+                assert!(normals.iter().all(|n| n.dot(&pt.coords) < 1.0 + 1.0e-3));
+            }
+        }
+    }
+}
@@ -2,7 +2,7 @@ use super::{MeshIntersectionError, TriangleTriangleIntersection};
 use crate::math::{Isometry, Real};
 use crate::query::point::point_query::PointQueryWithLocation;
 use crate::query::{visitors::BoundingVolumeIntersectionsSimultaneousVisitor, PointQuery};
-use crate::shape::{TriMesh, Triangle};
+use crate::shape::{TriMesh, TriMeshFlags, Triangle};
 use crate::utils;
 use crate::utils::hashmap::Entry;
 use crate::utils::hashmap::HashMap;
@@ -67,7 +67,9 @@ impl Default for MeshIntersectionTolerances {
 /// Computes the intersection of two meshes.
 ///
 /// The meshes must be oriented, have their half-edge topology computed, and must not be self-intersecting.
-/// The result mesh vertex coordinates are given in the local-space of `mesh1`.
+/// The result mesh vertex coordinates are given in the local-space of `mesh1`. If any of the meshes is
+/// tagged, each triangle of the result mesh is given the tag of the triangle it is part of (see
+/// [`TriMesh::triangle_tag`]).
 pub fn intersect_meshes(
     pos1: &Isometry<Real>,
     mesh1: &TriMesh,
@@ -149,6 +151,7 @@ pub fn intersect_meshes_with_tolerances(
     }
 
     // 3: Grab all triangles that are inside the other mesh but do not intersect it.
+    //    Only their face ids are collected, so they can be mapped to their indices and tags.
     extract_connected_components(
         &pos12,
         mesh1,
@@ -179,7 +182,8 @@ pub fn intersect_meshes_with_tolerances(
             ) as u32
         };
         // Add the inside vertices and triangles from mesh1
-        for mut face in new_indices1 {
+        for fid in new_indices1 {
+            let mut face = mesh1.indices()[fid as usize];
             if flip1 {
                 face.swap(0, 1);
             }
@@ -191,12 +195,13 @@ pub fn intersect_meshes_with_tolerances(
             ];
 
             if !is_topologically_degenerate(idx) {
-                insert_topology_indices(&mut topology_indices, idx);
+                insert_topology_indices(&mut topology_indices, idx, mesh1.triangle_tag(fid));
             }
         }
 
         // Add the inside vertices and triangles from mesh2
-        for mut face in new_indices2 {
+        for fid in new_indices2 {
+            let mut face = mesh2.indices()[fid as usize];
             if flip2 {
                 face.swap(0, 1);
             }
@@ -207,7 +212,7 @@ pub fn intersect_meshes_with_tolerances(
             ];
 
             if !is_topologically_degenerate(idx) {
-                insert_topology_indices(&mut topology_indices, idx);
+                insert_topology_indices(&mut topology_indices, idx, mesh2.triangle_tag(fid));
             }
         }
     }
@@ -283,9 +288,17 @@ pub fn intersect_meshes_with_tolerances(
     let vertices: Vec<_> = vertices.iter().map(|p| Point3::from(p.point)).collect();
 
     if !topology_indices.is_empty() {
-        Ok(Some(TriMesh::new(
+        let (indices, mut tags): (Vec<_>, Vec<_>) = topology_indices.into_values().unzip();
+
+        if mesh1.tags().is_empty() && mesh2.tags().is_empty() {
+            tags.clear();
+        }
+
+        Ok(Some(TriMesh::with_flags_and_tags(
             vertices,
-            topology_indices.into_values().collect(),
+            indices,
+            TriMeshFlags::empty(),
+            tags,
         )?))
     } else {
         Ok(None)
@@ -298,7 +311,7 @@ fn extract_connected_components(
     mesh2: &TriMesh,
     flip2: bool,
     deleted_faces1: &HashSet<u32>,
-    new_indices1: &mut Vec<[u32; 3]>,
+    new_indices1: &mut Vec<u32>,
 ) {
     let topo1 = mesh1.topology().unwrap();
     let mut visited: HashSet<u32> = HashSet::default();
@@ -341,7 +354,7 @@ fn extract_connected_components(
             continue; // Already visited.
         }
 
-        new_indices1.push(mesh1.indices()[face as usize]);
+        new_indices1.push(face);
 
         let eid = topo1.faces[face as usize].half_edge;
         let edge_a = &topo1.half_edges[eid as usize];
@@ -370,14 +383,9 @@ fn extract_connected_components(
                 // if the whole thing is inside or outside.
                 let repr_face = cc.grouped_faces[range[0]];
                 let repr_pt = mesh1.triangle(repr_face).center();
-                let indices = mesh1.indices();
 
                 if flip2 ^ mesh2.contains_local_point(&pos12.inverse_transform_point(&repr_pt)) {
-                    new_indices1.extend(
-                        cc.grouped_faces[range[0]..range[1]]
-                            .iter()
-                            .map(|fid| indices[*fid as usize]),
-                    )
+                    new_indices1.extend_from_slice(&cc.grouped_faces[range[0]..range[1]])
                 }
             }
         }
@@ -386,7 +394,7 @@ fn extract_connected_components(
         let repr_pt = mesh1.triangle(0).center();
 
         if flip2 ^ mesh2.contains_local_point(&pos12.inverse_transform_point(&repr_pt)) {
-            new_indices1.extend_from_slice(mesh1.indices());
+            new_indices1.extend(0..mesh1.indices().len() as u32);
         }
     }
 }
@@ -627,7 +635,7 @@ fn merge_triangle_sets(
     flip2: bool,
     metadata: &MeshIntersectionTolerances,
     point_set: &mut RTree<TreePoint>,
-    topology_indices: &mut HashMap<HashableTriangleIndices, [u32; 3]>,
+    topology_indices: &mut HashMap<HashableTriangleIndices, ([u32; 3], u32)>,
 ) -> Result<(), MeshIntersectionError> {
     // For each triangle, and each constraint edge associated to that triangle,
     // make a triangulation of the face and sort whether each generated
@@ -690,7 +698,11 @@ fn merge_triangle_sets(
                     return Err(MeshIntersectionError::DuplicateVertices);
                 }
 
-                insert_topology_indices(topology_indices, new_tri_idx);
+                insert_topology_indices(
+                    topology_indices,
+                    new_tri_idx,
+                    mesh1.triangle_tag(**triangle_id),
+                );
             }
         }
     }
@@ -704,12 +716,13 @@ fn merge_triangle_sets(
 // set of indices but opposite orientations. If this happens, both the new triangle, and the one it
 // matched with are removed (because they describe a degenerate piece of volume).
 fn insert_topology_indices(
-    topology_indices: &mut HashMap<HashableTriangleIndices, [u32; 3]>,
+    topology_indices: &mut HashMap<HashableTriangleIndices, ([u32; 3], u32)>,
     new_tri_idx: [u32; 3],
+    tag: u32,
 ) {
     match topology_indices.entry(new_tri_idx.into()) {
         Entry::Vacant(e) => {
-            let _ = e.insert(new_tri_idx);
+            let _ = e.insert((new_tri_idx, tag));
         }
         Entry::Occupied(e) => {
             fn same_orientation(a: &[u32; 3], b: &[u32; 3]) -> bool {
@@ -723,7 +736,7 @@ fn insert_topology_indices(
                 a[1] == b[(ib + 1) % 3]
             }
 
-            if !same_orientation(e.get(), &new_tri_idx) {
+            if !same_orientation(&e.get().0, &new_tri_idx) {
                 // If we are inserting two identical triangles but with mismatching
                 // orientations, we can just ignore both because they cover a degenerate
                 // 2D plane.
@@ -8,6 +8,10 @@ pub use self::convex_hull2::{convex_hull2 as convex_hull, convex_hull2_idx as co
 pub use self::convex_hull3::check_convex_hull;
 #[cfg(feature = "dim3")]
 pub use self::convex_hull3::{convex_hull, try_convex_hull, ConvexHullError};
+pub(crate) use self::half_spaces_intersection::half_spaces_intersection;
+pub use self::half_spaces_intersection::{
+    half_spaces_intersection_points, HalfSpacesIntersectionError,
+};
 #[cfg(all(feature = "dim3", feature = "spade"))]
 pub use self::mesh_intersection::{
     intersect_meshes, intersect_meshes_with_tolerances, MeshIntersectionError,
@@ -24,6 +28,7 @@ mod convex_hull2;
 #[cfg(feature = "dim3")]
 mod convex_hull3;
 pub(crate) mod convex_hull_utils;
+mod half_spaces_intersection;
 
 mod polygon_intersection;
 /// Approximate convex decomposition using the VHACD algorithm.
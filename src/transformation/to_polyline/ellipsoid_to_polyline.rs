@@ -0,0 +1,12 @@
+use crate::math::Real;
+use crate::shape::{Ball, Ellipsoid};
+use crate::transformation::utils;
+use alloc::vec::Vec;
+use na::Point2;
+
+impl Ellipsoid {
+    /// Discretize the boundary of this ellipse as a polygonal line.
+    pub fn to_polyline(&self, nsubdivs: u32) -> Vec<Point2<Real>> {
+        utils::scaled(Ball::new(1.0).to_polyline(nsubdivs), self.radii)
+    }
+}
@@ -0,0 +1,56 @@
+use crate::math::{Isometry, Real};
+use crate::shape::{Ball, TaperedCapsule};
+use crate::transformation::utils;
+use alloc::vec::Vec;
+use na::{self, ComplexField, Point2, RealField};
+
+impl TaperedCapsule {
+    /// Discretize the boundary of this tapered capsule as a polygonal line.
+    ///
+    /// Each ball contributes to the boundary with an arc subdivided proportionally to its angle,
+    /// with `nsubdiv` subdivisions for an angle of `π`.
+    pub fn to_polyline(&self, nsubdiv: u32) -> Vec<Point2<Real>> {
+        if self.is_degenerate() {
+            // The tapered capsule is just its biggest ball.
+            let (center, radius) = if self.radius_a >= self.radius_b {
+                (self.segment.a, self.radius_a)
+            } else {
+                (self.segment.b, self.radius_b)
+            };
+            let pos = Isometry::new(center.coords, 0.0);
+            return utils::transformed(Ball::new(radius).to_polyline(nsubdiv), pos);
+        }
+
+        let half_height = self.half_height();
+        let (sin, cos) = self.slope();
+        // The angle, from the `-Y` axis, of the normal of the lateral boundary on the `+X` side.
+        let phi_side = RealField::atan2(cos, -sin);
+
+        let mut points = Vec::new();
+        let mut push_arc = |center_y: Real, radius: Real, start: Real, end: Real| {
+            let n = ((nsubdiv as Real * (end - start) / Real::pi()).round() as u32).max(1);
+            for i in 0..=n {
+                let phi = start + (end - start) * (i as Real) / (n as Real);
+                points.push(Point2::new(
+                    radius * ComplexField::sin(phi),
+                    center_y - radius * ComplexField::cos(phi),
+                ));
+            }
+        };
+
+        push_arc(
+            half_height,
+            self.radius_b,
+            phi_side,
+            Real::two_pi() - phi_side,
+        );
+        push_arc(
+            -half_height,
+            self.radius_a,
+            Real::two_pi() - phi_side,
+            Real::two_pi() + phi_side,
+        );
+
+        utils::transformed(points, self.canonical_transform())
+    }
+}
@@ -25,7 +25,7 @@ impl Voxels {
         let aabb = Aabb::from_half_extents(Point::origin(), radius);
         let vtx = aabb.vertices();
 
-        for vox in self.voxels() {
+        for vox in self.filled_voxels() {
             match vox.state.voxel_type() {
                 VoxelType::Vertex => {
                     let mask = vox.state.feature_mask();
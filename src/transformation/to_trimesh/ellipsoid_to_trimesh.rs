@@ -0,0 +1,17 @@
+use crate::math::Real;
+use crate::shape::{Ball, Ellipsoid};
+use crate::transformation::utils;
+use alloc::vec::Vec;
+use na::Point3;
+
+impl Ellipsoid {
+    /// Discretize the boundary of this ellipsoid as a triangle-mesh.
+    pub fn to_trimesh(
+        &self,
+        ntheta_subdiv: u32,
+        nphi_subdiv: u32,
+    ) -> (Vec<Point3<Real>>, Vec<[u32; 3]>) {
+        let (vtx, idx) = Ball::new(1.0).to_trimesh(ntheta_subdiv, nphi_subdiv);
+        (utils::scaled(vtx, self.radii), idx)
+    }
+}
@@ -10,6 +10,12 @@ mod cuboid_to_trimesh;
 #[cfg(feature = "dim3")]
 mod cylinder_to_trimesh;
 #[cfg(feature = "dim3")]
+mod ellipsoid_to_trimesh;
+#[cfg(feature = "dim3")]
 mod heightfield_to_trimesh;
 #[cfg(feature = "dim3")]
+mod tapered_capsule_to_trimesh;
+#[cfg(feature = "dim3")]
+mod torus_to_trimesh;
+#[cfg(feature = "dim3")]
 mod voxels_to_trimesh;
@@ -0,0 +1,86 @@
+use crate::math::{Isometry, Real};
+use crate::shape::{Ball, TaperedCapsule};
+use crate::transformation::utils;
+use alloc::vec::Vec;
+use na::{self, ComplexField, Point3, RealField};
+
+impl TaperedCapsule {
+    /// Discretize the boundary of this tapered capsule as a triangle-mesh.
+    ///
+    /// The `nphi_subdiv` subdivisions along the meridians are distributed between the two
+    /// balls proportionally to the angle of their arc lying on the boundary.
+    pub fn to_trimesh(
+        &self,
+        ntheta_subdiv: u32,
+        nphi_subdiv: u32,
+    ) -> (Vec<Point3<Real>>, Vec<[u32; 3]>) {
+        if self.is_degenerate() {
+            // The tapered capsule is just its biggest ball.
+            let (center, radius) = if self.radius_a >= self.radius_b {
+                (self.segment.a, self.radius_a)
+            } else {
+                (self.segment.b, self.radius_b)
+            };
+            let (vtx, idx) = Ball::new(radius).to_trimesh(ntheta_subdiv, nphi_subdiv);
+            let pos = Isometry::new(center.coords, na::zero());
+            return (utils::transformed(vtx, pos), idx);
+        }
+
+        let dtheta = Real::two_pi() / (ntheta_subdiv as Real);
+        let half_height = self.half_height();
+        let (sin, cos) = self.slope();
+        // The angle, from the `-Y` axis, of the normal of the lateral boundary.
+        let phi_side = RealField::atan2(cos, -sin);
+        let nphi_a = ((nphi_subdiv as Real * phi_side / Real::pi()).round() as u32).max(1);
+        let nphi_b = nphi_subdiv.saturating_sub(nphi_a).max(1);
+
+        let mut coords = Vec::new();
+        coords.push(Point3::new(0.0, -half_height - self.radius_a, 0.0));
+
+        for i in 1..=nphi_a {
+            let phi = phi_side * (i as Real) / (nphi_a as Real);
+            utils::push_circle(
+                self.radius_a * ComplexField::sin(phi),
+                ntheta_subdiv,
+                dtheta,
+                -half_height - self.radius_a * ComplexField::cos(phi),
+                &mut coords,
+            );
+        }
+
+        for i in 0..nphi_b {
+            let phi = phi_side + (Real::pi() - phi_side) * (i as Real) / (nphi_b as Real);
+            utils::push_circle(
+                self.radius_b * ComplexField::sin(phi),
+                ntheta_subdiv,
+                dtheta,
+                half_height - self.radius_b * ComplexField::cos(phi),
+                &mut coords,
+            );
+        }
+
+        coords.push(Point3::new(0.0, half_height + self.radius_b, 0.0));
+
+        let mut idx = Vec::new();
+        utils::push_degenerate_top_ring_indices(1, 0, ntheta_subdiv, &mut idx);
+        utils::reverse_clockwising(&mut idx);
+
+        for i in 0..nphi_a + nphi_b - 1 {
+            utils::push_ring_indices(
+                1 + i * ntheta_subdiv,
+                1 + (i + 1) * ntheta_subdiv,
+                ntheta_subdiv,
+                &mut idx,
+            );
+        }
+
+        utils::push_degenerate_top_ring_indices(
+            coords.len() as u32 - 1 - ntheta_subdiv,
+            coords.len() as u32 - 1,
+            ntheta_subdiv,
+            &mut idx,
+        );
+
+        (utils::transformed(coords, self.canonical_transform()), idx)
+    }
+}
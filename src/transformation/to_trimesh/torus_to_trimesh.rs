@@ -0,0 +1,48 @@
+use crate::math::Real;
+use crate::shape::Torus;
+use crate::transformation::utils;
+use alloc::vec::Vec;
+use na::{self, ComplexField, Point3, RealField};
+
+impl Torus {
+    /// Discretize the boundary of this torus as a triangle-mesh.
+    ///
+    /// # Arguments:
+    /// * `nmajor_subdiv` - the number of subdivisions along the torus’ core circle.
+    /// * `nminor_subdiv` - the number of subdivisions along the torus’ tube cross-section.
+    pub fn to_trimesh(
+        &self,
+        nmajor_subdiv: u32,
+        nminor_subdiv: u32,
+    ) -> (Vec<Point3<Real>>, Vec<[u32; 3]>) {
+        let dtheta = Real::two_pi() / (nmajor_subdiv as Real);
+        let dphi = Real::two_pi() / (nminor_subdiv as Real);
+        let mut coords = Vec::new();
+        let mut indices = Vec::new();
+        let mut curr_phi: Real = 0.0;
+
+        // One circle per subdivision of the tube’s cross-section.
+        for _ in 0..nminor_subdiv {
+            utils::push_circle(
+                self.major_radius + self.minor_radius * ComplexField::cos(curr_phi),
+                nmajor_subdiv,
+                dtheta,
+                self.minor_radius * ComplexField::sin(curr_phi),
+                &mut coords,
+            );
+            curr_phi += dphi;
+        }
+
+        for i in 0..nminor_subdiv {
+            let next = (i + 1) % nminor_subdiv;
+            utils::push_ring_indices(
+                i * nmajor_subdiv,
+                next * nmajor_subdiv,
+                nmajor_subdiv,
+                &mut indices,
+            );
+        }
+
+        (coords, indices)
+    }
+}
@@ -14,7 +14,7 @@ impl Voxels {
 
         let mut vtx = vec![];
         let mut idx = vec![];
-        for vox in self.voxels() {
+        for vox in self.filled_voxels() {
             let mask = vox.state.free_faces();
             for i in 0..6 {
                 if mask.bits() & (1 << i) != 0 {